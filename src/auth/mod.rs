@@ -9,6 +9,8 @@
 //! - [`Session`]: Represents an authenticated session for API calls
 //! - [`AssociatedUser`]: User information for online (user-specific) sessions
 //! - [`oauth`]: OAuth 2.0 authorization code flow implementation
+//! - [`embedded`]: Session token authentication for embedded app requests
+//! - [`SessionStore`]: Pluggable session persistence, with [`InMemorySessionStore`]
 //!
 //! # Session Types
 //!
@@ -54,10 +56,13 @@
 //! ```
 
 mod associated_user;
+pub mod embedded;
 pub mod oauth;
 mod scopes;
 pub mod session;
+mod session_store;
 
 pub use associated_user::AssociatedUser;
 pub use scopes::AuthScopes;
-pub use session::Session;
+pub use session::{ScopeDrift, Session, SessionBuilder};
+pub use session_store::{InMemorySessionStore, SessionStore};
@@ -58,9 +58,10 @@
 //! This implementation matches the Ruby SDK's `ShopifyAPI::Auth::ClientCredentials`:
 //! - File: `lib/shopify_sdk/auth/client_credentials.rb`
 
-use crate::auth::oauth::OAuthError;
+use crate::auth::oauth::error::{request_id_header, OAuthError};
 use crate::auth::session::AccessTokenResponse;
 use crate::auth::Session;
+use crate::clients::build_reqwest_client;
 use crate::config::{ShopDomain, ShopifyConfig};
 use serde::Serialize;
 
@@ -127,7 +128,7 @@ pub async fn exchange_client_credentials(
     };
 
     // Step 4: Send the POST request
-    let client = reqwest::Client::new();
+    let client = build_reqwest_client(Some(config));
     let response = client
         .post(&token_url)
         .json(&request_body)
@@ -136,16 +137,23 @@ pub async fn exchange_client_credentials(
         .map_err(|e| OAuthError::ClientCredentialsFailed {
             status: 0,
             message: format!("Network error: {e}"),
+            request_id: None,
         })?;
 
     let status = response.status().as_u16();
+    let request_id = request_id_header(response.headers());
 
     // Step 5: Handle error responses
     if !response.status().is_success() {
         let error_body = response.text().await.unwrap_or_default();
+        if let Some(error) = OAuthError::from_token_endpoint_error(&error_body, request_id.clone())
+        {
+            return Err(error);
+        }
         return Err(OAuthError::ClientCredentialsFailed {
             status,
             message: error_body,
+            request_id,
         });
     }
 
@@ -157,6 +165,7 @@ pub async fn exchange_client_credentials(
             .map_err(|e| OAuthError::ClientCredentialsFailed {
                 status,
                 message: format!("Failed to parse token response: {e}"),
+                request_id,
             })?;
 
     // Step 7: Create and return the session
@@ -269,7 +278,9 @@ mod tests {
         let result = exchange_client_credentials(&config, &shop).await;
 
         match result {
-            Err(OAuthError::ClientCredentialsFailed { status, message }) => {
+            Err(OAuthError::ClientCredentialsFailed {
+                status, message, ..
+            }) => {
                 // Network error results in status 0, HTTP errors have status >= 400
                 assert!(status == 0 || status >= 400);
                 assert!(!message.is_empty());
@@ -11,11 +11,17 @@
 //! - [`OAuthError::TokenExchangeFailed`]: Token exchange request failed
 //! - [`OAuthError::ClientCredentialsFailed`]: Client credentials exchange request failed
 //! - [`OAuthError::TokenRefreshFailed`]: Token refresh or migration request failed
+//! - [`OAuthError::InvalidRequest`]: Token endpoint returned `invalid_request`
+//! - [`OAuthError::InvalidGrant`]: Token endpoint returned `invalid_grant`
+//! - [`OAuthError::InvalidSubjectToken`]: Token endpoint returned `invalid_subject_token`
+//! - [`OAuthError::UnsupportedGrantType`]: Token endpoint returned `unsupported_grant_type`
 //! - [`OAuthError::InvalidCallback`]: Callback parameters are malformed
+//! - [`OAuthError::InsufficientScopes`]: Merchant granted fewer scopes than required
 //! - [`OAuthError::MissingHostConfig`]: Host URL not configured for redirect URI
 //! - [`OAuthError::InvalidJwt`]: JWT validation failed (for token exchange)
 //! - [`OAuthError::NotEmbeddedApp`]: Token exchange requires embedded app configuration
 //! - [`OAuthError::NotPrivateApp`]: Client credentials requires non-embedded app configuration
+//! - [`OAuthError::SessionNotFound`]: No session is stored under the expected ID
 //! - [`OAuthError::HttpError`]: Wrapped HTTP client error
 //!
 //! # Example
@@ -40,17 +46,21 @@
 //! let error = OAuthError::ClientCredentialsFailed {
 //!     status: 401,
 //!     message: "Invalid credentials".to_string(),
+//!     request_id: None,
 //! };
 //! assert!(error.to_string().contains("401"));
 //!
 //! let error = OAuthError::TokenRefreshFailed {
 //!     status: 400,
 //!     message: "Invalid refresh token".to_string(),
+//!     request_id: None,
 //! };
 //! assert!(error.to_string().contains("400"));
 //! ```
 
+use crate::auth::AuthScopes;
 use crate::clients::HttpError;
+use serde::Deserialize;
 use thiserror::Error;
 
 /// Errors that can occur during OAuth operations.
@@ -76,18 +86,33 @@ use thiserror::Error;
 ///         OAuthError::StateMismatch { expected, received } => {
 ///             eprintln!("CSRF: State mismatch - expected {}, got {}", expected, received);
 ///         }
-///         OAuthError::TokenExchangeFailed { status, message } => {
+///         OAuthError::TokenExchangeFailed { status, message, .. } => {
 ///             eprintln!("Token exchange failed ({}): {}", status, message);
 ///         }
-///         OAuthError::ClientCredentialsFailed { status, message } => {
+///         OAuthError::ClientCredentialsFailed { status, message, .. } => {
 ///             eprintln!("Client credentials failed ({}): {}", status, message);
 ///         }
-///         OAuthError::TokenRefreshFailed { status, message } => {
+///         OAuthError::TokenRefreshFailed { status, message, .. } => {
 ///             eprintln!("Token refresh failed ({}): {}", status, message);
 ///         }
+///         OAuthError::InvalidRequest { description, .. } => {
+///             eprintln!("Malformed token request: {}", description);
+///         }
+///         OAuthError::InvalidGrant { description, .. } => {
+///             eprintln!("Grant rejected, re-authorize: {}", description);
+///         }
+///         OAuthError::InvalidSubjectToken { description, .. } => {
+///             eprintln!("Session token rejected: {}", description);
+///         }
+///         OAuthError::UnsupportedGrantType { description, .. } => {
+///             eprintln!("Grant type not supported: {}", description);
+///         }
 ///         OAuthError::InvalidCallback { reason } => {
 ///             eprintln!("Invalid callback: {}", reason);
 ///         }
+///         OAuthError::InsufficientScopes { requested, granted } => {
+///             eprintln!("Requested {}, but only {} was granted", requested, granted);
+///         }
 ///         OAuthError::MissingHostConfig => {
 ///             eprintln!("Configuration error: Host URL not configured");
 ///         }
@@ -100,6 +125,9 @@ use thiserror::Error;
 ///         OAuthError::NotPrivateApp => {
 ///             eprintln!("Client credentials only works for private apps");
 ///         }
+///         OAuthError::SessionNotFound { session_id } => {
+///             eprintln!("No stored session {}", session_id);
+///         }
 ///         OAuthError::HttpError(e) => {
 ///             eprintln!("HTTP error: {}", e);
 ///         }
@@ -139,6 +167,8 @@ pub enum OAuthError {
         status: u16,
         /// The error message from the response.
         message: String,
+        /// The `X-Request-Id` of the response (`None` for network errors).
+        request_id: Option<String>,
     },
 
     /// Client credentials exchange request failed.
@@ -155,6 +185,7 @@ pub enum OAuthError {
     /// let error = OAuthError::ClientCredentialsFailed {
     ///     status: 401,
     ///     message: "Invalid client credentials".to_string(),
+    ///     request_id: None,
     /// };
     /// assert!(error.to_string().contains("Client credentials"));
     /// assert!(error.to_string().contains("401"));
@@ -165,6 +196,8 @@ pub enum OAuthError {
         status: u16,
         /// The error message from the response or network error description.
         message: String,
+        /// The `X-Request-Id` of the response (`None` for network errors).
+        request_id: Option<String>,
     },
 
     /// Token refresh or migration request failed.
@@ -181,6 +214,7 @@ pub enum OAuthError {
     /// let error = OAuthError::TokenRefreshFailed {
     ///     status: 400,
     ///     message: "Invalid refresh token".to_string(),
+    ///     request_id: None,
     /// };
     /// assert!(error.to_string().contains("Token refresh"));
     /// assert!(error.to_string().contains("400"));
@@ -191,6 +225,70 @@ pub enum OAuthError {
         status: u16,
         /// The error message from the response or network error description.
         message: String,
+        /// The `X-Request-Id` of the response (`None` for network errors).
+        request_id: Option<String>,
+    },
+
+    /// The token endpoint returned `invalid_request`.
+    ///
+    /// The request was missing a required parameter or was otherwise
+    /// malformed.
+    #[error("Invalid token request: {description}")]
+    InvalidRequest {
+        /// The `error_description` from Shopify, or the error code if absent.
+        description: String,
+        /// The `X-Request-Id` of the response.
+        request_id: Option<String>,
+    },
+
+    /// The token endpoint returned `invalid_grant`.
+    ///
+    /// The authorization code, refresh token, or other grant is invalid,
+    /// expired, revoked, or was issued to another client. The merchant
+    /// usually has to go through OAuth again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::auth::oauth::OAuthError;
+    ///
+    /// let error = OAuthError::InvalidGrant {
+    ///     description: "refresh token has expired".to_string(),
+    ///     request_id: None,
+    /// };
+    /// assert!(error.to_string().contains("refresh token has expired"));
+    /// ```
+    #[error("Invalid grant: {description}")]
+    InvalidGrant {
+        /// The `error_description` from Shopify, or the error code if absent.
+        description: String,
+        /// The `X-Request-Id` of the response.
+        request_id: Option<String>,
+    },
+
+    /// The token endpoint returned `invalid_subject_token`.
+    ///
+    /// Shopify rejected the session token sent for token exchange, even
+    /// though it passed local JWT validation (for example, because it was
+    /// issued for another app or has already expired on Shopify's side).
+    #[error("Invalid subject token: {description}")]
+    InvalidSubjectToken {
+        /// The `error_description` from Shopify, or the error code if absent.
+        description: String,
+        /// The `X-Request-Id` of the response.
+        request_id: Option<String>,
+    },
+
+    /// The token endpoint returned `unsupported_grant_type`.
+    ///
+    /// The app is not allowed to use the requested grant type (for example,
+    /// client credentials for an app that is not a custom app).
+    #[error("Unsupported grant type: {description}")]
+    UnsupportedGrantType {
+        /// The `error_description` from Shopify, or the error code if absent.
+        description: String,
+        /// The `X-Request-Id` of the response.
+        request_id: Option<String>,
     },
 
     /// Callback parameters are invalid or malformed.
@@ -203,6 +301,32 @@ pub enum OAuthError {
         reason: String,
     },
 
+    /// The merchant granted fewer scopes than the app requires.
+    ///
+    /// Returned by `validate_auth_callback()` when the scopes in the token
+    /// response don't cover the scopes configured in `ShopifyConfig`, for
+    /// example because the merchant unchecked optional access on the grant
+    /// screen. The app should send the merchant through OAuth again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::auth::oauth::OAuthError;
+    ///
+    /// let error = OAuthError::InsufficientScopes {
+    ///     requested: "read_products,write_orders".parse().unwrap(),
+    ///     granted: "read_products".parse().unwrap(),
+    /// };
+    /// assert!(error.to_string().contains("write_orders"));
+    /// ```
+    #[error("Insufficient scopes: requested '{requested}', granted '{granted}'")]
+    InsufficientScopes {
+        /// The scopes configured for the app.
+        requested: AuthScopes,
+        /// The scopes the merchant actually granted.
+        granted: AuthScopes,
+    },
+
     /// Host URL is not configured in `ShopifyConfig`.
     ///
     /// The `begin_auth()` function requires a host URL to construct the
@@ -275,6 +399,29 @@ pub enum OAuthError {
     #[error("Client credentials requires a non-embedded app configuration")]
     NotPrivateApp,
 
+    /// No session is stored under the expected ID.
+    ///
+    /// Returned by helpers that read a session from a
+    /// [`SessionStore`](crate::auth::SessionStore), such as
+    /// [`migrate_and_store`](super::migrate_and_store), when the shop has no
+    /// stored session to work from.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::auth::oauth::OAuthError;
+    ///
+    /// let error = OAuthError::SessionNotFound {
+    ///     session_id: "offline_my-store.myshopify.com".to_string(),
+    /// };
+    /// assert!(error.to_string().contains("offline_my-store.myshopify.com"));
+    /// ```
+    #[error("No session stored with ID '{session_id}'")]
+    SessionNotFound {
+        /// The session ID that was looked up.
+        session_id: String,
+    },
+
     /// Wrapped HTTP client error.
     ///
     /// An error occurred during HTTP communication, such as a network failure
@@ -283,6 +430,92 @@ pub enum OAuthError {
     HttpError(#[from] HttpError),
 }
 
+/// An OAuth error response body (RFC 6749, section 5.2).
+#[derive(Debug, Deserialize)]
+struct TokenEndpointErrorResponse {
+    error: String,
+    error_description: Option<String>,
+}
+
+impl OAuthError {
+    /// Maps a documented OAuth error returned by the token endpoint to its
+    /// specific variant.
+    ///
+    /// Returns `None` if the body is not an OAuth error response or the error
+    /// code is not one with a dedicated variant, so callers can fall back to
+    /// their flow-specific error.
+    pub(crate) fn from_token_endpoint_error(
+        body: &str,
+        request_id: Option<String>,
+    ) -> Option<Self> {
+        let response: TokenEndpointErrorResponse = serde_json::from_str(body).ok()?;
+        let description = response
+            .error_description
+            .filter(|d| !d.is_empty())
+            .unwrap_or_else(|| response.error.clone());
+
+        match response.error.as_str() {
+            "invalid_request" => Some(Self::InvalidRequest {
+                description,
+                request_id,
+            }),
+            "invalid_grant" => Some(Self::InvalidGrant {
+                description,
+                request_id,
+            }),
+            "invalid_subject_token" => Some(Self::InvalidSubjectToken {
+                description,
+                request_id,
+            }),
+            "unsupported_grant_type" => Some(Self::UnsupportedGrantType {
+                description,
+                request_id,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Returns the `X-Request-Id` of the failed response, if any.
+    ///
+    /// Set for errors built from a token endpoint response and for wrapped
+    /// HTTP errors. Include it when reporting an issue to Shopify support.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::auth::oauth::OAuthError;
+    ///
+    /// let error = OAuthError::TokenExchangeFailed {
+    ///     status: 500,
+    ///     message: "Internal Server Error".to_string(),
+    ///     request_id: Some("abc-123".to_string()),
+    /// };
+    /// assert_eq!(error.request_id(), Some("abc-123"));
+    /// ```
+    #[must_use]
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Self::TokenExchangeFailed { request_id, .. }
+            | Self::ClientCredentialsFailed { request_id, .. }
+            | Self::TokenRefreshFailed { request_id, .. }
+            | Self::InvalidRequest { request_id, .. }
+            | Self::InvalidGrant { request_id, .. }
+            | Self::InvalidSubjectToken { request_id, .. }
+            | Self::UnsupportedGrantType { request_id, .. } => request_id.as_deref(),
+            Self::HttpError(e) => e.request_id(),
+            _ => None,
+        }
+    }
+}
+
+/// Reads the `X-Request-Id` header of a token endpoint response.
+pub(super) fn request_id_header(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    headers
+        .get("x-request-id")
+        .and_then(|value| value.to_str().ok())
+        .map(String::from)
+}
+
 // Verify OAuthError is Send + Sync at compile time
 const _: fn() = || {
     const fn assert_send_sync<T: Send + Sync>() {}
@@ -318,6 +551,7 @@ mod tests {
         let error = OAuthError::TokenExchangeFailed {
             status: 401,
             message: "Invalid client credentials".to_string(),
+            request_id: None,
         };
         let message = error.to_string();
         assert!(message.contains("401"));
@@ -352,6 +586,7 @@ mod tests {
         let error: &dyn std::error::Error = &OAuthError::TokenExchangeFailed {
             status: 400,
             message: "test".to_string(),
+            request_id: None,
         };
         let _ = error;
 
@@ -374,6 +609,7 @@ mod tests {
         let error: &dyn std::error::Error = &OAuthError::ClientCredentialsFailed {
             status: 401,
             message: "test".to_string(),
+            request_id: None,
         };
         let _ = error;
 
@@ -383,6 +619,7 @@ mod tests {
         let error: &dyn std::error::Error = &OAuthError::TokenRefreshFailed {
             status: 400,
             message: "test".to_string(),
+            request_id: None,
         };
         let _ = error;
     }
@@ -395,6 +632,17 @@ mod tests {
         assert!(error.to_string().contains("Shop domain is invalid"));
     }
 
+    #[test]
+    fn test_insufficient_scopes_includes_requested_and_granted() {
+        let error = OAuthError::InsufficientScopes {
+            requested: "write_orders".parse().unwrap(),
+            granted: "read_products".parse().unwrap(),
+        };
+        let message = error.to_string();
+        assert!(message.contains("write_orders"));
+        assert!(message.contains("read_products"));
+    }
+
     #[test]
     fn test_missing_host_config_message() {
         let error = OAuthError::MissingHostConfig;
@@ -487,6 +735,7 @@ mod tests {
         let error = OAuthError::ClientCredentialsFailed {
             status: 401,
             message: "Invalid client credentials".to_string(),
+            request_id: None,
         };
         let message = error.to_string();
         assert!(message.contains("Client credentials"));
@@ -508,6 +757,7 @@ mod tests {
         let client_creds_error: &dyn std::error::Error = &OAuthError::ClientCredentialsFailed {
             status: 500,
             message: "Server error".to_string(),
+            request_id: None,
         };
         assert!(client_creds_error
             .to_string()
@@ -529,6 +779,7 @@ mod tests {
         let client_creds_failed = OAuthError::ClientCredentialsFailed {
             status: 401,
             message: "test".to_string(),
+            request_id: None,
         };
         let not_private = OAuthError::NotPrivateApp;
 
@@ -553,6 +804,7 @@ mod tests {
         let error = OAuthError::TokenRefreshFailed {
             status: 400,
             message: "Invalid refresh token".to_string(),
+            request_id: None,
         };
         let message = error.to_string();
         assert!(message.contains("Token refresh"));
@@ -565,6 +817,7 @@ mod tests {
         let error = OAuthError::TokenRefreshFailed {
             status: 0,
             message: "Network error: connection refused".to_string(),
+            request_id: None,
         };
         let message = error.to_string();
         assert!(message.contains("Token refresh"));
@@ -577,6 +830,7 @@ mod tests {
         let error: &dyn std::error::Error = &OAuthError::TokenRefreshFailed {
             status: 401,
             message: "Unauthorized".to_string(),
+            request_id: None,
         };
         assert!(error.to_string().contains("Token refresh"));
     }
@@ -589,6 +843,7 @@ mod tests {
         let token_refresh_failed = OAuthError::TokenRefreshFailed {
             status: 400,
             message: "test".to_string(),
+            request_id: None,
         };
 
         std::thread::spawn(move || {
@@ -597,4 +852,89 @@ mod tests {
         .join()
         .unwrap();
     }
+
+    #[test]
+    fn test_token_endpoint_invalid_request_maps_to_variant() {
+        let error = OAuthError::from_token_endpoint_error(
+            r#"{"error":"invalid_request","error_description":"Missing client_id"}"#,
+            None,
+        );
+
+        assert!(matches!(
+            error,
+            Some(OAuthError::InvalidRequest { ref description, .. }) if description == "Missing client_id"
+        ));
+    }
+
+    #[test]
+    fn test_token_endpoint_invalid_grant_maps_to_variant() {
+        let error = OAuthError::from_token_endpoint_error(
+            r#"{"error":"invalid_grant","error_description":"The refresh token is invalid or expired"}"#,
+            None,
+        );
+
+        assert!(matches!(error, Some(OAuthError::InvalidGrant { .. })));
+    }
+
+    #[test]
+    fn test_token_endpoint_invalid_subject_token_maps_to_variant() {
+        let error =
+            OAuthError::from_token_endpoint_error(r#"{"error":"invalid_subject_token"}"#, None);
+
+        // Falls back to the error code when there is no description
+        assert!(matches!(
+            error,
+            Some(OAuthError::InvalidSubjectToken { ref description, .. }) if description == "invalid_subject_token"
+        ));
+    }
+
+    #[test]
+    fn test_token_endpoint_unsupported_grant_type_maps_to_variant() {
+        let error = OAuthError::from_token_endpoint_error(
+            r#"{"error":"unsupported_grant_type","error_description":"Client credentials are not enabled"}"#,
+            None,
+        );
+
+        assert!(matches!(
+            error,
+            Some(OAuthError::UnsupportedGrantType { .. })
+        ));
+    }
+
+    #[test]
+    fn test_token_endpoint_unknown_or_non_json_errors_are_not_mapped() {
+        assert!(
+            OAuthError::from_token_endpoint_error(r#"{"error":"server_error"}"#, None).is_none()
+        );
+        assert!(OAuthError::from_token_endpoint_error("Bad Gateway", None).is_none());
+        assert!(OAuthError::from_token_endpoint_error(r#"{"errors":"Not Found"}"#, None).is_none());
+    }
+
+    #[test]
+    fn test_request_id_header_is_read_from_response_headers() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("x-request-id", "abc-123".parse().unwrap());
+
+        assert_eq!(request_id_header(&headers).as_deref(), Some("abc-123"));
+        assert_eq!(request_id_header(&reqwest::header::HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn test_request_id_accessor() {
+        let error = OAuthError::TokenRefreshFailed {
+            status: 400,
+            message: "Bad Request".to_string(),
+            request_id: Some("req-1".to_string()),
+        };
+        assert_eq!(error.request_id(), Some("req-1"));
+
+        let error = OAuthError::HttpError(HttpError::Response(HttpResponseError {
+            code: 502,
+            message: "Bad Gateway".to_string(),
+            error_reference: Some("req-2".to_string()),
+        }));
+        assert_eq!(error.request_id(), Some("req-2"));
+
+        assert_eq!(OAuthError::InvalidHmac.request_id(), None);
+    }
 }
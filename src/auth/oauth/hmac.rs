@@ -196,7 +196,7 @@ pub fn validate_hmac(query: &AuthQuery, config: &ShopifyConfig) -> bool {
 }
 
 // Internal hex encoding since we don't want to add another dependency
-mod hex {
+pub(crate) mod hex {
     const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
 
     pub fn encode(bytes: impl AsRef<[u8]>) -> String {
@@ -47,8 +47,10 @@ const JWT_LEEWAY_SECS: u64 = 10;
 /// JWT payload for Shopify session tokens.
 ///
 /// This struct represents the decoded claims from a Shopify App Bridge session token.
-/// It is used internally during token exchange to validate the session token before
-/// exchanging it for an access token.
+/// It is used during token exchange to validate the session token before
+/// exchanging it for an access token, and is exposed as
+/// [`SessionTokenClaims`](crate::auth::embedded::SessionTokenClaims) for
+/// authenticating embedded app requests.
 ///
 /// # Thread Safety
 ///
@@ -170,7 +172,6 @@ impl JwtPayload {
     ///
     /// If `dest` is `https://my-store.myshopify.com`, this returns `my-store.myshopify.com`.
     #[must_use]
-    #[allow(dead_code)] // Part of internal API, used in tests
     pub fn shop(&self) -> &str {
         self.dest
             .strip_prefix("https://")
@@ -194,7 +195,6 @@ impl JwtPayload {
     /// }
     /// ```
     #[must_use]
-    #[allow(dead_code)] // Part of internal API, used in tests
     pub fn shopify_user_id(&self) -> Option<u64> {
         // Only return user ID if this is an admin session token
         if !self.is_admin_session_token() {
@@ -16,6 +16,8 @@
 //!
 //! 2. **Callback Validation** ([`validate_auth_callback`]): When the user is
 //!    redirected back, validate the callback and exchange the code for an access token.
+//!    Use [`validate_auth_callback_with_store`] with a [`StateStore`] to make each
+//!    state single-use.
 //!
 //! # Token Exchange (for Embedded Apps)
 //!
@@ -43,6 +45,7 @@
 //!
 //! - [`refresh_access_token`]: Refresh an expiring access token using a refresh token
 //! - [`migrate_to_expiring_token`]: One-time migration from non-expiring to expiring tokens
+//! - [`migrate_and_store`]: Migrates a shop's stored session once and saves the result
 //!
 //! Expiring tokens provide enhanced security by requiring periodic token rotation.
 //!
@@ -213,8 +216,9 @@ mod begin_auth;
 mod client_credentials;
 mod error;
 pub mod hmac;
-mod jwt_payload;
+pub(crate) mod jwt_payload;
 mod state;
+mod state_store;
 mod token_exchange;
 mod token_refresh;
 mod validate_callback;
@@ -225,9 +229,10 @@ pub use client_credentials::exchange_client_credentials;
 pub use error::OAuthError;
 pub use hmac::{compute_signature, constant_time_compare, validate_hmac};
 pub use state::StateParam;
+pub use state_store::{InMemoryStateStore, StateStore};
 pub use token_exchange::{exchange_offline_token, exchange_online_token};
-pub use token_refresh::{migrate_to_expiring_token, refresh_access_token};
-pub use validate_callback::validate_auth_callback;
+pub use token_refresh::{migrate_and_store, migrate_to_expiring_token, refresh_access_token};
+pub use validate_callback::{validate_auth_callback, validate_auth_callback_with_store};
 
 #[cfg(test)]
 mod tests {
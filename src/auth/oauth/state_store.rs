@@ -0,0 +1,187 @@
+//! Server-side storage of OAuth state parameters.
+//!
+//! [`begin_auth`](super::begin_auth) returns a state parameter that must come
+//! back unchanged in the callback. Comparing it with a value kept in the
+//! user's cookie stops cross-site request forgery, but the same state can be
+//! replayed until the cookie expires.
+//!
+//! A [`StateStore`] keeps issued states on the server and lets each one be
+//! used once: [`validate_auth_callback_with_store`](super::validate_auth_callback_with_store)
+//! consumes the callback's state and rejects it if it was never issued, has
+//! expired, or was already used.
+//!
+//! [`InMemoryStateStore`] works for a single process; apps running several
+//! instances should implement [`StateStore`] over a shared store such as Redis.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use std::time::Duration;
+//! use shopify_sdk::auth::oauth::{
+//!     begin_auth, validate_auth_callback_with_store, InMemoryStateStore, StateStore,
+//! };
+//!
+//! let store = InMemoryStateStore::new();
+//!
+//! // When starting OAuth
+//! let result = begin_auth(&config, &shop, "/auth/callback", true, None)?;
+//! store.store(result.state.as_ref(), Duration::from_secs(600)).await;
+//!
+//! // In the callback handler
+//! let session = validate_auth_callback_with_store(&config, &query, &store).await?;
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::webhooks::BoxFuture;
+
+/// Keeps issued OAuth states until they are used or expire.
+///
+/// Methods return boxed futures so implementations can be backed by
+/// asynchronous stores.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashSet;
+/// use std::sync::Mutex;
+/// use std::time::Duration;
+/// use shopify_sdk::auth::oauth::StateStore;
+/// use shopify_sdk::webhooks::BoxFuture;
+///
+/// #[derive(Default)]
+/// struct NeverExpiringStore {
+///     states: Mutex<HashSet<String>>,
+/// }
+///
+/// impl StateStore for NeverExpiringStore {
+///     fn store<'a>(&'a self, state: &'a str, _ttl: Duration) -> BoxFuture<'a, ()> {
+///         Box::pin(async move {
+///             self.states.lock().unwrap().insert(state.to_string());
+///         })
+///     }
+///
+///     fn consume<'a>(&'a self, state: &'a str) -> BoxFuture<'a, bool> {
+///         Box::pin(async move { self.states.lock().unwrap().remove(state) })
+///     }
+/// }
+/// ```
+pub trait StateStore: Send + Sync {
+    /// Records an issued state that stays valid for `ttl`.
+    fn store<'a>(&'a self, state: &'a str, ttl: Duration) -> BoxFuture<'a, ()>;
+
+    /// Removes a state, returning `true` if it was stored and had not expired.
+    ///
+    /// A state can only be consumed once.
+    fn consume<'a>(&'a self, state: &'a str) -> BoxFuture<'a, bool>;
+}
+
+/// An in-memory [`StateStore`] with per-state expiry.
+///
+/// Expired states are dropped whenever a new state is stored. A `ttl` too
+/// large to add to the current time never expires.
+#[derive(Debug, Default)]
+pub struct InMemoryStateStore {
+    /// State to the instant it expires, or `None` if it never does.
+    states: Mutex<HashMap<String, Option<Instant>>>,
+}
+
+impl InMemoryStateStore {
+    /// Creates an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of states currently held, including expired ones
+    /// not yet dropped.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns `true` if no states are held.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Locks the states, recovering them if a previous holder panicked.
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Option<Instant>>> {
+        self.states
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl StateStore for InMemoryStateStore {
+    fn store<'a>(&'a self, state: &'a str, ttl: Duration) -> BoxFuture<'a, ()> {
+        let now = Instant::now();
+        {
+            let mut states = self.lock();
+            states.retain(|_, expires_at| expires_at.map_or(true, |at| at > now));
+            states.insert(state.to_string(), now.checked_add(ttl));
+        }
+        Box::pin(async {})
+    }
+
+    fn consume<'a>(&'a self, state: &'a str) -> BoxFuture<'a, bool> {
+        let valid = self
+            .lock()
+            .remove(state)
+            .is_some_and(|expires_at| expires_at.map_or(true, |at| at > Instant::now()));
+        Box::pin(async move { valid })
+    }
+}
+
+// Verify InMemoryStateStore is Send + Sync at compile time
+const _: fn() = || {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<InMemoryStateStore>();
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_state_can_only_be_consumed_once() {
+        let store = InMemoryStateStore::new();
+        store.store("abc123", Duration::from_secs(600)).await;
+
+        assert!(store.consume("abc123").await);
+        assert!(!store.consume("abc123").await);
+        assert!(store.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_and_expired_states_are_rejected() {
+        let store = InMemoryStateStore::new();
+        store.store("expired", Duration::ZERO).await;
+
+        assert!(!store.consume("never-issued").await);
+        assert!(!store.consume("expired").await);
+    }
+
+    #[tokio::test]
+    async fn test_storing_drops_expired_states() {
+        let store = InMemoryStateStore::new();
+        store.store("expired", Duration::ZERO).await;
+        store.store("fresh", Duration::from_secs(600)).await;
+
+        assert_eq!(store.len(), 1);
+        assert!(store.consume("fresh").await);
+    }
+
+    #[tokio::test]
+    async fn test_unrepresentable_ttl_never_expires() {
+        let store = InMemoryStateStore::new();
+        store.store("expired", Duration::ZERO).await;
+        store.store("forever", Duration::MAX).await;
+
+        assert_eq!(store.len(), 1);
+        assert!(store.consume("forever").await);
+    }
+}
@@ -11,6 +11,7 @@
 //!
 //! - [`refresh_access_token`]: Refresh an expiring access token using a refresh token
 //! - [`migrate_to_expiring_token`]: One-time migration from non-expiring to expiring tokens
+//! - [`migrate_and_store`]: Migrates the stored offline session of a shop, at most once
 //!
 //! # Token Refresh Flow
 //!
@@ -66,9 +67,10 @@
 //! - `ShopifyAPI::Auth::TokenExchange.migrate_to_expiring_token`
 
 use super::token_exchange::RequestedTokenType;
-use crate::auth::oauth::OAuthError;
+use crate::auth::oauth::error::{request_id_header, OAuthError};
 use crate::auth::session::AccessTokenResponse;
-use crate::auth::Session;
+use crate::auth::{Session, SessionStore};
+use crate::clients::build_reqwest_client;
 use crate::config::{ShopDomain, ShopifyConfig};
 use serde::Serialize;
 
@@ -150,7 +152,7 @@ pub async fn refresh_access_token(
     };
 
     // Send the POST request
-    let client = reqwest::Client::new();
+    let client = build_reqwest_client(Some(config));
     let response = client
         .post(&token_url)
         .json(&request_body)
@@ -159,16 +161,23 @@ pub async fn refresh_access_token(
         .map_err(|e| OAuthError::TokenRefreshFailed {
             status: 0,
             message: format!("Network error: {e}"),
+            request_id: None,
         })?;
 
     let status = response.status().as_u16();
+    let request_id = request_id_header(response.headers());
 
     // Handle error responses
     if !response.status().is_success() {
         let error_body = response.text().await.unwrap_or_default();
+        if let Some(error) = OAuthError::from_token_endpoint_error(&error_body, request_id.clone())
+        {
+            return Err(error);
+        }
         return Err(OAuthError::TokenRefreshFailed {
             status,
             message: error_body,
+            request_id,
         });
     }
 
@@ -180,6 +189,7 @@ pub async fn refresh_access_token(
             .map_err(|e| OAuthError::TokenRefreshFailed {
                 status,
                 message: format!("Failed to parse token response: {e}"),
+                request_id,
             })?;
 
     // Create and return the session
@@ -253,7 +263,7 @@ pub async fn migrate_to_expiring_token(
     };
 
     // Send the POST request
-    let client = reqwest::Client::new();
+    let client = build_reqwest_client(Some(config));
     let response = client
         .post(&token_url)
         .json(&request_body)
@@ -262,16 +272,23 @@ pub async fn migrate_to_expiring_token(
         .map_err(|e| OAuthError::TokenRefreshFailed {
             status: 0,
             message: format!("Network error: {e}"),
+            request_id: None,
         })?;
 
     let status = response.status().as_u16();
+    let request_id = request_id_header(response.headers());
 
     // Handle error responses
     if !response.status().is_success() {
         let error_body = response.text().await.unwrap_or_default();
+        if let Some(error) = OAuthError::from_token_endpoint_error(&error_body, request_id.clone())
+        {
+            return Err(error);
+        }
         return Err(OAuthError::TokenRefreshFailed {
             status,
             message: error_body,
+            request_id,
         });
     }
 
@@ -283,6 +300,7 @@ pub async fn migrate_to_expiring_token(
             .map_err(|e| OAuthError::TokenRefreshFailed {
                 status,
                 message: format!("Failed to parse token response: {e}"),
+                request_id,
             })?;
 
     // Create and return the session
@@ -291,6 +309,73 @@ pub async fn migrate_to_expiring_token(
     Ok(session)
 }
 
+/// Migrates a shop's stored offline session to an expiring token and saves
+/// the result.
+///
+/// Loads the shop's offline session from `store`. If it already has a
+/// refresh token, it was migrated before and is returned unchanged without
+/// contacting Shopify. Otherwise its access token is migrated with
+/// [`migrate_to_expiring_token`] and the new session is stored under the
+/// same ID before being returned.
+///
+/// Checking the store first makes the helper safe to call on every startup
+/// or request: the irreversible migration happens once per shop. Concurrent
+/// calls for the same shop can still both migrate, so serialize them if
+/// that can happen in your app.
+///
+/// # Errors
+///
+/// - [`OAuthError::SessionNotFound`] if no offline session is stored for the shop
+/// - [`OAuthError::TokenRefreshFailed`] if the migration request fails
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use shopify_sdk::auth::oauth::migrate_and_store;
+///
+/// let session = migrate_and_store(&config, &shop, &session_store).await?;
+/// assert!(session.refresh_token.is_some());
+/// ```
+pub async fn migrate_and_store(
+    config: &ShopifyConfig,
+    shop: &ShopDomain,
+    store: &dyn SessionStore,
+) -> Result<Session, OAuthError> {
+    migrate_stored_session(shop, store, |access_token| async move {
+        migrate_to_expiring_token(config, shop, &access_token).await
+    })
+    .await
+}
+
+/// Loads the shop's offline session and, unless it already has a refresh
+/// token, replaces it in `store` with the session returned by `migrate`.
+async fn migrate_stored_session<F, Fut>(
+    shop: &ShopDomain,
+    store: &dyn SessionStore,
+    migrate: F,
+) -> Result<Session, OAuthError>
+where
+    F: FnOnce(String) -> Fut,
+    Fut: std::future::Future<Output = Result<Session, OAuthError>>,
+{
+    let session_id = Session::generate_offline_id(shop);
+    let session = store
+        .load(&session_id)
+        .await
+        .ok_or_else(|| OAuthError::SessionNotFound {
+            session_id: session_id.clone(),
+        })?;
+
+    if session.refresh_token.is_some() {
+        return Ok(session);
+    }
+
+    let migrated = migrate(session.access_token).await?;
+    store.store(&migrated).await;
+
+    Ok(migrated)
+}
+
 // Verify types are Send + Sync at compile time
 const _: fn() = || {
     const fn assert_send_sync<T: Send + Sync>() {}
@@ -379,7 +464,9 @@ mod tests {
 
         // Should fail with TokenRefreshFailed error (network or HTTP error)
         match result {
-            Err(OAuthError::TokenRefreshFailed { status, message }) => {
+            Err(OAuthError::TokenRefreshFailed {
+                status, message, ..
+            }) => {
                 // Network errors have status 0, HTTP errors have status >= 400
                 assert!(status == 0 || status >= 400);
                 assert!(!message.is_empty());
@@ -412,7 +499,9 @@ mod tests {
 
         // Should fail with TokenRefreshFailed error (network or HTTP error)
         match result {
-            Err(OAuthError::TokenRefreshFailed { status, message }) => {
+            Err(OAuthError::TokenRefreshFailed {
+                status, message, ..
+            }) => {
                 // Network errors have status 0, HTTP errors have status >= 400
                 assert!(status == 0 || status >= 400);
                 assert!(!message.is_empty());
@@ -432,6 +521,94 @@ mod tests {
         assert!(matches!(result, Err(OAuthError::TokenRefreshFailed { .. })));
     }
 
+    // === migrate_and_store tests ===
+
+    #[tokio::test]
+    async fn test_migrate_and_store_returns_already_migrated_session() {
+        use crate::auth::{AuthScopes, InMemorySessionStore};
+
+        let config = create_config();
+        let shop = ShopDomain::new("test-shop").unwrap();
+        let expiring = Session::builder(
+            Session::generate_offline_id(&shop),
+            shop.clone(),
+            "expiring-access-token".to_string(),
+        )
+        .scopes(AuthScopes::new())
+        .offline()
+        .refresh_token("refresh-token")
+        .build();
+        let store = InMemorySessionStore::new();
+        store.store(&expiring).await;
+
+        // A migration request would fail, since the shop doesn't exist
+        let session = migrate_and_store(&config, &shop, &store).await.unwrap();
+
+        assert_eq!(session, expiring);
+        assert_eq!(store.load(&expiring.id).await, Some(expiring));
+    }
+
+    #[tokio::test]
+    async fn test_migrate_and_store_replaces_stored_session_with_migrated_one() {
+        use crate::auth::{AuthScopes, InMemorySessionStore};
+
+        let shop = ShopDomain::new("test-shop").unwrap();
+        let legacy = Session::builder(
+            Session::generate_offline_id(&shop),
+            shop.clone(),
+            "legacy-access-token".to_string(),
+        )
+        .scopes(AuthScopes::new())
+        .offline()
+        .build();
+        let store = InMemorySessionStore::new();
+        store.store(&legacy).await;
+
+        let migrated = Session::from_access_token_response(
+            shop.clone(),
+            &AccessTokenResponse {
+                access_token: "expiring-access-token".to_string(),
+                scope: "read_products".to_string(),
+                expires_in: Some(3600),
+                associated_user_scope: None,
+                associated_user: None,
+                session: None,
+                refresh_token: Some("refresh-token".to_string()),
+                refresh_token_expires_in: Some(7_776_000),
+            },
+        );
+
+        let session = migrate_stored_session(&shop, &store, |access_token| {
+            assert_eq!(access_token, "legacy-access-token");
+            let migrated = migrated.clone();
+            async move { Ok(migrated) }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(session, migrated);
+        let stored = store.load(&legacy.id).await.unwrap();
+        assert_eq!(stored.access_token, "expiring-access-token");
+        assert_eq!(stored.refresh_token.as_deref(), Some("refresh-token"));
+    }
+
+    #[tokio::test]
+    async fn test_migrate_and_store_requires_stored_session() {
+        use crate::auth::InMemorySessionStore;
+
+        let config = create_config();
+        let shop = ShopDomain::new("test-shop").unwrap();
+
+        let result = migrate_and_store(&config, &shop, &InMemorySessionStore::new()).await;
+
+        match result {
+            Err(OAuthError::SessionNotFound { session_id }) => {
+                assert_eq!(session_id, Session::generate_offline_id(&shop));
+            }
+            other => panic!("Expected SessionNotFound error, got {other:?}"),
+        }
+    }
+
     // === Send + Sync tests ===
 
     #[test]
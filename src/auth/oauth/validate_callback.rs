@@ -1,7 +1,9 @@
 //! OAuth callback validation and token exchange.
 //!
 //! This module provides the [`validate_auth_callback`] function for validating
-//! OAuth callbacks from Shopify and exchanging authorization codes for access tokens.
+//! OAuth callbacks from Shopify and exchanging authorization codes for access tokens,
+//! and [`validate_auth_callback_with_store`] for apps that keep issued states in a
+//! [`StateStore`].
 //!
 //! # Overview
 //!
@@ -11,7 +13,8 @@
 //! 1. Validates the HMAC signature to ensure the request is from Shopify
 //! 2. Verifies the state parameter matches to prevent CSRF attacks
 //! 3. Exchanges the authorization code for an access token
-//! 4. Returns a [`Session`] ready for API calls
+//! 4. Checks the merchant granted every scope the app requires
+//! 5. Returns a [`Session`] ready for API calls
 //!
 //! # Example
 //!
@@ -30,11 +33,12 @@
 //! // session is now ready for API calls
 //! ```
 
-use crate::auth::oauth::error::OAuthError;
+use crate::auth::oauth::error::{request_id_header, OAuthError};
 use crate::auth::oauth::hmac::{constant_time_compare, validate_hmac};
-use crate::auth::oauth::AuthQuery;
+use crate::auth::oauth::{AuthQuery, StateStore};
 use crate::auth::session::AccessTokenResponse;
-use crate::auth::Session;
+use crate::auth::{AuthScopes, Session};
+use crate::clients::build_reqwest_client;
 use crate::config::{ShopDomain, ShopifyConfig};
 
 /// Request body for token exchange.
@@ -56,7 +60,9 @@ struct TokenExchangeRequest<'a> {
 /// 3. **Shop Validation**: Parses and validates the shop domain
 /// 4. **Token Exchange**: POSTs to Shopify's token endpoint to exchange the
 ///    authorization code for an access token
-/// 5. **Session Creation**: Returns a [`Session`] configured with the new token
+/// 5. **Scope Check**: Verifies the granted scopes cover the scopes
+///    configured in [`ShopifyConfig`]
+/// 6. **Session Creation**: Returns a [`Session`] configured with the new token
 ///
 /// # Arguments
 ///
@@ -74,6 +80,8 @@ struct TokenExchangeRequest<'a> {
 /// - [`OAuthError::StateMismatch`]: State parameter doesn't match expected
 /// - [`OAuthError::InvalidCallback`]: Shop domain is invalid
 /// - [`OAuthError::TokenExchangeFailed`]: Token exchange request failed
+/// - [`OAuthError::InsufficientScopes`]: The merchant granted fewer scopes
+///   than the app requires
 /// - [`OAuthError::HttpError`]: Network error during token exchange
 ///
 /// # Security Notes
@@ -121,6 +129,57 @@ pub async fn validate_auth_callback(
         });
     }
 
+    exchange_code(config, auth_query).await
+}
+
+/// Validates an OAuth callback against a [`StateStore`] and exchanges the
+/// code for an access token.
+///
+/// Works like [`validate_auth_callback`], but instead of comparing the state
+/// with a value supplied by the caller, it consumes the state from `store`.
+/// A state that was never stored, has expired, or was already used is
+/// rejected, so each authorization can only complete once.
+///
+/// The HMAC signature is checked before the state is consumed, so forged
+/// callbacks can't use up legitimate states.
+///
+/// # Errors
+///
+/// - [`OAuthError::InvalidHmac`]: HMAC signature validation failed
+/// - [`OAuthError::StateMismatch`]: The state is unknown, expired or already used
+/// - Any other error returned by [`validate_auth_callback`]
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use shopify_sdk::auth::oauth::{validate_auth_callback_with_store, InMemoryStateStore};
+///
+/// let session = validate_auth_callback_with_store(&config, &query, &state_store).await?;
+/// ```
+pub async fn validate_auth_callback_with_store<S: StateStore + ?Sized>(
+    config: &ShopifyConfig,
+    auth_query: &AuthQuery,
+    store: &S,
+) -> Result<Session, OAuthError> {
+    if !validate_hmac(auth_query, config) {
+        return Err(OAuthError::InvalidHmac);
+    }
+
+    if !store.consume(&auth_query.state).await {
+        return Err(OAuthError::StateMismatch {
+            expected: "a stored, unused state".to_string(),
+            received: auth_query.state.clone(),
+        });
+    }
+
+    exchange_code(config, auth_query).await
+}
+
+/// Exchanges the authorization code of a validated callback for a session.
+async fn exchange_code(
+    config: &ShopifyConfig,
+    auth_query: &AuthQuery,
+) -> Result<Session, OAuthError> {
     // Step 3: Parse and validate shop domain
     let shop = ShopDomain::new(&auth_query.shop).map_err(|_| OAuthError::InvalidCallback {
         reason: format!("Invalid shop domain: {}", auth_query.shop),
@@ -135,8 +194,8 @@ pub async fn validate_auth_callback(
         code: &auth_query.code,
     };
 
-    // Use reqwest directly since this is an unauthenticated request
-    let client = reqwest::Client::new();
+    // Unauthenticated request, sent without following redirects
+    let client = build_reqwest_client(Some(config));
     let response = client
         .post(&token_url)
         .json(&request_body)
@@ -145,15 +204,22 @@ pub async fn validate_auth_callback(
         .map_err(|e| OAuthError::TokenExchangeFailed {
             status: 0,
             message: format!("Network error: {e}"),
+            request_id: None,
         })?;
 
     let status = response.status().as_u16();
+    let request_id = request_id_header(response.headers());
 
     if !response.status().is_success() {
         let error_body = response.text().await.unwrap_or_default();
+        if let Some(error) = OAuthError::from_token_endpoint_error(&error_body, request_id.clone())
+        {
+            return Err(error);
+        }
         return Err(OAuthError::TokenExchangeFailed {
             status,
             message: error_body,
+            request_id,
         });
     }
 
@@ -165,14 +231,32 @@ pub async fn validate_auth_callback(
             .map_err(|e| OAuthError::TokenExchangeFailed {
                 status,
                 message: format!("Failed to parse token response: {e}"),
+                request_id,
             })?;
 
-    // Step 6: Create and return session
+    // Step 6: Create session and verify the granted scopes
     let session = Session::from_access_token_response(shop, &token_response);
+    check_granted_scopes(config.scopes(), &session.scopes)?;
 
     Ok(session)
 }
 
+/// Checks that the granted scopes cover the required ones.
+///
+/// Shopify normalizes the scopes it grants, so the sets are compared in
+/// their canonical form: a granted `write_products` satisfies a required
+/// `read_products`, and differences in case or order don't matter.
+fn check_granted_scopes(required: &AuthScopes, granted: &AuthScopes) -> Result<(), OAuthError> {
+    if granted.covers(required) {
+        Ok(())
+    } else {
+        Err(OAuthError::InsufficientScopes {
+            requested: required.clone(),
+            granted: granted.clone(),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -320,6 +404,63 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_validate_auth_callback_with_store_rejects_reused_state() {
+        use crate::auth::oauth::InMemoryStateStore;
+        use std::time::Duration;
+
+        let config = create_test_config();
+        let query = create_valid_auth_query("test-secret");
+        let store = InMemoryStateStore::new();
+        store.store("test-state", Duration::from_secs(600)).await;
+
+        // The first use gets past the state check and fails at token exchange
+        let first = validate_auth_callback_with_store(&config, &query, &store).await;
+        assert!(matches!(first, Err(OAuthError::TokenExchangeFailed { .. })));
+
+        let second = validate_auth_callback_with_store(&config, &query, &store).await;
+        assert!(matches!(second, Err(OAuthError::StateMismatch { .. })));
+    }
+
+    #[test]
+    fn test_reduced_scope_token_response_is_rejected() {
+        let required: AuthScopes = "read_products,write_orders".parse().unwrap();
+        let token_response: AccessTokenResponse = serde_json::from_value(serde_json::json!({
+            "access_token": "new-access-token",
+            "scope": "read_products"
+        }))
+        .unwrap();
+        let shop = ShopDomain::new("test-shop.myshopify.com").unwrap();
+        let session = Session::from_access_token_response(shop, &token_response);
+
+        match check_granted_scopes(&required, &session.scopes) {
+            Err(OAuthError::InsufficientScopes { requested, granted }) => {
+                assert_eq!(requested, required);
+                assert!(granted.iter().any(|scope| scope == "read_products"));
+                assert!(!granted.iter().any(|scope| scope == "write_orders"));
+            }
+            other => panic!("Expected InsufficientScopes error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_granted_scopes_satisfy_implied_requirements() {
+        let required: AuthScopes = "read_products,read_orders".parse().unwrap();
+        let granted: AuthScopes = "read_products,write_orders".parse().unwrap();
+
+        assert!(check_granted_scopes(&required, &granted).is_ok());
+        assert!(check_granted_scopes(&AuthScopes::new(), &granted).is_ok());
+    }
+
+    #[test]
+    fn test_normalized_granted_scopes_satisfy_configured_scopes() {
+        let required: AuthScopes = "read_products,write_products,read_orders".parse().unwrap();
+        let granted: AuthScopes = "READ_ORDERS,WRITE_PRODUCTS".parse().unwrap();
+
+        assert_eq!(required.canonical_string(), granted.canonical_string());
+        assert!(check_granted_scopes(&required, &granted).is_ok());
+    }
+
     #[test]
     fn test_constant_time_compare_in_state_validation() {
         // Verify we're using constant-time comparison
@@ -5,7 +5,7 @@
 
 use crate::error::ConfigError;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 use std::fmt;
 use std::str::FromStr;
 
@@ -68,10 +68,38 @@ impl AuthScopes {
     /// Returns `true` if this scope set covers all scopes in `other`.
     ///
     /// A scope set "covers" another if it contains all the scopes
-    /// from the other set (considering implied scopes).
+    /// from the other set (considering implied scopes). Both sets are
+    /// compared in their [canonical form](Self::canonical_string), so
+    /// differences in case don't matter.
     #[must_use]
     pub fn covers(&self, other: &Self) -> bool {
-        other.scopes.iter().all(|s| self.scopes.contains(s))
+        other.canonical_scopes().is_subset(&self.canonical_scopes())
+    }
+
+    /// Returns the scopes as a canonical comma-separated string.
+    ///
+    /// Shopify normalizes the scopes it grants, for example returning
+    /// `write_products` alone where `read_products,write_products` was
+    /// requested. The canonical string lowercases every scope, adds implied
+    /// scopes and sorts the result, so two sets granting the same access
+    /// produce the same string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::AuthScopes;
+    ///
+    /// let configured: AuthScopes = "read_products, write_products".parse().unwrap();
+    /// let granted: AuthScopes = "WRITE_PRODUCTS".parse().unwrap();
+    /// assert_eq!(configured.canonical_string(), granted.canonical_string());
+    /// assert_eq!(granted.canonical_string(), "read_products,write_products");
+    /// ```
+    #[must_use]
+    pub fn canonical_string(&self) -> String {
+        self.canonical_scopes()
+            .into_iter()
+            .collect::<Vec<_>>()
+            .join(",")
     }
 
     /// Returns an iterator over the scopes.
@@ -95,6 +123,21 @@ impl AuthScopes {
         }
     }
 
+    /// Returns the lowercased scopes with their implied scopes, in order.
+    pub(crate) fn canonical_scopes(&self) -> BTreeSet<String> {
+        let mut scopes: BTreeSet<String> = self
+            .scopes
+            .iter()
+            .map(|scope| scope.to_ascii_lowercase())
+            .collect();
+        let implied: Vec<String> = scopes
+            .iter()
+            .filter_map(|scope| Self::get_implied_scope(scope))
+            .collect();
+        scopes.extend(implied);
+        scopes
+    }
+
     fn get_implied_scope(scope: &str) -> Option<String> {
         scope
             .strip_prefix("unauthenticated_write_")
@@ -219,6 +262,23 @@ mod tests {
         assert!(!scopes.covers(&more_required));
     }
 
+    #[test]
+    fn test_canonical_string_matches_for_equivalent_scope_sets() {
+        let configured: AuthScopes = "write_orders, read_products, read_orders".parse().unwrap();
+        let granted = AuthScopes::from(vec![
+            "READ_PRODUCTS".to_string(),
+            "Write_Orders".to_string(),
+        ]);
+
+        assert_eq!(configured.canonical_string(), granted.canonical_string());
+        assert_eq!(
+            granted.canonical_string(),
+            "read_orders,read_products,write_orders"
+        );
+        assert!(granted.covers(&configured));
+        assert_eq!(AuthScopes::new().canonical_string(), "");
+    }
+
     #[test]
     fn test_auth_scopes_is_empty() {
         let empty = AuthScopes::new();
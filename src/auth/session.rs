@@ -296,6 +296,49 @@ impl Session {
         }
     }
 
+    /// Creates a builder for a session with the given ID, shop and token.
+    ///
+    /// The builder avoids the positional `is_online`/`expires` arguments of
+    /// [`Session::new`]. Sessions are offline with no scopes unless
+    /// configured otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::{Session, ShopDomain, AssociatedUser};
+    /// use chrono::{Utc, Duration};
+    ///
+    /// let shop = ShopDomain::new("my-store").unwrap();
+    /// let user = AssociatedUser::new(
+    ///     12345,
+    ///     "Jane".to_string(),
+    ///     "Doe".to_string(),
+    ///     "jane@example.com".to_string(),
+    ///     true, true, "en".to_string(), false,
+    /// );
+    ///
+    /// let session = Session::builder(
+    ///     Session::generate_online_id(&shop, 12345),
+    ///     shop,
+    ///     "access-token",
+    /// )
+    /// .scopes("read_products".parse().unwrap())
+    /// .online(user)
+    /// .expires_at(Utc::now() + Duration::hours(1))
+    /// .build();
+    ///
+    /// assert!(session.is_online);
+    /// assert!(session.is_active());
+    /// ```
+    #[must_use]
+    pub fn builder(
+        id: impl Into<String>,
+        shop: ShopDomain,
+        access_token: impl Into<String>,
+    ) -> SessionBuilder {
+        SessionBuilder::new(id, shop, access_token)
+    }
+
     /// Generates a session ID for an offline session.
     ///
     /// The ID format is `"offline_{shop}"` where `{shop}` is the full shop domain.
@@ -469,6 +512,136 @@ impl Session {
             Utc::now() + buffer > expires_at
         })
     }
+
+    /// Compares the session's scopes with the app's `configured` scopes.
+    ///
+    /// A stored session keeps the scopes granted when it was created. If the
+    /// app's configured scopes change afterwards, the session drifts from
+    /// them until the merchant re-authorizes the app. Both sets are compared
+    /// in their [canonical form](AuthScopes::canonical_string).
+    ///
+    /// Returns `None` if the scopes grant the same access.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::{AuthScopes, Session, ShopDomain};
+    ///
+    /// let session = Session::new(
+    ///     "session-id".to_string(),
+    ///     ShopDomain::new("my-store").unwrap(),
+    ///     "access-token".to_string(),
+    ///     "read_products".parse().unwrap(),
+    ///     false,
+    ///     None,
+    /// );
+    /// let configured: AuthScopes = "read_products,read_orders".parse().unwrap();
+    ///
+    /// let drift = session.scope_drift(&configured).unwrap();
+    /// assert_eq!(drift.missing, vec!["read_orders"]);
+    /// assert!(drift.requires_reauth());
+    /// ```
+    #[must_use]
+    pub fn scope_drift(&self, configured: &AuthScopes) -> Option<ScopeDrift> {
+        let granted = self.scopes.canonical_scopes();
+        let configured = configured.canonical_scopes();
+
+        let drift = ScopeDrift {
+            missing: configured.difference(&granted).cloned().collect(),
+            unexpected: granted.difference(&configured).cloned().collect(),
+        };
+        (!drift.missing.is_empty() || !drift.unexpected.is_empty()).then_some(drift)
+    }
+}
+
+/// How a session's scopes differ from the app's configured scopes.
+///
+/// Returned by [`Session::scope_drift`]. Scopes are listed in canonical
+/// form, sorted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScopeDrift {
+    /// Configured scopes the session was not granted.
+    pub missing: Vec<String>,
+    /// Granted scopes the app no longer configures.
+    pub unexpected: Vec<String>,
+}
+
+impl ScopeDrift {
+    /// Returns `true` if the session lacks configured scopes.
+    ///
+    /// Requests needing those scopes fail until the merchant re-authorizes
+    /// the app. Extra granted scopes alone don't require re-authorization.
+    #[must_use]
+    pub fn requires_reauth(&self) -> bool {
+        !self.missing.is_empty()
+    }
+}
+
+/// Builder for constructing [`Session`] instances.
+///
+/// Created with [`Session::builder`].
+#[derive(Debug)]
+pub struct SessionBuilder {
+    session: Session,
+}
+
+impl SessionBuilder {
+    /// Creates a new builder for an offline session with no scopes.
+    fn new(id: impl Into<String>, shop: ShopDomain, access_token: impl Into<String>) -> Self {
+        Self {
+            session: Session::new(
+                id.into(),
+                shop,
+                access_token.into(),
+                AuthScopes::new(),
+                false,
+                None,
+            ),
+        }
+    }
+
+    /// Sets the OAuth scopes granted to the session.
+    #[must_use]
+    pub fn scopes(mut self, scopes: AuthScopes) -> Self {
+        self.session.scopes = scopes;
+        self
+    }
+
+    /// Makes this an online session for the given user.
+    #[must_use]
+    pub fn online(mut self, user: AssociatedUser) -> Self {
+        self.session.is_online = true;
+        self.session.associated_user = Some(user);
+        self
+    }
+
+    /// Makes this an offline session, clearing any associated user.
+    #[must_use]
+    pub fn offline(mut self) -> Self {
+        self.session.is_online = false;
+        self.session.associated_user = None;
+        self
+    }
+
+    /// Sets when the session's access token expires.
+    #[must_use]
+    pub const fn expires_at(mut self, expires: DateTime<Utc>) -> Self {
+        self.session.expires = Some(expires);
+        self
+    }
+
+    /// Sets the refresh token for expiring offline tokens.
+    #[must_use]
+    pub fn refresh_token(mut self, token: impl Into<String>) -> Self {
+        self.session.refresh_token = Some(token.into());
+        self
+    }
+
+    /// Builds the [`Session`].
+    #[must_use]
+    pub fn build(self) -> Session {
+        self.session
+    }
 }
 
 /// OAuth access token response from Shopify.
@@ -571,6 +744,46 @@ mod tests {
         )
     }
 
+    // === Session builder tests ===
+
+    #[test]
+    fn test_builder_creates_online_session_with_user() {
+        let shop = sample_shop();
+        let session = Session::builder(
+            Session::generate_online_id(&shop, 12345),
+            shop,
+            "access-token",
+        )
+        .scopes(sample_scopes())
+        .online(sample_user())
+        .expires_at(Utc::now() - Duration::hours(1))
+        .build();
+
+        assert!(session.is_online);
+        assert_eq!(session.id, "my-store.myshopify.com_12345");
+        assert_eq!(session.scopes, sample_scopes());
+        assert_eq!(session.associated_user.as_ref().map(|u| u.id), Some(12345));
+        assert!(session.expired());
+        assert!(!session.is_active());
+    }
+
+    #[test]
+    fn test_builder_creates_offline_session() {
+        let shop = sample_shop();
+        let session = Session::builder(Session::generate_offline_id(&shop), shop, "access-token")
+            .online(sample_user())
+            .offline()
+            .refresh_token("refresh-token")
+            .build();
+
+        assert!(!session.is_online);
+        assert!(session.associated_user.is_none());
+        assert_eq!(session.refresh_token.as_deref(), Some("refresh-token"));
+        assert!(session.expires.is_none());
+        assert!(!session.expired());
+        assert!(session.is_active());
+    }
+
     // === Existing Session tests ===
 
     #[test]
@@ -814,6 +1027,20 @@ mod tests {
         assert_eq!(id, "my-store.myshopify.com_12345");
     }
 
+    #[test]
+    fn test_offline_and_online_ids_do_not_collide() {
+        let shop = ShopDomain::new("my-store").unwrap();
+        let offline = Session::generate_offline_id(&shop);
+        let online = Session::generate_online_id(&shop, 12345);
+
+        assert_ne!(offline, online);
+        assert!(offline.starts_with("offline_"));
+        assert!(!online.starts_with("offline_"));
+        // Shop names can't contain underscores, so no shop can produce an
+        // online ID that starts with "offline_"
+        assert!(online.starts_with(&format!("{}_", shop.as_ref())));
+    }
+
     #[test]
     fn test_from_access_token_response_with_offline_response() {
         let shop = ShopDomain::new("my-store").unwrap();
@@ -1217,4 +1444,30 @@ mod tests {
             restored.refresh_token_expires_at
         );
     }
+
+    #[test]
+    fn test_scope_drift_compares_canonical_scopes() {
+        let session = Session::new(
+            "offline_my-store.myshopify.com".to_string(),
+            sample_shop(),
+            "token".to_string(),
+            "WRITE_PRODUCTS,read_customers".parse().unwrap(),
+            false,
+            None,
+        );
+
+        let same: AuthScopes = "read_products,write_products,read_customers"
+            .parse()
+            .unwrap();
+        assert_eq!(session.scope_drift(&same), None);
+
+        let changed: AuthScopes = "write_products,read_orders".parse().unwrap();
+        let drift = session.scope_drift(&changed).unwrap();
+        assert_eq!(drift.missing, vec!["read_orders"]);
+        assert_eq!(drift.unexpected, vec!["read_customers"]);
+        assert!(drift.requires_reauth());
+
+        let narrowed: AuthScopes = "write_products".parse().unwrap();
+        assert!(!session.scope_drift(&narrowed).unwrap().requires_reauth());
+    }
 }
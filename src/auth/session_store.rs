@@ -0,0 +1,165 @@
+//! Pluggable persistence for sessions.
+//!
+//! The SDK hands sessions back to the app after OAuth, token exchange and
+//! token refresh, and leaves storing them to the app. A [`SessionStore`]
+//! gives helpers that need to read and write sessions, such as
+//! [`migrate_and_store`](crate::auth::oauth::migrate_and_store), a common
+//! interface to the app's storage.
+//!
+//! [`InMemorySessionStore`] works for tests and single-process apps; production
+//! apps should implement [`SessionStore`] over their database.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use shopify_sdk::auth::{InMemorySessionStore, SessionStore};
+//! use shopify_sdk::Session;
+//!
+//! let store = InMemorySessionStore::new();
+//!
+//! // After OAuth completes
+//! let session = validate_auth_callback(&config, &query, &state).await?;
+//! store.store(&session).await;
+//!
+//! // Later, when handling a webhook for the shop
+//! let session = store.load(&Session::generate_offline_id(&shop)).await;
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use crate::auth::Session;
+use crate::webhooks::BoxFuture;
+
+/// Loads, saves and deletes sessions by ID.
+///
+/// Session IDs are those produced by [`Session::generate_offline_id`] and
+/// [`Session::generate_online_id`]. Methods return boxed futures so
+/// implementations can be backed by asynchronous stores.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use std::sync::Mutex;
+/// use shopify_sdk::auth::SessionStore;
+/// use shopify_sdk::webhooks::BoxFuture;
+/// use shopify_sdk::Session;
+///
+/// #[derive(Default)]
+/// struct MapStore {
+///     sessions: Mutex<HashMap<String, Session>>,
+/// }
+///
+/// impl SessionStore for MapStore {
+///     fn load<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Option<Session>> {
+///         Box::pin(async move { self.sessions.lock().unwrap().get(id).cloned() })
+///     }
+///
+///     fn store<'a>(&'a self, session: &'a Session) -> BoxFuture<'a, ()> {
+///         Box::pin(async move {
+///             self.sessions.lock().unwrap().insert(session.id.clone(), session.clone());
+///         })
+///     }
+///
+///     fn delete<'a>(&'a self, id: &'a str) -> BoxFuture<'a, ()> {
+///         Box::pin(async move {
+///             self.sessions.lock().unwrap().remove(id);
+///         })
+///     }
+/// }
+/// ```
+pub trait SessionStore: Send + Sync {
+    /// Returns the session with this ID, if one is stored.
+    fn load<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Option<Session>>;
+
+    /// Saves a session under its ID, replacing any session stored there.
+    fn store<'a>(&'a self, session: &'a Session) -> BoxFuture<'a, ()>;
+
+    /// Removes the session with this ID, if one is stored.
+    fn delete<'a>(&'a self, id: &'a str) -> BoxFuture<'a, ()>;
+}
+
+/// An in-memory [`SessionStore`].
+///
+/// Sessions are lost when the process exits.
+#[derive(Debug, Default)]
+pub struct InMemorySessionStore {
+    sessions: Mutex<HashMap<String, Session>>,
+}
+
+impl InMemorySessionStore {
+    /// Creates an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of stored sessions.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns `true` if no sessions are stored.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Locks the sessions, recovering them if a previous holder panicked.
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, Session>> {
+        self.sessions.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl SessionStore for InMemorySessionStore {
+    fn load<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Option<Session>> {
+        let session = self.lock().get(id).cloned();
+        Box::pin(async move { session })
+    }
+
+    fn store<'a>(&'a self, session: &'a Session) -> BoxFuture<'a, ()> {
+        self.lock().insert(session.id.clone(), session.clone());
+        Box::pin(async {})
+    }
+
+    fn delete<'a>(&'a self, id: &'a str) -> BoxFuture<'a, ()> {
+        self.lock().remove(id);
+        Box::pin(async {})
+    }
+}
+
+// Verify InMemorySessionStore is Send + Sync at compile time
+const _: fn() = || {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<InMemorySessionStore>();
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::AuthScopes;
+    use crate::config::ShopDomain;
+
+    #[tokio::test]
+    async fn test_in_memory_store_round_trips_sessions() {
+        let shop = ShopDomain::new("test-shop").unwrap();
+        let session = Session::new(
+            Session::generate_offline_id(&shop),
+            shop,
+            "access-token".to_string(),
+            AuthScopes::new(),
+            false,
+            None,
+        );
+        let store = InMemorySessionStore::new();
+
+        store.store(&session).await;
+        assert_eq!(store.load(&session.id).await, Some(session.clone()));
+
+        store.delete(&session.id).await;
+        assert_eq!(store.load(&session.id).await, None);
+        assert!(store.is_empty());
+    }
+}
@@ -0,0 +1,264 @@
+//! Collection of deprecation notices encountered while the app runs.
+//!
+//! The SDK reports deprecated usage in several places: a warning when a
+//! [`RestClient`](crate::clients::RestClient) is created, a warning when the
+//! configured API version is past its support window, operations on
+//! deprecated REST resources such as `PriceRule` and `ScriptTag`, and the
+//! `X-Shopify-API-Deprecated-Reason` header Shopify adds to Admin and
+//! Storefront API responses for deprecated endpoints and fields. Each is
+//! logged as it happens.
+//!
+//! A [`DeprecationRegistry`] gathers them into one list, so an app can see
+//! everything it uses that is deprecated, for example to print a report at
+//! shutdown or fail a test run.
+//!
+//! # Example
+//!
+//! ```rust
+//! use shopify_sdk::{ShopifyConfig, ApiKey, ApiSecretKey};
+//! use shopify_sdk::clients::DeprecationRegistry;
+//!
+//! let registry = DeprecationRegistry::new();
+//!
+//! let config = ShopifyConfig::builder()
+//!     .api_key(ApiKey::new("key").unwrap())
+//!     .api_secret_key(ApiSecretKey::new("secret").unwrap())
+//!     .deprecation_registry(registry.clone())
+//!     .build()
+//!     .unwrap();
+//!
+//! // ... make requests with clients built from `config` ...
+//!
+//! for notice in registry.notices() {
+//!     println!("{:?}: {}", notice.source, notice.reason);
+//! }
+//! ```
+
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use crate::clients::ApiDeprecationInfo;
+
+/// Where a deprecation notice came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeprecationSource {
+    /// The configured API version is no longer supported by Shopify.
+    ApiVersion,
+    /// A REST Admin API client was created; the REST Admin API is deprecated
+    /// in favour of GraphQL.
+    RestApi,
+    /// A response carried the `X-Shopify-API-Deprecated-Reason` header.
+    Response,
+    /// An operation was run on a deprecated REST resource, such as
+    /// `PriceRule` or `ScriptTag`.
+    Resource,
+}
+
+/// A single deprecated usage.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeprecationNotice {
+    /// Where the notice came from.
+    pub source: DeprecationSource,
+    /// A description of what is deprecated.
+    pub reason: String,
+    /// The request path involved, for notices from responses.
+    pub path: Option<String>,
+}
+
+impl From<&ApiDeprecationInfo> for DeprecationNotice {
+    fn from(info: &ApiDeprecationInfo) -> Self {
+        Self {
+            source: DeprecationSource::Response,
+            reason: info.reason.clone(),
+            path: info.path.clone(),
+        }
+    }
+}
+
+/// A shared list of the deprecation notices encountered during a run.
+///
+/// Set one on the config with
+/// [`ShopifyConfigBuilder::deprecation_registry`](crate::config::ShopifyConfigBuilder::deprecation_registry);
+/// every client built from that config records into it. Cloning a
+/// `DeprecationRegistry` shares the same list.
+///
+/// Repeated notices are recorded once, so a deprecated endpoint called in a
+/// loop shows up as a single entry.
+#[derive(Clone, Debug, Default)]
+pub struct DeprecationRegistry {
+    notices: Arc<Mutex<Vec<DeprecationNotice>>>,
+}
+
+impl DeprecationRegistry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a notice, unless an identical one is already recorded.
+    pub fn record(&self, notice: DeprecationNotice) {
+        let mut notices = self.lock();
+        if !notices.contains(&notice) {
+            notices.push(notice);
+        }
+    }
+
+    /// Returns the recorded notices, in the order they were first seen.
+    #[must_use]
+    pub fn notices(&self) -> Vec<DeprecationNotice> {
+        self.lock().clone()
+    }
+
+    /// Returns `true` if no notices have been recorded.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Removes all recorded notices.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Locks the list, recovering it if a previous holder panicked.
+    fn lock(&self) -> MutexGuard<'_, Vec<DeprecationNotice>> {
+        self.notices.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+// Verify DeprecationRegistry is Send + Sync at compile time
+const _: fn() = || {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<DeprecationRegistry>();
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::{DataType, HttpClient, HttpMethod, HttpRequest, RestClient};
+    use crate::config::{ApiKey, ApiSecretKey, ShopDomain, ShopifyConfig};
+    use crate::{AuthScopes, Session};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn create_test_session() -> Session {
+        Session::new(
+            "test-session".to_string(),
+            ShopDomain::new("test-shop").unwrap(),
+            "test-access-token".to_string(),
+            AuthScopes::new(),
+            false,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_registry_records_each_notice_once() {
+        let registry = DeprecationRegistry::new();
+        let notice = DeprecationNotice {
+            source: DeprecationSource::Response,
+            reason: "Field `Product.bodyHtml` is deprecated".to_string(),
+            path: Some("graphql.json".to_string()),
+        };
+
+        registry.record(notice.clone());
+        registry.clone().record(notice.clone());
+
+        assert_eq!(registry.notices(), vec![notice]);
+        registry.clear();
+        assert!(registry.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rest_client_and_deprecated_response_register_notices() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/admin/api/2026-04/graphql.json"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(
+                        "X-Shopify-API-Deprecated-Reason",
+                        "Field `Product.bodyHtml` is deprecated",
+                    )
+                    .set_body_json(serde_json::json!({ "data": {} })),
+            )
+            .mount(&server)
+            .await;
+
+        let registry = DeprecationRegistry::new();
+        let config = ShopifyConfig::builder()
+            .api_key(ApiKey::new("key").unwrap())
+            .api_secret_key(ApiSecretKey::new("secret").unwrap())
+            .deprecation_registry(registry.clone())
+            .build()
+            .unwrap();
+        let session = create_test_session();
+
+        RestClient::new(&session, Some(&config)).unwrap();
+
+        let server_url = reqwest::Url::parse(&server.uri()).unwrap();
+        let client = HttpClient::new("/admin/api/2026-04", &session, Some(&config))
+            .with_url_rewriter(move |url| {
+                url.set_scheme("http").unwrap();
+                url.set_host(server_url.host_str()).unwrap();
+                url.set_port(server_url.port()).unwrap();
+            });
+        let request = HttpRequest::builder(HttpMethod::Post, "graphql.json")
+            .body(serde_json::json!({ "query": "{ product(id: 1) { bodyHtml } }" }))
+            .body_type(DataType::Json)
+            .build()
+            .unwrap();
+        client.request(request).await.unwrap();
+
+        let notices = registry.notices();
+        assert_eq!(notices.len(), 2);
+        assert_eq!(notices[0].source, DeprecationSource::RestApi);
+        assert_eq!(
+            notices[1],
+            DeprecationNotice {
+                source: DeprecationSource::Response,
+                reason: "Field `Product.bodyHtml` is deprecated".to_string(),
+                path: Some("graphql.json".to_string()),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deprecated_resource_operations_register_notices() {
+        use crate::rest::resources::v2026_04::{PriceRule, ScriptTag};
+        use crate::rest::RestResource;
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "price_rules": [],
+                "script_tags": []
+            })))
+            .mount(&server)
+            .await;
+
+        let registry = DeprecationRegistry::new();
+        let config = ShopifyConfig::builder()
+            .api_key(ApiKey::new("key").unwrap())
+            .api_secret_key(ApiSecretKey::new("secret").unwrap())
+            .deprecation_registry(registry.clone())
+            .build()
+            .unwrap();
+        let client = RestClient::new(&create_test_session(), Some(&config))
+            .unwrap()
+            .with_mock_server(&server.uri());
+        registry.clear();
+
+        PriceRule::all(&client, None).await.unwrap();
+        ScriptTag::all(&client, None).await.unwrap();
+        PriceRule::all(&client, None).await.unwrap();
+
+        let notices = registry.notices();
+        assert_eq!(notices.len(), 2);
+        assert!(notices
+            .iter()
+            .all(|notice| notice.source == DeprecationSource::Resource));
+        assert_eq!(notices[0].reason, PriceRule::DEPRECATION.unwrap());
+        assert_eq!(notices[1].reason, ScriptTag::DEPRECATION.unwrap());
+    }
+}
@@ -11,6 +11,7 @@
 //! - [`MaxHttpRetriesExceededError`]: When retry attempts are exhausted
 //! - [`InvalidHttpRequestError`]: When a request fails validation before sending
 //! - [`HttpError`]: Unified error type encompassing all HTTP-related errors
+//! - [`ErrorKind`]: Coarse classification of an error for retry/re-auth decisions
 //!
 //! # Example
 //!
@@ -31,6 +32,12 @@
 //!     Err(HttpError::Network(e)) => {
 //!         println!("Network error: {}", e);
 //!     }
+//!     Err(HttpError::ConnectTimeout(e)) => {
+//!         println!("Could not connect in time: {}", e);
+//!     }
+//!     Err(HttpError::ServiceUnavailable { retry_after, .. }) => {
+//!         println!("Shopify is down for maintenance, retry after {:?}s", retry_after);
+//!     }
 //! }
 //! ```
 
@@ -143,6 +150,13 @@ pub enum InvalidHttpRequestError {
         /// The HTTP method that requires a body.
         method: String,
     },
+
+    /// The request does not resolve to a valid URL.
+    #[error("Invalid request URL {url}.")]
+    InvalidUrl {
+        /// The URL that failed to parse.
+        url: String,
+    },
 }
 
 /// Unified error type for all HTTP-related errors.
@@ -163,6 +177,8 @@ pub enum InvalidHttpRequestError {
 ///     Err(HttpError::MaxRetries(e)) => { /* handle retry exhaustion */ }
 ///     Err(HttpError::InvalidRequest(e)) => { /* handle validation error */ }
 ///     Err(HttpError::Network(e)) => { /* handle network error */ }
+///     Err(HttpError::ConnectTimeout(e)) => { /* handle connect timeout */ }
+///     Err(HttpError::ServiceUnavailable { .. }) => { /* handle maintenance */ }
 /// }
 /// ```
 #[derive(Debug, Error)]
@@ -181,7 +197,184 @@ pub enum HttpError {
 
     /// Network or connection error.
     #[error("Network error: {0}")]
-    Network(#[from] reqwest::Error),
+    Network(#[source] reqwest::Error),
+
+    /// The connection could not be established within the configured
+    /// [`connect_timeout`](crate::ShopifyConfig::connect_timeout).
+    ///
+    /// The request was never sent, so it is always safe to retry.
+    #[error("Timed out connecting to Shopify: {0}")]
+    ConnectTimeout(#[source] reqwest::Error),
+
+    /// Shopify is temporarily unavailable, typically for maintenance.
+    ///
+    /// Returned for a 503 response whose body is not JSON, such as the HTML
+    /// page Shopify serves during maintenance. The body is not parsed.
+    #[error("Shopify is temporarily unavailable (503)")]
+    ServiceUnavailable {
+        /// Seconds to wait before retrying, from the `Retry-After` header.
+        retry_after: Option<f64>,
+        /// The `X-Request-Id` of the response, if present.
+        error_reference: Option<String>,
+    },
+}
+
+impl From<reqwest::Error> for HttpError {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_connect() && error.is_timeout() {
+            Self::ConnectTimeout(error)
+        } else {
+            Self::Network(error)
+        }
+    }
+}
+
+impl HttpError {
+    /// Classifies this error into an [`ErrorKind`].
+    ///
+    /// - Network errors, connect timeouts, maintenance pages ([`HttpError::ServiceUnavailable`])
+    ///   and 500/502/503/504 responses are [`ErrorKind::Retryable`]
+    /// - 429 responses are [`ErrorKind::RateLimited`]
+    /// - 401/403 responses are [`ErrorKind::Auth`]
+    /// - Other 4xx responses and invalid requests are [`ErrorKind::Client`]
+    /// - Other 5xx responses are [`ErrorKind::Server`]
+    ///
+    /// When retries have been exhausted ([`HttpError::MaxRetries`]), a 5xx or
+    /// 429 status is reported as [`ErrorKind::Server`] since retrying again is
+    /// unlikely to help.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::clients::{ErrorKind, HttpError, HttpResponseError};
+    ///
+    /// let error = HttpError::Response(HttpResponseError {
+    ///     code: 401,
+    ///     message: r#"{"error":"Unauthorized"}"#.to_string(),
+    ///     error_reference: None,
+    /// });
+    /// assert_eq!(error.kind(), ErrorKind::Auth);
+    /// ```
+    #[must_use]
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Response(e) => ErrorKind::from_status(e.code),
+            Self::MaxRetries(e) => match ErrorKind::from_status(e.code) {
+                ErrorKind::Retryable | ErrorKind::RateLimited => ErrorKind::Server,
+                kind => kind,
+            },
+            Self::InvalidRequest(_) => ErrorKind::Client,
+            Self::Network(_) | Self::ConnectTimeout(_) | Self::ServiceUnavailable { .. } => {
+                ErrorKind::Retryable
+            }
+        }
+    }
+
+    /// Returns the `X-Request-Id` of the failed response, if any.
+    ///
+    /// Include this when reporting an issue to Shopify support.
+    /// Invalid requests and network errors never reached Shopify, so they
+    /// have no request ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::clients::{HttpError, HttpResponseError};
+    ///
+    /// let error = HttpError::Response(HttpResponseError {
+    ///     code: 404,
+    ///     message: r#"{"error":"Not Found"}"#.to_string(),
+    ///     error_reference: Some("abc-123".to_string()),
+    /// });
+    /// assert_eq!(error.request_id(), Some("abc-123"));
+    /// ```
+    #[must_use]
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Self::Response(e) => e.error_reference.as_deref(),
+            Self::MaxRetries(e) => e.error_reference.as_deref(),
+            Self::ServiceUnavailable {
+                error_reference, ..
+            } => error_reference.as_deref(),
+            Self::InvalidRequest(_) | Self::Network(_) | Self::ConnectTimeout(_) => None,
+        }
+    }
+
+    /// Returns the delay Shopify asked for before retrying, in seconds.
+    ///
+    /// Only [`HttpError::ServiceUnavailable`] carries a `Retry-After` value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::clients::HttpError;
+    ///
+    /// let error = HttpError::ServiceUnavailable {
+    ///     retry_after: Some(30.0),
+    ///     error_reference: None,
+    /// };
+    /// assert_eq!(error.retry_after(), Some(30.0));
+    /// ```
+    #[must_use]
+    pub const fn retry_after(&self) -> Option<f64> {
+        match self {
+            Self::ServiceUnavailable { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+}
+
+/// Coarse classification of an API error.
+///
+/// Use [`HttpError::kind`] or
+/// [`ResourceError::kind`](crate::rest::ResourceError::kind) to decide how to
+/// react to a failure without matching on every error variant.
+///
+/// # Example
+///
+/// ```rust
+/// use shopify_sdk::clients::ErrorKind;
+///
+/// assert_eq!(ErrorKind::from_status(429), ErrorKind::RateLimited);
+/// assert_eq!(ErrorKind::from_status(503), ErrorKind::Retryable);
+/// assert_eq!(ErrorKind::from_status(403), ErrorKind::Auth);
+/// assert_eq!(ErrorKind::from_status(422), ErrorKind::Client);
+/// assert!(ErrorKind::RateLimited.is_retryable());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// A transient failure; the request may succeed if retried.
+    Retryable,
+    /// The request was invalid and should not be retried as-is.
+    Client,
+    /// The server failed in a way that retrying is unlikely to fix.
+    Server,
+    /// The access token is missing, invalid, or lacks the required scopes.
+    Auth,
+    /// The request was throttled (HTTP 429); retry after backing off.
+    RateLimited,
+}
+
+impl ErrorKind {
+    /// Classifies an HTTP status code.
+    #[must_use]
+    pub const fn from_status(code: u16) -> Self {
+        match code {
+            429 => Self::RateLimited,
+            401 | 403 => Self::Auth,
+            500 | 502 | 503 | 504 => Self::Retryable,
+            501 | 505..=599 => Self::Server,
+            _ => Self::Client,
+        }
+    }
+
+    /// Returns `true` if the request may succeed when retried later.
+    ///
+    /// This is the case for [`ErrorKind::Retryable`] and [`ErrorKind::RateLimited`].
+    #[must_use]
+    pub const fn is_retryable(self) -> bool {
+        matches!(self, Self::Retryable | Self::RateLimited)
+    }
 }
 
 #[cfg(test)]
@@ -263,4 +456,102 @@ mod tests {
         let invalid_error: &dyn std::error::Error = &InvalidHttpRequestError::MissingBodyType;
         let _ = invalid_error;
     }
+
+    #[test]
+    fn test_error_kind_from_status() {
+        assert_eq!(ErrorKind::from_status(429), ErrorKind::RateLimited);
+        assert_eq!(ErrorKind::from_status(401), ErrorKind::Auth);
+        assert_eq!(ErrorKind::from_status(403), ErrorKind::Auth);
+        assert_eq!(ErrorKind::from_status(400), ErrorKind::Client);
+        assert_eq!(ErrorKind::from_status(404), ErrorKind::Client);
+        assert_eq!(ErrorKind::from_status(422), ErrorKind::Client);
+        assert_eq!(ErrorKind::from_status(500), ErrorKind::Retryable);
+        assert_eq!(ErrorKind::from_status(502), ErrorKind::Retryable);
+        assert_eq!(ErrorKind::from_status(503), ErrorKind::Retryable);
+        assert_eq!(ErrorKind::from_status(504), ErrorKind::Retryable);
+        assert_eq!(ErrorKind::from_status(501), ErrorKind::Server);
+    }
+
+    #[test]
+    fn test_error_kind_is_retryable() {
+        assert!(ErrorKind::Retryable.is_retryable());
+        assert!(ErrorKind::RateLimited.is_retryable());
+        assert!(!ErrorKind::Client.is_retryable());
+        assert!(!ErrorKind::Server.is_retryable());
+        assert!(!ErrorKind::Auth.is_retryable());
+    }
+
+    #[test]
+    fn test_http_error_kind_for_response_errors() {
+        let error = HttpError::Response(HttpResponseError {
+            code: 401,
+            message: "test".to_string(),
+            error_reference: None,
+        });
+        assert_eq!(error.kind(), ErrorKind::Auth);
+
+        let error = HttpError::Response(HttpResponseError {
+            code: 503,
+            message: "test".to_string(),
+            error_reference: None,
+        });
+        assert_eq!(error.kind(), ErrorKind::Retryable);
+    }
+
+    #[test]
+    fn test_http_error_kind_for_exhausted_retries() {
+        let error = HttpError::MaxRetries(MaxHttpRetriesExceededError {
+            code: 429,
+            tries: 3,
+            message: "test".to_string(),
+            error_reference: None,
+        });
+        assert_eq!(error.kind(), ErrorKind::Server);
+        assert!(!error.kind().is_retryable());
+
+        let error = HttpError::MaxRetries(MaxHttpRetriesExceededError {
+            code: 500,
+            tries: 3,
+            message: "test".to_string(),
+            error_reference: None,
+        });
+        assert_eq!(error.kind(), ErrorKind::Server);
+        assert!(!error.kind().is_retryable());
+    }
+
+    #[test]
+    fn test_http_error_kind_for_service_unavailable() {
+        let error = HttpError::ServiceUnavailable {
+            retry_after: Some(120.0),
+            error_reference: Some("abc-123".to_string()),
+        };
+
+        assert_eq!(error.kind(), ErrorKind::Retryable);
+        assert_eq!(error.retry_after(), Some(120.0));
+        assert_eq!(
+            error.to_string(),
+            "Shopify is temporarily unavailable (503)"
+        );
+        assert_eq!(error.request_id(), Some("abc-123"));
+    }
+
+    #[test]
+    fn test_http_error_request_id() {
+        let error = HttpError::MaxRetries(MaxHttpRetriesExceededError {
+            code: 429,
+            tries: 3,
+            message: "test".to_string(),
+            error_reference: Some("req-429".to_string()),
+        });
+        assert_eq!(error.request_id(), Some("req-429"));
+
+        let error = HttpError::InvalidRequest(InvalidHttpRequestError::MissingBodyType);
+        assert_eq!(error.request_id(), None);
+    }
+
+    #[test]
+    fn test_http_error_kind_for_invalid_request() {
+        let error = HttpError::InvalidRequest(InvalidHttpRequestError::MissingBodyType);
+        assert_eq!(error.kind(), ErrorKind::Client);
+    }
 }
@@ -0,0 +1,342 @@
+//! Per-shop client factory for multi-tenant apps.
+//!
+//! This module provides the [`ClientFactory`] type, which caches one reqwest
+//! client (and therefore one connection pool) and one [`RateLimiter`] per
+//! shop and hands out lightweight [`GraphqlClient`] and [`RestClient`]
+//! instances bound to a session. Building a reqwest client initializes TLS
+//! and a fresh pool, so creating one per request is wasteful for apps serving
+//! many shops.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use shopify_sdk::clients::ClientFactory;
+//!
+//! // Create once at startup and share across request handlers
+//! let factory = ClientFactory::new(config);
+//!
+//! // Per request: load the shop's session and get a client for it
+//! let client = factory.graphql(&session);
+//! let response = client.query("query { shop { name } }", None, None, None).await?;
+//! ```
+
+use std::collections::VecDeque;
+use std::num::NonZeroUsize;
+use std::sync::{Mutex, PoisonError};
+
+use crate::auth::Session;
+use crate::clients::http_client::build_reqwest_client;
+use crate::clients::{GraphqlClient, RateLimiter, RestClient, RestError};
+use crate::config::{ShopDomain, ShopifyConfig};
+
+/// Default number of shops whose clients are kept cached.
+pub const DEFAULT_CLIENT_CACHE_CAPACITY: usize = 100;
+
+/// Creates API clients that share a cached reqwest client and rate limiter
+/// per shop.
+///
+/// Cached entries are keyed by [`ShopDomain`] and kept in a least recently
+/// used cache, so memory stays bounded by the configured capacity. When a new
+/// shop is seen while the cache is full, the least recently used shop's entry
+/// is dropped.
+///
+/// Every [`RestClient`] handed out for a shop uses that shop's
+/// [`RateLimiter`], so paced operations such as
+/// [`RestResource::create_all`](crate::rest::RestResource::create_all) wait
+/// on the shop's call limit rather than on a bucket of their own.
+///
+/// # Thread Safety
+///
+/// `ClientFactory` is `Send + Sync`, so a single instance can be shared
+/// across request handlers (e.g. behind an `Arc`).
+///
+/// # Example
+///
+/// ```rust
+/// use std::num::NonZeroUsize;
+/// use shopify_sdk::clients::ClientFactory;
+/// use shopify_sdk::{ShopifyConfig, ApiKey, ApiSecretKey};
+///
+/// let config = ShopifyConfig::builder()
+///     .api_key(ApiKey::new("key").unwrap())
+///     .api_secret_key(ApiSecretKey::new("secret").unwrap())
+///     .build()
+///     .unwrap();
+///
+/// let factory = ClientFactory::with_capacity(config, NonZeroUsize::new(500).unwrap());
+/// assert_eq!(factory.capacity(), 500);
+/// assert!(factory.is_empty());
+/// ```
+#[derive(Debug)]
+pub struct ClientFactory {
+    /// Configuration applied to every client.
+    config: ShopifyConfig,
+    /// Maximum number of cached shops.
+    capacity: NonZeroUsize,
+    /// Cached shop entries, most recently used first.
+    cache: Mutex<VecDeque<(ShopDomain, ShopEntry)>>,
+}
+
+/// The resources shared by every client for one shop.
+#[derive(Clone, Debug)]
+struct ShopEntry {
+    /// The shop's reqwest client and connection pool.
+    reqwest_client: reqwest::Client,
+    /// The shop's REST call limit bucket.
+    rate_limiter: RateLimiter,
+}
+
+// Verify ClientFactory is Send + Sync at compile time
+const _: fn() = || {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<ClientFactory>();
+};
+
+impl ClientFactory {
+    /// Creates a factory with the [default capacity](DEFAULT_CLIENT_CACHE_CAPACITY).
+    #[must_use]
+    pub fn new(config: ShopifyConfig) -> Self {
+        Self::with_capacity(
+            config,
+            NonZeroUsize::new(DEFAULT_CLIENT_CACHE_CAPACITY).unwrap_or(NonZeroUsize::MIN),
+        )
+    }
+
+    /// Creates a factory that caches clients for at most `capacity` shops.
+    #[must_use]
+    pub fn with_capacity(config: ShopifyConfig, capacity: NonZeroUsize) -> Self {
+        Self {
+            config,
+            capacity,
+            cache: Mutex::new(VecDeque::with_capacity(capacity.get())),
+        }
+    }
+
+    /// Returns the configuration applied to every client.
+    #[must_use]
+    pub const fn config(&self) -> &ShopifyConfig {
+        &self.config
+    }
+
+    /// Returns the maximum number of shops kept in the cache.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity.get()
+    }
+
+    /// Returns the number of shops currently cached.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock_cache().len()
+    }
+
+    /// Returns `true` if no shops are cached.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock_cache().is_empty()
+    }
+
+    /// Returns a GraphQL client for the session's shop.
+    ///
+    /// The client uses the factory's configured API version.
+    #[must_use]
+    pub fn graphql(&self, session: &Session) -> GraphqlClient {
+        GraphqlClient::with_reqwest_client(
+            session,
+            Some(&self.config),
+            self.entry_for(&session.shop).reqwest_client,
+        )
+    }
+
+    /// Returns a REST client for the session's shop.
+    ///
+    /// The client uses the factory's configured API version and the shop's
+    /// shared [`RateLimiter`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RestError::RestApiDisabled`] if the REST API is disabled in
+    /// the configuration.
+    pub fn rest(&self, session: &Session) -> Result<RestClient, RestError> {
+        let entry = self.entry_for(&session.shop);
+        RestClient::with_reqwest_client(session, Some(&self.config), entry.reqwest_client)
+            .map(|client| client.with_rate_limiter(entry.rate_limiter))
+    }
+
+    /// Returns the rate limiter shared by the shop's REST clients, caching
+    /// an entry for the shop if there is none yet.
+    ///
+    /// Hand it to other clients for the same shop, such as a
+    /// [`GraphqlClient`] pacing on the call limit header, to draw from the
+    /// same bucket.
+    #[must_use]
+    pub fn rate_limiter(&self, shop: &ShopDomain) -> RateLimiter {
+        self.entry_for(shop).rate_limiter
+    }
+
+    /// Returns the cached entry for a shop, building and caching one if
+    /// needed.
+    fn entry_for(&self, shop: &ShopDomain) -> ShopEntry {
+        let mut cache = self.lock_cache();
+
+        if let Some(index) = cache.iter().position(|(cached, _)| cached == shop) {
+            // Move to the front to mark it most recently used
+            if let Some(entry) = cache.remove(index) {
+                let shared = entry.1.clone();
+                cache.push_front(entry);
+                return shared;
+            }
+        }
+
+        let entry = ShopEntry {
+            reqwest_client: build_reqwest_client(Some(&self.config)),
+            rate_limiter: RateLimiter::default(),
+        };
+        if cache.len() >= self.capacity.get() {
+            cache.pop_back();
+        }
+        cache.push_front((shop.clone(), entry.clone()));
+        entry
+    }
+
+    /// Locks the cache, recovering from a poisoned lock since the cache
+    /// cannot be left in an inconsistent state.
+    fn lock_cache(&self) -> std::sync::MutexGuard<'_, VecDeque<(ShopDomain, ShopEntry)>> {
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the cached shops, most recently used first.
+    #[cfg(test)]
+    fn cached_shops(&self) -> Vec<String> {
+        self.lock_cache()
+            .iter()
+            .map(|(shop, _)| shop.as_ref().to_string())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::AuthScopes;
+    use crate::config::{ApiKey, ApiSecretKey};
+
+    fn create_config() -> ShopifyConfig {
+        ShopifyConfig::builder()
+            .api_key(ApiKey::new("test-key").unwrap())
+            .api_secret_key(ApiSecretKey::new("test-secret").unwrap())
+            .build()
+            .unwrap()
+    }
+
+    fn create_session(shop: &str) -> Session {
+        Session::new(
+            Session::generate_offline_id(&ShopDomain::new(shop).unwrap()),
+            ShopDomain::new(shop).unwrap(),
+            "test-token".to_string(),
+            AuthScopes::new(),
+            false,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_same_shop_reuses_cached_reqwest_client() {
+        let factory = ClientFactory::new(create_config());
+        let session = create_session("store-a");
+
+        let _first = factory.graphql(&session);
+        let _second = factory.rest(&session).unwrap();
+
+        assert_eq!(factory.len(), 1);
+        assert_eq!(factory.cached_shops(), ["store-a.myshopify.com"]);
+    }
+
+    #[test]
+    fn test_different_shops_get_separate_clients() {
+        let factory = ClientFactory::new(create_config());
+
+        let _a = factory.graphql(&create_session("store-a"));
+        let _b = factory.graphql(&create_session("store-b"));
+
+        assert_eq!(factory.len(), 2);
+        assert_eq!(
+            factory.cached_shops(),
+            ["store-b.myshopify.com", "store-a.myshopify.com"]
+        );
+    }
+
+    #[test]
+    fn test_least_recently_used_shop_is_evicted_at_capacity() {
+        let factory = ClientFactory::with_capacity(create_config(), NonZeroUsize::new(2).unwrap());
+
+        let _a = factory.graphql(&create_session("store-a"));
+        let _b = factory.graphql(&create_session("store-b"));
+        // Touch store-a so store-b becomes least recently used
+        let _a_again = factory.graphql(&create_session("store-a"));
+        let _c = factory.graphql(&create_session("store-c"));
+
+        assert_eq!(factory.len(), 2);
+        assert_eq!(
+            factory.cached_shops(),
+            ["store-c.myshopify.com", "store-a.myshopify.com"]
+        );
+    }
+
+    #[test]
+    fn test_rest_clients_for_a_shop_share_its_rate_limiter() {
+        let factory = ClientFactory::new(create_config());
+        let shop_a = create_session("store-a");
+
+        let first = factory.rest(&shop_a).unwrap();
+        let second = factory.rest(&shop_a).unwrap();
+        let other_shop = factory.rest(&create_session("store-b")).unwrap();
+
+        assert!(first
+            .rate_limiter()
+            .shares_bucket_with(second.rate_limiter()));
+        assert!(first
+            .rate_limiter()
+            .shares_bucket_with(&factory.rate_limiter(&shop_a.shop)));
+        assert!(!first
+            .rate_limiter()
+            .shares_bucket_with(other_shop.rate_limiter()));
+    }
+
+    #[tokio::test]
+    async fn test_call_limit_observed_by_one_client_paces_the_shops_other_clients() {
+        use crate::clients::ApiCallLimit;
+        use std::time::Duration;
+
+        let factory = ClientFactory::new(create_config());
+        let shop_a = create_session("store-a");
+        let first = factory.rest(&shop_a).unwrap();
+        let second = factory.rest(&shop_a).unwrap();
+        let other_shop = factory.rest(&create_session("store-b")).unwrap();
+
+        // A full bucket reported to one client...
+        first.rate_limiter().observe(ApiCallLimit {
+            request_count: 1,
+            bucket_size: 1,
+        });
+
+        // ...makes the shop's other client wait for it to drain
+        let wait = Duration::from_millis(50);
+        assert!(tokio::time::timeout(wait, second.rate_limiter().acquire())
+            .await
+            .is_err());
+        assert!(
+            tokio::time::timeout(wait, other_shop.rate_limiter().acquire())
+                .await
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_clients_are_bound_to_session_and_config() {
+        let factory = ClientFactory::new(create_config());
+        let client = factory.graphql(&create_session("store-a"));
+
+        assert_eq!(client.api_version(), factory.config().api_version());
+    }
+}
@@ -0,0 +1,882 @@
+//! Bulk operation helpers for the GraphQL Admin API.
+//!
+//! Bulk mutations run a single mutation once per line of a JSONL variables
+//! file, asynchronously on Shopify's side. [`run_mutation`] stages the
+//! variables file and starts the operation, and [`wait_for_completion`]
+//! polls it until it finishes. [`current`] reports the shop's running
+//! operation and [`cancel`] stops one that is taking too long.
+//! [`BulkResultReader`] reads the JSONL results of a bulk query back into
+//! nested objects.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use std::time::Duration;
+//! use shopify_sdk::clients::graphql::bulk::{self, BulkOperationType};
+//!
+//! let jsonl = br#"{"input": {"title": "Hat"}}
+//! {"input": {"title": "Scarf"}}
+//! "#;
+//!
+//! let operation = client
+//!     .run_bulk_mutation(
+//!         "mutation call($input: ProductInput!) { productCreate(input: $input) { product { id } userErrors { field message } } }",
+//!         jsonl.to_vec(),
+//!     )
+//!     .await?;
+//!
+//! let finished = bulk::wait_for_completion(&client, &operation.id, Duration::from_secs(5)).await?;
+//! println!("Results: {:?}", finished.url);
+//!
+//! // Or give up on a mutation that is still running
+//! if let Some(running) = client.current_bulk_operation(BulkOperationType::Mutation).await? {
+//!     client.cancel_bulk_operation(&running.id).await?;
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::io::{BufRead, Lines};
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{json, Map, Value};
+
+use super::payload::{parse_response, root_payload};
+use super::staged_uploads::{self, StagedUploadInput, StagedUploadResource};
+use super::{GraphqlClient, GraphqlError};
+
+/// Mutation used by [`run_mutation`].
+const RUN_MUTATION_MUTATION: &str = r"
+mutation bulkOperationRunMutation($mutation: String!, $stagedUploadPath: String!) {
+    bulkOperationRunMutation(mutation: $mutation, stagedUploadPath: $stagedUploadPath) {
+        bulkOperation {
+            id
+            status
+            errorCode
+            objectCount
+            url
+            partialDataUrl
+        }
+        userErrors {
+            field
+            message
+        }
+    }
+}
+";
+
+/// Query used by [`operation`].
+const OPERATION_QUERY: &str = r"
+query bulkOperation($id: ID!) {
+    bulkOperation(id: $id) {
+        id
+        status
+        errorCode
+        objectCount
+        url
+        partialDataUrl
+    }
+}
+";
+
+/// Mutation used by [`cancel`].
+const CANCEL_MUTATION: &str = r"
+mutation bulkOperationCancel($id: ID!) {
+    bulkOperationCancel(id: $id) {
+        bulkOperation {
+            id
+            status
+            errorCode
+            objectCount
+            url
+            partialDataUrl
+        }
+        userErrors {
+            field
+            message
+        }
+    }
+}
+";
+
+/// Query used by [`current`].
+const CURRENT_OPERATION_QUERY: &str = r"
+query currentBulkOperation($type: BulkOperationType!) {
+    currentBulkOperation(type: $type) {
+        id
+        status
+        errorCode
+        objectCount
+        url
+        partialDataUrl
+    }
+}
+";
+
+/// File name used when staging bulk mutation variables.
+const VARIABLES_FILENAME: &str = "bulk_mutation_variables.jsonl";
+
+/// MIME type used when staging bulk mutation variables.
+const VARIABLES_MIME_TYPE: &str = "text/jsonl";
+
+/// Key linking a bulk result line to its parent.
+const PARENT_ID_KEY: &str = "__parentId";
+
+/// Key children are nested under when their type is unknown.
+const FALLBACK_CHILDREN_KEY: &str = "__children";
+
+/// The status of a bulk operation.
+///
+/// Deserializes from Shopify's `BulkOperationStatus` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BulkOperationStatus {
+    /// The operation has been created but not started.
+    Created,
+    /// The operation is running.
+    Running,
+    /// The operation finished successfully.
+    Completed,
+    /// The operation is being canceled.
+    Canceling,
+    /// The operation was canceled.
+    Canceled,
+    /// The operation failed.
+    Failed,
+    /// The operation's results are no longer available.
+    Expired,
+}
+
+impl BulkOperationStatus {
+    /// Returns `true` if the operation will not change status again.
+    #[must_use]
+    pub const fn is_finished(self) -> bool {
+        matches!(
+            self,
+            Self::Completed | Self::Canceled | Self::Failed | Self::Expired
+        )
+    }
+}
+
+impl fmt::Display for BulkOperationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Self::Created => "CREATED",
+            Self::Running => "RUNNING",
+            Self::Completed => "COMPLETED",
+            Self::Canceling => "CANCELING",
+            Self::Canceled => "CANCELED",
+            Self::Failed => "FAILED",
+            Self::Expired => "EXPIRED",
+        };
+        f.write_str(status)
+    }
+}
+
+/// The kind of bulk operation.
+///
+/// A shop can run one bulk query and one bulk mutation at the same time, so
+/// [`current`] needs to know which one to report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BulkOperationType {
+    /// A bulk query, started with `bulkOperationRunQuery`.
+    Query,
+    /// A bulk mutation, started with [`run_mutation`].
+    Mutation,
+}
+
+/// A bulk operation and its progress.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkOperation {
+    /// The operation GID (e.g. `gid://shopify/BulkOperation/1`).
+    pub id: String,
+    /// The current status.
+    pub status: BulkOperationStatus,
+    /// Why the operation failed, if it did (e.g. `"INTERNAL_SERVER_ERROR"`).
+    #[serde(default)]
+    pub error_code: Option<String>,
+    /// The number of objects processed so far.
+    #[serde(default, deserialize_with = "deserialize_object_count")]
+    pub object_count: Option<u64>,
+    /// The URL of the JSONL results, once completed.
+    #[serde(default)]
+    pub url: Option<String>,
+    /// The URL of partial results, if the operation failed part way.
+    #[serde(default)]
+    pub partial_data_url: Option<String>,
+}
+
+/// Reads `objectCount`, which Shopify sends as an `UnsignedInt64` string.
+fn deserialize_object_count<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<Value>::deserialize(deserializer)? {
+        Some(Value::String(s)) => s.parse().map(Some).map_err(serde::de::Error::custom),
+        Some(Value::Number(n)) => Ok(n.as_u64()),
+        _ => Ok(None),
+    }
+}
+
+/// Stages a JSONL variables file and starts a bulk mutation.
+///
+/// Each line of `variables_jsonl` is a JSON object holding the variables for
+/// one run of `mutation`. The file is uploaded with
+/// [`staged_uploads`] and its staged path passed to
+/// `bulkOperationRunMutation`. The returned operation is usually still
+/// [`Created`](BulkOperationStatus::Created); use [`wait_for_completion`]
+/// to wait for it to finish.
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors, including a failed upload.
+/// Returns [`GraphqlError::UserErrors`] if Shopify rejects the upload or the
+/// mutation (for example, when another bulk mutation is already running).
+/// Returns [`GraphqlError::MissingData`] if a response has no payload.
+pub async fn run_mutation(
+    client: &GraphqlClient,
+    mutation: &str,
+    variables_jsonl: Vec<u8>,
+) -> Result<BulkOperation, GraphqlError> {
+    let input = StagedUploadInput::new(
+        StagedUploadResource::BulkMutationVariables,
+        VARIABLES_FILENAME,
+        VARIABLES_MIME_TYPE,
+    );
+    let target = staged_uploads::create(client, vec![input.clone()])
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| GraphqlError::MissingData {
+            field: "data.stagedUploadsCreate.stagedTargets".to_string(),
+            request_id: None,
+        })?;
+    let staged_upload_path = target
+        .staged_upload_path()
+        .ok_or_else(|| GraphqlError::MissingData {
+            field: "data.stagedUploadsCreate.stagedTargets.parameters.key".to_string(),
+            request_id: None,
+        })?
+        .to_string();
+
+    staged_uploads::upload(&target, &input, variables_jsonl).await?;
+
+    let variables = run_mutation_variables(mutation, &staged_upload_path);
+    let response = client
+        .query(RUN_MUTATION_MUTATION, Some(variables), None, None)
+        .await?;
+
+    parse_operation(
+        &parse_response(&response, |body| {
+            root_payload(body, "bulkOperationRunMutation")
+        })?["bulkOperation"],
+        "data.bulkOperationRunMutation.bulkOperation",
+    )
+}
+
+/// Fetches the current state of a bulk operation.
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if the query is rejected.
+/// Returns [`GraphqlError::MissingData`] if the operation is not found.
+pub async fn operation(client: &GraphqlClient, id: &str) -> Result<BulkOperation, GraphqlError> {
+    let response = client
+        .query(OPERATION_QUERY, Some(json!({ "id": id })), None, None)
+        .await?;
+
+    parse_operation(
+        parse_response(&response, |body| root_payload(body, "bulkOperation"))?,
+        "data.bulkOperation",
+    )
+}
+
+/// Fetches the shop's most recent bulk operation of the given type.
+///
+/// Returns `None` if the shop has never run a bulk operation of that type.
+/// The operation may already be finished; check
+/// [`BulkOperationStatus::is_finished`].
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if the query is rejected.
+/// Returns [`GraphqlError::MissingData`] if the response cannot be parsed.
+pub async fn current(
+    client: &GraphqlClient,
+    operation_type: BulkOperationType,
+) -> Result<Option<BulkOperation>, GraphqlError> {
+    let response = client
+        .query(
+            CURRENT_OPERATION_QUERY,
+            Some(json!({ "type": operation_type })),
+            None,
+            None,
+        )
+        .await?;
+
+    parse_response(&response, parse_current_operation)
+}
+
+/// Cancels a running bulk operation.
+///
+/// Wraps the `bulkOperationCancel` mutation. Cancellation is asynchronous:
+/// the returned operation is usually [`Canceling`](BulkOperationStatus::Canceling)
+/// and becomes [`Canceled`](BulkOperationStatus::Canceled) shortly after.
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if Shopify refuses to cancel the
+/// operation (for example, because it has already finished).
+/// Returns [`GraphqlError::MissingData`] if the response cannot be parsed.
+pub async fn cancel(client: &GraphqlClient, id: &str) -> Result<BulkOperation, GraphqlError> {
+    let response = client
+        .query(CANCEL_MUTATION, Some(json!({ "id": id })), None, None)
+        .await?;
+
+    parse_operation(
+        &parse_response(&response, |body| root_payload(body, "bulkOperationCancel"))?
+            ["bulkOperation"],
+        "data.bulkOperationCancel.bulkOperation",
+    )
+}
+
+/// Polls a bulk operation every `interval` until it finishes.
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::BulkOperationFailed`] if the operation ends in any
+/// status other than [`Completed`](BulkOperationStatus::Completed).
+/// Returns any error from [`operation`].
+pub async fn wait_for_completion(
+    client: &GraphqlClient,
+    id: &str,
+    interval: Duration,
+) -> Result<BulkOperation, GraphqlError> {
+    poll_until_finished(interval, || operation(client, id)).await
+}
+
+/// Fetches an operation through `fetch` until it finishes.
+async fn poll_until_finished<F, Fut>(
+    interval: Duration,
+    mut fetch: F,
+) -> Result<BulkOperation, GraphqlError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<BulkOperation, GraphqlError>>,
+{
+    loop {
+        let operation = fetch().await?;
+        match operation.status {
+            BulkOperationStatus::Completed => return Ok(operation),
+            status if status.is_finished() => {
+                return Err(GraphqlError::BulkOperationFailed {
+                    id: operation.id,
+                    status,
+                    error_code: operation.error_code,
+                    partial_data_url: operation.partial_data_url,
+                })
+            }
+            status => {
+                tracing::debug!("Bulk operation {} is {status}", operation.id);
+                tokio::time::sleep(interval).await;
+            }
+        }
+    }
+}
+
+/// Reads the JSONL results of a bulk query, nesting child lines under their
+/// parents.
+///
+/// Bulk query results are flattened: every node is written on its own line,
+/// and nodes from a nested connection carry a `__parentId` naming the object
+/// they belong to. Shopify writes a parent before its children, so the
+/// reader assembles one top-level object at a time and yields it once the
+/// next top-level line starts (or the input ends).
+///
+/// Children are collected into an array on their parent, keyed by their
+/// type: the child's `__typename` if the query selected it, otherwise the
+/// type in its GID (`ProductVariant` for `gid://shopify/ProductVariant/1`),
+/// otherwise `__children`. The `__parentId` key is removed, and children of
+/// children are nested the same way.
+///
+/// Blank lines are skipped. A line that isn't a JSON object, or whose parent
+/// hasn't been seen, is reported as
+/// [`GraphqlError::InvalidBulkResultLine`]; reading continues with the next
+/// line, so callers can log the error and carry on.
+///
+/// # Example
+///
+/// ```rust
+/// use shopify_sdk::clients::graphql::bulk::BulkResultReader;
+///
+/// let jsonl = r#"{"id":"gid://shopify/Product/1","title":"Hat"}
+/// {"id":"gid://shopify/ProductVariant/11","sku":"HAT-S","__parentId":"gid://shopify/Product/1"}
+/// "#;
+///
+/// let products = BulkResultReader::new(jsonl.as_bytes())
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+///
+/// assert_eq!(products[0]["title"], "Hat");
+/// assert_eq!(products[0]["ProductVariant"][0]["sku"], "HAT-S");
+/// ```
+#[derive(Debug)]
+pub struct BulkResultReader<R> {
+    /// The remaining input lines.
+    lines: Lines<R>,
+    /// The number of the last line read, starting at 1.
+    line: usize,
+    /// The top-level object being assembled.
+    current: Option<BulkResultObject>,
+}
+
+impl<R: BufRead> BulkResultReader<R> {
+    /// Creates a reader over JSONL bulk results.
+    ///
+    /// Pass the downloaded results as bytes (`&[u8]` implements `BufRead`),
+    /// or wrap a file in a [`BufReader`](std::io::BufReader) to avoid
+    /// loading large results into memory.
+    pub fn new(reader: R) -> Self {
+        Self {
+            lines: reader.lines(),
+            line: 0,
+            current: None,
+        }
+    }
+
+    /// Adds a line to the current object, returning the previous object if
+    /// the line starts a new one.
+    fn push_line(&mut self, text: &str) -> Result<Option<Value>, GraphqlError> {
+        let mut object = match serde_json::from_str(text) {
+            Ok(Value::Object(object)) => object,
+            Ok(_) => return Err(self.invalid_line("expected a JSON object")),
+            Err(e) => return Err(self.invalid_line(e.to_string())),
+        };
+
+        let Some(parent_id) = object.remove(PARENT_ID_KEY) else {
+            let next = BulkResultObject::new(Value::Object(object));
+            return Ok(self.current.replace(next).map(|current| current.root));
+        };
+
+        let parent_id = parent_id.as_str().unwrap_or_default();
+        let attached = self.current.as_mut().map_or_else(
+            || Err(format!("parent {parent_id} not found")),
+            |current| current.attach(parent_id, object),
+        );
+        attached
+            .map(|()| None)
+            .map_err(|reason| self.invalid_line(reason))
+    }
+
+    /// Builds the error for the last line read.
+    fn invalid_line(&self, reason: impl Into<String>) -> GraphqlError {
+        GraphqlError::InvalidBulkResultLine {
+            line: self.line,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for BulkResultReader<R> {
+    type Item = Result<Value, GraphqlError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some(line) = self.lines.next() else {
+                return self.current.take().map(|current| Ok(current.root));
+            };
+            self.line += 1;
+
+            let text = match line {
+                Ok(text) => text,
+                Err(e) => return Some(Err(self.invalid_line(e.to_string()))),
+            };
+            if text.trim().is_empty() {
+                continue;
+            }
+
+            match self.push_line(&text) {
+                Ok(Some(finished)) => return Some(Ok(finished)),
+                Ok(None) => {}
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+/// A top-level bulk result object and the location of every node in it.
+#[derive(Debug)]
+struct BulkResultObject {
+    /// The assembled object.
+    root: Value,
+    /// Each node's path from the root, as `(children key, index)` steps,
+    /// keyed by node ID.
+    paths: HashMap<String, Vec<(String, usize)>>,
+}
+
+impl BulkResultObject {
+    /// Starts assembling a top-level object.
+    fn new(root: Value) -> Self {
+        let mut paths = HashMap::new();
+        if let Some(id) = root["id"].as_str() {
+            paths.insert(id.to_string(), Vec::new());
+        }
+        Self { root, paths }
+    }
+
+    /// Nests `child` under the node with ID `parent_id`.
+    fn attach(&mut self, parent_id: &str, child: Map<String, Value>) -> Result<(), String> {
+        let Some(mut path) = self.paths.get(parent_id).cloned() else {
+            return Err(format!("parent {parent_id} not found"));
+        };
+
+        let key = child_key(&child);
+        let child_id = child.get("id").and_then(Value::as_str).map(String::from);
+        let parent = path.iter().fold(&mut self.root, |node, (key, index)| {
+            &mut node[key.as_str()][*index]
+        });
+        let Some(Value::Array(children)) = parent
+            .as_object_mut()
+            .map(|parent| parent.entry(key.clone()).or_insert_with(|| json!([])))
+        else {
+            return Err(format!("parent {parent_id} already has a `{key}` field"));
+        };
+
+        children.push(Value::Object(child));
+        if let Some(id) = child_id {
+            path.push((key, children.len() - 1));
+            self.paths.insert(id, path);
+        }
+        Ok(())
+    }
+}
+
+/// Returns the key a child line is nested under on its parent.
+fn child_key(child: &Map<String, Value>) -> String {
+    child
+        .get("__typename")
+        .and_then(Value::as_str)
+        .or_else(|| {
+            child
+                .get("id")
+                .and_then(Value::as_str)
+                .and_then(|id| id.strip_prefix("gid://shopify/"))
+                .and_then(|rest| rest.split('/').next())
+        })
+        .unwrap_or(FALLBACK_CHILDREN_KEY)
+        .to_string()
+}
+
+/// Builds the variables for the `bulkOperationRunMutation` mutation.
+fn run_mutation_variables(mutation: &str, staged_upload_path: &str) -> Value {
+    json!({
+        "mutation": mutation,
+        "stagedUploadPath": staged_upload_path,
+    })
+}
+
+/// Parses a `currentBulkOperation` response, which is `null` when the shop
+/// has no bulk operation of the requested type.
+fn parse_current_operation(body: &Value) -> Result<Option<BulkOperation>, GraphqlError> {
+    let has_errors = body["errors"].as_array().is_some_and(|e| !e.is_empty());
+    if body["data"]["currentBulkOperation"].is_null() && !has_errors {
+        return Ok(None);
+    }
+
+    parse_operation(
+        root_payload(body, "currentBulkOperation")?,
+        "data.currentBulkOperation",
+    )
+    .map(Some)
+}
+
+/// Parses a bulk operation, reporting `path` if it is missing or malformed.
+fn parse_operation(value: &Value, path: &str) -> Result<BulkOperation, GraphqlError> {
+    serde_json::from_value(value.clone()).map_err(|_| GraphqlError::MissingData {
+        field: path.to_string(),
+        request_id: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MUTATION: &str =
+        "mutation call($input: ProductInput!) { productCreate(input: $input) { product { id } } }";
+
+    fn operation_with_status(status: BulkOperationStatus) -> BulkOperation {
+        BulkOperation {
+            id: "gid://shopify/BulkOperation/1".to_string(),
+            status,
+            error_code: None,
+            object_count: None,
+            url: None,
+            partial_data_url: None,
+        }
+    }
+
+    #[test]
+    fn test_run_mutation_references_staged_upload_path() {
+        assert!(RUN_MUTATION_MUTATION.contains(
+            "bulkOperationRunMutation(mutation: $mutation, stagedUploadPath: $stagedUploadPath)"
+        ));
+
+        let variables = run_mutation_variables(MUTATION, "tmp/123/bulk/variables.jsonl");
+
+        assert_eq!(
+            variables,
+            json!({
+                "mutation": MUTATION,
+                "stagedUploadPath": "tmp/123/bulk/variables.jsonl"
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_run_mutation_response() {
+        let body = json!({
+            "data": {
+                "bulkOperationRunMutation": {
+                    "bulkOperation": {
+                        "id": "gid://shopify/BulkOperation/1",
+                        "status": "CREATED",
+                        "errorCode": null,
+                        "objectCount": "0",
+                        "url": null,
+                        "partialDataUrl": null
+                    },
+                    "userErrors": []
+                }
+            }
+        });
+
+        let payload = root_payload(&body, "bulkOperationRunMutation").unwrap();
+        let operation = parse_operation(&payload["bulkOperation"], "path").unwrap();
+
+        assert_eq!(operation.status, BulkOperationStatus::Created);
+        assert_eq!(operation.object_count, Some(0));
+    }
+
+    #[tokio::test]
+    async fn test_failed_status_surfaces_bulk_operation_failed() {
+        let mut statuses =
+            vec![BulkOperationStatus::Running, BulkOperationStatus::Failed].into_iter();
+
+        let result = poll_until_finished(Duration::ZERO, || {
+            let mut operation = operation_with_status(statuses.next().unwrap());
+            if operation.status == BulkOperationStatus::Failed {
+                operation.error_code = Some("INTERNAL_SERVER_ERROR".to_string());
+            }
+            async move { Ok(operation) }
+        })
+        .await;
+
+        match result {
+            Err(GraphqlError::BulkOperationFailed {
+                status, error_code, ..
+            }) => {
+                assert_eq!(status, BulkOperationStatus::Failed);
+                assert_eq!(error_code.as_deref(), Some("INTERNAL_SERVER_ERROR"));
+            }
+            other => panic!("expected BulkOperationFailed, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_poll_returns_completed_operation() {
+        let mut statuses = vec![
+            BulkOperationStatus::Created,
+            BulkOperationStatus::Running,
+            BulkOperationStatus::Completed,
+        ]
+        .into_iter();
+        let mut polls = 0;
+
+        let operation = poll_until_finished(Duration::ZERO, || {
+            polls += 1;
+            let operation = operation_with_status(statuses.next().unwrap());
+            async move { Ok(operation) }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(operation.status, BulkOperationStatus::Completed);
+        assert_eq!(polls, 3);
+    }
+
+    #[test]
+    fn test_parse_cancel_response() {
+        assert!(CANCEL_MUTATION.contains("bulkOperationCancel(id: $id)"));
+
+        let body = json!({
+            "data": {
+                "bulkOperationCancel": {
+                    "bulkOperation": {
+                        "id": "gid://shopify/BulkOperation/1",
+                        "status": "CANCELING",
+                        "errorCode": null,
+                        "objectCount": "1200",
+                        "url": null,
+                        "partialDataUrl": null
+                    },
+                    "userErrors": []
+                }
+            }
+        });
+
+        let payload = root_payload(&body, "bulkOperationCancel").unwrap();
+        let operation = parse_operation(&payload["bulkOperation"], "path").unwrap();
+
+        assert_eq!(operation.status, BulkOperationStatus::Canceling);
+        assert_eq!(operation.object_count, Some(1200));
+    }
+
+    #[test]
+    fn test_cancel_surfaces_user_errors() {
+        let body = json!({
+            "data": {
+                "bulkOperationCancel": {
+                    "bulkOperation": null,
+                    "userErrors": [{
+                        "field": ["id"],
+                        "message": "A bulk operation cannot be canceled when it is completed"
+                    }]
+                }
+            }
+        });
+
+        assert!(matches!(
+            root_payload(&body, "bulkOperationCancel"),
+            Err(GraphqlError::UserErrors { .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_running_current_operation() {
+        let body = json!({
+            "data": {
+                "currentBulkOperation": {
+                    "id": "gid://shopify/BulkOperation/2",
+                    "status": "RUNNING",
+                    "errorCode": null,
+                    "objectCount": "5348",
+                    "url": null,
+                    "partialDataUrl": null
+                }
+            }
+        });
+
+        let operation = parse_current_operation(&body).unwrap().unwrap();
+
+        assert_eq!(operation.id, "gid://shopify/BulkOperation/2");
+        assert_eq!(operation.status, BulkOperationStatus::Running);
+        assert_eq!(operation.object_count, Some(5348));
+        assert_eq!(operation.url, None);
+    }
+
+    #[test]
+    fn test_parse_current_operation_when_none_has_run() {
+        let body = json!({ "data": { "currentBulkOperation": null } });
+
+        assert_eq!(parse_current_operation(&body).unwrap(), None);
+        assert_eq!(
+            json!({ "type": BulkOperationType::Mutation }),
+            json!({ "type": "MUTATION" })
+        );
+    }
+
+    #[test]
+    fn test_status_is_finished() {
+        assert!(!BulkOperationStatus::Running.is_finished());
+        assert!(!BulkOperationStatus::Canceling.is_finished());
+        assert!(BulkOperationStatus::Completed.is_finished());
+        assert!(BulkOperationStatus::Expired.is_finished());
+        assert_eq!(BulkOperationStatus::Canceled.to_string(), "CANCELED");
+    }
+
+    #[test]
+    fn test_bulk_result_reader_nests_variants_under_products() {
+        let jsonl = r#"{"id":"gid://shopify/Product/1","title":"Hat"}
+{"id":"gid://shopify/ProductVariant/11","sku":"HAT-S","__parentId":"gid://shopify/Product/1"}
+{"id":"gid://shopify/ProductVariant/12","sku":"HAT-L","__parentId":"gid://shopify/Product/1"}
+{"id":"gid://shopify/Product/2","title":"Scarf"}
+{"id":"gid://shopify/ProductVariant/21","sku":"SCARF","__parentId":"gid://shopify/Product/2"}
+{"id":"gid://shopify/Product/3","title":"Gloves"}
+"#;
+
+        let products = BulkResultReader::new(jsonl.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(products.len(), 3);
+        assert_eq!(
+            products[0],
+            json!({
+                "id": "gid://shopify/Product/1",
+                "title": "Hat",
+                "ProductVariant": [
+                    { "id": "gid://shopify/ProductVariant/11", "sku": "HAT-S" },
+                    { "id": "gid://shopify/ProductVariant/12", "sku": "HAT-L" }
+                ]
+            })
+        );
+        assert_eq!(products[1]["ProductVariant"][0]["sku"], "SCARF");
+        assert!(products[2].get("ProductVariant").is_none());
+    }
+
+    #[test]
+    fn test_bulk_result_reader_nests_grandchildren_by_typename() {
+        let jsonl = r#"{"id":"gid://shopify/Product/1"}
+{"id":"gid://shopify/ProductVariant/11","__typename":"ProductVariant","__parentId":"gid://shopify/Product/1"}
+{"namespace":"custom","key":"size","__typename":"Metafield","__parentId":"gid://shopify/ProductVariant/11"}
+{"alt":"Front","__parentId":"gid://shopify/Product/1"}
+"#;
+
+        let products = BulkResultReader::new(jsonl.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        let variant = &products[0]["ProductVariant"][0];
+        assert_eq!(variant["Metafield"][0]["key"], "size");
+        assert_eq!(products[0]["__children"][0]["alt"], "Front");
+    }
+
+    #[test]
+    fn test_bulk_result_reader_skips_blank_and_reports_invalid_lines() {
+        let jsonl = "{\"id\":\"gid://shopify/Product/1\"}\r\n\n\
+                     not json\n\
+                     {\"id\":\"gid://shopify/ProductVariant/9\",\"__parentId\":\"gid://shopify/Product/7\"}\n\
+                     {\"id\":\"gid://shopify/Product/2\"}\n";
+
+        let results: Vec<_> = BulkResultReader::new(jsonl.as_bytes()).collect();
+
+        assert_eq!(results.len(), 4);
+        assert!(matches!(
+            results[0],
+            Err(GraphqlError::InvalidBulkResultLine { line: 3, .. })
+        ));
+        match &results[1] {
+            Err(GraphqlError::InvalidBulkResultLine { line, reason }) => {
+                assert_eq!(*line, 4);
+                assert!(reason.contains("gid://shopify/Product/7"));
+            }
+            other => panic!("expected an invalid line, got {other:?}"),
+        }
+        assert_eq!(
+            results[2].as_ref().unwrap(),
+            &json!({ "id": "gid://shopify/Product/1" })
+        );
+        assert_eq!(
+            results[3].as_ref().unwrap()["id"],
+            "gid://shopify/Product/2"
+        );
+    }
+}
@@ -5,10 +5,18 @@
 
 use std::collections::HashMap;
 
-use crate::auth::Session;
-use crate::clients::graphql::GraphqlError;
-use crate::clients::{DataType, HttpClient, HttpMethod, HttpRequest, HttpResponse};
-use crate::config::{ApiVersion, ShopifyConfig};
+use crate::auth::{ScopeDrift, Session};
+use crate::clients::graphql::bulk::{self, BulkOperation, BulkOperationType};
+use crate::clients::graphql::cost_budget::{CostBudget, CostReservation};
+use crate::clients::graphql::payload::{reject_incremental_delivery, root_payload};
+use crate::clients::graphql::{idempotent, persisted, GraphqlError};
+use crate::clients::http_client::RETRY_WAIT_TIME;
+use crate::clients::http_client::{build_reqwest_client, check_scope_drift};
+use crate::clients::{
+    DataType, HttpClient, HttpError, HttpMethod, HttpRequest, HttpResponse, RateLimitSignal,
+    RateLimiter,
+};
+use crate::config::{ApiVersion, ShopDomain, ShopifyConfig};
 
 /// GraphQL API client for Shopify Admin API.
 ///
@@ -58,6 +66,14 @@ pub struct GraphqlClient {
     http_client: HttpClient,
     /// The API version being used.
     api_version: ApiVersion,
+    /// The shop requests are made against, used to key the cost budget.
+    shop: ShopDomain,
+    /// Optional cap on query cost consumed for the shop.
+    cost_budget: Option<CostBudget>,
+    /// Optional request pacing from the call limit header.
+    rate_limiter: Option<RateLimiter>,
+    /// How the session's scopes differ from the configured scopes, if checked.
+    scope_drift: Option<ScopeDrift>,
 }
 
 // Verify GraphqlClient is Send + Sync at compile time
@@ -100,7 +116,7 @@ impl GraphqlClient {
     #[must_use]
     pub fn new(session: &Session, config: Option<&ShopifyConfig>) -> Self {
         let api_version = config.map_or_else(ApiVersion::latest, |c| c.api_version().clone());
-        Self::create_client(session, config, api_version)
+        Self::create_client(session, config, api_version, build_reqwest_client(config))
     }
 
     /// Creates a new GraphQL client with a specific API version override.
@@ -154,7 +170,19 @@ impl GraphqlClient {
             }
         }
 
-        Self::create_client(session, config, version)
+        Self::create_client(session, config, version, build_reqwest_client(config))
+    }
+
+    /// Creates a GraphQL client for the configured API version that shares
+    /// an existing reqwest client, as used by
+    /// [`ClientFactory`](crate::clients::ClientFactory).
+    pub(crate) fn with_reqwest_client(
+        session: &Session,
+        config: Option<&ShopifyConfig>,
+        reqwest_client: reqwest::Client,
+    ) -> Self {
+        let api_version = config.map_or_else(ApiVersion::latest, |c| c.api_version().clone());
+        Self::create_client(session, config, api_version, reqwest_client)
     }
 
     /// Internal helper to create the client with shared logic.
@@ -162,25 +190,110 @@ impl GraphqlClient {
         session: &Session,
         config: Option<&ShopifyConfig>,
         api_version: ApiVersion,
+        reqwest_client: reqwest::Client,
     ) -> Self {
         // Construct base path: /admin/api/{version}
         let base_path = format!("/admin/api/{api_version}");
 
         // Create internal HTTP client
-        let http_client = HttpClient::new(base_path, session, config);
+        let http_client =
+            HttpClient::with_reqwest_client(base_path, session, config, reqwest_client);
 
         Self {
             http_client,
             api_version,
+            shop: session.shop.clone(),
+            cost_budget: None,
+            rate_limiter: None,
+            scope_drift: check_scope_drift(session, config),
         }
     }
 
+    /// Caps the query cost this client consumes for its shop.
+    ///
+    /// Before each request the client waits until the shop's expected cost
+    /// fits within the budget's window and reserves it, then settles the
+    /// reservation with the cost the response reports. Share one budget
+    /// between clients by cloning it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use shopify_sdk::clients::graphql::CostBudget;
+    ///
+    /// let budget = CostBudget::per_minute(500);
+    /// let client = GraphqlClient::new(&session, None).with_cost_budget(budget.clone());
+    /// ```
+    #[must_use]
+    pub fn with_cost_budget(mut self, cost_budget: CostBudget) -> Self {
+        self.cost_budget = Some(cost_budget);
+        self
+    }
+
+    /// Creates a client for the test shop whose requests go to the mock
+    /// server at `server_uri` instead.
+    #[cfg(test)]
+    #[must_use]
+    pub fn for_mock_server(server_uri: &str) -> Self {
+        let session = Session::new(
+            "test-session".to_string(),
+            ShopDomain::new("test-shop").unwrap(),
+            "test-access-token".to_string(),
+            crate::auth::AuthScopes::new(),
+            false,
+            None,
+        );
+        let server_url = reqwest::Url::parse(server_uri).unwrap();
+        let mut client = Self::new(&session, None);
+        client.http_client = client.http_client.with_url_rewriter(move |url| {
+            url.set_scheme("http").unwrap();
+            url.set_host(server_url.host_str()).unwrap();
+            url.set_port(server_url.port()).unwrap();
+        });
+        client
+    }
+
+    /// Returns the client's cost budget, if one was set.
+    #[must_use]
+    pub const fn cost_budget(&self) -> Option<&CostBudget> {
+        self.cost_budget.as_ref()
+    }
+
+    /// Paces requests using the `X-Shopify-Shop-Api-Call-Limit` header.
+    ///
+    /// Some responses carry no `extensions.cost`, which leaves a
+    /// [`CostBudget`] nothing to act on. When such a response has the call
+    /// limit header, the limiter observes it, and the client acquires from
+    /// the limiter before each request, as [`RestClient`](crate::clients::RestClient)
+    /// does. Read the signal a response carried with
+    /// [`HttpResponse::rate_limit_signal`].
+    #[must_use]
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Returns the client's rate limiter, if one was set.
+    #[must_use]
+    pub const fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_ref()
+    }
+
     /// Returns the API version being used by this client.
     #[must_use]
     pub const fn api_version(&self) -> &ApiVersion {
         &self.api_version
     }
 
+    /// Returns how the session's scopes differ from the configured scopes.
+    ///
+    /// Only checked when [`ShopifyConfigBuilder::check_scope_drift`](crate::ShopifyConfigBuilder::check_scope_drift)
+    /// is enabled; `None` otherwise or if the scopes match.
+    #[must_use]
+    pub const fn scope_drift(&self) -> Option<&ScopeDrift> {
+        self.scope_drift.as_ref()
+    }
+
     /// Executes a GraphQL query against the Admin API.
     ///
     /// This method sends a POST request to the `graphql.json` endpoint with
@@ -204,6 +317,9 @@ impl GraphqlClient {
     ///
     /// Returns [`GraphqlError::Http`] for HTTP-level errors (network errors,
     /// non-2xx responses, retry exhaustion).
+    /// Returns [`GraphqlError::InvalidDocument`] without sending the request
+    /// if the `graphql-validate` feature is enabled and the document is
+    /// malformed.
     ///
     /// Note that GraphQL-level errors (user errors, validation errors) are
     /// returned with HTTP 200 status and contained in `response.body["errors"]`.
@@ -299,6 +415,184 @@ impl GraphqlClient {
             .await
     }
 
+    /// Executes a GraphQL query as an automatic persisted query (APQ).
+    ///
+    /// The first request sends only the query's SHA-256 hash. If the server
+    /// has not seen the hash (`PersistedQueryNotFound`) or does not support
+    /// persisted queries (`PersistedQueryNotSupported`), the request is sent
+    /// again with the full query and the hash so it can be registered. Later
+    /// calls with the same query then only send the hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The GraphQL query string
+    /// * `variables` - Optional variables for the query
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphqlError::Http`] for HTTP-level errors.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use serde_json::json;
+    ///
+    /// let response = client.query_persisted(
+    ///     "query GetProduct($id: ID!) { product(id: $id) { title } }",
+    ///     Some(json!({ "id": "gid://shopify/Product/123" })),
+    /// ).await?;
+    /// ```
+    pub async fn query_persisted(
+        &self,
+        query: &str,
+        variables: Option<serde_json::Value>,
+    ) -> Result<HttpResponse, GraphqlError> {
+        persisted::execute(query, variables, |body| {
+            self.send_body(body, None, None, false)
+        })
+        .await
+    }
+
+    /// Executes a mutation, retrying only when it is safe to do so.
+    ///
+    /// Retrying a mutation after a timeout risks applying it twice, because
+    /// the server may have processed the first request. This method only
+    /// retries when the connection could not be established, i.e. the
+    /// request never left the client. Timeouts, dropped connections and any
+    /// HTTP response (including 429 and 5xx) are returned without retrying.
+    ///
+    /// If `idempotency_key` is given, it is added to the variables as
+    /// `idempotencyKey`, so mutations that accept one can declare
+    /// `$idempotencyKey: String!` and pass it to the relevant input field or
+    /// `@idempotent(key:)` directive.
+    ///
+    /// # Arguments
+    ///
+    /// * `mutation` - The GraphQL mutation string
+    /// * `variables` - Optional variables for the mutation
+    /// * `idempotency_key` - Optional idempotency key to add to the variables
+    /// * `max_attempts` - Maximum number of attempts, including the first
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphqlError::Http`] for HTTP-level errors, including the
+    /// last connection error once `max_attempts` is reached.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use serde_json::json;
+    ///
+    /// let response = client.mutate_idempotent(
+    ///     r#"mutation Adjust($input: InventoryAdjustQuantitiesInput!, $idempotencyKey: String!) {
+    ///         inventoryAdjustQuantities(input: $input) @idempotent(key: $idempotencyKey) {
+    ///             userErrors { field message }
+    ///         }
+    ///     }"#,
+    ///     Some(json!({ "input": input })),
+    ///     Some("adjust-order-1001"),
+    ///     3,
+    /// ).await?;
+    /// ```
+    pub async fn mutate_idempotent(
+        &self,
+        mutation: &str,
+        variables: Option<serde_json::Value>,
+        idempotency_key: Option<&str>,
+        max_attempts: u32,
+    ) -> Result<HttpResponse, GraphqlError> {
+        let variables = idempotent::with_idempotency_key(variables, idempotency_key);
+        idempotent::execute(
+            max_attempts,
+            std::time::Duration::from_secs(RETRY_WAIT_TIME),
+            || self.query(mutation, variables.clone(), None, None),
+        )
+        .await
+    }
+
+    /// Stages a JSONL variables file and starts a bulk mutation.
+    ///
+    /// Convenience wrapper around [`bulk::run_mutation`]; see there for
+    /// details. Poll the returned operation with
+    /// [`bulk::wait_for_completion`].
+    ///
+    /// # Arguments
+    ///
+    /// * `mutation` - The mutation to run once per line of variables
+    /// * `variables_jsonl` - JSONL contents, one variables object per line
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphqlError::Http`] for HTTP-level errors, including a failed upload.
+    /// Returns [`GraphqlError::UserErrors`] if Shopify rejects the upload or
+    /// the mutation.
+    /// Returns [`GraphqlError::MissingData`] if a response has no payload.
+    pub async fn run_bulk_mutation(
+        &self,
+        mutation: &str,
+        variables_jsonl: Vec<u8>,
+    ) -> Result<BulkOperation, GraphqlError> {
+        bulk::run_mutation(self, mutation, variables_jsonl).await
+    }
+
+    /// Fetches the shop's most recent bulk operation of the given type.
+    ///
+    /// Convenience wrapper around [`bulk::current`]. Returns `None` if the
+    /// shop has never run a bulk operation of that type.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphqlError::Http`] for HTTP-level errors.
+    /// Returns [`GraphqlError::UserErrors`] if the query is rejected.
+    /// Returns [`GraphqlError::MissingData`] if the response cannot be parsed.
+    pub async fn current_bulk_operation(
+        &self,
+        operation_type: BulkOperationType,
+    ) -> Result<Option<BulkOperation>, GraphqlError> {
+        bulk::current(self, operation_type).await
+    }
+
+    /// Cancels a running bulk operation.
+    ///
+    /// Convenience wrapper around [`bulk::cancel`]. The returned operation is
+    /// usually still canceling.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphqlError::Http`] for HTTP-level errors.
+    /// Returns [`GraphqlError::UserErrors`] if Shopify refuses to cancel the
+    /// operation.
+    /// Returns [`GraphqlError::MissingData`] if the response cannot be parsed.
+    pub async fn cancel_bulk_operation(&self, id: &str) -> Result<BulkOperation, GraphqlError> {
+        bulk::cancel(self, id).await
+    }
+
+    /// Verifies that the session's access token is still accepted.
+    ///
+    /// Runs a trivial `{ shop { name } }` query, which has no side effects
+    /// and needs no access scopes. Useful as a startup or health check.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphqlError::InvalidToken`] if Shopify rejects the token
+    /// with HTTP 401 or 403.
+    /// Returns [`GraphqlError::Http`] for other HTTP-level errors.
+    /// Returns [`GraphqlError::UserErrors`] or [`GraphqlError::MissingData`]
+    /// if the response does not contain the shop.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// match client.ping().await {
+    ///     Ok(()) => println!("Token is valid"),
+    ///     Err(GraphqlError::InvalidToken { .. }) => println!("Token must be re-issued"),
+    ///     Err(e) => println!("Could not reach Shopify: {e}"),
+    /// }
+    /// ```
+    pub async fn ping(&self) -> Result<(), GraphqlError> {
+        ping_result(self.query(PING_QUERY, None, None, None).await)
+    }
+
     /// Internal helper to execute a GraphQL query with shared logic.
     async fn execute_query(
         &self,
@@ -308,12 +602,26 @@ impl GraphqlClient {
         tries: Option<u32>,
         debug: bool,
     ) -> Result<HttpResponse, GraphqlError> {
+        #[cfg(feature = "graphql-validate")]
+        super::validate::validate_document(query)?;
+
         // Construct the request body
         let body = serde_json::json!({
             "query": query,
             "variables": variables
         });
 
+        self.send_body(body, headers, tries, debug).await
+    }
+
+    /// Internal helper to POST a prepared request body to `graphql.json`.
+    async fn send_body(
+        &self,
+        body: serde_json::Value,
+        headers: Option<HashMap<String, String>>,
+        tries: Option<u32>,
+        debug: bool,
+    ) -> Result<HttpResponse, GraphqlError> {
         // Build the request
         let mut builder = HttpRequest::builder(HttpMethod::Post, "graphql.json")
             .body(body)
@@ -332,7 +640,53 @@ impl GraphqlClient {
 
         // Build and execute the request
         let request = builder.build().map_err(|e| GraphqlError::Http(e.into()))?;
-        self.http_client.request(request).await.map_err(Into::into)
+
+        let reservation = match &self.cost_budget {
+            Some(budget) => Some(budget.acquire(&self.shop).await),
+            None => None,
+        };
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.acquire().await;
+        }
+        let response = self.http_client.request(request).await?;
+        self.observe_rate_limit(&response, reservation);
+        reject_incremental_delivery(response)
+    }
+
+    /// Settles the cost budget reservation with the cost a response reports
+    /// or, when it reports no cost, feeds its call limit to the rate limiter.
+    fn observe_rate_limit(&self, response: &HttpResponse, reservation: Option<CostReservation>) {
+        match response.rate_limit_signal() {
+            Some(RateLimitSignal::QueryCost(cost)) => {
+                if let (Some(reservation), Some(cost)) = (reservation, cost.cost()) {
+                    reservation.settle(cost);
+                }
+            }
+            Some(RateLimitSignal::CallLimit(limit)) => {
+                if let Some(limiter) = &self.rate_limiter {
+                    limiter.observe(limit);
+                }
+            }
+            None => {}
+        }
+    }
+}
+
+/// Query used by [`GraphqlClient::ping`].
+const PING_QUERY: &str = "query { shop { name } }";
+
+/// Maps the outcome of a ping query, turning 401/403 into
+/// [`GraphqlError::InvalidToken`].
+fn ping_result(result: Result<HttpResponse, GraphqlError>) -> Result<(), GraphqlError> {
+    match result {
+        Ok(response) => root_payload(&response.body, "shop").map(|_| ()),
+        Err(GraphqlError::Http(HttpError::Response(e))) if matches!(e.code, 401 | 403) => {
+            Err(GraphqlError::InvalidToken {
+                code: e.code,
+                error_reference: e.error_reference,
+            })
+        }
+        Err(e) => Err(e),
     }
 }
 
@@ -419,4 +773,278 @@ mod tests {
 
         assert_eq!(client.api_version(), &ApiVersion::V2024_07);
     }
+
+    /// Collects the messages of `WARN` events.
+    #[derive(Default)]
+    struct WarningCollector {
+        warnings: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl tracing::Subscriber for WarningCollector {
+        fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            tracing::span::Id::from_u64(1)
+        }
+
+        fn record(&self, _: &tracing::span::Id, _: &tracing::span::Record<'_>) {}
+
+        fn record_follows_from(&self, _: &tracing::span::Id, _: &tracing::span::Id) {}
+
+        fn event(&self, event: &tracing::Event<'_>) {
+            struct Message<'a>(&'a mut String);
+            impl tracing::field::Visit for Message<'_> {
+                fn record_debug(
+                    &mut self,
+                    field: &tracing::field::Field,
+                    value: &dyn std::fmt::Debug,
+                ) {
+                    use std::fmt::Write;
+                    let _ = write!(self.0, "{}={value:?} ", field.name());
+                }
+            }
+
+            if *event.metadata().level() == tracing::Level::WARN {
+                let mut message = String::new();
+                event.record(&mut Message(&mut message));
+                self.warnings.lock().unwrap().push(message);
+            }
+        }
+
+        fn enter(&self, _: &tracing::span::Id) {}
+
+        fn exit(&self, _: &tracing::span::Id) {}
+    }
+
+    #[test]
+    fn test_graphql_client_warns_when_session_scopes_drift() {
+        use crate::config::{ApiKey, ApiSecretKey};
+
+        let mut session = create_test_session();
+        session.scopes = "read_products".parse().unwrap();
+        let config = ShopifyConfig::builder()
+            .api_key(ApiKey::new("test-key").unwrap())
+            .api_secret_key(ApiSecretKey::new("test-secret").unwrap())
+            .scopes("read_products,read_orders".parse().unwrap())
+            .check_scope_drift(true)
+            .build()
+            .unwrap();
+
+        let collector = std::sync::Arc::new(WarningCollector::default());
+        let client = tracing::subscriber::with_default(collector.clone(), || {
+            GraphqlClient::new(&session, Some(&config))
+        });
+
+        let drift = client.scope_drift().unwrap();
+        assert_eq!(drift.missing, vec!["read_orders"]);
+        assert!(drift.unexpected.is_empty());
+
+        let warnings = collector.warnings.lock().unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("Re-authorize the app"));
+        assert!(warnings[0].contains("missing=read_orders"));
+        drop(warnings);
+
+        // Matching scopes, or the check left disabled, produce no hint
+        session.scopes = "read_orders,read_products".parse().unwrap();
+        assert!(GraphqlClient::new(&session, Some(&config))
+            .scope_drift()
+            .is_none());
+        session.scopes = AuthScopes::new();
+        assert!(GraphqlClient::new(&session, None).scope_drift().is_none());
+    }
+
+    // === Ping Tests ===
+
+    fn unauthorized(code: u16) -> Result<HttpResponse, GraphqlError> {
+        Err(GraphqlError::Http(HttpError::Response(
+            crate::clients::HttpResponseError {
+                code,
+                message: r#"{"errors":"[API] Invalid API key or access token"}"#.to_string(),
+                error_reference: Some("req-123".to_string()),
+            },
+        )))
+    }
+
+    #[test]
+    fn test_ping_maps_401_to_invalid_token() {
+        let result = ping_result(unauthorized(401));
+
+        assert!(matches!(
+            result,
+            Err(GraphqlError::InvalidToken { code: 401, error_reference: Some(ref id) }) if id == "req-123"
+        ));
+    }
+
+    #[test]
+    fn test_ping_maps_403_to_invalid_token() {
+        assert!(matches!(
+            ping_result(unauthorized(403)),
+            Err(GraphqlError::InvalidToken { code: 403, .. })
+        ));
+    }
+
+    #[test]
+    fn test_ping_returns_ok_for_shop_response() {
+        let response = HttpResponse::new(
+            200,
+            HashMap::new(),
+            serde_json::json!({ "data": { "shop": { "name": "Test Shop" } } }),
+        );
+
+        assert!(ping_result(Ok(response)).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_multipart_response_returns_incremental_delivery_error() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let body = concat!(
+            "\r\n---\r\n",
+            "Content-Type: application/json\r\n\r\n",
+            r#"{"data":{"product":{"id":"gid://shopify/Product/1"}},"hasNext":true}"#,
+            "\r\n---\r\n",
+            "Content-Type: application/json\r\n\r\n",
+            r#"{"incremental":[{"path":["product"],"data":{"title":"Hat"}}],"hasNext":false}"#,
+            "\r\n-----\r\n",
+        );
+        Mock::given(method("POST"))
+            .and(path(format!(
+                "/admin/api/{}/graphql.json",
+                ApiVersion::latest()
+            )))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_raw(body, "multipart/mixed; boundary=\"-\"; deferSpec=20220824"),
+            )
+            .mount(&server)
+            .await;
+
+        let server_url = reqwest::Url::parse(&server.uri()).unwrap();
+        let mut client = GraphqlClient::new(&create_test_session(), None);
+        client.http_client = client.http_client.with_url_rewriter(move |url| {
+            url.set_scheme("http").unwrap();
+            url.set_host(server_url.host_str()).unwrap();
+            url.set_port(server_url.port()).unwrap();
+        });
+
+        let result = client
+            .query(
+                "query { product(id: 1) { id ... @defer { title } } }",
+                None,
+                None,
+                None,
+            )
+            .await;
+
+        match result {
+            Err(GraphqlError::IncrementalDeliveryUnsupported { content_type }) => {
+                assert!(content_type.starts_with("multipart/mixed"));
+            }
+            other => panic!("Expected IncrementalDeliveryUnsupported, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cost_budget_delays_requests_once_exhausted() {
+        use crate::clients::graphql::CostBudget;
+        use std::time::{Duration, Instant};
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path(format!(
+                "/admin/api/{}/graphql.json",
+                ApiVersion::latest()
+            )))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "shop": { "name": "Test Shop" } },
+                "extensions": {
+                    "cost": { "requestedQueryCost": 10, "actualQueryCost": 6 }
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let server_url = reqwest::Url::parse(&server.uri()).unwrap();
+        let budget = CostBudget::new(10, Duration::from_millis(200));
+        let mut client =
+            GraphqlClient::new(&create_test_session(), None).with_cost_budget(budget.clone());
+        client.http_client = client.http_client.with_url_rewriter(move |url| {
+            url.set_scheme("http").unwrap();
+            url.set_host(server_url.host_str()).unwrap();
+            url.set_port(server_url.port()).unwrap();
+        });
+
+        let started = Instant::now();
+        client.query(PING_QUERY, None, None, None).await.unwrap();
+        assert!(started.elapsed() < Duration::from_millis(150));
+        assert_eq!(budget.consumed(&client.shop), 6);
+
+        // The next query is expected to cost 6 too, which only fits once the
+        // first query's cost leaves the window
+        client.query(PING_QUERY, None, None, None).await.unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(150));
+        assert_eq!(budget.consumed(&client.shop), 6);
+    }
+
+    #[tokio::test]
+    async fn test_call_limit_header_surfaces_without_query_cost() {
+        use crate::clients::ApiCallLimit;
+        use std::time::{Duration, Instant};
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path(format!(
+                "/admin/api/{}/graphql.json",
+                ApiVersion::latest()
+            )))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("X-Shopify-Shop-Api-Call-Limit", "2/2")
+                    .set_body_json(serde_json::json!({
+                        "data": { "shop": { "name": "Test Shop" } }
+                    })),
+            )
+            .mount(&server)
+            .await;
+
+        let server_url = reqwest::Url::parse(&server.uri()).unwrap();
+        let mut client = GraphqlClient::new(&create_test_session(), None)
+            .with_rate_limiter(RateLimiter::new(40, 20.0));
+        client.http_client = client.http_client.with_url_rewriter(move |url| {
+            url.set_scheme("http").unwrap();
+            url.set_host(server_url.host_str()).unwrap();
+            url.set_port(server_url.port()).unwrap();
+        });
+
+        let response = client.query(PING_QUERY, None, None, None).await.unwrap();
+        assert_eq!(
+            response.rate_limit_signal(),
+            Some(RateLimitSignal::CallLimit(ApiCallLimit {
+                request_count: 2,
+                bucket_size: 2,
+            }))
+        );
+
+        // The limiter adopted the reported full bucket and waits for it to drain
+        let started = Instant::now();
+        client.query(PING_QUERY, None, None, None).await.unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(40));
+    }
+
+    #[test]
+    fn test_ping_passes_through_other_errors() {
+        assert!(matches!(
+            ping_result(unauthorized(500)),
+            Err(GraphqlError::Http(_))
+        ));
+    }
 }
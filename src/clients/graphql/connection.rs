@@ -0,0 +1,135 @@
+//! Typed GraphQL connections.
+//!
+//! Deserialize a connection field from a response into [`Connection`] and
+//! convert it into a transport-agnostic [`Page`] with
+//! [`Connection::into_page`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use serde::Deserialize;
+//! use serde_json::json;
+//! use shopify_sdk::clients::graphql::Connection;
+//!
+//! #[derive(Deserialize)]
+//! struct Product {
+//!     id: String,
+//! }
+//!
+//! let body = json!({
+//!     "nodes": [{ "id": "gid://shopify/Product/1" }],
+//!     "pageInfo": { "hasNextPage": true, "endCursor": "abc" }
+//! });
+//!
+//! let connection: Connection<Product> = serde_json::from_value(body).unwrap();
+//! let page = connection.into_page();
+//!
+//! assert_eq!(page.items[0].id, "gid://shopify/Product/1");
+//! assert_eq!(page.next_cursor.as_deref(), Some("abc"));
+//! ```
+
+use serde::Deserialize;
+
+use crate::clients::Page;
+
+/// A GraphQL connection, selected with either `nodes` or `edges { node }`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Connection<T> {
+    /// The nodes, when selected directly with `nodes`.
+    pub nodes: Option<Vec<T>>,
+    /// The edges, when selected with `edges { node }`.
+    pub edges: Option<Vec<Edge<T>>>,
+    /// Pagination state for the connection.
+    pub page_info: PageInfo,
+}
+
+/// An edge in a GraphQL connection.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Edge<T> {
+    /// The node at this edge.
+    pub node: T,
+    /// The cursor for this edge, if selected.
+    #[serde(default)]
+    pub cursor: Option<String>,
+}
+
+/// The `pageInfo` of a GraphQL connection.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageInfo {
+    /// Whether more items follow this page.
+    #[serde(default)]
+    pub has_next_page: bool,
+    /// The cursor of the last item on this page.
+    #[serde(default)]
+    pub end_cursor: Option<String>,
+    /// Whether items precede this page.
+    #[serde(default)]
+    pub has_previous_page: bool,
+    /// The cursor of the first item on this page.
+    #[serde(default)]
+    pub start_cursor: Option<String>,
+}
+
+impl<T> Connection<T> {
+    /// Converts the connection into a [`Page`].
+    ///
+    /// The next cursor is the connection's `endCursor`, and is only set
+    /// when `hasNextPage` is true.
+    #[must_use]
+    pub fn into_page(self) -> Page<T> {
+        let items = match (self.nodes, self.edges) {
+            (Some(nodes), _) => nodes,
+            (None, Some(edges)) => edges.into_iter().map(|edge| edge.node).collect(),
+            (None, None) => Vec::new(),
+        };
+        let has_next = self.page_info.has_next_page;
+
+        Page {
+            items,
+            next_cursor: self.page_info.end_cursor.filter(|_| has_next),
+            has_next,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_edges_connection_into_page() {
+        let connection: Connection<serde_json::Value> = serde_json::from_value(json!({
+            "edges": [
+                { "cursor": "a", "node": { "id": "gid://shopify/Product/1" } },
+                { "cursor": "b", "node": { "id": "gid://shopify/Product/2" } }
+            ],
+            "pageInfo": { "hasNextPage": true, "endCursor": "b" }
+        }))
+        .unwrap();
+
+        let page = connection.into_page();
+
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.items[1]["id"], "gid://shopify/Product/2");
+        assert_eq!(page.next_cursor.as_deref(), Some("b"));
+        assert!(page.has_next);
+    }
+
+    #[test]
+    fn test_last_page_has_no_next_cursor() {
+        let connection: Connection<u32> = serde_json::from_value(json!({
+            "nodes": [1, 2, 3],
+            "pageInfo": { "hasNextPage": false, "endCursor": "c" }
+        }))
+        .unwrap();
+
+        let page = connection.into_page();
+
+        assert_eq!(page.items, vec![1, 2, 3]);
+        assert_eq!(page.next_cursor, None);
+        assert!(!page.has_next);
+    }
+}
@@ -0,0 +1,370 @@
+//! Per-shop caps on GraphQL query cost.
+//!
+//! Shopify throttles the GraphQL Admin API by query cost rather than request
+//! count. Apps serving many shops often want a tighter cap of their own, so a
+//! single shop can't use up a shared worker pool or an agreed share of the
+//! limit.
+//!
+//! A [`CostBudget`] tracks query cost per shop over a sliding window. Before
+//! a request, [`CostBudget::acquire`] waits until the shop's expected cost
+//! fits under the limit and reserves it, so concurrent requests can't all be
+//! admitted against the same headroom. Once the response arrives, the
+//! reservation is settled with the `actualQueryCost` from its
+//! `extensions.cost`. A [`GraphqlClient`](super::GraphqlClient) given a
+//! budget with
+//! [`GraphqlClient::with_cost_budget`](super::GraphqlClient::with_cost_budget)
+//! does both for every request.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use shopify_sdk::clients::graphql::CostBudget;
+//!
+//! // At most 500 points per shop per minute, shared by every client
+//! let budget = CostBudget::per_minute(500);
+//!
+//! let client = GraphqlClient::new(&session, None).with_cost_budget(budget.clone());
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::config::ShopDomain;
+
+/// Cost expected of a shop's first query, before any cost has been seen.
+const DEFAULT_EXPECTED_COST: u64 = 1;
+
+/// A cost counted against a shop's budget.
+#[derive(Debug)]
+struct CostEntry {
+    /// When the cost was reserved or recorded.
+    at: Instant,
+    /// The reserved or actual cost.
+    cost: u64,
+    /// Identifies the reservation that made the entry, if any.
+    reservation: Option<u64>,
+}
+
+/// Costs counted against one shop, oldest first.
+#[derive(Debug)]
+struct ShopCosts {
+    /// Entries within the window.
+    log: VecDeque<CostEntry>,
+    /// Cost reserved for the shop's next query: the cost of its last one.
+    expected: u64,
+    /// Identifier for the next reservation.
+    next_reservation: u64,
+}
+
+impl Default for ShopCosts {
+    fn default() -> Self {
+        Self {
+            log: VecDeque::new(),
+            expected: DEFAULT_EXPECTED_COST,
+            next_reservation: 0,
+        }
+    }
+}
+
+/// A sliding-window cap on GraphQL query cost, tracked separately per shop.
+///
+/// Cloning a `CostBudget` shares the same tracking, so one budget can be
+/// handed to every client the app creates.
+///
+/// The cost of a query is only known once its response arrives, so each
+/// request reserves the cost of the shop's previous query (one for its first)
+/// and the reservation is replaced with the actual cost afterwards. A query
+/// costing more than expected can still take the shop past the limit; the
+/// requests after it wait until enough cost leaves the window.
+#[derive(Clone, Debug)]
+pub struct CostBudget {
+    /// Maximum cost per shop within `window`.
+    limit: u64,
+    /// How long a recorded cost counts against the budget.
+    window: Duration,
+    /// Costs counted against each shop, keyed by shop domain.
+    shops: Arc<Mutex<HashMap<String, ShopCosts>>>,
+}
+
+impl CostBudget {
+    /// Creates a budget allowing `limit` cost per shop within each `window`.
+    #[must_use]
+    pub fn new(limit: u64, window: Duration) -> Self {
+        Self {
+            limit,
+            window,
+            shops: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Creates a budget allowing `limit` cost per shop per minute.
+    #[must_use]
+    pub fn per_minute(limit: u64) -> Self {
+        Self::new(limit, Duration::from_secs(60))
+    }
+
+    /// Returns the maximum cost per shop within the window.
+    #[must_use]
+    pub const fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Returns the length of the sliding window.
+    #[must_use]
+    pub const fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// Waits until `shop` has room for its expected cost, then reserves it.
+    ///
+    /// The expected cost is the cost of the shop's last settled query, capped
+    /// at the limit. Settle the returned reservation with the actual cost
+    /// once it is known; dropping it unsettled releases the reserved cost.
+    pub async fn acquire(&self, shop: &ShopDomain) -> CostReservation {
+        loop {
+            match self.try_reserve(shop) {
+                Ok(reservation) => return reservation,
+                Err(wait) => tokio::time::sleep(wait).await,
+            }
+        }
+    }
+
+    /// Records cost consumed by a query for `shop` outside a reservation.
+    pub fn record(&self, shop: &ShopDomain, cost: u64) {
+        if cost == 0 {
+            return;
+        }
+        let now = Instant::now();
+        let mut shops = self.lock();
+        let costs = shops.entry(shop.as_ref().to_string()).or_default();
+        self.expire(&mut costs.log, now);
+        costs.log.push_back(CostEntry {
+            at: now,
+            cost,
+            reservation: None,
+        });
+        drop(shops);
+    }
+
+    /// Returns the cost `shop` has consumed or reserved within the current
+    /// window.
+    #[must_use]
+    pub fn consumed(&self, shop: &ShopDomain) -> u64 {
+        let now = Instant::now();
+        self.lock().get(shop.as_ref()).map_or(0, |costs| {
+            costs
+                .log
+                .iter()
+                .filter(|entry| now.saturating_duration_since(entry.at) < self.window)
+                .map(|entry| entry.cost)
+                .sum()
+        })
+    }
+
+    /// Reserves the expected cost of `shop` if it fits, or returns how long
+    /// to wait before trying again.
+    fn try_reserve(&self, shop: &ShopDomain) -> Result<CostReservation, Duration> {
+        let now = Instant::now();
+        let mut shops = self.lock();
+        let costs = shops.entry(shop.as_ref().to_string()).or_default();
+        self.expire(&mut costs.log, now);
+        let cost = costs.expected.min(self.limit);
+        if let Some(wait) = self.wait_for_room(&costs.log, cost, now) {
+            return Err(wait);
+        }
+
+        let id = costs.next_reservation;
+        costs.next_reservation += 1;
+        costs.log.push_back(CostEntry {
+            at: now,
+            cost,
+            reservation: Some(id),
+        });
+        drop(shops);
+
+        Ok(CostReservation {
+            budget: self.clone(),
+            shop: shop.as_ref().to_string(),
+            id,
+        })
+    }
+
+    /// Replaces the cost of reservation `id` with `cost`, or removes it when
+    /// `cost` is `None`.
+    fn settle(&self, shop: &str, id: u64, cost: Option<u64>) {
+        let mut shops = self.lock();
+        let Some(costs) = shops.get_mut(shop) else {
+            return;
+        };
+        if let Some(cost) = cost {
+            costs.expected = cost;
+        }
+        if let Some(index) = costs
+            .log
+            .iter()
+            .position(|entry| entry.reservation == Some(id))
+        {
+            match cost {
+                Some(cost) => {
+                    let entry = &mut costs.log[index];
+                    entry.cost = cost;
+                    entry.reservation = None;
+                }
+                None => {
+                    costs.log.remove(index);
+                }
+            }
+        }
+        drop(shops);
+    }
+
+    /// Drops costs that have left the window.
+    fn expire(&self, log: &mut VecDeque<CostEntry>, now: Instant) {
+        while log
+            .front()
+            .is_some_and(|entry| now.saturating_duration_since(entry.at) >= self.window)
+        {
+            log.pop_front();
+        }
+    }
+
+    /// Returns how long to wait until `cost` more fits under the limit, or
+    /// `None` if it already does.
+    fn wait_for_room(
+        &self,
+        log: &VecDeque<CostEntry>,
+        cost: u64,
+        now: Instant,
+    ) -> Option<Duration> {
+        let fits = |consumed: u64| consumed.saturating_add(cost) <= self.limit;
+        let mut consumed: u64 = log.iter().map(|entry| entry.cost).sum();
+        if fits(consumed) {
+            return None;
+        }
+        log.iter().find_map(|entry| {
+            consumed -= entry.cost;
+            fits(consumed).then(|| (entry.at + self.window).saturating_duration_since(now))
+        })
+    }
+
+    /// Locks the shops, recovering them if a previous holder panicked.
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, ShopCosts>> {
+        self.shops.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Cost reserved for one request by [`CostBudget::acquire`].
+///
+/// Call [`settle`](Self::settle) with the query's actual cost once the
+/// response arrives. Dropping the reservation without settling it releases
+/// the reserved cost, e.g. when the request failed before Shopify ran it.
+#[derive(Debug)]
+#[must_use = "dropping a reservation releases the reserved cost"]
+pub struct CostReservation {
+    /// The budget the cost is reserved in.
+    budget: CostBudget,
+    /// The shop the cost is reserved for.
+    shop: String,
+    /// Identifies the reservation's entry in the shop's log.
+    id: u64,
+}
+
+impl CostReservation {
+    /// Replaces the reserved cost with the cost the query actually had.
+    ///
+    /// The shop's next request reserves this cost.
+    pub fn settle(self, cost: u64) {
+        self.budget.settle(&self.shop, self.id, Some(cost));
+    }
+}
+
+impl Drop for CostReservation {
+    fn drop(&mut self) {
+        // A settled reservation no longer has an entry to release
+        self.budget.settle(&self.shop, self.id, None);
+    }
+}
+
+// Verify CostBudget is Send + Sync at compile time
+const _: fn() = || {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<CostBudget>();
+    assert_send_sync::<CostReservation>();
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_acquire_waits_once_budget_is_exhausted() {
+        let budget = CostBudget::per_minute(100);
+        let shop = ShopDomain::new("test-shop").unwrap();
+
+        budget.acquire(&shop).await.settle(60);
+        let started = Instant::now();
+        tokio::time::advance(Duration::from_secs(20)).await;
+
+        // The shop's next query is expected to cost 60 as well, which only
+        // fits once the first leaves the window
+        let reservation = budget.acquire(&shop).await;
+        let waited = started.elapsed();
+        assert!(waited >= Duration::from_secs(60) && waited < Duration::from_secs(61));
+        assert_eq!(budget.consumed(&shop), 60);
+
+        reservation.settle(30);
+        assert_eq!(budget.consumed(&shop), 30);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_concurrent_acquires_reserve_cost_before_it_is_reported() {
+        let budget = CostBudget::per_minute(100);
+        let shop = ShopDomain::new("test-shop").unwrap();
+        budget.acquire(&shop).await.settle(40);
+        let started = Instant::now();
+        tokio::time::advance(Duration::from_secs(10)).await;
+
+        let reserve = || async {
+            let reservation = budget.acquire(&shop).await;
+            (reservation, started.elapsed())
+        };
+        let ((_first, first_wait), (_second, second_wait)) = tokio::join!(reserve(), reserve());
+
+        // Only one more query expected to cost 40 fits next to the first
+        let mut waits = [first_wait, second_wait];
+        waits.sort();
+        assert_eq!(waits[0], Duration::from_secs(10));
+        assert!(waits[1] >= Duration::from_secs(60) && waits[1] < Duration::from_secs(61));
+        assert_eq!(budget.consumed(&shop), 80);
+    }
+
+    #[tokio::test]
+    async fn test_dropping_a_reservation_releases_its_cost() {
+        let budget = CostBudget::per_minute(100);
+        let shop = ShopDomain::new("test-shop").unwrap();
+
+        budget.acquire(&shop).await.settle(25);
+        let reservation = budget.acquire(&shop).await;
+        assert_eq!(budget.consumed(&shop), 50);
+
+        drop(reservation);
+        assert_eq!(budget.consumed(&shop), 25);
+    }
+
+    #[tokio::test]
+    async fn test_budget_is_tracked_per_shop() {
+        let budget = CostBudget::per_minute(10);
+        let busy = ShopDomain::new("busy-shop").unwrap();
+        let quiet = ShopDomain::new("quiet-shop").unwrap();
+
+        budget.record(&busy, 10);
+        let started = Instant::now();
+        let _reservation = budget.clone().acquire(&quiet).await;
+
+        assert!(started.elapsed() < Duration::from_millis(50));
+        assert_eq!(budget.consumed(&quiet), DEFAULT_EXPECTED_COST);
+    }
+}
@@ -0,0 +1,280 @@
+//! Counting helper for GraphQL connections.
+//!
+//! Several REST `count.json` endpoints are being removed. The GraphQL Admin
+//! API exposes dedicated `...Count` root fields for common resources (for
+//! example `productsCount`); other connections have to be paginated.
+//! [`count`] picks the right approach for a connection.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use shopify_sdk::clients::graphql;
+//!
+//! let active = graphql::count(&client, "products", Some("status:active")).await?;
+//! let locations = graphql::count(&client, "locations", None).await?;
+//! ```
+
+use serde_json::{json, Value};
+
+use super::payload::{parse_response, root_payload};
+use super::{GraphqlClient, GraphqlError, UserError};
+
+/// Connections that have a `{connection}Count` root field.
+const COUNT_FIELD_CONNECTIONS: &[&str] = &[
+    "blogs",
+    "collections",
+    "customers",
+    "discountNodes",
+    "draftOrders",
+    "giftCards",
+    "locations",
+    "orders",
+    "productVariants",
+    "products",
+    "webhookSubscriptions",
+];
+
+/// Page size used when counting by pagination.
+const PAGE_SIZE: u32 = 250;
+
+/// Counts the nodes in a root connection.
+///
+/// Uses the connection's `...Count` field when Shopify provides one (e.g.
+/// `productsCount` for `products`), and otherwise pages through the
+/// connection 250 nodes at a time and sums the results.
+///
+/// Shopify caps `...Count` fields at 10,000 by default; larger totals are
+/// reported as 10,000.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the requests
+/// * `connection` - The root connection field name (e.g. `"products"`)
+/// * `query` - An optional search query (e.g. `"status:active"`)
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if `connection` is not a valid field
+/// name or Shopify rejects the query.
+/// Returns [`GraphqlError::MissingData`] if a response has no count or page.
+pub async fn count(
+    client: &GraphqlClient,
+    connection: &str,
+    query: Option<&str>,
+) -> Result<u64, GraphqlError> {
+    validate_connection(connection)?;
+
+    let variables = json!({ "query": query });
+
+    if let Some(field) = count_field(connection) {
+        let response = client
+            .query(
+                &count_query(&field, query.is_some()),
+                Some(variables),
+                None,
+                None,
+            )
+            .await?;
+        return parse_response(&response, |body| parse_count(body, &field));
+    }
+
+    let page_query = page_query(connection, query.is_some());
+    let mut total = 0;
+    let mut after: Option<String> = None;
+    loop {
+        let mut variables = variables.clone();
+        variables["after"] = json!(after);
+
+        let response = client
+            .query(&page_query, Some(variables), None, None)
+            .await?;
+        let (page_count, next_cursor) =
+            parse_response(&response, |body| parse_page(body, connection))?;
+        total += page_count;
+
+        match next_cursor {
+            Some(cursor) => after = Some(cursor),
+            None => return Ok(total),
+        }
+    }
+}
+
+/// Rejects connection names that are not plain GraphQL field names, since
+/// the name is interpolated into the query document.
+fn validate_connection(connection: &str) -> Result<(), GraphqlError> {
+    let valid = connection
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic())
+        && connection
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(GraphqlError::UserErrors {
+            errors: vec![UserError {
+                field: Some(vec!["connection".to_string()]),
+                message: format!("'{connection}' is not a valid connection field name"),
+            }],
+            request_id: None,
+        })
+    }
+}
+
+/// Returns the `...Count` field for a connection, if Shopify provides one.
+fn count_field(connection: &str) -> Option<String> {
+    COUNT_FIELD_CONNECTIONS
+        .contains(&connection)
+        .then(|| format!("{connection}Count"))
+}
+
+/// Builds the query for a `...Count` field.
+fn count_query(field: &str, with_search: bool) -> String {
+    if with_search {
+        format!("query Count($query: String) {{ {field}(query: $query) {{ count }} }}")
+    } else {
+        format!("query Count {{ {field} {{ count }} }}")
+    }
+}
+
+/// Builds the query for one page of a connection.
+fn page_query(connection: &str, with_search: bool) -> String {
+    let (declaration, argument) = if with_search {
+        (", $query: String", ", query: $query")
+    } else {
+        ("", "")
+    };
+    format!(
+        "query CountPage($after: String{declaration}) {{ \
+         {connection}(first: {PAGE_SIZE}, after: $after{argument}) {{ \
+         edges {{ cursor }} pageInfo {{ hasNextPage endCursor }} }} }}"
+    )
+}
+
+/// Parses the total from a `...Count` response.
+fn parse_count(body: &Value, field: &str) -> Result<u64, GraphqlError> {
+    root_payload(body, field)?["count"]
+        .as_u64()
+        .ok_or_else(|| GraphqlError::MissingData {
+            field: format!("data.{field}.count"),
+            request_id: None,
+        })
+}
+
+/// Parses one page of a connection, returning its node count and the
+/// cursor for the next page if there is one.
+fn parse_page(body: &Value, connection: &str) -> Result<(u64, Option<String>), GraphqlError> {
+    let payload = root_payload(body, connection)?;
+    let edges = payload["edges"]
+        .as_array()
+        .ok_or_else(|| GraphqlError::MissingData {
+            field: format!("data.{connection}.edges"),
+            request_id: None,
+        })?;
+
+    let next_cursor = if payload["pageInfo"]["hasNextPage"].as_bool() == Some(true) {
+        payload["pageInfo"]["endCursor"].as_str().map(String::from)
+    } else {
+        None
+    };
+
+    Ok((edges.len() as u64, next_cursor))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mocked_products_count_response() {
+        let body = json!({
+            "data": {
+                "productsCount": { "count": 1234 }
+            },
+            "extensions": {
+                "cost": { "requestedQueryCost": 1 }
+            }
+        });
+
+        assert_eq!(parse_count(&body, "productsCount").unwrap(), 1234);
+    }
+
+    #[test]
+    fn test_parse_count_missing_field() {
+        let body = json!({ "data": { "productsCount": {} } });
+
+        let result = parse_count(&body, "productsCount");
+
+        assert!(
+            matches!(result, Err(GraphqlError::MissingData { field, .. }) if field == "data.productsCount.count")
+        );
+    }
+
+    #[test]
+    fn test_count_field_uses_known_count_fields() {
+        assert_eq!(count_field("products").as_deref(), Some("productsCount"));
+        assert_eq!(count_field("orders").as_deref(), Some("ordersCount"));
+        assert_eq!(count_field("metaobjectDefinitions"), None);
+    }
+
+    #[test]
+    fn test_count_query_with_and_without_search() {
+        assert_eq!(
+            count_query("productsCount", true),
+            "query Count($query: String) { productsCount(query: $query) { count } }"
+        );
+        assert_eq!(
+            count_query("locationsCount", false),
+            "query Count { locationsCount { count } }"
+        );
+    }
+
+    #[test]
+    fn test_page_query_requests_250_nodes() {
+        let query = page_query("metaobjectDefinitions", false);
+
+        assert!(query.contains("metaobjectDefinitions(first: 250, after: $after)"));
+        assert!(query.contains("pageInfo { hasNextPage endCursor }"));
+        assert!(page_query("files", true).contains("query: $query"));
+    }
+
+    #[test]
+    fn test_parse_page_returns_next_cursor_only_when_more_pages() {
+        let body = json!({
+            "data": {
+                "files": {
+                    "edges": [{ "cursor": "a" }, { "cursor": "b" }],
+                    "pageInfo": { "hasNextPage": true, "endCursor": "b" }
+                }
+            }
+        });
+        assert_eq!(
+            parse_page(&body, "files").unwrap(),
+            (2, Some("b".to_string()))
+        );
+
+        let last = json!({
+            "data": {
+                "files": {
+                    "edges": [{ "cursor": "c" }],
+                    "pageInfo": { "hasNextPage": false, "endCursor": "c" }
+                }
+            }
+        });
+        assert_eq!(parse_page(&last, "files").unwrap(), (1, None));
+    }
+
+    #[test]
+    fn test_validate_connection_rejects_non_identifiers() {
+        assert!(validate_connection("products").is_ok());
+        assert!(validate_connection("product_variants").is_ok());
+        assert!(matches!(
+            validate_connection("products { id } }"),
+            Err(GraphqlError::UserErrors { .. })
+        ));
+        assert!(validate_connection("").is_err());
+    }
+}
@@ -0,0 +1,734 @@
+//! Customer helpers for the GraphQL Admin API.
+//!
+//! Marketing consent is awkward to set through the REST `Customer` resource,
+//! where the consent fields are split across several legacy attributes.
+//! [`create`] wraps `customerCreate`, whose `emailMarketingConsent` and
+//! `smsMarketingConsent` inputs carry the state, opt-in level and consent
+//! time together. `customerUpdate` does not accept consent, so [`update`]
+//! sends it through `customerEmailMarketingConsentUpdate` and
+//! `customerSmsMarketingConsentUpdate` instead.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use shopify_sdk::clients::graphql::customers::{
+//!     self, CustomerInput, EmailMarketingConsent, MarketingOptInLevel, MarketingState,
+//! };
+//!
+//! let customer = customers::create(
+//!     &client,
+//!     CustomerInput {
+//!         email: Some("bob@example.com".to_string()),
+//!         first_name: Some("Bob".to_string()),
+//!         email_marketing_consent: Some(EmailMarketingConsent {
+//!             marketing_state: MarketingState::Subscribed,
+//!             marketing_opt_in_level: Some(MarketingOptInLevel::ConfirmedOptIn),
+//!             consent_updated_at: None,
+//!         }),
+//!         ..Default::default()
+//!     },
+//! )
+//! .await?;
+//!
+//! customers::update(
+//!     &client,
+//!     &customer.id,
+//!     CustomerInput {
+//!         tags: vec!["newsletter".to_string()],
+//!         ..Default::default()
+//!     },
+//! )
+//! .await?;
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::payload::{parse_response, root_payload};
+use super::{GraphqlClient, GraphqlError};
+
+/// Mutation used by [`create`].
+const CREATE_MUTATION: &str = r"
+mutation customerCreate($input: CustomerInput!) {
+    customerCreate(input: $input) {
+        customer {
+            id
+            email
+            phone
+            firstName
+            lastName
+            tags
+            emailMarketingConsent {
+                marketingState
+                marketingOptInLevel
+                consentUpdatedAt
+            }
+            smsMarketingConsent {
+                marketingState
+                marketingOptInLevel
+                consentUpdatedAt
+            }
+        }
+        userErrors {
+            field
+            message
+        }
+    }
+}
+";
+
+/// Mutation used by [`update`].
+const UPDATE_MUTATION: &str = r"
+mutation customerUpdate($input: CustomerInput!) {
+    customerUpdate(input: $input) {
+        customer {
+            id
+            email
+            phone
+            firstName
+            lastName
+            tags
+            emailMarketingConsent {
+                marketingState
+                marketingOptInLevel
+                consentUpdatedAt
+            }
+            smsMarketingConsent {
+                marketingState
+                marketingOptInLevel
+                consentUpdatedAt
+            }
+        }
+        userErrors {
+            field
+            message
+        }
+    }
+}
+";
+
+/// Mutation used by [`update`] for email marketing consent.
+const EMAIL_CONSENT_MUTATION: &str = r"
+mutation customerEmailMarketingConsentUpdate($input: CustomerEmailMarketingConsentUpdateInput!) {
+    customerEmailMarketingConsentUpdate(input: $input) {
+        customer {
+            id
+            email
+            phone
+            firstName
+            lastName
+            tags
+            emailMarketingConsent {
+                marketingState
+                marketingOptInLevel
+                consentUpdatedAt
+            }
+            smsMarketingConsent {
+                marketingState
+                marketingOptInLevel
+                consentUpdatedAt
+            }
+        }
+        userErrors {
+            field
+            message
+        }
+    }
+}
+";
+
+/// Mutation used by [`update`] for SMS marketing consent.
+const SMS_CONSENT_MUTATION: &str = r"
+mutation customerSmsMarketingConsentUpdate($input: CustomerSmsMarketingConsentUpdateInput!) {
+    customerSmsMarketingConsentUpdate(input: $input) {
+        customer {
+            id
+            email
+            phone
+            firstName
+            lastName
+            tags
+            emailMarketingConsent {
+                marketingState
+                marketingOptInLevel
+                consentUpdatedAt
+            }
+            smsMarketingConsent {
+                marketingState
+                marketingOptInLevel
+                consentUpdatedAt
+            }
+        }
+        userErrors {
+            field
+            message
+        }
+    }
+}
+";
+
+/// A customer's marketing subscription state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MarketingState {
+    /// The customer is subscribed.
+    Subscribed,
+    /// The customer has not subscribed.
+    NotSubscribed,
+    /// The customer is awaiting confirmation of a double opt-in.
+    Pending,
+    /// The customer has unsubscribed.
+    Unsubscribed,
+    /// The customer's marketing information was redacted.
+    Redacted,
+    /// The state is not valid for the channel.
+    Invalid,
+}
+
+/// How a customer opted in to marketing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MarketingOptInLevel {
+    /// The customer opted in without confirming.
+    SingleOptIn,
+    /// The customer opted in and confirmed, e.g. through a confirmation email.
+    ConfirmedOptIn,
+    /// The opt-in level is unknown.
+    Unknown,
+}
+
+/// Email marketing consent, sent as Shopify's
+/// `CustomerEmailMarketingConsentInput` and returned as
+/// `CustomerEmailMarketingConsentState`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailMarketingConsent {
+    /// The subscription state.
+    pub marketing_state: MarketingState,
+    /// How the customer opted in, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub marketing_opt_in_level: Option<MarketingOptInLevel>,
+    /// When consent was given or withdrawn. Shopify uses the request time
+    /// if unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consent_updated_at: Option<DateTime<Utc>>,
+}
+
+/// SMS marketing consent, sent as Shopify's
+/// `CustomerSmsMarketingConsentInput` and returned as
+/// `CustomerSmsMarketingConsentState`.
+///
+/// Requires the customer to have a phone number.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SmsMarketingConsent {
+    /// The subscription state.
+    pub marketing_state: MarketingState,
+    /// How the customer opted in, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub marketing_opt_in_level: Option<MarketingOptInLevel>,
+    /// When consent was given or withdrawn. Shopify uses the request time
+    /// if unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consent_updated_at: Option<DateTime<Utc>>,
+}
+
+/// Input for [`create`] and [`update`].
+///
+/// Fields map to Shopify's `CustomerInput`. Unset fields are omitted, so
+/// [`update`] leaves them unchanged.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerInput {
+    /// The customer's email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// The customer's phone number, in E.164 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    /// The customer's first name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    /// The customer's last name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// A note about the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// Tags for the customer. On update, these replace the existing tags.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Email marketing consent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_marketing_consent: Option<EmailMarketingConsent>,
+    /// SMS marketing consent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sms_marketing_consent: Option<SmsMarketingConsent>,
+}
+
+/// A customer returned by [`create`] and [`update`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Customer {
+    /// The customer GID.
+    pub id: String,
+    /// The customer's email address.
+    #[serde(default)]
+    pub email: Option<String>,
+    /// The customer's phone number.
+    #[serde(default)]
+    pub phone: Option<String>,
+    /// The customer's first name.
+    #[serde(default)]
+    pub first_name: Option<String>,
+    /// The customer's last name.
+    #[serde(default)]
+    pub last_name: Option<String>,
+    /// The customer's tags.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Email marketing consent, if the customer has an email address.
+    #[serde(default)]
+    pub email_marketing_consent: Option<EmailMarketingConsent>,
+    /// SMS marketing consent, if the customer has a phone number.
+    #[serde(default)]
+    pub sms_marketing_consent: Option<SmsMarketingConsent>,
+}
+
+/// Creates a customer.
+///
+/// Wraps the `customerCreate` mutation.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the request
+/// * `input` - The customer's details and marketing consent
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if Shopify rejects the customer
+/// (for example, an email address that is already taken).
+/// Returns [`GraphqlError::MissingData`] if the response has no customer.
+pub async fn create(
+    client: &GraphqlClient,
+    input: CustomerInput,
+) -> Result<Customer, GraphqlError> {
+    mutate(
+        client,
+        CREATE_MUTATION,
+        customer_variables(None, &input),
+        "customerCreate",
+    )
+    .await
+}
+
+/// Updates a customer.
+///
+/// Only the fields set on `input` are changed. Profile fields go through
+/// the `customerUpdate` mutation, which does not accept marketing consent.
+/// Consent is sent afterwards through `customerEmailMarketingConsentUpdate`
+/// and `customerSmsMarketingConsentUpdate`, and `customerUpdate` is skipped
+/// when only consent is set. The returned customer is the one from the last
+/// mutation sent.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the request
+/// * `customer_id` - The customer GID (e.g. `gid://shopify/Customer/1`)
+/// * `input` - The fields to change
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if Shopify rejects the update.
+/// Returns [`GraphqlError::MissingData`] if the response has no customer.
+/// The mutations are not atomic: if a consent mutation fails, the changes
+/// sent before it are kept.
+pub async fn update(
+    client: &GraphqlClient,
+    customer_id: &str,
+    mut input: CustomerInput,
+) -> Result<Customer, GraphqlError> {
+    let email_consent = input.email_marketing_consent.take();
+    let sms_consent = input.sms_marketing_consent.take();
+
+    let mut mutations = Vec::new();
+    if input != CustomerInput::default() || (email_consent.is_none() && sms_consent.is_none()) {
+        mutations.push((
+            UPDATE_MUTATION,
+            customer_variables(Some(customer_id), &input),
+            "customerUpdate",
+        ));
+    }
+    if let Some(consent) = email_consent {
+        mutations.push((
+            EMAIL_CONSENT_MUTATION,
+            json!({ "input": { "customerId": customer_id, "emailMarketingConsent": consent } }),
+            "customerEmailMarketingConsentUpdate",
+        ));
+    }
+    if let Some(consent) = sms_consent {
+        mutations.push((
+            SMS_CONSENT_MUTATION,
+            json!({ "input": { "customerId": customer_id, "smsMarketingConsent": consent } }),
+            "customerSmsMarketingConsentUpdate",
+        ));
+    }
+
+    let mut customer = None;
+    for (mutation, variables, field) in mutations {
+        customer = Some(mutate(client, mutation, variables, field).await?);
+    }
+    customer.ok_or_else(|| GraphqlError::MissingData {
+        field: "data.customerUpdate.customer".to_string(),
+        request_id: None,
+    })
+}
+
+/// Sends a customer mutation and parses the customer from its payload.
+async fn mutate(
+    client: &GraphqlClient,
+    mutation: &str,
+    variables: Value,
+    field: &str,
+) -> Result<Customer, GraphqlError> {
+    let response = client.query(mutation, Some(variables), None, None).await?;
+    parse_response(&response, |body| parse_customer(body, field))
+}
+
+/// Builds the variables for [`create`] and [`update`].
+fn customer_variables(customer_id: Option<&str>, input: &CustomerInput) -> Value {
+    let mut input = serde_json::to_value(input).unwrap_or_else(|_| json!({}));
+    if let Some(id) = customer_id {
+        input["id"] = json!(id);
+    }
+    json!({ "input": input })
+}
+
+/// Parses the customer from a customer mutation response.
+fn parse_customer(body: &Value, field: &str) -> Result<Customer, GraphqlError> {
+    let payload = root_payload(body, field)?;
+    serde_json::from_value(payload["customer"].clone()).map_err(|_| GraphqlError::MissingData {
+        field: format!("data.{field}.customer"),
+        request_id: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consent_inputs_map_to_graphql() {
+        let input = CustomerInput {
+            email: Some("bob@example.com".to_string()),
+            phone: Some("+16135550135".to_string()),
+            email_marketing_consent: Some(EmailMarketingConsent {
+                marketing_state: MarketingState::Subscribed,
+                marketing_opt_in_level: Some(MarketingOptInLevel::ConfirmedOptIn),
+                consent_updated_at: Some(
+                    DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z")
+                        .unwrap()
+                        .with_timezone(&Utc),
+                ),
+            }),
+            sms_marketing_consent: Some(SmsMarketingConsent {
+                marketing_state: MarketingState::NotSubscribed,
+                marketing_opt_in_level: None,
+                consent_updated_at: None,
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            customer_variables(None, &input),
+            json!({
+                "input": {
+                    "email": "bob@example.com",
+                    "phone": "+16135550135",
+                    "emailMarketingConsent": {
+                        "marketingState": "SUBSCRIBED",
+                        "marketingOptInLevel": "CONFIRMED_OPT_IN",
+                        "consentUpdatedAt": "2026-01-01T00:00:00Z"
+                    },
+                    "smsMarketingConsent": {
+                        "marketingState": "NOT_SUBSCRIBED"
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_update_variables_include_id_and_only_set_fields() {
+        let input = CustomerInput {
+            tags: vec!["newsletter".to_string()],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            customer_variables(Some("gid://shopify/Customer/1"), &input),
+            json!({
+                "input": {
+                    "id": "gid://shopify/Customer/1",
+                    "tags": ["newsletter"]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_customer_with_consent() {
+        let body = json!({
+            "data": {
+                "customerCreate": {
+                    "customer": {
+                        "id": "gid://shopify/Customer/1",
+                        "email": "bob@example.com",
+                        "phone": null,
+                        "firstName": "Bob",
+                        "lastName": null,
+                        "tags": [],
+                        "emailMarketingConsent": {
+                            "marketingState": "SUBSCRIBED",
+                            "marketingOptInLevel": "SINGLE_OPT_IN",
+                            "consentUpdatedAt": "2026-01-01T00:00:00Z"
+                        },
+                        "smsMarketingConsent": null
+                    },
+                    "userErrors": []
+                }
+            }
+        });
+
+        let customer = parse_customer(&body, "customerCreate").unwrap();
+        assert_eq!(customer.id, "gid://shopify/Customer/1");
+        assert_eq!(customer.first_name.as_deref(), Some("Bob"));
+        let consent = customer.email_marketing_consent.unwrap();
+        assert_eq!(consent.marketing_state, MarketingState::Subscribed);
+        assert_eq!(
+            consent.marketing_opt_in_level,
+            Some(MarketingOptInLevel::SingleOptIn)
+        );
+        assert!(customer.sms_marketing_consent.is_none());
+    }
+
+    fn customer_payload(field: &str) -> Value {
+        json!({
+            "data": {
+                field: {
+                    "customer": {
+                        "id": "gid://shopify/Customer/1",
+                        "email": "bob@example.com",
+                        "tags": ["newsletter"],
+                        "emailMarketingConsent": { "marketingState": "SUBSCRIBED" },
+                        "smsMarketingConsent": null
+                    },
+                    "userErrors": []
+                }
+            }
+        })
+    }
+
+    fn sent_variables(request: &wiremock::Request) -> (String, Value) {
+        let body: Value = serde_json::from_slice(&request.body).unwrap();
+        (
+            body["query"].as_str().unwrap().to_string(),
+            body["variables"].clone(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_email_user_error_is_surfaced() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": {
+                    "customerCreate": {
+                        "customer": null,
+                        "userErrors": [
+                            { "field": ["email"], "message": "Email has already been taken" }
+                        ]
+                    }
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = GraphqlClient::for_mock_server(&server.uri());
+
+        let result = create(
+            &client,
+            CustomerInput {
+                email: Some("bob@example.com".to_string()),
+                ..Default::default()
+            },
+        )
+        .await;
+
+        let requests = server.received_requests().await.unwrap();
+        let (query, variables) = sent_variables(&requests[0]);
+        assert!(query.contains("customerCreate(input: $input)"));
+        assert_eq!(
+            variables,
+            json!({ "input": { "email": "bob@example.com" } })
+        );
+        match result {
+            Err(GraphqlError::UserErrors { errors, .. }) => {
+                assert_eq!(errors.len(), 1);
+                assert_eq!(errors[0].message, "Email has already been taken");
+                assert_eq!(errors[0].field, Some(vec!["email".to_string()]));
+            }
+            other => panic!("Expected UserErrors, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_sends_consent_through_consent_mutations() {
+        use wiremock::matchers::{body_string_contains, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        for field in [
+            "customerUpdate",
+            "customerEmailMarketingConsentUpdate",
+            "customerSmsMarketingConsentUpdate",
+        ] {
+            Mock::given(method("POST"))
+                .and(body_string_contains(format!("{field}(input: $input)")))
+                .respond_with(ResponseTemplate::new(200).set_body_json(customer_payload(field)))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+        let client = GraphqlClient::for_mock_server(&server.uri());
+        let email_consent = EmailMarketingConsent {
+            marketing_state: MarketingState::Subscribed,
+            marketing_opt_in_level: Some(MarketingOptInLevel::ConfirmedOptIn),
+            consent_updated_at: None,
+        };
+
+        let customer = update(
+            &client,
+            "gid://shopify/Customer/1",
+            CustomerInput {
+                tags: vec!["newsletter".to_string()],
+                email_marketing_consent: Some(email_consent),
+                sms_marketing_consent: Some(SmsMarketingConsent {
+                    marketing_state: MarketingState::Unsubscribed,
+                    marketing_opt_in_level: None,
+                    consent_updated_at: None,
+                }),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(customer.id, "gid://shopify/Customer/1");
+        let requests = server.received_requests().await.unwrap();
+        let sent: Vec<(String, Value)> = requests.iter().map(sent_variables).collect();
+        assert_eq!(sent.len(), 3);
+        assert!(sent[0].0.contains("customerUpdate(input: $input)"));
+        assert_eq!(
+            sent[0].1,
+            json!({
+                "input": { "id": "gid://shopify/Customer/1", "tags": ["newsletter"] }
+            })
+        );
+        assert!(sent[1].0.contains("customerEmailMarketingConsentUpdate("));
+        assert_eq!(
+            sent[1].1,
+            json!({
+                "input": {
+                    "customerId": "gid://shopify/Customer/1",
+                    "emailMarketingConsent": {
+                        "marketingState": "SUBSCRIBED",
+                        "marketingOptInLevel": "CONFIRMED_OPT_IN"
+                    }
+                }
+            })
+        );
+        assert!(sent[2].0.contains("customerSmsMarketingConsentUpdate("));
+        assert_eq!(
+            sent[2].1,
+            json!({
+                "input": {
+                    "customerId": "gid://shopify/Customer/1",
+                    "smsMarketingConsent": { "marketingState": "UNSUBSCRIBED" }
+                }
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_with_only_consent_skips_customer_update() {
+        use wiremock::matchers::{body_string_contains, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("customerUpdate(input: $input)"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_string_contains(
+                "customerEmailMarketingConsentUpdate(input: $input)",
+            ))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(customer_payload("customerEmailMarketingConsentUpdate")),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = GraphqlClient::for_mock_server(&server.uri());
+
+        let customer = update(
+            &client,
+            "gid://shopify/Customer/1",
+            CustomerInput {
+                email_marketing_consent: Some(EmailMarketingConsent {
+                    marketing_state: MarketingState::Subscribed,
+                    marketing_opt_in_level: None,
+                    consent_updated_at: None,
+                }),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            customer.email_marketing_consent.unwrap().marketing_state,
+            MarketingState::Subscribed
+        );
+    }
+
+    #[test]
+    fn test_mutations_declare_input_and_select_consent() {
+        assert!(CREATE_MUTATION.contains("customerCreate(input: $input)"));
+        assert!(UPDATE_MUTATION.contains("customerUpdate(input: $input)"));
+        assert!(
+            EMAIL_CONSENT_MUTATION.contains("customerEmailMarketingConsentUpdate(input: $input)")
+        );
+        assert!(SMS_CONSENT_MUTATION.contains("customerSmsMarketingConsentUpdate(input: $input)"));
+        for mutation in [
+            CREATE_MUTATION,
+            UPDATE_MUTATION,
+            EMAIL_CONSENT_MUTATION,
+            SMS_CONSENT_MUTATION,
+        ] {
+            assert!(mutation.contains("emailMarketingConsent {"));
+            assert!(mutation.contains("smsMarketingConsent {"));
+            assert!(mutation.contains("userErrors"));
+        }
+    }
+}
@@ -0,0 +1,289 @@
+//! Discount helpers for the GraphQL Admin API.
+//!
+//! The REST `PriceRule` and `DiscountCode` resources are deprecated for
+//! creating discounts. This module wraps the `discountCodeBasicCreate`
+//! mutation with typed inputs instead.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use chrono::Utc;
+//! use shopify_sdk::clients::graphql::discounts::{
+//!     self, BasicCodeDiscountInput, CustomerSelection, DiscountValue, MinimumRequirement,
+//! };
+//!
+//! let input = BasicCodeDiscountInput {
+//!     title: "10% off orders over $50".to_string(),
+//!     code: "SAVE10".to_string(),
+//!     starts_at: Utc::now(),
+//!     ends_at: None,
+//!     customer_selection: CustomerSelection::All,
+//!     value: DiscountValue::Percentage(0.1),
+//!     minimum_requirement: Some(MinimumRequirement::Subtotal("50.00".to_string())),
+//!     usage_limit: Some(100),
+//! };
+//!
+//! let discount_id = discounts::create_basic_code(&client, input).await?;
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde_json::{json, Value};
+
+use super::payload::{parse_response, root_payload};
+use super::{GraphqlClient, GraphqlError};
+
+/// Mutation used by [`create_basic_code`].
+const CREATE_BASIC_CODE_MUTATION: &str = r"
+mutation discountCodeBasicCreate($basicCodeDiscount: DiscountCodeBasicInput!) {
+    discountCodeBasicCreate(basicCodeDiscount: $basicCodeDiscount) {
+        codeDiscountNode {
+            id
+        }
+        userErrors {
+            field
+            message
+        }
+    }
+}
+";
+
+/// Which customers are eligible for a discount.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CustomerSelection {
+    /// Every customer is eligible.
+    All,
+    /// Only the given customers (by GID) are eligible.
+    Customers(Vec<String>),
+    /// Only customers in the given segments (by GID) are eligible.
+    Segments(Vec<String>),
+}
+
+/// The value a discount takes off.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiscountValue {
+    /// A percentage off, expressed as a fraction between `0.0` and `1.0`
+    /// (e.g. `0.1` for 10% off).
+    Percentage(f64),
+    /// A fixed amount off, in the shop's currency.
+    FixedAmount {
+        /// The amount as a decimal string (e.g. `"5.00"`).
+        amount: String,
+        /// Whether the amount is taken off each eligible item rather than
+        /// once per order.
+        applies_on_each_item: bool,
+    },
+}
+
+/// The minimum a cart must reach before a discount applies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MinimumRequirement {
+    /// A minimum subtotal, as a decimal string (e.g. `"50.00"`).
+    Subtotal(String),
+    /// A minimum number of items.
+    Quantity(u64),
+}
+
+/// Input for [`create_basic_code`].
+///
+/// The discount applies to all items. Fields map to Shopify's
+/// `DiscountCodeBasicInput`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BasicCodeDiscountInput {
+    /// The discount's title, shown to merchants in the admin.
+    pub title: String,
+    /// The code customers enter at checkout.
+    pub code: String,
+    /// When the discount becomes active.
+    pub starts_at: DateTime<Utc>,
+    /// When the discount expires, if ever.
+    pub ends_at: Option<DateTime<Utc>>,
+    /// Which customers are eligible.
+    pub customer_selection: CustomerSelection,
+    /// The value the discount takes off.
+    pub value: DiscountValue,
+    /// The minimum the cart must reach, if any.
+    pub minimum_requirement: Option<MinimumRequirement>,
+    /// The maximum number of times the code can be used in total.
+    pub usage_limit: Option<u32>,
+}
+
+impl BasicCodeDiscountInput {
+    /// Converts the input into the `DiscountCodeBasicInput` GraphQL shape.
+    fn to_graphql(&self) -> Value {
+        let customer_selection = match &self.customer_selection {
+            CustomerSelection::All => json!({ "all": true }),
+            CustomerSelection::Customers(ids) => json!({ "customers": { "add": ids } }),
+            CustomerSelection::Segments(ids) => json!({ "customerSegments": { "add": ids } }),
+        };
+
+        let value = match &self.value {
+            DiscountValue::Percentage(percentage) => json!({ "percentage": percentage }),
+            DiscountValue::FixedAmount {
+                amount,
+                applies_on_each_item,
+            } => json!({
+                "discountAmount": {
+                    "amount": amount,
+                    "appliesOnEachItem": applies_on_each_item,
+                }
+            }),
+        };
+
+        let mut input = json!({
+            "title": self.title,
+            "code": self.code,
+            "startsAt": self.starts_at.to_rfc3339(),
+            "customerSelection": customer_selection,
+            "customerGets": {
+                "value": value,
+                "items": { "all": true },
+            },
+        });
+
+        if let Some(ends_at) = self.ends_at {
+            input["endsAt"] = json!(ends_at.to_rfc3339());
+        }
+
+        if let Some(requirement) = &self.minimum_requirement {
+            input["minimumRequirement"] = match requirement {
+                MinimumRequirement::Subtotal(amount) => json!({
+                    "subtotal": { "greaterThanOrEqualToSubtotal": amount }
+                }),
+                MinimumRequirement::Quantity(quantity) => json!({
+                    "quantity": { "greaterThanOrEqualToQuantity": quantity.to_string() }
+                }),
+            };
+        }
+
+        if let Some(usage_limit) = self.usage_limit {
+            input["usageLimit"] = json!(usage_limit);
+        }
+
+        input
+    }
+}
+
+/// Creates a basic discount code.
+///
+/// Wraps the `discountCodeBasicCreate` mutation.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the request
+/// * `input` - The discount definition
+///
+/// # Returns
+///
+/// The GID of the created discount node (e.g. `gid://shopify/DiscountCodeNode/1`).
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if Shopify rejects the discount
+/// (for example, a duplicate code).
+/// Returns [`GraphqlError::MissingData`] if the response has no discount node.
+pub async fn create_basic_code(
+    client: &GraphqlClient,
+    input: BasicCodeDiscountInput,
+) -> Result<String, GraphqlError> {
+    let variables = json!({ "basicCodeDiscount": input.to_graphql() });
+    let response = client
+        .query(CREATE_BASIC_CODE_MUTATION, Some(variables), None, None)
+        .await?;
+
+    parse_response(&response, |body| {
+        root_payload(body, "discountCodeBasicCreate")?["codeDiscountNode"]["id"]
+            .as_str()
+            .map(String::from)
+            .ok_or_else(|| GraphqlError::MissingData {
+                field: "data.discountCodeBasicCreate.codeDiscountNode.id".to_string(),
+                request_id: None,
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn starts_at() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_percentage_code_with_minimum_subtotal_variables() {
+        let input = BasicCodeDiscountInput {
+            title: "10% off orders over $50".to_string(),
+            code: "SAVE10".to_string(),
+            starts_at: starts_at(),
+            ends_at: None,
+            customer_selection: CustomerSelection::All,
+            value: DiscountValue::Percentage(0.1),
+            minimum_requirement: Some(MinimumRequirement::Subtotal("50.00".to_string())),
+            usage_limit: Some(100),
+        };
+
+        assert_eq!(
+            input.to_graphql(),
+            json!({
+                "title": "10% off orders over $50",
+                "code": "SAVE10",
+                "startsAt": "2026-01-01T00:00:00+00:00",
+                "customerSelection": { "all": true },
+                "customerGets": {
+                    "value": { "percentage": 0.1 },
+                    "items": { "all": true }
+                },
+                "minimumRequirement": {
+                    "subtotal": { "greaterThanOrEqualToSubtotal": "50.00" }
+                },
+                "usageLimit": 100
+            })
+        );
+    }
+
+    #[test]
+    fn test_fixed_amount_code_for_selected_customers() {
+        let input = BasicCodeDiscountInput {
+            title: "VIP $5 off".to_string(),
+            code: "VIP5".to_string(),
+            starts_at: starts_at(),
+            ends_at: Some(starts_at() + chrono::Duration::days(30)),
+            customer_selection: CustomerSelection::Customers(vec![
+                "gid://shopify/Customer/1".to_string()
+            ]),
+            value: DiscountValue::FixedAmount {
+                amount: "5.00".to_string(),
+                applies_on_each_item: false,
+            },
+            minimum_requirement: Some(MinimumRequirement::Quantity(2)),
+            usage_limit: None,
+        };
+
+        let variables = input.to_graphql();
+
+        assert_eq!(variables["endsAt"], "2026-01-31T00:00:00+00:00");
+        assert_eq!(
+            variables["customerSelection"],
+            json!({ "customers": { "add": ["gid://shopify/Customer/1"] } })
+        );
+        assert_eq!(
+            variables["customerGets"]["value"],
+            json!({ "discountAmount": { "amount": "5.00", "appliesOnEachItem": false } })
+        );
+        assert_eq!(
+            variables["minimumRequirement"],
+            json!({ "quantity": { "greaterThanOrEqualToQuantity": "2" } })
+        );
+        assert!(variables.get("usageLimit").is_none());
+    }
+
+    #[test]
+    fn test_create_basic_code_mutation_declares_input() {
+        assert!(CREATE_BASIC_CODE_MUTATION
+            .contains("discountCodeBasicCreate(basicCodeDiscount: $basicCodeDiscount)"));
+        assert!(CREATE_BASIC_CODE_MUTATION.contains("userErrors"));
+    }
+}
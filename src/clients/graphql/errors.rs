@@ -6,11 +6,20 @@
 //! # Error Handling
 //!
 //! The SDK uses specific error types for different failure scenarios.
-//! For GraphQL, only HTTP-level errors are exposed. GraphQL-level errors
+//! For raw queries, only HTTP-level errors are exposed. GraphQL-level errors
 //! (such as user errors or validation errors) are returned in the response
 //! body with HTTP status 200, and are the user's responsibility to parse.
 //!
+//! The typed mutation helpers (such as [`inventory`](super::inventory)) parse
+//! the response body themselves and surface those errors as SDK errors.
+//!
 //! - [`GraphqlError::Http`]: Wraps underlying HTTP errors
+//! - [`GraphqlError::UserErrors`]: `userErrors` or top-level `errors` returned by a helper
+//! - [`GraphqlError::MissingData`]: A helper's expected payload was absent
+//! - [`GraphqlError::BulkOperationFailed`]: A bulk operation ended without completing
+//! - [`GraphqlError::InvalidToken`]: The access token was rejected by [`GraphqlClient::ping`](super::GraphqlClient::ping)
+//! - [`GraphqlError::StorefrontErrors`]: Top-level `errors` returned by [`StorefrontClient::query_data`](crate::StorefrontClient::query_data)
+//! - [`GraphqlError::IncrementalDeliveryUnsupported`]: The response was a multipart incremental delivery
 //!
 //! # Example
 //!
@@ -29,12 +38,64 @@
 //!     Err(GraphqlError::Http(e)) => {
 //!         println!("HTTP error: {}", e);
 //!     }
+//!     Err(e) => {
+//!         println!("Other error: {}", e);
+//!     }
 //! }
 //! ```
 
+use super::bulk::BulkOperationStatus;
 use crate::clients::HttpError;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+/// A single user error returned by a GraphQL mutation.
+///
+/// Shopify reports business-logic failures (invalid input, missing
+/// permissions on a resource, etc.) in a mutation's `userErrors` field
+/// while still responding with HTTP 200.
+///
+/// # Example
+///
+/// ```rust
+/// use shopify_sdk::clients::graphql::UserError;
+///
+/// let error: UserError = serde_json::from_value(serde_json::json!({
+///     "field": ["input", "locationId"],
+///     "message": "Location not found"
+/// })).unwrap();
+///
+/// assert_eq!(error.message, "Location not found");
+/// assert_eq!(error.to_string(), "input.locationId: Location not found");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserError {
+    /// Path to the input field that caused the error, if any.
+    #[serde(default)]
+    pub field: Option<Vec<String>>,
+
+    /// Human-readable description of the error.
+    pub message: String,
+}
+
+impl std::fmt::Display for UserError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.field {
+            Some(field) if !field.is_empty() => write!(f, "{}: {}", field.join("."), self.message),
+            _ => f.write_str(&self.message),
+        }
+    }
+}
+
+/// Joins user error messages for display.
+fn join_user_errors(errors: &[UserError]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
 /// Error type for GraphQL API operations.
 ///
 /// This enum provides error types for GraphQL API operations,
@@ -43,7 +104,9 @@ use thiserror::Error;
 ///
 /// Note that GraphQL-level errors (like user errors, validation errors)
 /// are returned with HTTP 200 status and are contained in the response
-/// body's `errors` field. These are not treated as SDK errors.
+/// body's `errors` field. [`GraphqlClient::query`](super::GraphqlClient::query)
+/// does not treat these as SDK errors; the typed helpers report them as
+/// [`GraphqlError::UserErrors`].
 ///
 /// # Example
 ///
@@ -68,6 +131,151 @@ pub enum GraphqlError {
     /// It includes network errors, non-2xx responses, and retry exhaustion.
     #[error(transparent)]
     Http(#[from] HttpError),
+
+    /// The operation returned user errors or top-level GraphQL errors.
+    ///
+    /// Only returned by the typed helpers, which inspect the response body.
+    #[error("GraphQL operation failed: {}", join_user_errors(.errors))]
+    UserErrors {
+        /// The errors reported by Shopify.
+        errors: Vec<UserError>,
+        /// The `X-Request-Id` of the response that reported them, if any.
+        request_id: Option<String>,
+    },
+
+    /// The response did not contain the expected payload.
+    ///
+    /// Only returned by the typed helpers, which inspect the response body.
+    #[error("GraphQL response is missing expected data: {field}")]
+    MissingData {
+        /// The path of the missing field.
+        field: String,
+        /// The `X-Request-Id` of the response missing it, if any.
+        request_id: Option<String>,
+    },
+
+    /// A bulk operation finished in a status other than `COMPLETED`.
+    ///
+    /// Returned by [`bulk::wait_for_completion`](super::bulk::wait_for_completion).
+    #[error("Bulk operation {id} ended with status {status}{}", .error_code.as_ref().map(|c| format!(" ({c})")).unwrap_or_default())]
+    BulkOperationFailed {
+        /// The operation GID.
+        id: String,
+        /// The final status.
+        status: BulkOperationStatus,
+        /// Shopify's error code, if any.
+        error_code: Option<String>,
+        /// The URL of any partial results.
+        partial_data_url: Option<String>,
+    },
+
+    /// The access token was rejected (HTTP 401 or 403).
+    ///
+    /// Only returned by [`GraphqlClient::ping`](super::GraphqlClient::ping),
+    /// where the probe query needs no access scopes, so a rejection means the
+    /// token is invalid, revoked, or belongs to another shop.
+    #[error("Access token is invalid or has been revoked (HTTP {code})")]
+    InvalidToken {
+        /// The HTTP status code (401 or 403).
+        code: u16,
+        /// The request ID from Shopify, if available.
+        error_reference: Option<String>,
+    },
+
+    /// The query document is malformed and was not sent.
+    ///
+    /// Returned by queries only when the `graphql-validate` feature is
+    /// enabled, which checks documents locally for unbalanced braces, a
+    /// missing operation keyword and undeclared variables before sending
+    /// them. [`FieldSet`](super::FieldSet) also returns it for invalid field
+    /// names and arguments.
+    #[error("Invalid GraphQL document: {reason}")]
+    InvalidDocument {
+        /// What is wrong with the document.
+        reason: String,
+    },
+
+    /// The Storefront API returned top-level `errors`.
+    ///
+    /// Only returned by [`StorefrontClient::query_data`](crate::StorefrontClient::query_data).
+    /// Each error's `path`, if present, is reported as the [`UserError::field`].
+    #[error("Storefront API returned errors: {}", join_user_errors(.errors))]
+    StorefrontErrors {
+        /// The errors reported by Shopify.
+        errors: Vec<UserError>,
+    },
+
+    /// The response used incremental delivery, which the SDK can't parse.
+    ///
+    /// Queries using `@defer` or `@stream` may be answered with a
+    /// `multipart/mixed` response made of several JSON parts instead of a
+    /// single JSON body. Remove those directives from the query to receive
+    /// the whole result at once.
+    #[error("GraphQL response uses incremental delivery ({content_type}), which is not supported; remove @defer and @stream from the query")]
+    IncrementalDeliveryUnsupported {
+        /// The `Content-Type` of the response.
+        content_type: String,
+    },
+
+    /// A line of bulk operation results could not be read.
+    ///
+    /// Returned by [`bulk::BulkResultReader`](super::bulk::BulkResultReader)
+    /// for a line that isn't a JSON object or whose parent wasn't seen.
+    #[error("Invalid bulk result line {line}: {reason}")]
+    InvalidBulkResultLine {
+        /// The line number, starting at 1.
+        line: usize,
+        /// What is wrong with the line.
+        reason: String,
+    },
+}
+
+impl GraphqlError {
+    /// Returns the `X-Request-Id` of the failed response, if any.
+    ///
+    /// HTTP-level failures carry the ID of the failed response; see
+    /// [`HttpError::request_id`]. [`UserErrors`](Self::UserErrors) and
+    /// [`MissingData`](Self::MissingData) carry the ID of the successful
+    /// response whose body reported them. Errors raised before a request
+    /// was sent have none.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::clients::graphql::GraphqlError;
+    /// use shopify_sdk::clients::{HttpError, HttpResponseError};
+    ///
+    /// let error = GraphqlError::Http(HttpError::Response(HttpResponseError {
+    ///     code: 500,
+    ///     message: r#"{"errors":"Internal error"}"#.to_string(),
+    ///     error_reference: Some("abc-123".to_string()),
+    /// }));
+    /// assert_eq!(error.request_id(), Some("abc-123"));
+    /// ```
+    #[must_use]
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Self::Http(e) => e.request_id(),
+            Self::UserErrors { request_id, .. } | Self::MissingData { request_id, .. } => {
+                request_id.as_deref()
+            }
+            _ => None,
+        }
+    }
+
+    /// Sets the request ID of a [`UserErrors`](Self::UserErrors) or
+    /// [`MissingData`](Self::MissingData) error found in a response body.
+    ///
+    /// Other variants are returned unchanged.
+    #[must_use]
+    pub(crate) fn with_request_id(mut self, id: Option<&str>) -> Self {
+        if let Self::UserErrors { request_id, .. } | Self::MissingData { request_id, .. } =
+            &mut self
+        {
+            *request_id = id.map(String::from);
+        }
+        self
+    }
 }
 
 #[cfg(test)]
@@ -89,6 +297,32 @@ mod tests {
         assert!(message.contains("Not Found"));
     }
 
+    #[tokio::test]
+    async fn test_request_id_captured_for_failed_graphql_request() {
+        use crate::clients::graphql::GraphqlClient;
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(400)
+                    .insert_header("X-Request-Id", "req-400")
+                    .set_body_json(serde_json::json!({ "errors": "Bad query" })),
+            )
+            .mount(&server)
+            .await;
+        let client = GraphqlClient::for_mock_server(&server.uri());
+
+        let error = client
+            .query("{ shop { name } }", None, None, None)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, GraphqlError::Http(_)));
+        assert_eq!(error.request_id(), Some("req-400"));
+    }
+
     #[test]
     fn test_all_error_variants_implement_std_error() {
         // Http variant
@@ -99,6 +333,85 @@ mod tests {
                 error_reference: None,
             }));
         let _ = http_error;
+
+        // UserErrors variant
+        let user_errors: &dyn std::error::Error = &GraphqlError::UserErrors {
+            errors: vec![],
+            request_id: None,
+        };
+        let _ = user_errors;
+
+        // MissingData variant
+        let missing: &dyn std::error::Error = &GraphqlError::MissingData {
+            field: "data.shop".to_string(),
+            request_id: None,
+        };
+        let _ = missing;
+
+        // BulkOperationFailed variant
+        let bulk_failed: &dyn std::error::Error = &GraphqlError::BulkOperationFailed {
+            id: "gid://shopify/BulkOperation/1".to_string(),
+            status: BulkOperationStatus::Failed,
+            error_code: Some("INTERNAL_SERVER_ERROR".to_string()),
+            partial_data_url: None,
+        };
+        assert_eq!(
+            bulk_failed.to_string(),
+            "Bulk operation gid://shopify/BulkOperation/1 ended with status FAILED (INTERNAL_SERVER_ERROR)"
+        );
+
+        // InvalidToken variant
+        let invalid_token: &dyn std::error::Error = &GraphqlError::InvalidToken {
+            code: 401,
+            error_reference: None,
+        };
+        let _ = invalid_token;
+
+        // StorefrontErrors variant
+        let storefront: &dyn std::error::Error = &GraphqlError::StorefrontErrors {
+            errors: vec![UserError {
+                field: Some(vec!["products".to_string()]),
+                message: "Throttled".to_string(),
+            }],
+        };
+        assert_eq!(
+            storefront.to_string(),
+            "Storefront API returned errors: products: Throttled"
+        );
+    }
+
+    #[test]
+    fn test_user_errors_message_joins_all_errors() {
+        let error = GraphqlError::UserErrors {
+            errors: vec![
+                UserError {
+                    field: Some(vec!["input".to_string(), "title".to_string()]),
+                    message: "Title can't be blank".to_string(),
+                },
+                UserError {
+                    field: None,
+                    message: "Something else".to_string(),
+                },
+            ],
+            request_id: None,
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "GraphQL operation failed: input.title: Title can't be blank; Something else"
+        );
+    }
+
+    #[test]
+    fn test_user_error_deserializes_with_null_field() {
+        let error: UserError = serde_json::from_value(serde_json::json!({
+            "field": null,
+            "message": "Access denied"
+        }))
+        .unwrap();
+
+        assert_eq!(error.field, None);
+        assert_eq!(error.to_string(), "Access denied");
     }
 
     #[test]
@@ -0,0 +1,363 @@
+//! Reusable field selections for GraphQL documents.
+//!
+//! Queries that return the same type tend to repeat the same selection, and
+//! the copies drift apart as fields are added to one and not the others. A
+//! [`FieldSet`] defines a selection once; it renders as a selection set to
+//! interpolate into any number of queries, or as a named fragment.
+//!
+//! Field names and arguments are validated as they are added, so a field set
+//! can't break the structure of the documents it is interpolated into.
+
+use std::fmt;
+
+use super::GraphqlError;
+
+/// A reusable selection of GraphQL fields.
+///
+/// Build a set from field names, add nested selections with
+/// [`nested`](Self::nested), then interpolate it with `{}`: it renders as a
+/// selection set such as `{ id title }`. [`fragment`](Self::fragment)
+/// renders it as a fragment definition instead.
+///
+/// Adding a field that is already in the set has no effect, and nested
+/// selections for the same field are merged, so sets can be combined with
+/// [`merge`](Self::merge) without duplicating fields.
+///
+/// # Example
+///
+/// ```rust
+/// use shopify_sdk::clients::graphql::FieldSet;
+///
+/// let image = FieldSet::new(["url", "altText"]).unwrap();
+/// let product = FieldSet::new(["id", "title", "handle"])
+///     .unwrap()
+///     .nested("featuredImage", image)
+///     .unwrap();
+///
+/// let query = format!("query($id: ID!) {{ product(id: $id) {product} }}");
+/// assert_eq!(
+///     query,
+///     "query($id: ID!) { product(id: $id) { id title handle featuredImage { url altText } } }"
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FieldSet {
+    fields: Vec<Field>,
+}
+
+/// A single field of a [`FieldSet`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Field {
+    /// The field name.
+    name: String,
+    /// The field's arguments, including the parentheses.
+    arguments: Option<String>,
+    /// The nested selection, for object fields.
+    selection: Option<FieldSet>,
+}
+
+impl FieldSet {
+    /// Creates a set of scalar fields.
+    ///
+    /// Each entry is a field name, optionally followed by arguments
+    /// (e.g. `"metafield(namespace: \"custom\", key: \"size\")"`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphqlError::InvalidDocument`] if an entry is not a valid
+    /// field.
+    pub fn new<I, S>(fields: I) -> Result<Self, GraphqlError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        fields
+            .into_iter()
+            .try_fold(Self::default(), |set, field| set.field(field.as_ref()))
+    }
+
+    /// Adds a scalar field.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphqlError::InvalidDocument`] if `field` is not a valid
+    /// field name, optionally followed by arguments.
+    pub fn field(mut self, field: &str) -> Result<Self, GraphqlError> {
+        let (name, arguments) = parse_field(field)?;
+        self.push(Field {
+            name,
+            arguments,
+            selection: None,
+        });
+        Ok(self)
+    }
+
+    /// Adds a field with a nested selection, such as `featuredImage` or
+    /// `variants(first: 10)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphqlError::InvalidDocument`] if `field` is not a valid
+    /// field, or if `fields` is empty.
+    pub fn nested(mut self, field: &str, fields: Self) -> Result<Self, GraphqlError> {
+        let (name, arguments) = parse_field(field)?;
+        if fields.is_empty() {
+            return Err(invalid(format!("`{name}` has an empty selection")));
+        }
+        self.push(Field {
+            name,
+            arguments,
+            selection: Some(fields),
+        });
+        Ok(self)
+    }
+
+    /// Adds every field of `other` to this set.
+    #[must_use]
+    pub fn merge(mut self, other: Self) -> Self {
+        for field in other.fields {
+            self.push(field);
+        }
+        self
+    }
+
+    /// Returns `true` if the set has no fields.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Renders the set as a fragment definition, such as
+    /// `fragment ProductFields on Product { id title }`.
+    ///
+    /// Append the definition to a document and spread it with
+    /// `...ProductFields`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphqlError::InvalidDocument`] if `name` or `type_condition`
+    /// is not a valid name, or if the set is empty.
+    pub fn fragment(&self, name: &str, type_condition: &str) -> Result<String, GraphqlError> {
+        validate_name(name)?;
+        validate_name(type_condition)?;
+        if self.is_empty() {
+            return Err(invalid(format!("fragment `{name}` has no fields")));
+        }
+        Ok(format!("fragment {name} on {type_condition} {self}"))
+    }
+
+    /// Adds a field, merging it with an existing field of the same name and
+    /// arguments.
+    fn push(&mut self, field: Field) {
+        let existing = self
+            .fields
+            .iter_mut()
+            .find(|existing| existing.name == field.name && existing.arguments == field.arguments);
+
+        match (existing, field.selection) {
+            (None, selection) => self.fields.push(Field { selection, ..field }),
+            (Some(existing), Some(selection)) => {
+                existing.selection = Some(match existing.selection.take() {
+                    Some(current) => current.merge(selection),
+                    None => selection,
+                });
+            }
+            (Some(_), None) => {}
+        }
+    }
+}
+
+impl fmt::Display for FieldSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+        for field in &self.fields {
+            write!(f, " {}", field.name)?;
+            if let Some(arguments) = &field.arguments {
+                f.write_str(arguments)?;
+            }
+            if let Some(selection) = &field.selection {
+                write!(f, " {selection}")?;
+            }
+        }
+        f.write_str(" }")
+    }
+}
+
+/// Splits a field into its name and arguments, validating both.
+fn parse_field(field: &str) -> Result<(String, Option<String>), GraphqlError> {
+    let field = field.trim();
+    let (name, arguments) = field
+        .find('(')
+        .map_or((field, ""), |start| field.split_at(start));
+    let name = name.trim_end();
+    validate_name(name)?;
+
+    if arguments.is_empty() {
+        return Ok((name.to_string(), None));
+    }
+    validate_arguments(name, arguments)?;
+    Ok((name.to_string(), Some(arguments.to_string())))
+}
+
+/// Checks that `name` is a GraphQL name: a letter or underscore followed by
+/// letters, digits or underscores.
+fn validate_name(name: &str) -> Result<(), GraphqlError> {
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(invalid(format!("`{name}` is not a valid GraphQL name")))
+    }
+}
+
+/// Checks that `arguments` is a single parenthesized argument list that
+/// can't open a selection or comment out the rest of the document.
+fn validate_arguments(name: &str, arguments: &str) -> Result<(), GraphqlError> {
+    let rejected = || invalid(format!("`{name}` has invalid arguments `{arguments}`"));
+    let mut depth = 0_usize;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, c) in arguments.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                '\n' => return Err(rejected()),
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    // The list must end the field
+                    return if i + 1 == arguments.len() {
+                        Ok(())
+                    } else {
+                        Err(rejected())
+                    };
+                }
+            }
+            '{' | '}' | '#' => return Err(rejected()),
+            _ => {}
+        }
+    }
+    Err(rejected())
+}
+
+/// Builds a [`GraphqlError::InvalidDocument`] error.
+fn invalid(reason: impl Into<String>) -> GraphqlError {
+    GraphqlError::InvalidDocument {
+        reason: reason.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn product_fields() -> FieldSet {
+        FieldSet::new(["id", "title", "handle"])
+            .unwrap()
+            .nested(
+                "variants(first: 10)",
+                FieldSet::new(["id", "sku", "price"]).unwrap(),
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_shared_field_set_expands_into_each_query() {
+        let fields = product_fields();
+
+        let by_id = format!("query($id: ID!) {{ product(id: $id) {fields} }}");
+        let by_handle =
+            format!("query($handle: String!) {{ productByHandle(handle: $handle) {fields} }}");
+
+        let selection = "{ id title handle variants(first: 10) { id sku price } }";
+        assert_eq!(
+            by_id,
+            format!("query($id: ID!) {{ product(id: $id) {selection} }}")
+        );
+        assert_eq!(
+            by_handle,
+            format!("query($handle: String!) {{ productByHandle(handle: $handle) {selection} }}")
+        );
+    }
+
+    #[test]
+    fn test_fragment_renders_named_definition() {
+        assert_eq!(
+            product_fields().fragment("ProductFields", "Product").unwrap(),
+            "fragment ProductFields on Product { id title handle variants(first: 10) { id sku price } }"
+        );
+        assert!(FieldSet::default().fragment("Empty", "Product").is_err());
+        assert!(product_fields()
+            .fragment("Product Fields", "Product")
+            .is_err());
+    }
+
+    #[test]
+    fn test_merge_deduplicates_fields_and_nested_selections() {
+        let extra = FieldSet::new(["title", "vendor"])
+            .unwrap()
+            .nested(
+                "variants(first: 10)",
+                FieldSet::new(["sku", "barcode"]).unwrap(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            product_fields().merge(extra).to_string(),
+            "{ id title handle variants(first: 10) { id sku price barcode } vendor }"
+        );
+    }
+
+    #[test]
+    fn test_invalid_fields_are_rejected() {
+        for field in [
+            "",
+            "1title",
+            "title }",
+            "title # comment",
+            "product { id }",
+            "variants(first: 10",
+            "variants(first: 10) { id }",
+            "variants(first: 10)(last: 1)",
+            "metafield(key: \"size)",
+        ] {
+            assert!(
+                matches!(
+                    FieldSet::new([field]),
+                    Err(GraphqlError::InvalidDocument { .. })
+                ),
+                "expected {field:?} to be rejected"
+            );
+        }
+
+        assert!(FieldSet::default()
+            .nested("featuredImage", FieldSet::default())
+            .is_err());
+    }
+
+    #[test]
+    fn test_arguments_may_contain_strings_with_punctuation() {
+        let fields =
+            FieldSet::new([r#"metafield(namespace: "custom", key: "size (cm)")"#]).unwrap();
+
+        assert_eq!(
+            fields.to_string(),
+            r#"{ metafield(namespace: "custom", key: "size (cm)") }"#
+        );
+    }
+}
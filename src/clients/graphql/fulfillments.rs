@@ -0,0 +1,424 @@
+//! Fulfillment helpers for the GraphQL Admin API.
+//!
+//! Fulfillments are created against fulfillment orders. [`create`] wraps
+//! `fulfillmentCreateV2`, fulfilling some or all of a fulfillment order's
+//! line items with optional tracking, and [`update_tracking`] wraps
+//! `fulfillmentTrackingInfoUpdate` for adding or correcting tracking once a
+//! shipment is on its way.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use shopify_sdk::clients::graphql::fulfillments::{self, FulfillmentLineItemInput, TrackingInput};
+//!
+//! let fulfillment = fulfillments::create(
+//!     &client,
+//!     "gid://shopify/FulfillmentOrder/1046000778",
+//!     Some(TrackingInput {
+//!         company: Some("UPS".to_string()),
+//!         number: Some("1Z999AA10123456784".to_string()),
+//!         ..Default::default()
+//!     }),
+//!     true,
+//!     vec![FulfillmentLineItemInput {
+//!         id: "gid://shopify/FulfillmentOrderLineItem/1025578643".to_string(),
+//!         quantity: 1,
+//!     }],
+//! )
+//! .await?;
+//!
+//! // The carrier reissued the label
+//! fulfillments::update_tracking(
+//!     &client,
+//!     &fulfillment.id,
+//!     TrackingInput {
+//!         company: Some("UPS".to_string()),
+//!         number: Some("1Z999AA10123456795".to_string()),
+//!         ..Default::default()
+//!     },
+//!     false,
+//! )
+//! .await?;
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::payload::{parse_response, root_payload};
+use super::{GraphqlClient, GraphqlError};
+
+/// Mutation used by [`create`].
+const CREATE_MUTATION: &str = r"
+mutation fulfillmentCreateV2($fulfillment: FulfillmentV2Input!) {
+    fulfillmentCreateV2(fulfillment: $fulfillment) {
+        fulfillment {
+            id
+            status
+            trackingInfo {
+                company
+                number
+                url
+            }
+        }
+        userErrors {
+            field
+            message
+        }
+    }
+}
+";
+
+/// Mutation used by [`update_tracking`].
+const UPDATE_TRACKING_MUTATION: &str = r"
+mutation fulfillmentTrackingInfoUpdate($fulfillmentId: ID!, $trackingInfoInput: FulfillmentTrackingInput!, $notifyCustomer: Boolean) {
+    fulfillmentTrackingInfoUpdate(fulfillmentId: $fulfillmentId, trackingInfoInput: $trackingInfoInput, notifyCustomer: $notifyCustomer) {
+        fulfillment {
+            id
+            status
+            trackingInfo {
+                company
+                number
+                url
+            }
+        }
+        userErrors {
+            field
+            message
+        }
+    }
+}
+";
+
+/// Tracking details for a fulfillment.
+///
+/// Maps to Shopify's `FulfillmentTrackingInput`. Use `number`/`url` for a
+/// single package, or `numbers`/`urls` for a shipment split across several.
+/// Unset fields are omitted from the input. When `url` is omitted, Shopify
+/// generates one for known carriers.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackingInput {
+    /// The carrier name (e.g. `"UPS"`, `"Canada Post"`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+    /// The tracking number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<String>,
+    /// The tracking URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Tracking numbers for a multi-package shipment.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub numbers: Vec<String>,
+    /// Tracking URLs for a multi-package shipment, in the same order as `numbers`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub urls: Vec<String>,
+}
+
+/// A fulfillment order line item to fulfill.
+///
+/// Maps to Shopify's `FulfillmentOrderLineItemInput`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FulfillmentLineItemInput {
+    /// The fulfillment order line item GID.
+    pub id: String,
+    /// The quantity to fulfill.
+    pub quantity: u32,
+}
+
+/// A fulfillment returned by [`create`] or [`update_tracking`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Fulfillment {
+    /// The fulfillment GID.
+    pub id: String,
+    /// The fulfillment status (e.g. `"SUCCESS"`).
+    pub status: String,
+    /// The tracking details, one entry per package.
+    #[serde(default)]
+    pub tracking_info: Vec<TrackingInfo>,
+}
+
+/// Tracking details of a [`Fulfillment`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct TrackingInfo {
+    /// The carrier name.
+    #[serde(default)]
+    pub company: Option<String>,
+    /// The tracking number.
+    #[serde(default)]
+    pub number: Option<String>,
+    /// The tracking URL.
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+/// Creates a fulfillment for a fulfillment order.
+///
+/// Wraps the `fulfillmentCreateV2` mutation.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the request
+/// * `fulfillment_order_id` - The fulfillment order GID
+/// * `tracking` - Tracking details, if the shipment has them
+/// * `notify_customer` - Whether Shopify emails the customer a shipping confirmation
+/// * `line_items` - The line items to fulfill; empty fulfills every remaining item
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if Shopify rejects the fulfillment
+/// (for example, a quantity above what remains to be fulfilled).
+/// Returns [`GraphqlError::MissingData`] if the response cannot be parsed.
+pub async fn create(
+    client: &GraphqlClient,
+    fulfillment_order_id: &str,
+    tracking: Option<TrackingInput>,
+    notify_customer: bool,
+    line_items: Vec<FulfillmentLineItemInput>,
+) -> Result<Fulfillment, GraphqlError> {
+    let variables = create_variables(
+        fulfillment_order_id,
+        tracking.as_ref(),
+        notify_customer,
+        &line_items,
+    );
+    let response = client
+        .query(CREATE_MUTATION, Some(variables), None, None)
+        .await?;
+
+    parse_response(&response, |body| {
+        parse_fulfillment(body, "fulfillmentCreateV2")
+    })
+}
+
+/// Replaces the tracking details of a fulfillment.
+///
+/// Wraps the `fulfillmentTrackingInfoUpdate` mutation.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the request
+/// * `fulfillment_id` - The fulfillment GID
+/// * `tracking` - The new tracking details
+/// * `notify_customer` - Whether Shopify emails the customer a shipping update
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if Shopify rejects the update
+/// (for example, an unknown or cancelled fulfillment).
+/// Returns [`GraphqlError::MissingData`] if the response cannot be parsed.
+pub async fn update_tracking(
+    client: &GraphqlClient,
+    fulfillment_id: &str,
+    tracking: TrackingInput,
+    notify_customer: bool,
+) -> Result<Fulfillment, GraphqlError> {
+    let variables = update_tracking_variables(fulfillment_id, &tracking, notify_customer);
+    let response = client
+        .query(UPDATE_TRACKING_MUTATION, Some(variables), None, None)
+        .await?;
+
+    parse_response(&response, |body| {
+        parse_fulfillment(body, "fulfillmentTrackingInfoUpdate")
+    })
+}
+
+/// Builds the variables for the `fulfillmentCreateV2` mutation.
+fn create_variables(
+    fulfillment_order_id: &str,
+    tracking: Option<&TrackingInput>,
+    notify_customer: bool,
+    line_items: &[FulfillmentLineItemInput],
+) -> Value {
+    let mut line_items_by_order = json!({ "fulfillmentOrderId": fulfillment_order_id });
+    if !line_items.is_empty() {
+        line_items_by_order["fulfillmentOrderLineItems"] = json!(line_items);
+    }
+
+    let mut fulfillment = json!({
+        "lineItemsByFulfillmentOrder": [line_items_by_order],
+        "notifyCustomer": notify_customer,
+    });
+    if let Some(tracking) = tracking {
+        fulfillment["trackingInfo"] = json!(tracking);
+    }
+
+    json!({ "fulfillment": fulfillment })
+}
+
+/// Builds the variables for the `fulfillmentTrackingInfoUpdate` mutation.
+fn update_tracking_variables(
+    fulfillment_id: &str,
+    tracking: &TrackingInput,
+    notify_customer: bool,
+) -> Value {
+    json!({
+        "fulfillmentId": fulfillment_id,
+        "trackingInfoInput": tracking,
+        "notifyCustomer": notify_customer,
+    })
+}
+
+/// Parses the `fulfillment` from a fulfillment mutation response.
+fn parse_fulfillment(body: &Value, field: &str) -> Result<Fulfillment, GraphqlError> {
+    let payload = root_payload(body, field)?;
+    serde_json::from_value(payload["fulfillment"].clone()).map_err(|_| GraphqlError::MissingData {
+        field: format!("data.{field}.fulfillment"),
+        request_id: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::graphql::UserError;
+
+    #[test]
+    fn test_create_variables_map_line_items_and_tracking() {
+        let variables = create_variables(
+            "gid://shopify/FulfillmentOrder/1046000778",
+            Some(&TrackingInput {
+                company: Some("UPS".to_string()),
+                numbers: vec![
+                    "1Z999AA10123456784".to_string(),
+                    "1Z999AA10123456795".to_string(),
+                ],
+                ..Default::default()
+            }),
+            true,
+            &[FulfillmentLineItemInput {
+                id: "gid://shopify/FulfillmentOrderLineItem/1025578643".to_string(),
+                quantity: 2,
+            }],
+        );
+
+        assert_eq!(
+            variables,
+            json!({
+                "fulfillment": {
+                    "lineItemsByFulfillmentOrder": [{
+                        "fulfillmentOrderId": "gid://shopify/FulfillmentOrder/1046000778",
+                        "fulfillmentOrderLineItems": [{
+                            "id": "gid://shopify/FulfillmentOrderLineItem/1025578643",
+                            "quantity": 2
+                        }]
+                    }],
+                    "notifyCustomer": true,
+                    "trackingInfo": {
+                        "company": "UPS",
+                        "numbers": ["1Z999AA10123456784", "1Z999AA10123456795"]
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_create_variables_without_line_items_or_tracking() {
+        let variables = create_variables(
+            "gid://shopify/FulfillmentOrder/1046000778",
+            None,
+            false,
+            &[],
+        );
+
+        assert_eq!(
+            variables,
+            json!({
+                "fulfillment": {
+                    "lineItemsByFulfillmentOrder": [{
+                        "fulfillmentOrderId": "gid://shopify/FulfillmentOrder/1046000778"
+                    }],
+                    "notifyCustomer": false
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_update_tracking_variables() {
+        let variables = update_tracking_variables(
+            "gid://shopify/Fulfillment/255858046",
+            &TrackingInput {
+                company: Some("Canada Post".to_string()),
+                number: Some("7025 7220 1234 5678".to_string()),
+                url: Some("https://www.canadapost-postescanada.ca/track".to_string()),
+                ..Default::default()
+            },
+            true,
+        );
+
+        assert_eq!(
+            variables,
+            json!({
+                "fulfillmentId": "gid://shopify/Fulfillment/255858046",
+                "trackingInfoInput": {
+                    "company": "Canada Post",
+                    "number": "7025 7220 1234 5678",
+                    "url": "https://www.canadapost-postescanada.ca/track"
+                },
+                "notifyCustomer": true
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_fulfillment() {
+        let body = json!({
+            "data": {
+                "fulfillmentCreateV2": {
+                    "fulfillment": {
+                        "id": "gid://shopify/Fulfillment/255858046",
+                        "status": "SUCCESS",
+                        "trackingInfo": [{
+                            "company": "UPS",
+                            "number": "1Z999AA10123456784",
+                            "url": "https://www.ups.com/track?tracknum=1Z999AA10123456784"
+                        }]
+                    },
+                    "userErrors": []
+                }
+            }
+        });
+
+        let fulfillment = parse_fulfillment(&body, "fulfillmentCreateV2").unwrap();
+
+        assert_eq!(fulfillment.id, "gid://shopify/Fulfillment/255858046");
+        assert_eq!(fulfillment.status, "SUCCESS");
+        assert_eq!(
+            fulfillment.tracking_info[0].number.as_deref(),
+            Some("1Z999AA10123456784")
+        );
+    }
+
+    #[test]
+    fn test_parse_fulfillment_surfaces_user_errors() {
+        let body = json!({
+            "data": {
+                "fulfillmentTrackingInfoUpdate": {
+                    "fulfillment": null,
+                    "userErrors": [{
+                        "field": ["fulfillmentId"],
+                        "message": "Fulfillment does not exist."
+                    }]
+                }
+            }
+        });
+
+        let result = parse_fulfillment(&body, "fulfillmentTrackingInfoUpdate");
+
+        match result {
+            Err(GraphqlError::UserErrors { errors, .. }) => assert_eq!(
+                errors,
+                vec![UserError {
+                    field: Some(vec!["fulfillmentId".to_string()]),
+                    message: "Fulfillment does not exist.".to_string(),
+                }]
+            ),
+            other => panic!("Expected UserErrors, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,265 @@
+//! Shopify Functions helpers for the GraphQL Admin API.
+//!
+//! Apps that ship Shopify Functions (discounts, cart transforms, validations,
+//! and so on) need the ID of each deployed function to install it, and
+//! usually configure it through a JSON metafield on the object that runs the
+//! function. [`list`] returns the functions available to the app, and
+//! [`set_configuration`] writes a configuration metafield via `metafieldsSet`.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use shopify_sdk::clients::graphql::functions;
+//! use serde_json::json;
+//!
+//! let functions = functions::list(&client).await?;
+//! let discount = functions
+//!     .iter()
+//!     .find(|f| f.api_type == "product_discounts")
+//!     .expect("function is deployed");
+//!
+//! // After creating the automatic discount that runs `discount.id`:
+//! functions::set_configuration(
+//!     &client,
+//!     "gid://shopify/DiscountAutomaticNode/1",
+//!     "$app:volume-discount",
+//!     "function-configuration",
+//!     &json!({ "percentage": 10, "minimumQuantity": 3 }),
+//! )
+//! .await?;
+//! ```
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::payload::{parse_response, root_payload};
+use super::{Connection, GraphqlClient, GraphqlError};
+use crate::clients::Page;
+
+/// Query used by [`list`].
+const LIST_QUERY: &str = r"
+query shopifyFunctions($after: String) {
+    shopifyFunctions(first: 250, after: $after) {
+        nodes {
+            id
+            title
+            apiType
+            description
+            app {
+                title
+            }
+        }
+        pageInfo {
+            hasNextPage
+            endCursor
+        }
+    }
+}
+";
+
+/// Mutation used by [`set_configuration`].
+const METAFIELDS_SET_MUTATION: &str = r"
+mutation metafieldsSet($metafields: [MetafieldsSetInput!]!) {
+    metafieldsSet(metafields: $metafields) {
+        metafields {
+            id
+        }
+        userErrors {
+            field
+            message
+        }
+    }
+}
+";
+
+/// A Shopify Function deployed by the app.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShopifyFunction {
+    /// The function ID, passed as `functionId` when installing the function.
+    pub id: String,
+    /// The function's title.
+    pub title: String,
+    /// The Function API the function implements (e.g. `"product_discounts"`).
+    pub api_type: String,
+    /// The function's description.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The title of the app that owns the function.
+    #[serde(default, deserialize_with = "deserialize_app_title", rename = "app")]
+    pub app_title: Option<String>,
+}
+
+/// Deserializes `app { title }` into the app's title.
+fn deserialize_app_title<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct App {
+        title: Option<String>,
+    }
+
+    Ok(Option::<App>::deserialize(deserializer)?.and_then(|app| app.title))
+}
+
+/// Lists the Shopify Functions available to the app.
+///
+/// Wraps the `shopifyFunctions` query, following pagination until every
+/// function has been fetched.
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if the query itself is rejected.
+/// Returns [`GraphqlError::MissingData`] if the response cannot be parsed.
+pub async fn list(client: &GraphqlClient) -> Result<Vec<ShopifyFunction>, GraphqlError> {
+    let mut functions = Vec::new();
+    let mut after: Option<String> = None;
+
+    loop {
+        let variables = json!({ "after": after });
+        let response = client
+            .query(LIST_QUERY, Some(variables), None, None)
+            .await?;
+
+        let page = parse_response(&response, parse_functions)?;
+        functions.extend(page.items);
+
+        match page.next_cursor {
+            Some(cursor) => after = Some(cursor),
+            None => return Ok(functions),
+        }
+    }
+}
+
+/// Sets a function's JSON configuration metafield.
+///
+/// Wraps the `metafieldsSet` mutation with a metafield of type `json`.
+/// Functions read their configuration from a metafield on the object that
+/// runs them, such as a discount node, cart transform or validation.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the request
+/// * `owner_id` - The GID of the object that runs the function
+/// * `namespace` - The metafield namespace (e.g. `"$app:my-function"`)
+/// * `key` - The metafield key (e.g. `"function-configuration"`)
+/// * `configuration` - The configuration to store
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if Shopify rejects the metafield
+/// (for example, an unknown owner or a namespace the app doesn't own).
+/// Returns [`GraphqlError::MissingData`] if the response has no payload.
+pub async fn set_configuration(
+    client: &GraphqlClient,
+    owner_id: &str,
+    namespace: &str,
+    key: &str,
+    configuration: &Value,
+) -> Result<(), GraphqlError> {
+    let variables = set_configuration_variables(owner_id, namespace, key, configuration);
+    let response = client
+        .query(METAFIELDS_SET_MUTATION, Some(variables), None, None)
+        .await?;
+
+    parse_response(&response, |body| root_payload(body, "metafieldsSet"))?;
+    Ok(())
+}
+
+/// Builds the variables for the `metafieldsSet` mutation.
+fn set_configuration_variables(
+    owner_id: &str,
+    namespace: &str,
+    key: &str,
+    configuration: &Value,
+) -> Value {
+    json!({
+        "metafields": [{
+            "ownerId": owner_id,
+            "namespace": namespace,
+            "key": key,
+            "type": "json",
+            "value": configuration.to_string(),
+        }]
+    })
+}
+
+/// Parses a page of functions from a `shopifyFunctions` response.
+fn parse_functions(body: &Value) -> Result<Page<ShopifyFunction>, GraphqlError> {
+    let payload = root_payload(body, "shopifyFunctions")?;
+    serde_json::from_value::<Connection<ShopifyFunction>>(payload.clone())
+        .map(Connection::into_page)
+        .map_err(|_| GraphqlError::MissingData {
+            field: "data.shopifyFunctions".to_string(),
+            request_id: None,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_shopify_functions_response() {
+        let body = json!({
+            "data": {
+                "shopifyFunctions": {
+                    "nodes": [
+                        {
+                            "id": "01J5Z4XGQ4T7Y9K2V3N6M8P0QR",
+                            "title": "Volume discount",
+                            "apiType": "product_discounts",
+                            "description": "Discounts bulk purchases",
+                            "app": { "title": "Discount Kit" }
+                        },
+                        {
+                            "id": "01J5Z4XGQ4T7Y9K2V3N6M8P0QS",
+                            "title": "Block PO boxes",
+                            "apiType": "cart_checkout_validation",
+                            "description": null,
+                            "app": null
+                        }
+                    ],
+                    "pageInfo": { "hasNextPage": true, "endCursor": "cursor-2" }
+                }
+            }
+        });
+
+        let page = parse_functions(&body).unwrap();
+
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.items[0].id, "01J5Z4XGQ4T7Y9K2V3N6M8P0QR");
+        assert_eq!(page.items[0].api_type, "product_discounts");
+        assert_eq!(page.items[0].app_title.as_deref(), Some("Discount Kit"));
+        assert_eq!(page.items[1].api_type, "cart_checkout_validation");
+        assert_eq!(page.items[1].description, None);
+        assert_eq!(page.items[1].app_title, None);
+        assert_eq!(page.next_cursor.as_deref(), Some("cursor-2"));
+    }
+
+    #[test]
+    fn test_set_configuration_variables_serialize_json_value() {
+        let variables = set_configuration_variables(
+            "gid://shopify/DiscountAutomaticNode/1",
+            "$app:volume-discount",
+            "function-configuration",
+            &json!({ "percentage": 10 }),
+        );
+
+        assert_eq!(
+            variables,
+            json!({
+                "metafields": [{
+                    "ownerId": "gid://shopify/DiscountAutomaticNode/1",
+                    "namespace": "$app:volume-discount",
+                    "key": "function-configuration",
+                    "type": "json",
+                    "value": "{\"percentage\":10}"
+                }]
+            })
+        );
+    }
+}
@@ -0,0 +1,187 @@
+//! Retry handling for idempotent GraphQL mutations.
+//!
+//! See [`GraphqlClient::mutate_idempotent`](super::GraphqlClient::mutate_idempotent).
+
+use std::future::Future;
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+use super::GraphqlError;
+use crate::clients::{HttpError, HttpResponse};
+
+/// Variable name used to pass an idempotency key to a mutation.
+pub const IDEMPOTENCY_KEY_VARIABLE: &str = "idempotencyKey";
+
+/// Returns `true` if the request failed before it reached the server, so
+/// sending it again cannot cause a duplicate write.
+///
+/// Only connection failures qualify, including connect timeouts. Other
+/// timeouts, dropped connections and any received response (including 5xx)
+/// may mean the mutation was applied.
+pub fn failed_before_send(error: &GraphqlError) -> bool {
+    match error {
+        GraphqlError::Http(HttpError::ConnectTimeout(_)) => true,
+        GraphqlError::Http(HttpError::Network(e)) => e.is_connect(),
+        _ => false,
+    }
+}
+
+/// Adds the idempotency key to the mutation variables, if one is given.
+pub fn with_idempotency_key(variables: Option<Value>, key: Option<&str>) -> Option<Value> {
+    let Some(key) = key else {
+        return variables;
+    };
+
+    let mut variables = match variables {
+        Some(Value::Object(map)) => Value::Object(map),
+        _ => json!({}),
+    };
+    variables[IDEMPOTENCY_KEY_VARIABLE] = json!(key);
+    Some(variables)
+}
+
+/// Sends a mutation through `send`, retrying only when the previous attempt
+/// failed before the request was sent.
+pub async fn execute<F, Fut>(
+    max_attempts: u32,
+    wait: Duration,
+    mut send: F,
+) -> Result<HttpResponse, GraphqlError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<HttpResponse, GraphqlError>>,
+{
+    let mut attempt = 1;
+    loop {
+        match send().await {
+            Err(error) if attempt < max_attempts && failed_before_send(&error) => {
+                tracing::debug!("Mutation attempt {attempt} could not connect, retrying: {error}");
+                attempt += 1;
+                tokio::time::sleep(wait).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use wiremock::matchers::method;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// Produces a real connection-refused error from reqwest.
+    async fn connection_refused() -> GraphqlError {
+        let error = reqwest::Client::new()
+            .post("http://127.0.0.1:1/graphql.json")
+            .send()
+            .await
+            .unwrap_err();
+        assert!(error.is_connect());
+        GraphqlError::Http(HttpError::Network(error))
+    }
+
+    /// Produces a real timeout error from a server that accepted the request
+    /// but did not respond in time.
+    async fn timeout_after_send(server: &MockServer) -> GraphqlError {
+        let error = reqwest::Client::builder()
+            .timeout(Duration::from_millis(50))
+            .build()
+            .unwrap()
+            .post(format!("{}/graphql.json", server.uri()))
+            .send()
+            .await
+            .unwrap_err();
+        assert!(error.is_timeout());
+        GraphqlError::Http(HttpError::Network(error))
+    }
+
+    fn ok_response() -> HttpResponse {
+        HttpResponse::new(
+            200,
+            HashMap::new(),
+            json!({ "data": { "productCreate": { "userErrors": [] } } }),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_connection_refused_before_send_is_retried() {
+        let mut attempts = 0;
+        let mut errors = vec![connection_refused().await].into_iter();
+
+        let result = execute(3, Duration::ZERO, || {
+            attempts += 1;
+            let outcome = errors.next().map_or_else(|| Ok(ok_response()), Err);
+            async move { outcome }
+        })
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(attempts, 2);
+    }
+
+    #[tokio::test]
+    async fn test_timeout_after_send_is_not_retried() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(2)))
+            .mount(&server)
+            .await;
+
+        let mut attempts = 0;
+        let mut errors = vec![timeout_after_send(&server).await].into_iter();
+
+        let result = execute(3, Duration::ZERO, || {
+            attempts += 1;
+            let outcome = errors.next().map_or_else(|| Ok(ok_response()), Err);
+            async move { outcome }
+        })
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(GraphqlError::Http(HttpError::Network(_)))
+        ));
+        assert_eq!(attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_connection_refused_stops_at_max_attempts() {
+        let mut attempts = 0;
+
+        let result = execute(2, Duration::ZERO, || {
+            attempts += 1;
+            async { Err(connection_refused().await) }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts, 2);
+    }
+
+    #[test]
+    fn test_response_errors_are_not_retried() {
+        let error = GraphqlError::Http(HttpError::Response(crate::clients::HttpResponseError {
+            code: 503,
+            message: "Service unavailable".to_string(),
+            error_reference: None,
+        }));
+
+        assert!(!failed_before_send(&error));
+    }
+
+    #[test]
+    fn test_with_idempotency_key() {
+        assert_eq!(
+            with_idempotency_key(Some(json!({ "input": { "title": "Hat" } })), Some("key-1")),
+            Some(json!({ "input": { "title": "Hat" }, "idempotencyKey": "key-1" }))
+        );
+        assert_eq!(
+            with_idempotency_key(None, Some("key-1")),
+            Some(json!({ "idempotencyKey": "key-1" }))
+        );
+        assert_eq!(with_idempotency_key(None, None), None);
+    }
+}
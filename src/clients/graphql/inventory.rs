@@ -0,0 +1,361 @@
+//! Inventory helpers for the GraphQL Admin API.
+//!
+//! These functions wrap the `inventoryActivate` and `inventoryDeactivate`
+//! mutations, which connect and disconnect an inventory item to a location.
+//! Compared to the REST `InventoryLevel` connect/delete endpoints they return
+//! descriptive `userErrors` and work with locations managed by fulfillment
+//! services.
+//!
+//! [`item`] fetches an inventory item with its unit cost as a typed [`Money`]
+//! value, where the REST `InventoryItem::cost` is a bare string.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use shopify_sdk::clients::graphql::{inventory, GraphqlClient};
+//!
+//! let client = GraphqlClient::new(&session, Some(&config));
+//!
+//! // Stock the item at a location with 10 available units
+//! let level_id = inventory::activate(
+//!     &client,
+//!     "gid://shopify/InventoryItem/30322695",
+//!     "gid://shopify/Location/124656943",
+//!     Some(10),
+//! ).await?;
+//!
+//! // Later, stop stocking it there
+//! inventory::deactivate(&client, &level_id).await?;
+//!
+//! // Read the item's unit cost
+//! let item = inventory::item(&client, "gid://shopify/InventoryItem/30322695").await?;
+//! if let Some(cost) = item.unit_cost {
+//!     println!("Unit cost: {} {}", cost.amount, cost.currency_code);
+//! }
+//! ```
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::payload::{parse_response, root_payload};
+use super::{GraphqlClient, GraphqlError};
+
+/// Mutation used by [`activate`].
+const ACTIVATE_MUTATION: &str = r"
+mutation inventoryActivate($inventoryItemId: ID!, $locationId: ID!, $available: Int) {
+    inventoryActivate(inventoryItemId: $inventoryItemId, locationId: $locationId, available: $available) {
+        inventoryLevel {
+            id
+        }
+        userErrors {
+            field
+            message
+        }
+    }
+}
+";
+
+/// Mutation used by [`deactivate`].
+const DEACTIVATE_MUTATION: &str = r"
+mutation inventoryDeactivate($inventoryLevelId: ID!) {
+    inventoryDeactivate(inventoryLevelId: $inventoryLevelId) {
+        userErrors {
+            field
+            message
+        }
+    }
+}
+";
+
+/// Query used by [`item`].
+const ITEM_QUERY: &str = r"
+query inventoryItem($id: ID!) {
+    inventoryItem(id: $id) {
+        id
+        sku
+        tracked
+        unitCost {
+            amount
+            currencyCode
+        }
+        measurement {
+            weight {
+                unit
+                value
+            }
+        }
+    }
+}
+";
+
+/// A monetary amount, as returned by GraphQL's `MoneyV2`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Money {
+    /// The decimal amount, kept as a string to preserve precision.
+    pub amount: String,
+    /// The ISO 4217 currency code (e.g. `"USD"`).
+    pub currency_code: String,
+}
+
+/// A weight, as returned by GraphQL's `Weight`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Weight {
+    /// The unit of measurement (e.g. `"GRAMS"`, `"POUNDS"`).
+    pub unit: String,
+    /// The weight in `unit`.
+    pub value: f64,
+}
+
+/// The physical measurements of an inventory item.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct InventoryItemMeasurement {
+    /// The item's weight, if set.
+    #[serde(default)]
+    pub weight: Option<Weight>,
+}
+
+/// An inventory item, as returned by the GraphQL `inventoryItem` query.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryItemGql {
+    /// The inventory item GID.
+    pub id: String,
+    /// The item's SKU.
+    #[serde(default)]
+    pub sku: Option<String>,
+    /// Whether Shopify tracks inventory quantities for the item.
+    pub tracked: bool,
+    /// The cost of one unit of the item, if set.
+    #[serde(default)]
+    pub unit_cost: Option<Money>,
+    /// The item's physical measurements.
+    #[serde(default)]
+    pub measurement: Option<InventoryItemMeasurement>,
+}
+
+/// Fetches an inventory item.
+///
+/// Wraps the `inventoryItem` query.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the request
+/// * `id` - The inventory item GID (e.g. `gid://shopify/InventoryItem/1`)
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if the query itself is rejected.
+/// Returns [`GraphqlError::MissingData`] if the item is not found or the
+/// response cannot be parsed.
+pub async fn item(client: &GraphqlClient, id: &str) -> Result<InventoryItemGql, GraphqlError> {
+    let variables = json!({ "id": id });
+    let response = client
+        .query(ITEM_QUERY, Some(variables), None, None)
+        .await?;
+
+    parse_response(&response, parse_item)
+}
+
+/// Activates an inventory item at a location.
+///
+/// Wraps the `inventoryActivate` mutation. Once activated, the location
+/// stocks the item and it can have inventory quantities set.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the request
+/// * `inventory_item_id` - The inventory item GID (e.g. `gid://shopify/InventoryItem/1`)
+/// * `location_id` - The location GID (e.g. `gid://shopify/Location/1`)
+/// * `available` - Optional initial available quantity at the location
+///
+/// # Returns
+///
+/// The GID of the resulting inventory level.
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if Shopify rejects the activation.
+/// Returns [`GraphqlError::MissingData`] if the response has no inventory level.
+pub async fn activate(
+    client: &GraphqlClient,
+    inventory_item_id: &str,
+    location_id: &str,
+    available: Option<i64>,
+) -> Result<String, GraphqlError> {
+    let variables = activate_variables(inventory_item_id, location_id, available);
+    let response = client
+        .query(ACTIVATE_MUTATION, Some(variables), None, None)
+        .await?;
+
+    parse_response(&response, |body| {
+        root_payload(body, "inventoryActivate")?["inventoryLevel"]["id"]
+            .as_str()
+            .map(String::from)
+            .ok_or_else(|| GraphqlError::MissingData {
+                field: "data.inventoryActivate.inventoryLevel.id".to_string(),
+                request_id: None,
+            })
+    })
+}
+
+/// Deactivates an inventory level, disconnecting its item from the location.
+///
+/// Wraps the `inventoryDeactivate` mutation. Shopify rejects deactivation
+/// of the only location an item is stocked at, or of a location with
+/// committed inventory; those cases are reported as user errors.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the request
+/// * `inventory_level_id` - The inventory level GID, as returned by [`activate`]
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if Shopify rejects the deactivation.
+pub async fn deactivate(
+    client: &GraphqlClient,
+    inventory_level_id: &str,
+) -> Result<(), GraphqlError> {
+    let variables = deactivate_variables(inventory_level_id);
+    let response = client
+        .query(DEACTIVATE_MUTATION, Some(variables), None, None)
+        .await?;
+
+    parse_response(&response, |body| root_payload(body, "inventoryDeactivate"))?;
+    Ok(())
+}
+
+/// Builds the variables for the `inventoryActivate` mutation.
+fn activate_variables(inventory_item_id: &str, location_id: &str, available: Option<i64>) -> Value {
+    let mut variables = json!({
+        "inventoryItemId": inventory_item_id,
+        "locationId": location_id,
+    });
+    if let Some(available) = available {
+        variables["available"] = json!(available);
+    }
+    variables
+}
+
+/// Builds the variables for the `inventoryDeactivate` mutation.
+fn deactivate_variables(inventory_level_id: &str) -> Value {
+    json!({ "inventoryLevelId": inventory_level_id })
+}
+
+/// Parses the item from an `inventoryItem` response.
+fn parse_item(body: &Value) -> Result<InventoryItemGql, GraphqlError> {
+    let payload = root_payload(body, "inventoryItem")?;
+    serde_json::from_value(payload.clone()).map_err(|_| GraphqlError::MissingData {
+        field: "data.inventoryItem".to_string(),
+        request_id: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_activate_mutation_declares_expected_inputs() {
+        assert!(ACTIVATE_MUTATION.contains("inventoryActivate(inventoryItemId: $inventoryItemId, locationId: $locationId, available: $available)"));
+        assert!(ACTIVATE_MUTATION.contains("userErrors"));
+    }
+
+    #[test]
+    fn test_activate_variables_include_available_when_set() {
+        let variables = activate_variables(
+            "gid://shopify/InventoryItem/1",
+            "gid://shopify/Location/2",
+            Some(10),
+        );
+
+        assert_eq!(
+            variables,
+            json!({
+                "inventoryItemId": "gid://shopify/InventoryItem/1",
+                "locationId": "gid://shopify/Location/2",
+                "available": 10
+            })
+        );
+    }
+
+    #[test]
+    fn test_activate_variables_omit_available_when_unset() {
+        let variables = activate_variables(
+            "gid://shopify/InventoryItem/1",
+            "gid://shopify/Location/2",
+            None,
+        );
+
+        assert!(variables.get("available").is_none());
+    }
+
+    #[test]
+    fn test_parse_item_with_money_unit_cost() {
+        let body = json!({
+            "data": {
+                "inventoryItem": {
+                    "id": "gid://shopify/InventoryItem/30322695",
+                    "sku": "IPOD2008PINK",
+                    "tracked": true,
+                    "unitCost": { "amount": "25.0", "currencyCode": "USD" },
+                    "measurement": {
+                        "weight": { "unit": "GRAMS", "value": 120.5 }
+                    }
+                }
+            }
+        });
+
+        let item = parse_item(&body).unwrap();
+
+        assert_eq!(item.sku.as_deref(), Some("IPOD2008PINK"));
+        assert!(item.tracked);
+        assert_eq!(
+            item.unit_cost,
+            Some(Money {
+                amount: "25.0".to_string(),
+                currency_code: "USD".to_string(),
+            })
+        );
+        let weight = item.measurement.and_then(|m| m.weight).unwrap();
+        assert_eq!(weight.unit, "GRAMS");
+        assert!((weight.value - 120.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_parse_item_without_unit_cost() {
+        let body = json!({
+            "data": {
+                "inventoryItem": {
+                    "id": "gid://shopify/InventoryItem/1",
+                    "sku": null,
+                    "tracked": false,
+                    "unitCost": null,
+                    "measurement": { "weight": null }
+                }
+            }
+        });
+
+        let item = parse_item(&body).unwrap();
+
+        assert_eq!(item.unit_cost, None);
+        assert_eq!(item.measurement.unwrap().weight, None);
+    }
+
+    #[test]
+    fn test_deactivate_mutation_and_variables() {
+        assert!(DEACTIVATE_MUTATION
+            .contains("inventoryDeactivate(inventoryLevelId: $inventoryLevelId)"));
+        assert!(DEACTIVATE_MUTATION.contains("userErrors"));
+
+        let variables = deactivate_variables("gid://shopify/InventoryLevel/1?inventory_item_id=1");
+        assert_eq!(
+            variables,
+            json!({ "inventoryLevelId": "gid://shopify/InventoryLevel/1?inventory_item_id=1" })
+        );
+    }
+}
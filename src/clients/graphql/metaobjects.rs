@@ -0,0 +1,565 @@
+//! Metaobject helpers for the GraphQL Admin API.
+//!
+//! Metaobjects hold custom structured content defined by a metaobject
+//! definition, and have no REST equivalent. This module wraps the
+//! `metaobjectCreate`, `metaobjectUpdate` and `metaobjectDelete` mutations
+//! and the `metaobject` and `metaobjects` queries.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use shopify_sdk::clients::graphql::metaobjects::{self, MetaobjectInput};
+//!
+//! let input = MetaobjectInput {
+//!     r#type: "designer".to_string(),
+//!     handle: Some("jane-doe".to_string()),
+//!     fields: vec![
+//!         ("name".to_string(), "Jane Doe".to_string()),
+//!         ("bio".to_string(), "Designs our summer line.".to_string()),
+//!     ],
+//! };
+//!
+//! let designer = metaobjects::create(&client, &input).await?;
+//! let all = metaobjects::list_by_type(&client, "designer").await?;
+//! ```
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::payload::{parse_response, root_payload};
+use super::{Connection, GraphqlClient, GraphqlError};
+use crate::clients::Page;
+
+/// Mutation used by [`create`].
+const CREATE_MUTATION: &str = r"
+mutation metaobjectCreate($metaobject: MetaobjectCreateInput!) {
+    metaobjectCreate(metaobject: $metaobject) {
+        metaobject {
+            id
+            type
+            handle
+            displayName
+            updatedAt
+            fields {
+                key
+                value
+            }
+        }
+        userErrors {
+            field
+            message
+        }
+    }
+}
+";
+
+/// Mutation used by [`update`].
+const UPDATE_MUTATION: &str = r"
+mutation metaobjectUpdate($id: ID!, $metaobject: MetaobjectUpdateInput!) {
+    metaobjectUpdate(id: $id, metaobject: $metaobject) {
+        metaobject {
+            id
+            type
+            handle
+            displayName
+            updatedAt
+            fields {
+                key
+                value
+            }
+        }
+        userErrors {
+            field
+            message
+        }
+    }
+}
+";
+
+/// Mutation used by [`delete`].
+const DELETE_MUTATION: &str = r"
+mutation metaobjectDelete($id: ID!) {
+    metaobjectDelete(id: $id) {
+        deletedId
+        userErrors {
+            field
+            message
+        }
+    }
+}
+";
+
+/// Query used by [`get`].
+const GET_QUERY: &str = r"
+query metaobject($id: ID!) {
+    metaobject(id: $id) {
+        id
+        type
+        handle
+        displayName
+        updatedAt
+        fields {
+            key
+            value
+        }
+    }
+}
+";
+
+/// Query used by [`list_by_type`].
+const LIST_QUERY: &str = r"
+query metaobjects($type: String!, $after: String) {
+    metaobjects(type: $type, first: 250, after: $after) {
+        nodes {
+            id
+            type
+            handle
+            displayName
+            updatedAt
+            fields {
+                key
+                value
+            }
+        }
+        pageInfo {
+            hasNextPage
+            endCursor
+        }
+    }
+}
+";
+
+/// Input for [`create`] and [`update`].
+///
+/// Fields map to Shopify's `MetaobjectCreateInput`; each `(key, value)` pair
+/// in `fields` sets one field, with the value in the string form Shopify
+/// expects for the field's type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetaobjectInput {
+    /// The metaobject definition type (e.g. `"designer"`).
+    ///
+    /// A metaobject's type can't be changed, so [`update`] ignores it.
+    pub r#type: String,
+    /// The metaobject's handle. Shopify generates one when omitted on create.
+    pub handle: Option<String>,
+    /// The fields to set, as `(key, value)` pairs.
+    pub fields: Vec<(String, String)>,
+}
+
+impl MetaobjectInput {
+    /// Converts the input into the `MetaobjectCreateInput` GraphQL shape.
+    fn to_create_graphql(&self) -> Value {
+        let mut input = self.to_update_graphql();
+        input["type"] = json!(self.r#type);
+        input
+    }
+
+    /// Converts the input into the `MetaobjectUpdateInput` GraphQL shape.
+    fn to_update_graphql(&self) -> Value {
+        let fields: Vec<Value> = self
+            .fields
+            .iter()
+            .map(|(key, value)| json!({ "key": key, "value": value }))
+            .collect();
+
+        let mut input = json!({ "fields": fields });
+        if let Some(handle) = &self.handle {
+            input["handle"] = json!(handle);
+        }
+        input
+    }
+}
+
+/// A metaobject returned by the GraphQL Admin API.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Metaobject {
+    /// The metaobject GID (e.g. `gid://shopify/Metaobject/1`).
+    pub id: String,
+    /// The metaobject definition type.
+    pub r#type: String,
+    /// The metaobject's unique handle within its type.
+    pub handle: String,
+    /// The name shown for the metaobject in the admin.
+    #[serde(default)]
+    pub display_name: Option<String>,
+    /// When the metaobject was last updated, as an ISO 8601 timestamp.
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    /// The metaobject's fields.
+    #[serde(default)]
+    pub fields: Vec<MetaobjectField>,
+}
+
+impl Metaobject {
+    /// Returns the value of the field with this key, if it is set.
+    #[must_use]
+    pub fn field(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|field| field.key == key)
+            .and_then(|field| field.value.as_deref())
+    }
+}
+
+/// A single field of a [`Metaobject`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct MetaobjectField {
+    /// The field key from the metaobject definition.
+    pub key: String,
+    /// The field value in its serialized string form, if set.
+    #[serde(default)]
+    pub value: Option<String>,
+}
+
+/// Creates a metaobject.
+///
+/// Wraps the `metaobjectCreate` mutation.
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if Shopify rejects the metaobject
+/// (for example, an unknown type, a duplicate handle or an invalid value).
+/// Returns [`GraphqlError::MissingData`] if the response has no metaobject.
+pub async fn create(
+    client: &GraphqlClient,
+    input: &MetaobjectInput,
+) -> Result<Metaobject, GraphqlError> {
+    let variables = create_variables(input);
+    let response = client
+        .query(CREATE_MUTATION, Some(variables), None, None)
+        .await?;
+
+    parse_response(&response, |body| {
+        parse_mutation_metaobject(body, "metaobjectCreate")
+    })
+}
+
+/// Updates a metaobject's handle and fields.
+///
+/// Wraps the `metaobjectUpdate` mutation. Only the fields listed in `input`
+/// are changed; `input.type` is ignored.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the request
+/// * `id` - The metaobject GID (e.g. `gid://shopify/Metaobject/1`)
+/// * `input` - The handle and fields to set
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if Shopify rejects the update.
+/// Returns [`GraphqlError::MissingData`] if the response has no metaobject.
+pub async fn update(
+    client: &GraphqlClient,
+    id: &str,
+    input: &MetaobjectInput,
+) -> Result<Metaobject, GraphqlError> {
+    let variables = update_variables(id, input);
+    let response = client
+        .query(UPDATE_MUTATION, Some(variables), None, None)
+        .await?;
+
+    parse_response(&response, |body| {
+        parse_mutation_metaobject(body, "metaobjectUpdate")
+    })
+}
+
+/// Deletes a metaobject.
+///
+/// Wraps the `metaobjectDelete` mutation.
+///
+/// # Returns
+///
+/// The GID of the deleted metaobject.
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if Shopify rejects the deletion.
+/// Returns [`GraphqlError::MissingData`] if the response has no deleted ID.
+pub async fn delete(client: &GraphqlClient, id: &str) -> Result<String, GraphqlError> {
+    let variables = json!({ "id": id });
+    let response = client
+        .query(DELETE_MUTATION, Some(variables), None, None)
+        .await?;
+
+    parse_response(&response, |body| {
+        root_payload(body, "metaobjectDelete")?["deletedId"]
+            .as_str()
+            .map(String::from)
+            .ok_or_else(|| GraphqlError::MissingData {
+                field: "data.metaobjectDelete.deletedId".to_string(),
+                request_id: None,
+            })
+    })
+}
+
+/// Fetches a metaobject by GID.
+///
+/// Wraps the `metaobject` query. Returns `Ok(None)` if no metaobject has
+/// this ID.
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if the query itself is rejected.
+/// Returns [`GraphqlError::MissingData`] if the response cannot be parsed.
+pub async fn get(client: &GraphqlClient, id: &str) -> Result<Option<Metaobject>, GraphqlError> {
+    let variables = json!({ "id": id });
+    let response = client.query(GET_QUERY, Some(variables), None, None).await?;
+
+    parse_response(&response, parse_get)
+}
+
+/// Lists every metaobject of a type.
+///
+/// Wraps the `metaobjects` query, following pagination until every
+/// metaobject has been fetched.
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if the query itself is rejected.
+/// Returns [`GraphqlError::MissingData`] if the response cannot be parsed.
+pub async fn list_by_type(
+    client: &GraphqlClient,
+    metaobject_type: &str,
+) -> Result<Vec<Metaobject>, GraphqlError> {
+    let mut metaobjects = Vec::new();
+    let mut after: Option<String> = None;
+
+    loop {
+        let variables = json!({ "type": metaobject_type, "after": after });
+        let response = client
+            .query(LIST_QUERY, Some(variables), None, None)
+            .await?;
+
+        let page = parse_response(&response, parse_list)?;
+        metaobjects.extend(page.items);
+
+        match page.next_cursor {
+            Some(cursor) => after = Some(cursor),
+            None => return Ok(metaobjects),
+        }
+    }
+}
+
+/// Builds the variables for the `metaobjectCreate` mutation.
+fn create_variables(input: &MetaobjectInput) -> Value {
+    json!({ "metaobject": input.to_create_graphql() })
+}
+
+/// Builds the variables for the `metaobjectUpdate` mutation.
+fn update_variables(id: &str, input: &MetaobjectInput) -> Value {
+    json!({ "id": id, "metaobject": input.to_update_graphql() })
+}
+
+/// Parses the metaobject from a create or update mutation response.
+fn parse_mutation_metaobject(body: &Value, field: &str) -> Result<Metaobject, GraphqlError> {
+    let payload = root_payload(body, field)?;
+    serde_json::from_value(payload["metaobject"].clone()).map_err(|_| GraphqlError::MissingData {
+        field: format!("data.{field}.metaobject"),
+        request_id: None,
+    })
+}
+
+/// Parses the metaobject from a `metaobject` query response.
+fn parse_get(body: &Value) -> Result<Option<Metaobject>, GraphqlError> {
+    if body["data"]["metaobject"].is_null() && body["errors"].is_null() {
+        return Ok(None);
+    }
+    let payload = root_payload(body, "metaobject")?;
+    serde_json::from_value(payload.clone())
+        .map(Some)
+        .map_err(|_| GraphqlError::MissingData {
+            field: "data.metaobject".to_string(),
+            request_id: None,
+        })
+}
+
+/// Parses a page of metaobjects from a `metaobjects` response.
+fn parse_list(body: &Value) -> Result<Page<Metaobject>, GraphqlError> {
+    let payload = root_payload(body, "metaobjects")?;
+    serde_json::from_value::<Connection<Metaobject>>(payload.clone())
+        .map(Connection::into_page)
+        .map_err(|_| GraphqlError::MissingData {
+            field: "data.metaobjects".to_string(),
+            request_id: None,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn designer_input() -> MetaobjectInput {
+        MetaobjectInput {
+            r#type: "designer".to_string(),
+            handle: Some("jane-doe".to_string()),
+            fields: vec![
+                ("name".to_string(), "Jane Doe".to_string()),
+                ("featured".to_string(), "true".to_string()),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_create_variables_include_type_handle_and_fields() {
+        assert_eq!(
+            create_variables(&designer_input()),
+            json!({
+                "metaobject": {
+                    "type": "designer",
+                    "handle": "jane-doe",
+                    "fields": [
+                        { "key": "name", "value": "Jane Doe" },
+                        { "key": "featured", "value": "true" }
+                    ]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_update_variables_omit_type_and_missing_handle() {
+        let input = MetaobjectInput {
+            handle: None,
+            ..designer_input()
+        };
+
+        assert_eq!(
+            update_variables("gid://shopify/Metaobject/1", &input),
+            json!({
+                "id": "gid://shopify/Metaobject/1",
+                "metaobject": {
+                    "fields": [
+                        { "key": "name", "value": "Jane Doe" },
+                        { "key": "featured", "value": "true" }
+                    ]
+                }
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_sends_metaobject_variables() {
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({
+                "variables": {
+                    "metaobject": {
+                        "type": "designer",
+                        "handle": "jane-doe",
+                        "fields": [
+                            { "key": "name", "value": "Jane Doe" },
+                            { "key": "featured", "value": "true" }
+                        ]
+                    }
+                }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": {
+                    "metaobjectCreate": {
+                        "metaobject": {
+                            "id": "gid://shopify/Metaobject/1",
+                            "type": "designer",
+                            "handle": "jane-doe",
+                            "displayName": "Jane Doe",
+                            "updatedAt": "2026-01-01T00:00:00Z",
+                            "fields": [
+                                { "key": "name", "value": "Jane Doe" },
+                                { "key": "featured", "value": "true" }
+                            ]
+                        },
+                        "userErrors": []
+                    }
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = GraphqlClient::for_mock_server(&server.uri());
+
+        let metaobject = create(&client, &designer_input()).await.unwrap();
+
+        assert_eq!(metaobject.id, "gid://shopify/Metaobject/1");
+        assert_eq!(metaobject.field("name"), Some("Jane Doe"));
+    }
+
+    #[test]
+    fn test_parse_metaobjects_list_response() {
+        let body = json!({
+            "data": {
+                "metaobjects": {
+                    "nodes": [
+                        {
+                            "id": "gid://shopify/Metaobject/1",
+                            "type": "designer",
+                            "handle": "jane-doe",
+                            "displayName": "Jane Doe",
+                            "updatedAt": "2026-01-01T00:00:00Z",
+                            "fields": [
+                                { "key": "name", "value": "Jane Doe" },
+                                { "key": "bio", "value": null }
+                            ]
+                        },
+                        {
+                            "id": "gid://shopify/Metaobject/2",
+                            "type": "designer",
+                            "handle": "john-roe",
+                            "displayName": null,
+                            "updatedAt": null,
+                            "fields": []
+                        }
+                    ],
+                    "pageInfo": { "hasNextPage": false, "endCursor": null }
+                }
+            }
+        });
+
+        let page = parse_list(&body).unwrap();
+
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.items[0].handle, "jane-doe");
+        assert_eq!(page.items[0].r#type, "designer");
+        assert_eq!(page.items[0].field("name"), Some("Jane Doe"));
+        assert_eq!(page.items[0].field("bio"), None);
+        assert_eq!(page.items[1].display_name, None);
+        assert!(page.items[1].fields.is_empty());
+        assert_eq!(page.next_cursor, None);
+    }
+
+    #[test]
+    fn test_mutation_surfaces_user_errors() {
+        let body = json!({
+            "data": {
+                "metaobjectCreate": {
+                    "metaobject": null,
+                    "userErrors": [
+                        { "field": ["metaobject", "handle"], "message": "Handle has already been taken" }
+                    ]
+                }
+            }
+        });
+
+        match parse_mutation_metaobject(&body, "metaobjectCreate").unwrap_err() {
+            GraphqlError::UserErrors { errors, .. } => {
+                assert_eq!(errors[0].message, "Handle has already been taken");
+            }
+            other => panic!("expected user errors, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_get_returns_none_for_unknown_id() {
+        let body = json!({ "data": { "metaobject": null } });
+
+        assert_eq!(parse_get(&body).unwrap(), None);
+    }
+}
@@ -10,6 +10,28 @@
 //!
 //! - [`GraphqlClient`]: The GraphQL API client with `query()` and `query_with_debug()` methods
 //! - [`GraphqlError`]: Error type for GraphQL API operations
+//! - [`Connection`]: A typed connection that converts into a [`Page`](crate::clients::Page)
+//! - [`CostBudget`]: A per-shop cap on query cost, set with [`GraphqlClient::with_cost_budget`]
+//! - [`CostReservation`]: Cost reserved by [`CostBudget::acquire`] until the actual cost is known
+//! - [`FieldSet`]: A reusable field selection to interpolate into queries
+//!
+//! [`count()`] counts the nodes in a root connection, using Shopify's
+//! `...Count` fields where available.
+//!
+//! Typed helpers for common mutations live in submodules:
+//!
+//! - [`bulk`]: Run bulk mutations, poll bulk operations and cancel them
+//! - [`customers`]: Create and update customers with marketing consent
+//! - [`discounts`]: Create discount codes
+//! - [`fulfillments`]: Create fulfillments and update their tracking
+//! - [`functions`]: List Shopify Functions and set their configuration metafields
+//! - [`inventory`]: Fetch inventory items and activate or deactivate them at locations
+//! - [`metaobjects`]: Create, update, delete, fetch and list metaobjects
+//! - [`order_risk`]: Create order risk assessments
+//! - [`products`]: Attach media to products, uploading local files first
+//! - [`staged_uploads`]: Stage files for mutations that consume uploads
+//! - [`translations`]: Fetch translatable content and register translations
+//! - [`variants`]: Create and update many product variants at once
 //!
 //! # GraphQL is the Recommended API
 //!
@@ -84,8 +106,34 @@
 //! automatic retries on 429 (rate limited) and 500 (server error) responses
 //! by specifying the `tries` parameter in query methods.
 
+pub mod bulk;
 mod client;
+mod connection;
+mod cost_budget;
+mod count;
+pub mod customers;
+pub mod discounts;
 mod errors;
+mod field_set;
+pub mod fulfillments;
+pub mod functions;
+mod idempotent;
+pub mod inventory;
+pub mod metaobjects;
+pub mod order_risk;
+pub(crate) mod payload;
+mod persisted;
+pub mod products;
+pub mod staged_uploads;
+pub mod translations;
+#[cfg(feature = "graphql-validate")]
+mod validate;
+pub mod variants;
+mod versions;
 
 pub use client::GraphqlClient;
-pub use errors::GraphqlError;
+pub use connection::{Connection, Edge, PageInfo};
+pub use cost_budget::{CostBudget, CostReservation};
+pub use count::count;
+pub use errors::{GraphqlError, UserError};
+pub use field_set::FieldSet;
@@ -0,0 +1,189 @@
+//! Order risk helpers for the GraphQL Admin API.
+//!
+//! The REST `OrderRisk` create endpoint is deprecated. This module wraps the
+//! `orderRiskAssessmentCreate` mutation, which lets fraud apps attach a risk
+//! level and the facts behind it to an order.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use shopify_sdk::clients::graphql::order_risk::{
+//!     self, RiskFact, RiskFactSentiment, RiskLevel,
+//! };
+//!
+//! order_risk::create_assessment(
+//!     &client,
+//!     "gid://shopify/Order/450789469",
+//!     RiskLevel::High,
+//!     vec![RiskFact {
+//!         description: "Billing and shipping countries differ".to_string(),
+//!         sentiment: RiskFactSentiment::Negative,
+//!     }],
+//! )
+//! .await?;
+//! ```
+
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use super::payload::{parse_response, root_payload};
+use super::{GraphqlClient, GraphqlError};
+
+/// Mutation used by [`create_assessment`].
+const CREATE_ASSESSMENT_MUTATION: &str = r"
+mutation orderRiskAssessmentCreate($orderRiskAssessmentInput: OrderRiskAssessmentCreateInput!) {
+    orderRiskAssessmentCreate(orderRiskAssessmentInput: $orderRiskAssessmentInput) {
+        orderRiskAssessment {
+            riskLevel
+        }
+        userErrors {
+            field
+            message
+        }
+    }
+}
+";
+
+/// The overall risk level of an assessment.
+///
+/// Serializes to Shopify's `RiskAssessmentResult` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RiskLevel {
+    /// The order is likely fraudulent.
+    High,
+    /// The order may be fraudulent.
+    Medium,
+    /// The order is unlikely to be fraudulent.
+    Low,
+    /// No risk was found.
+    None,
+    /// The assessment is still in progress.
+    Pending,
+}
+
+/// Whether a fact raises or lowers the risk of an order.
+///
+/// Serializes to Shopify's `RiskFactSentiment` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RiskFactSentiment {
+    /// The fact lowers the risk.
+    Positive,
+    /// The fact does not affect the risk.
+    Neutral,
+    /// The fact raises the risk.
+    Negative,
+}
+
+/// A single signal contributing to a risk assessment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RiskFact {
+    /// A merchant-facing description of the signal.
+    pub description: String,
+    /// How the signal affects the risk.
+    pub sentiment: RiskFactSentiment,
+}
+
+/// Creates a risk assessment for an order.
+///
+/// Wraps the `orderRiskAssessmentCreate` mutation.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the request
+/// * `order_id` - The order GID (e.g. `gid://shopify/Order/1`)
+/// * `risk_level` - The overall risk level
+/// * `facts` - The signals behind the assessment
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if Shopify rejects the assessment
+/// (for example, an unknown order).
+/// Returns [`GraphqlError::MissingData`] if the response has no payload.
+pub async fn create_assessment(
+    client: &GraphqlClient,
+    order_id: &str,
+    risk_level: RiskLevel,
+    facts: Vec<RiskFact>,
+) -> Result<(), GraphqlError> {
+    let variables = create_assessment_variables(order_id, risk_level, &facts);
+    let response = client
+        .query(CREATE_ASSESSMENT_MUTATION, Some(variables), None, None)
+        .await?;
+
+    parse_response(&response, |body| {
+        root_payload(body, "orderRiskAssessmentCreate")
+    })?;
+    Ok(())
+}
+
+/// Builds the variables for the `orderRiskAssessmentCreate` mutation.
+fn create_assessment_variables(order_id: &str, risk_level: RiskLevel, facts: &[RiskFact]) -> Value {
+    json!({
+        "orderRiskAssessmentInput": {
+            "orderId": order_id,
+            "riskLevel": risk_level,
+            "facts": facts,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_assessment_variables_include_facts() {
+        let variables = create_assessment_variables(
+            "gid://shopify/Order/1",
+            RiskLevel::High,
+            &[
+                RiskFact {
+                    description: "Billing and shipping countries differ".to_string(),
+                    sentiment: RiskFactSentiment::Negative,
+                },
+                RiskFact {
+                    description: "Returning customer".to_string(),
+                    sentiment: RiskFactSentiment::Positive,
+                },
+            ],
+        );
+
+        assert_eq!(
+            variables,
+            json!({
+                "orderRiskAssessmentInput": {
+                    "orderId": "gid://shopify/Order/1",
+                    "riskLevel": "HIGH",
+                    "facts": [
+                        {
+                            "description": "Billing and shipping countries differ",
+                            "sentiment": "NEGATIVE"
+                        },
+                        {
+                            "description": "Returning customer",
+                            "sentiment": "POSITIVE"
+                        }
+                    ]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_risk_level_serialization() {
+        assert_eq!(json!(RiskLevel::None), "NONE");
+        assert_eq!(json!(RiskLevel::Pending), "PENDING");
+        assert_eq!(json!(RiskFactSentiment::Neutral), "NEUTRAL");
+    }
+
+    #[test]
+    fn test_create_assessment_mutation_declares_input() {
+        assert!(CREATE_ASSESSMENT_MUTATION.contains(
+            "orderRiskAssessmentCreate(orderRiskAssessmentInput: $orderRiskAssessmentInput)"
+        ));
+        assert!(CREATE_ASSESSMENT_MUTATION.contains("userErrors"));
+    }
+}
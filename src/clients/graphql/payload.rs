@@ -0,0 +1,256 @@
+//! Response parsing shared by the typed GraphQL helpers.
+
+use serde_json::Value;
+
+use super::errors::{GraphqlError, UserError};
+use crate::clients::HttpResponse;
+
+/// Rejects responses delivered incrementally as `multipart/mixed`.
+///
+/// The HTTP client only parses JSON bodies, so a multipart response would
+/// otherwise surface as an empty body and a confusing missing-data error.
+pub fn reject_incremental_delivery(response: HttpResponse) -> Result<HttpResponse, GraphqlError> {
+    let content_type = response
+        .headers
+        .get("content-type")
+        .and_then(|values| values.first());
+
+    match content_type {
+        Some(content_type)
+            if content_type
+                .trim_start()
+                .to_ascii_lowercase()
+                .starts_with("multipart/mixed") =>
+        {
+            Err(GraphqlError::IncrementalDeliveryUnsupported {
+                content_type: content_type.clone(),
+            })
+        }
+        _ => Ok(response),
+    }
+}
+
+/// Runs `parse` on the body of `response`, setting the response's
+/// `X-Request-Id` on any [`GraphqlError::UserErrors`] or
+/// [`GraphqlError::MissingData`] it returns.
+pub fn parse_response<'a, T>(
+    response: &'a HttpResponse,
+    parse: impl FnOnce(&'a Value) -> Result<T, GraphqlError>,
+) -> Result<T, GraphqlError> {
+    parse(&response.body).map_err(|error| error.with_request_id(response.request_id()))
+}
+
+/// Extracts the payload of a mutation or query root field from a response body.
+///
+/// Returns [`GraphqlError::UserErrors`] if the body contains top-level
+/// `errors` or the payload contains a non-empty `userErrors` (or
+/// `mediaUserErrors`) array, and
+/// [`GraphqlError::MissingData`] if the payload is absent.
+pub fn root_payload<'a>(body: &'a Value, field: &str) -> Result<&'a Value, GraphqlError> {
+    let payload = &body["data"][field];
+
+    if payload.is_null() {
+        if let Some(errors) = body["errors"].as_array().filter(|e| !e.is_empty()) {
+            return Err(GraphqlError::UserErrors {
+                errors: errors
+                    .iter()
+                    .map(|e| UserError {
+                        field: None,
+                        message: e["message"].as_str().unwrap_or("Unknown error").to_string(),
+                    })
+                    .collect(),
+                request_id: None,
+            });
+        }
+        return Err(GraphqlError::MissingData {
+            field: format!("data.{field}"),
+            request_id: None,
+        });
+    }
+
+    for key in ["userErrors", "mediaUserErrors"] {
+        let Some(user_errors) = payload[key].as_array() else {
+            continue;
+        };
+        if !user_errors.is_empty() {
+            let errors = user_errors
+                .iter()
+                .map(|e| {
+                    serde_json::from_value(e.clone()).unwrap_or_else(|_| UserError {
+                        field: None,
+                        message: e.to_string(),
+                    })
+                })
+                .collect();
+            return Err(GraphqlError::UserErrors {
+                errors,
+                request_id: None,
+            });
+        }
+    }
+
+    Ok(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_root_payload_returns_payload_on_success() {
+        let body = json!({
+            "data": {
+                "inventoryActivate": {
+                    "inventoryLevel": { "id": "gid://shopify/InventoryLevel/1" },
+                    "userErrors": []
+                }
+            }
+        });
+
+        let payload = root_payload(&body, "inventoryActivate").unwrap();
+        assert_eq!(
+            payload["inventoryLevel"]["id"],
+            "gid://shopify/InventoryLevel/1"
+        );
+    }
+
+    #[test]
+    fn test_root_payload_surfaces_user_errors() {
+        let body = json!({
+            "data": {
+                "inventoryActivate": {
+                    "inventoryLevel": null,
+                    "userErrors": [
+                        { "field": ["locationId"], "message": "Location not found" }
+                    ]
+                }
+            }
+        });
+
+        match root_payload(&body, "inventoryActivate").unwrap_err() {
+            GraphqlError::UserErrors { errors, .. } => {
+                assert_eq!(errors.len(), 1);
+                assert_eq!(errors[0].field, Some(vec!["locationId".to_string()]));
+                assert_eq!(errors[0].message, "Location not found");
+            }
+            other => panic!("Expected UserErrors, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_root_payload_surfaces_media_user_errors() {
+        let body = json!({
+            "data": {
+                "productCreateMedia": {
+                    "media": [],
+                    "mediaUserErrors": [
+                        { "field": ["media", "0", "originalSource"], "message": "Image URL is invalid" }
+                    ]
+                }
+            }
+        });
+
+        match root_payload(&body, "productCreateMedia").unwrap_err() {
+            GraphqlError::UserErrors { errors, .. } => {
+                assert_eq!(errors[0].message, "Image URL is invalid");
+            }
+            other => panic!("Expected UserErrors, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_root_payload_surfaces_top_level_errors() {
+        let body = json!({
+            "data": null,
+            "errors": [{ "message": "Access denied for inventoryActivate field." }]
+        });
+
+        match root_payload(&body, "inventoryActivate").unwrap_err() {
+            GraphqlError::UserErrors { errors, .. } => {
+                assert_eq!(
+                    errors[0].message,
+                    "Access denied for inventoryActivate field."
+                );
+            }
+            other => panic!("Expected UserErrors, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_root_payload_reports_missing_data() {
+        let body = json!({ "data": {} });
+
+        assert!(matches!(
+            root_payload(&body, "inventoryActivate"),
+            Err(GraphqlError::MissingData { field, .. }) if field == "data.inventoryActivate"
+        ));
+    }
+
+    async fn server_answering(body: Value) -> wiremock::MockServer {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("X-Request-Id", "req-200")
+                    .set_body_json(body),
+            )
+            .mount(&server)
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn test_user_errors_carry_response_request_id() {
+        use crate::clients::graphql::{inventory, GraphqlClient};
+
+        let server = server_answering(json!({
+            "data": {
+                "inventoryDeactivate": {
+                    "userErrors": [
+                        { "field": ["inventoryLevelId"], "message": "Inventory level not found" }
+                    ]
+                }
+            }
+        }))
+        .await;
+        let client = GraphqlClient::for_mock_server(&server.uri());
+
+        let error = inventory::deactivate(&client, "gid://shopify/InventoryLevel/1")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, GraphqlError::UserErrors { .. }));
+        assert_eq!(error.request_id(), Some("req-200"));
+    }
+
+    #[tokio::test]
+    async fn test_missing_data_carries_response_request_id() {
+        use crate::clients::graphql::{inventory, GraphqlClient};
+
+        let server = server_answering(json!({
+            "data": { "inventoryActivate": { "inventoryLevel": null, "userErrors": [] } }
+        }))
+        .await;
+        let client = GraphqlClient::for_mock_server(&server.uri());
+
+        let error = inventory::activate(
+            &client,
+            "gid://shopify/InventoryItem/1",
+            "gid://shopify/Location/1",
+            None,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(
+            error,
+            GraphqlError::MissingData { ref field, .. }
+                if field == "data.inventoryActivate.inventoryLevel.id"
+        ));
+        assert_eq!(error.request_id(), Some("req-200"));
+    }
+}
@@ -0,0 +1,159 @@
+//! Automatic persisted query (APQ) support for the GraphQL client.
+//!
+//! See [`GraphqlClient::query_persisted`](super::GraphqlClient::query_persisted).
+
+use std::future::Future;
+
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+use super::GraphqlError;
+use crate::auth::oauth::hmac::hex;
+use crate::clients::HttpResponse;
+
+/// Error messages indicating the server could not use the query hash alone.
+const PERSISTED_QUERY_MISSES: [&str; 2] = ["PersistedQueryNotFound", "PersistedQueryNotSupported"];
+
+/// Returns the lowercase hex SHA-256 hash of a query.
+fn query_hash(query: &str) -> String {
+    hex::encode(Sha256::digest(query.as_bytes()))
+}
+
+/// Builds the request body for an APQ request.
+///
+/// The full query is only included when `query` is `Some`.
+fn persisted_body(query: Option<&str>, hash: &str, variables: Option<&Value>) -> Value {
+    let mut body = json!({
+        "variables": variables,
+        "extensions": {
+            "persistedQuery": {
+                "version": 1,
+                "sha256Hash": hash,
+            }
+        }
+    });
+    if let Some(query) = query {
+        body["query"] = json!(query);
+    }
+    body
+}
+
+/// Returns `true` if the response reports that the hash was not usable.
+fn is_persisted_query_miss(body: &Value) -> bool {
+    body["errors"].as_array().is_some_and(|errors| {
+        errors.iter().any(|error| {
+            error["message"]
+                .as_str()
+                .is_some_and(|message| PERSISTED_QUERY_MISSES.contains(&message))
+                || error["extensions"]["code"]
+                    .as_str()
+                    .is_some_and(|code| code.starts_with("PERSISTED_QUERY_NOT_"))
+        })
+    })
+}
+
+/// Runs the APQ exchange, sending request bodies through `send`.
+///
+/// Sends the hash alone first and retries once with the full query on a miss.
+pub async fn execute<F, Fut>(
+    query: &str,
+    variables: Option<Value>,
+    mut send: F,
+) -> Result<HttpResponse, GraphqlError>
+where
+    F: FnMut(Value) -> Fut,
+    Fut: Future<Output = Result<HttpResponse, GraphqlError>>,
+{
+    let hash = query_hash(query);
+
+    let response = send(persisted_body(None, &hash, variables.as_ref())).await?;
+    if !is_persisted_query_miss(&response.body) {
+        return Ok(response);
+    }
+
+    tracing::debug!("Persisted query {hash} not found; sending full query");
+    send(persisted_body(Some(query), &hash, variables.as_ref())).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    const QUERY: &str = "query { shop { name } }";
+
+    fn response(body: Value) -> HttpResponse {
+        HttpResponse::new(200, HashMap::new(), body)
+    }
+
+    #[test]
+    fn test_query_hash_is_sha256_hex() {
+        assert_eq!(
+            query_hash(""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(query_hash(QUERY).len(), 64);
+    }
+
+    #[tokio::test]
+    async fn test_execute_retries_with_full_query_on_not_found() {
+        let mut sent: Vec<Value> = Vec::new();
+        let mut responses = vec![
+            response(json!({ "errors": [{ "message": "PersistedQueryNotFound" }] })),
+            response(json!({ "data": { "shop": { "name": "Test Shop" } } })),
+        ]
+        .into_iter();
+
+        let result = execute(QUERY, None, |body| {
+            sent.push(body);
+            let next = responses.next().unwrap();
+            async move { Ok(next) }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(result.body["data"]["shop"]["name"], "Test Shop");
+        assert_eq!(sent.len(), 2);
+
+        let hash = query_hash(QUERY);
+        assert!(sent[0].get("query").is_none());
+        assert_eq!(sent[0]["extensions"]["persistedQuery"]["sha256Hash"], hash);
+        assert_eq!(sent[1]["query"], QUERY);
+        assert_eq!(sent[1]["extensions"]["persistedQuery"]["sha256Hash"], hash);
+    }
+
+    #[tokio::test]
+    async fn test_execute_sends_hash_only_on_hit() {
+        let mut calls = 0;
+
+        let result = execute(QUERY, Some(json!({ "first": 1 })), |body| {
+            calls += 1;
+            assert!(body.get("query").is_none());
+            assert_eq!(body["variables"]["first"], 1);
+            async {
+                Ok(response(
+                    json!({ "data": { "shop": { "name": "Cached" } } }),
+                ))
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(calls, 1);
+        assert_eq!(result.body["data"]["shop"]["name"], "Cached");
+    }
+
+    #[test]
+    fn test_is_persisted_query_miss() {
+        assert!(is_persisted_query_miss(
+            &json!({ "errors": [{ "message": "PersistedQueryNotSupported" }] })
+        ));
+        assert!(is_persisted_query_miss(&json!({
+            "errors": [{ "message": "Not found", "extensions": { "code": "PERSISTED_QUERY_NOT_FOUND" } }]
+        })));
+        assert!(!is_persisted_query_miss(
+            &json!({ "errors": [{ "message": "Field 'foo' doesn't exist" }] })
+        ));
+        assert!(!is_persisted_query_miss(&json!({ "data": {} })));
+    }
+}
@@ -0,0 +1,428 @@
+//! Product media helpers for the GraphQL Admin API.
+//!
+//! [`attach_media`] wraps `productCreateMedia`, attaching images, videos and
+//! 3D models that are already reachable by URL. [`attach_media_from_bytes`]
+//! handles local files: it stages the upload with
+//! [`staged_uploads`], uploads the bytes, then attaches
+//! the staged file to the product.
+//!
+//! Media is processed asynchronously by Shopify, so the returned
+//! [`Media::status`] is usually `UPLOADED` or `PROCESSING` rather than `READY`.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use shopify_sdk::clients::graphql::products::{self, MediaContentType, MediaInput, MediaUpload};
+//!
+//! let product_id = "gid://shopify/Product/1";
+//!
+//! // Media hosted elsewhere
+//! products::attach_media(
+//!     &client,
+//!     product_id,
+//!     vec![MediaInput {
+//!         original_source: "https://example.com/shirt-front.jpg".to_string(),
+//!         media_content_type: MediaContentType::Image,
+//!         alt: Some("Front of the shirt".to_string()),
+//!     }],
+//! )
+//! .await?;
+//!
+//! // A local file
+//! let media = products::attach_media_from_bytes(
+//!     &client,
+//!     product_id,
+//!     MediaUpload {
+//!         filename: "shirt-back.png".to_string(),
+//!         mime_type: "image/png".to_string(),
+//!         media_content_type: MediaContentType::Image,
+//!         alt: Some("Back of the shirt".to_string()),
+//!         contents: std::fs::read("shirt-back.png")?,
+//!     },
+//! )
+//! .await?;
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::payload::{parse_response, root_payload};
+use super::staged_uploads::{self, StagedUploadInput, StagedUploadResource, StagedUploadTarget};
+use super::{GraphqlClient, GraphqlError, UserError};
+
+/// Mutation used by [`attach_media`].
+const CREATE_MEDIA_MUTATION: &str = r"
+mutation productCreateMedia($productId: ID!, $media: [CreateMediaInput!]!) {
+    productCreateMedia(productId: $productId, media: $media) {
+        media {
+            id
+            alt
+            mediaContentType
+            status
+        }
+        mediaUserErrors {
+            field
+            message
+        }
+    }
+}
+";
+
+/// The kind of media to attach.
+///
+/// Serializes to Shopify's `MediaContentType` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MediaContentType {
+    /// An image.
+    Image,
+    /// A video hosted by Shopify.
+    Video,
+    /// A video hosted outside Shopify, such as on a video platform.
+    ExternalVideo,
+    /// A 3D model.
+    #[serde(rename = "MODEL_3D")]
+    Model3d,
+}
+
+impl MediaContentType {
+    /// Returns the staged upload resource for this kind of media, or `None`
+    /// for external videos, which are attached by URL.
+    const fn staged_resource(self) -> Option<StagedUploadResource> {
+        match self {
+            Self::Image => Some(StagedUploadResource::Image),
+            Self::Video => Some(StagedUploadResource::Video),
+            Self::Model3d => Some(StagedUploadResource::Model3d),
+            Self::ExternalVideo => None,
+        }
+    }
+}
+
+/// Media to attach to a product.
+///
+/// Maps to Shopify's `CreateMediaInput`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaInput {
+    /// The URL of the media, or a staged upload's resource URL.
+    pub original_source: String,
+    /// The kind of media.
+    pub media_content_type: MediaContentType,
+    /// Alt text for the media.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alt: Option<String>,
+}
+
+/// A local file to upload with [`attach_media_from_bytes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MediaUpload {
+    /// The file name (e.g. `"shirt-back.png"`).
+    pub filename: String,
+    /// The MIME type of the file (e.g. `"image/png"`).
+    pub mime_type: String,
+    /// The kind of media. External videos cannot be uploaded.
+    pub media_content_type: MediaContentType,
+    /// Alt text for the media.
+    pub alt: Option<String>,
+    /// The file contents.
+    pub contents: Vec<u8>,
+}
+
+/// Media returned by [`attach_media`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Media {
+    /// The media GID.
+    #[serde(default)]
+    pub id: Option<String>,
+    /// The alt text.
+    #[serde(default)]
+    pub alt: Option<String>,
+    /// The kind of media (e.g. `"IMAGE"`).
+    pub media_content_type: String,
+    /// The processing status (e.g. `"UPLOADED"`, `"PROCESSING"`, `"READY"`).
+    pub status: String,
+}
+
+/// Attaches media to a product.
+///
+/// Wraps the `productCreateMedia` mutation. Media is returned in the same
+/// order as `media`.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the request
+/// * `product_id` - The product GID (e.g. `gid://shopify/Product/1`)
+/// * `media` - The media to attach
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] with the `mediaUserErrors` if Shopify
+/// rejects the media (for example, an unreachable source URL).
+/// Returns [`GraphqlError::MissingData`] if the response cannot be parsed.
+pub async fn attach_media(
+    client: &GraphqlClient,
+    product_id: &str,
+    media: Vec<MediaInput>,
+) -> Result<Vec<Media>, GraphqlError> {
+    let variables = attach_media_variables(product_id, &media);
+    let response = client
+        .query(CREATE_MEDIA_MUTATION, Some(variables), None, None)
+        .await?;
+
+    parse_response(&response, parse_media)
+}
+
+/// Uploads a local file and attaches it to a product.
+///
+/// Stages the upload with `stagedUploadsCreate`, uploads the bytes to the
+/// staged target, then attaches the staged file with [`attach_media`].
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the request
+/// * `product_id` - The product GID (e.g. `gid://shopify/Product/1`)
+/// * `upload` - The file to upload
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::UserErrors`] without sending anything if
+/// `upload` is an external video, which can only be attached by URL.
+/// Returns any error from staging, uploading or attaching the file.
+/// Returns [`GraphqlError::MissingData`] if the staged target has no
+/// resource URL.
+pub async fn attach_media_from_bytes(
+    client: &GraphqlClient,
+    product_id: &str,
+    upload: MediaUpload,
+) -> Result<Media, GraphqlError> {
+    let input = staged_input(&upload)?;
+    let target = staged_uploads::create(client, vec![input.clone()])
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| GraphqlError::MissingData {
+            field: "data.stagedUploadsCreate.stagedTargets".to_string(),
+            request_id: None,
+        })?;
+
+    let media = media_input_for_target(&target, &upload)?;
+    staged_uploads::upload(&target, &input, upload.contents).await?;
+
+    attach_media(client, product_id, vec![media])
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| GraphqlError::MissingData {
+            field: "data.productCreateMedia.media".to_string(),
+            request_id: None,
+        })
+}
+
+/// Builds the variables for the `productCreateMedia` mutation.
+fn attach_media_variables(product_id: &str, media: &[MediaInput]) -> Value {
+    json!({
+        "productId": product_id,
+        "media": media,
+    })
+}
+
+/// Builds the staged upload input for a local media file.
+fn staged_input(upload: &MediaUpload) -> Result<StagedUploadInput, GraphqlError> {
+    let resource =
+        upload
+            .media_content_type
+            .staged_resource()
+            .ok_or_else(|| GraphqlError::UserErrors {
+                errors: vec![UserError {
+                    field: Some(vec!["mediaContentType".to_string()]),
+                    message: "External videos cannot be uploaded; attach them by URL".to_string(),
+                }],
+                request_id: None,
+            })?;
+
+    Ok(StagedUploadInput {
+        // Shopify requires the size for videos and 3D models
+        file_size: Some(upload.contents.len().to_string()),
+        ..StagedUploadInput::new(resource, &upload.filename, &upload.mime_type)
+    })
+}
+
+/// Builds the media input that attaches a staged file.
+fn media_input_for_target(
+    target: &StagedUploadTarget,
+    upload: &MediaUpload,
+) -> Result<MediaInput, GraphqlError> {
+    let original_source = target
+        .resource_url
+        .clone()
+        .ok_or_else(|| GraphqlError::MissingData {
+            field: "data.stagedUploadsCreate.stagedTargets.resourceUrl".to_string(),
+            request_id: None,
+        })?;
+
+    Ok(MediaInput {
+        original_source,
+        media_content_type: upload.media_content_type,
+        alt: upload.alt.clone(),
+    })
+}
+
+/// Parses the `media` list from a `productCreateMedia` response.
+fn parse_media(body: &Value) -> Result<Vec<Media>, GraphqlError> {
+    let payload = root_payload(body, "productCreateMedia")?;
+    serde_json::from_value(payload["media"].clone()).map_err(|_| GraphqlError::MissingData {
+        field: "data.productCreateMedia.media".to_string(),
+        request_id: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::graphql::staged_uploads::StagedUploadParameter;
+
+    fn png_upload() -> MediaUpload {
+        MediaUpload {
+            filename: "shirt-back.png".to_string(),
+            mime_type: "image/png".to_string(),
+            media_content_type: MediaContentType::Image,
+            alt: Some("Back of the shirt".to_string()),
+            contents: vec![0x89, b'P', b'N', b'G'],
+        }
+    }
+
+    #[test]
+    fn test_attach_media_variables() {
+        let variables = attach_media_variables(
+            "gid://shopify/Product/1",
+            &[
+                MediaInput {
+                    original_source: "https://example.com/shirt-front.jpg".to_string(),
+                    media_content_type: MediaContentType::Image,
+                    alt: Some("Front of the shirt".to_string()),
+                },
+                MediaInput {
+                    original_source: "https://example.com/shirt.glb".to_string(),
+                    media_content_type: MediaContentType::Model3d,
+                    alt: None,
+                },
+            ],
+        );
+
+        assert_eq!(
+            variables,
+            json!({
+                "productId": "gid://shopify/Product/1",
+                "media": [
+                    {
+                        "originalSource": "https://example.com/shirt-front.jpg",
+                        "mediaContentType": "IMAGE",
+                        "alt": "Front of the shirt"
+                    },
+                    {
+                        "originalSource": "https://example.com/shirt.glb",
+                        "mediaContentType": "MODEL_3D"
+                    }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_staged_upload_then_attach_uses_resource_url() {
+        let upload = png_upload();
+
+        let input = staged_input(&upload).unwrap();
+        assert_eq!(
+            json!(input),
+            json!({
+                "resource": "IMAGE",
+                "filename": "shirt-back.png",
+                "mimeType": "image/png",
+                "fileSize": "4"
+            })
+        );
+
+        let target = StagedUploadTarget {
+            url: "https://shopify-staged-uploads.storage.googleapis.com".to_string(),
+            resource_url: Some(
+                "https://shopify-staged-uploads.storage.googleapis.com/tmp/1/shirt-back.png"
+                    .to_string(),
+            ),
+            parameters: vec![StagedUploadParameter {
+                name: "key".to_string(),
+                value: "tmp/1/shirt-back.png".to_string(),
+            }],
+        };
+
+        assert_eq!(
+            media_input_for_target(&target, &upload).unwrap(),
+            MediaInput {
+                original_source:
+                    "https://shopify-staged-uploads.storage.googleapis.com/tmp/1/shirt-back.png"
+                        .to_string(),
+                media_content_type: MediaContentType::Image,
+                alt: Some("Back of the shirt".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_staged_input_rejects_external_video() {
+        let upload = MediaUpload {
+            media_content_type: MediaContentType::ExternalVideo,
+            ..png_upload()
+        };
+
+        assert!(matches!(
+            staged_input(&upload),
+            Err(GraphqlError::UserErrors { .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_media_surfaces_media_user_errors() {
+        let body = json!({
+            "data": {
+                "productCreateMedia": {
+                    "media": [],
+                    "mediaUserErrors": [{
+                        "field": ["media", "0", "originalSource"],
+                        "message": "Image URL is invalid"
+                    }]
+                }
+            }
+        });
+
+        match parse_media(&body) {
+            Err(GraphqlError::UserErrors { errors, .. }) => {
+                assert_eq!(errors[0].message, "Image URL is invalid");
+            }
+            other => panic!("Expected UserErrors, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_media() {
+        let body = json!({
+            "data": {
+                "productCreateMedia": {
+                    "media": [{
+                        "id": "gid://shopify/MediaImage/1",
+                        "alt": "Front of the shirt",
+                        "mediaContentType": "IMAGE",
+                        "status": "UPLOADED"
+                    }],
+                    "mediaUserErrors": []
+                }
+            }
+        });
+
+        let media = parse_media(&body).unwrap();
+
+        assert_eq!(media[0].id.as_deref(), Some("gid://shopify/MediaImage/1"));
+        assert_eq!(media[0].status, "UPLOADED");
+    }
+}
@@ -0,0 +1,301 @@
+//! Staged upload helpers for the GraphQL Admin API.
+//!
+//! Files such as bulk mutation variables and product media are not sent
+//! through GraphQL directly. Instead, [`create`] asks Shopify for an upload
+//! target via the `stagedUploadsCreate` mutation, [`upload`] posts the file
+//! to that target, and the target's resource URL or path is then passed to
+//! the mutation that consumes the file.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use shopify_sdk::clients::graphql::staged_uploads::{
+//!     self, StagedUploadInput, StagedUploadResource,
+//! };
+//!
+//! let input = StagedUploadInput::new(
+//!     StagedUploadResource::BulkMutationVariables,
+//!     "variables.jsonl",
+//!     "text/jsonl",
+//! );
+//! let target = staged_uploads::create(&client, vec![input.clone()]).await?.remove(0);
+//! staged_uploads::upload(&target, &input, jsonl_bytes).await?;
+//! ```
+
+use reqwest::multipart::{Form, Part};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::payload::{parse_response, root_payload};
+use super::{GraphqlClient, GraphqlError};
+use crate::clients::http_client::build_reqwest_client;
+use crate::clients::{HttpError, HttpResponseError};
+
+/// Mutation used by [`create`].
+const CREATE_MUTATION: &str = r"
+mutation stagedUploadsCreate($input: [StagedUploadInput!]!) {
+    stagedUploadsCreate(input: $input) {
+        stagedTargets {
+            url
+            resourceUrl
+            parameters {
+                name
+                value
+            }
+        }
+        userErrors {
+            field
+            message
+        }
+    }
+}
+";
+
+/// The kind of resource a staged upload is for.
+///
+/// Serializes to Shopify's `StagedUploadTargetGenerateUploadResource` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StagedUploadResource {
+    /// A JSONL file of variables for `bulkOperationRunMutation`.
+    BulkMutationVariables,
+    /// A generic file.
+    File,
+    /// An image.
+    Image,
+    /// A video.
+    Video,
+    /// A 3D model.
+    #[serde(rename = "MODEL_3D")]
+    Model3d,
+}
+
+/// Describes a file to stage.
+///
+/// Maps to Shopify's `StagedUploadInput`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StagedUploadInput {
+    /// The kind of resource being uploaded.
+    pub resource: StagedUploadResource,
+    /// The file name.
+    pub filename: String,
+    /// The MIME type of the file (e.g. `"text/jsonl"`).
+    pub mime_type: String,
+    /// The HTTP method used to upload the file. Defaults to `POST` when unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_method: Option<String>,
+    /// The size of the file in bytes. Required for videos and 3D models.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<String>,
+}
+
+impl StagedUploadInput {
+    /// Creates an input for a `POST` upload without a declared file size.
+    #[must_use]
+    pub fn new(
+        resource: StagedUploadResource,
+        filename: impl Into<String>,
+        mime_type: impl Into<String>,
+    ) -> Self {
+        Self {
+            resource,
+            filename: filename.into(),
+            mime_type: mime_type.into(),
+            http_method: None,
+            file_size: None,
+        }
+    }
+}
+
+/// A form parameter that must be sent along with a staged upload.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct StagedUploadParameter {
+    /// The form field name.
+    pub name: String,
+    /// The form field value.
+    pub value: String,
+}
+
+/// Where and how to upload a staged file.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StagedUploadTarget {
+    /// The URL to upload the file to.
+    pub url: String,
+    /// The URL to pass to mutations that consume the file, if any.
+    #[serde(default)]
+    pub resource_url: Option<String>,
+    /// Form parameters to send before the file.
+    pub parameters: Vec<StagedUploadParameter>,
+}
+
+impl StagedUploadTarget {
+    /// Returns the value of a form parameter.
+    #[must_use]
+    pub fn parameter(&self, name: &str) -> Option<&str> {
+        self.parameters
+            .iter()
+            .find(|p| p.name == name)
+            .map(|p| p.value.as_str())
+    }
+
+    /// Returns the staged upload path (the `key` parameter).
+    ///
+    /// This is the value `bulkOperationRunMutation` expects as its
+    /// `stagedUploadPath` argument.
+    #[must_use]
+    pub fn staged_upload_path(&self) -> Option<&str> {
+        self.parameter("key")
+    }
+}
+
+/// Requests upload targets for one or more files.
+///
+/// Wraps the `stagedUploadsCreate` mutation. Targets are returned in the
+/// same order as `inputs`.
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if Shopify rejects an input.
+/// Returns [`GraphqlError::MissingData`] if the response has no targets.
+pub async fn create(
+    client: &GraphqlClient,
+    inputs: Vec<StagedUploadInput>,
+) -> Result<Vec<StagedUploadTarget>, GraphqlError> {
+    let variables = json!({ "input": inputs });
+    let response = client
+        .query(CREATE_MUTATION, Some(variables), None, None)
+        .await?;
+
+    parse_response(&response, parse_targets)
+}
+
+/// Uploads a file to a staged upload target.
+///
+/// Sends a multipart form containing the target's parameters followed by the
+/// file contents, as Shopify's storage provider requires.
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] if the upload fails or the storage provider
+/// responds with a non-2xx status.
+pub async fn upload(
+    target: &StagedUploadTarget,
+    input: &StagedUploadInput,
+    contents: Vec<u8>,
+) -> Result<(), GraphqlError> {
+    let file = Part::bytes(contents)
+        .file_name(input.filename.clone())
+        .mime_str(&input.mime_type)
+        .map_err(HttpError::from)?;
+    let form = target
+        .parameters
+        .iter()
+        .fold(Form::new(), |form, p| {
+            form.text(p.name.clone(), p.value.clone())
+        })
+        .part("file", file);
+
+    let response = build_reqwest_client(None)
+        .post(&target.url)
+        .multipart(form)
+        .send()
+        .await
+        .map_err(HttpError::from)?;
+
+    let status = response.status();
+    if status.is_success() {
+        return Ok(());
+    }
+
+    let message = response.text().await.unwrap_or_default();
+    Err(HttpError::Response(HttpResponseError {
+        code: status.as_u16(),
+        message: format!("Staged upload failed: {message}"),
+        error_reference: None,
+    })
+    .into())
+}
+
+/// Parses the `stagedTargets` list from a `stagedUploadsCreate` response.
+fn parse_targets(body: &Value) -> Result<Vec<StagedUploadTarget>, GraphqlError> {
+    let payload = root_payload(body, "stagedUploadsCreate")?;
+    serde_json::from_value(payload["stagedTargets"].clone()).map_err(|_| {
+        GraphqlError::MissingData {
+            field: "data.stagedUploadsCreate.stagedTargets".to_string(),
+            request_id: None,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_serialization() {
+        let input = StagedUploadInput::new(
+            StagedUploadResource::BulkMutationVariables,
+            "variables.jsonl",
+            "text/jsonl",
+        );
+
+        assert_eq!(
+            json!(input),
+            json!({
+                "resource": "BULK_MUTATION_VARIABLES",
+                "filename": "variables.jsonl",
+                "mimeType": "text/jsonl"
+            })
+        );
+        assert_eq!(json!(StagedUploadResource::Model3d), "MODEL_3D");
+    }
+
+    #[test]
+    fn test_parse_targets_and_staged_upload_path() {
+        let body = json!({
+            "data": {
+                "stagedUploadsCreate": {
+                    "stagedTargets": [{
+                        "url": "https://shopify-staged-uploads.storage.googleapis.com",
+                        "resourceUrl": null,
+                        "parameters": [
+                            { "name": "key", "value": "tmp/123/bulk/variables.jsonl" },
+                            { "name": "Content-Type", "value": "text/jsonl" }
+                        ]
+                    }],
+                    "userErrors": []
+                }
+            }
+        });
+
+        let targets = parse_targets(&body).unwrap();
+
+        assert_eq!(targets.len(), 1);
+        assert_eq!(
+            targets[0].staged_upload_path(),
+            Some("tmp/123/bulk/variables.jsonl")
+        );
+        assert_eq!(targets[0].parameter("Content-Type"), Some("text/jsonl"));
+        assert_eq!(targets[0].resource_url, None);
+    }
+
+    #[test]
+    fn test_parse_targets_surfaces_user_errors() {
+        let body = json!({
+            "data": {
+                "stagedUploadsCreate": {
+                    "stagedTargets": [],
+                    "userErrors": [{ "field": ["input"], "message": "Invalid mime type" }]
+                }
+            }
+        });
+
+        assert!(matches!(
+            parse_targets(&body),
+            Err(GraphqlError::UserErrors { .. })
+        ));
+    }
+}
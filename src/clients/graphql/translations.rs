@@ -0,0 +1,244 @@
+//! Translation helpers for the GraphQL Admin API.
+//!
+//! Registering a translation requires the digest of the original content
+//! being translated, so Shopify can detect when the source changes. Use
+//! [`get_translatable_content`] to fetch those digests, then pass them to
+//! [`register`] via the `translationsRegister` mutation.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use shopify_sdk::clients::graphql::translations::{self, TranslationInput};
+//!
+//! let product_id = "gid://shopify/Product/1";
+//! let content = translations::get_translatable_content(&client, product_id).await?;
+//!
+//! let inputs = content
+//!     .iter()
+//!     .filter(|c| c.key == "title")
+//!     .map(|c| TranslationInput {
+//!         locale: "fr".to_string(),
+//!         key: c.key.clone(),
+//!         value: "Chemise".to_string(),
+//!         translatable_content_digest: c.digest.clone().unwrap_or_default(),
+//!     })
+//!     .collect();
+//!
+//! translations::register(&client, product_id, inputs).await?;
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::payload::{parse_response, root_payload};
+use super::{GraphqlClient, GraphqlError};
+
+/// Query used by [`get_translatable_content`].
+const TRANSLATABLE_CONTENT_QUERY: &str = r"
+query translatableResource($resourceId: ID!) {
+    translatableResource(resourceId: $resourceId) {
+        resourceId
+        translatableContent {
+            key
+            value
+            digest
+            locale
+        }
+    }
+}
+";
+
+/// Mutation used by [`register`].
+const REGISTER_MUTATION: &str = r"
+mutation translationsRegister($resourceId: ID!, $translations: [TranslationInput!]!) {
+    translationsRegister(resourceId: $resourceId, translations: $translations) {
+        translations {
+            key
+            locale
+            value
+        }
+        userErrors {
+            field
+            message
+        }
+    }
+}
+";
+
+/// A single translation to register for a resource.
+///
+/// Maps to Shopify's `TranslationInput`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslationInput {
+    /// The target locale (e.g. `"fr"`).
+    pub locale: String,
+    /// The key of the field being translated (e.g. `"title"`).
+    pub key: String,
+    /// The translated value.
+    pub value: String,
+    /// The digest of the original content, from [`TranslatableContent::digest`].
+    pub translatable_content_digest: String,
+}
+
+/// A translatable field of a resource, in its original locale.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct TranslatableContent {
+    /// The key of the field (e.g. `"title"`, `"body_html"`).
+    pub key: String,
+    /// The original value of the field.
+    #[serde(default)]
+    pub value: Option<String>,
+    /// The digest to pass as `translatable_content_digest` when registering.
+    #[serde(default)]
+    pub digest: Option<String>,
+    /// The locale of the original value.
+    pub locale: String,
+}
+
+/// Fetches the translatable fields of a resource along with their digests.
+///
+/// Wraps the `translatableResource` query.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the request
+/// * `resource_id` - The resource GID (e.g. `gid://shopify/Product/1`)
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if the query itself is rejected.
+/// Returns [`GraphqlError::MissingData`] if the resource is not found or
+/// the response cannot be parsed.
+pub async fn get_translatable_content(
+    client: &GraphqlClient,
+    resource_id: &str,
+) -> Result<Vec<TranslatableContent>, GraphqlError> {
+    let variables = json!({ "resourceId": resource_id });
+    let response = client
+        .query(TRANSLATABLE_CONTENT_QUERY, Some(variables), None, None)
+        .await?;
+
+    parse_response(&response, parse_translatable_content)
+}
+
+/// Registers translations for a resource.
+///
+/// Wraps the `translationsRegister` mutation. Existing translations for the
+/// same locale and key are replaced.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the request
+/// * `resource_id` - The resource GID (e.g. `gid://shopify/Product/1`)
+/// * `translations` - The translations to register
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if Shopify rejects a translation
+/// (for example, a stale content digest or an unpublished locale).
+/// Returns [`GraphqlError::MissingData`] if the response has no payload.
+pub async fn register(
+    client: &GraphqlClient,
+    resource_id: &str,
+    translations: Vec<TranslationInput>,
+) -> Result<(), GraphqlError> {
+    let variables = register_variables(resource_id, &translations);
+    let response = client
+        .query(REGISTER_MUTATION, Some(variables), None, None)
+        .await?;
+
+    parse_response(&response, |body| root_payload(body, "translationsRegister"))?;
+    Ok(())
+}
+
+/// Builds the variables for the `translationsRegister` mutation.
+fn register_variables(resource_id: &str, translations: &[TranslationInput]) -> Value {
+    json!({
+        "resourceId": resource_id,
+        "translations": translations,
+    })
+}
+
+/// Parses the `translatableContent` list from a `translatableResource` response.
+fn parse_translatable_content(body: &Value) -> Result<Vec<TranslatableContent>, GraphqlError> {
+    let payload = root_payload(body, "translatableResource")?;
+    serde_json::from_value(payload["translatableContent"].clone()).map_err(|_| {
+        GraphqlError::MissingData {
+            field: "data.translatableResource.translatableContent".to_string(),
+            request_id: None,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_mutation_and_variables() {
+        assert!(REGISTER_MUTATION.contains(
+            "translationsRegister(resourceId: $resourceId, translations: $translations)"
+        ));
+        assert!(REGISTER_MUTATION.contains("userErrors"));
+
+        let variables = register_variables(
+            "gid://shopify/Product/1",
+            &[TranslationInput {
+                locale: "fr".to_string(),
+                key: "title".to_string(),
+                value: "Chemise".to_string(),
+                translatable_content_digest: "abc123".to_string(),
+            }],
+        );
+
+        assert_eq!(
+            variables,
+            json!({
+                "resourceId": "gid://shopify/Product/1",
+                "translations": [{
+                    "locale": "fr",
+                    "key": "title",
+                    "value": "Chemise",
+                    "translatableContentDigest": "abc123"
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_translatable_content() {
+        let body = json!({
+            "data": {
+                "translatableResource": {
+                    "resourceId": "gid://shopify/Product/1",
+                    "translatableContent": [
+                        { "key": "title", "value": "Shirt", "digest": "abc123", "locale": "en" },
+                        { "key": "body_html", "value": null, "digest": null, "locale": "en" }
+                    ]
+                }
+            }
+        });
+
+        let content = parse_translatable_content(&body).unwrap();
+
+        assert_eq!(content.len(), 2);
+        assert_eq!(content[0].key, "title");
+        assert_eq!(content[0].value.as_deref(), Some("Shirt"));
+        assert_eq!(content[0].digest.as_deref(), Some("abc123"));
+        assert_eq!(content[1].value, None);
+    }
+
+    #[test]
+    fn test_parse_translatable_content_for_missing_resource() {
+        let body = json!({ "data": { "translatableResource": null } });
+
+        let result = parse_translatable_content(&body);
+
+        assert!(
+            matches!(result, Err(GraphqlError::MissingData { field, .. }) if field == "data.translatableResource")
+        );
+    }
+}
@@ -0,0 +1,249 @@
+//! Local syntax checks for GraphQL documents.
+//!
+//! Enabled by the `graphql-validate` feature. [`GraphqlClient::query`](super::GraphqlClient::query)
+//! runs [`validate_document`] before sending, so obviously malformed documents
+//! fail without a round trip. The checks are deliberately shallow and do not
+//! consult the schema:
+//!
+//! - the document starts with `query`, `mutation`, `subscription`,
+//!   `fragment` or `{`
+//! - braces, parentheses and brackets are balanced
+//! - every variable used is declared by an operation
+
+use super::GraphqlError;
+
+/// Keywords a GraphQL document may start with.
+const LEADING_KEYWORDS: [&str; 4] = ["query", "mutation", "subscription", "fragment"];
+
+/// A significant token of a GraphQL document.
+#[derive(Debug, PartialEq, Eq)]
+enum Token {
+    /// A punctuator such as `{` or `:`.
+    Punct(char),
+    /// A name, keyword or number.
+    Name(String),
+    /// A variable reference or definition, without the `$`.
+    Variable(String),
+}
+
+/// Checks a GraphQL document for basic syntax errors.
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::InvalidDocument`] describing the first problem found.
+pub fn validate_document(document: &str) -> Result<(), GraphqlError> {
+    let tokens = tokenize(document)?;
+
+    match tokens.first() {
+        Some(Token::Punct('{')) => {}
+        Some(Token::Name(name)) if LEADING_KEYWORDS.contains(&name.as_str()) => {}
+        _ => {
+            return Err(invalid(
+                "document must start with `query`, `mutation` or `{`",
+            ))
+        }
+    }
+
+    check_balanced(&tokens)?;
+    check_variables(&tokens)
+}
+
+/// Splits a document into tokens, skipping whitespace, commas, comments and
+/// string literals.
+fn tokenize(document: &str) -> Result<Vec<Token>, GraphqlError> {
+    let mut tokens = Vec::new();
+    let mut chars = document.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '#' => while chars.next_if(|&c| c != '\n').is_some() {},
+            '"' => skip_string(&mut chars)?,
+            '$' => {
+                let mut name = String::new();
+                while let Some(c) = chars.next_if(|&c| c.is_alphanumeric() || c == '_') {
+                    name.push(c);
+                }
+                tokens.push(Token::Variable(name));
+            }
+            c if c.is_alphanumeric() || c == '_' || c == '-' => {
+                let mut name = c.to_string();
+                while let Some(c) = chars.next_if(|&c| c.is_alphanumeric() || c == '_' || c == '.')
+                {
+                    name.push(c);
+                }
+                tokens.push(Token::Name(name));
+            }
+            c if c.is_whitespace() || c == ',' => {}
+            c => tokens.push(Token::Punct(c)),
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Skips a string literal whose opening quote has been consumed.
+///
+/// Handles both `"..."` strings with escapes and `"""..."""` block strings.
+fn skip_string(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> Result<(), GraphqlError> {
+    if chars.next_if_eq(&'"').is_some() {
+        if chars.next_if_eq(&'"').is_none() {
+            // An empty "" string
+            return Ok(());
+        }
+        let mut quotes = 0;
+        for c in chars.by_ref() {
+            quotes = if c == '"' { quotes + 1 } else { 0 };
+            if quotes == 3 {
+                return Ok(());
+            }
+        }
+        return Err(invalid("unterminated block string"));
+    }
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '"' => return Ok(()),
+            '\n' => break,
+            _ => {}
+        }
+    }
+    Err(invalid("unterminated string"))
+}
+
+/// Checks that every opening brace, parenthesis and bracket is closed in order.
+fn check_balanced(tokens: &[Token]) -> Result<(), GraphqlError> {
+    let mut open = Vec::new();
+
+    for token in tokens {
+        let Token::Punct(c) = token else { continue };
+        match c {
+            '{' | '(' | '[' => open.push(*c),
+            '}' | ')' | ']' => {
+                let expected = match c {
+                    '}' => '{',
+                    ')' => '(',
+                    _ => '[',
+                };
+                if open.pop() != Some(expected) {
+                    return Err(invalid(&format!("unexpected `{c}`")));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    open.last()
+        .map_or(Ok(()), |c| Err(invalid(&format!("unclosed `{c}`"))))
+}
+
+/// Checks that every variable used in a selection set is declared.
+///
+/// A variable followed by `:` outside any selection set is a declaration,
+/// as in `query Get($id: ID!)`. Declarations from all operations are
+/// accepted, since fragments may use the variables of any operation.
+fn check_variables(tokens: &[Token]) -> Result<(), GraphqlError> {
+    let mut declared = Vec::new();
+    let mut used = Vec::new();
+    let mut brace_depth = 0_usize;
+
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Punct('{') => brace_depth += 1,
+            Token::Punct('}') => brace_depth = brace_depth.saturating_sub(1),
+            Token::Variable(name) => {
+                let is_definition =
+                    brace_depth == 0 && tokens.get(i + 1) == Some(&Token::Punct(':'));
+                if is_definition {
+                    declared.push(name.as_str());
+                } else {
+                    used.push(name.as_str());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    used.into_iter()
+        .find(|name| !declared.contains(name))
+        .map_or(Ok(()), |name| {
+            Err(invalid(&format!("variable `${name}` is not declared")))
+        })
+}
+
+/// Builds an [`GraphqlError::InvalidDocument`] error.
+fn invalid(reason: &str) -> GraphqlError {
+    GraphqlError::InvalidDocument {
+        reason: reason.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reason(document: &str) -> String {
+        match validate_document(document) {
+            Err(GraphqlError::InvalidDocument { reason }) => reason,
+            other => panic!("Expected InvalidDocument, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_valid_documents_pass() {
+        validate_document("{ shop { name } }").unwrap();
+        validate_document(
+            r#"
+            # Fetch a product
+            query GetProduct($id: ID!, $first: Int = 10) {
+                product(id: $id) {
+                    title
+                    description(truncateAt: 20) # "}" in a comment
+                    variants(first: $first, query: "title:{") { nodes { id } }
+                }
+            }
+            "#,
+        )
+        .unwrap();
+        validate_document(
+            r#"mutation { productCreate(input: { title: """Block "{" string""" }) { product { id } } }"#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_unbalanced_brace_is_rejected() {
+        assert_eq!(reason("query { shop { name }"), "unclosed `{`");
+        assert_eq!(reason("query { shop { name } } }"), "unexpected `}`");
+        assert_eq!(
+            reason("query { products(first: 5 { id } }"),
+            "unexpected `}`"
+        );
+    }
+
+    #[test]
+    fn test_undeclared_variable_is_rejected() {
+        assert_eq!(
+            reason("query GetProduct { product(id: $id) { title } }"),
+            "variable `$id` is not declared"
+        );
+        assert_eq!(
+            reason("query GetProduct($id: ID!) { product(id: $productId) { title } }"),
+            "variable `$productId` is not declared"
+        );
+    }
+
+    #[test]
+    fn test_document_must_start_with_operation() {
+        assert_eq!(
+            reason("shop { name }"),
+            "document must start with `query`, `mutation` or `{`"
+        );
+        assert_eq!(
+            reason(""),
+            "document must start with `query`, `mutation` or `{`"
+        );
+    }
+}
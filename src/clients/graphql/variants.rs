@@ -0,0 +1,314 @@
+//! Bulk product variant helpers for the GraphQL Admin API.
+//!
+//! Creating or updating variants one at a time over REST costs a request per
+//! variant, and REST caps products at fewer variants than GraphQL. [`bulk_create`]
+//! and [`bulk_update`] wrap `productVariantsBulkCreate` and
+//! `productVariantsBulkUpdate`, changing many variants of a product in one
+//! mutation.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use shopify_sdk::clients::graphql::variants::{self, VariantInput, VariantOptionValueInput};
+//!
+//! let product_id = "gid://shopify/Product/1";
+//! let inputs = ["Small", "Medium", "Large"]
+//!     .iter()
+//!     .map(|size| VariantInput {
+//!         option_values: vec![VariantOptionValueInput {
+//!             option_name: "Size".to_string(),
+//!             name: (*size).to_string(),
+//!         }],
+//!         price: Some("19.99".to_string()),
+//!         ..Default::default()
+//!     })
+//!     .collect();
+//!
+//! let created = variants::bulk_create(&client, product_id, inputs).await?;
+//!
+//! // Later, reprice them all at once
+//! let updates = created
+//!     .iter()
+//!     .map(|v| VariantInput {
+//!         id: Some(v.id.clone()),
+//!         price: Some("24.99".to_string()),
+//!         ..Default::default()
+//!     })
+//!     .collect();
+//! variants::bulk_update(&client, product_id, updates).await?;
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::payload::{parse_response, root_payload};
+use super::{GraphqlClient, GraphqlError};
+
+/// Mutation used by [`bulk_create`].
+const BULK_CREATE_MUTATION: &str = r"
+mutation productVariantsBulkCreate($productId: ID!, $variants: [ProductVariantsBulkInput!]!) {
+    productVariantsBulkCreate(productId: $productId, variants: $variants) {
+        productVariants {
+            id
+            title
+            price
+            sku
+        }
+        userErrors {
+            field
+            message
+        }
+    }
+}
+";
+
+/// Mutation used by [`bulk_update`].
+const BULK_UPDATE_MUTATION: &str = r"
+mutation productVariantsBulkUpdate($productId: ID!, $variants: [ProductVariantsBulkInput!]!) {
+    productVariantsBulkUpdate(productId: $productId, variants: $variants) {
+        productVariants {
+            id
+            title
+            price
+            sku
+        }
+        userErrors {
+            field
+            message
+        }
+    }
+}
+";
+
+/// A variant to create or update.
+///
+/// Maps to Shopify's `ProductVariantsBulkInput`. Set `id` when updating;
+/// leave it unset when creating. Unset fields are omitted from the input.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VariantInput {
+    /// The variant GID, required for [`bulk_update`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The option values that identify the variant (e.g. `Size: Small`).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub option_values: Vec<VariantOptionValueInput>,
+    /// The variant price.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+    /// The price before a markdown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compare_at_price: Option<String>,
+    /// The barcode, UPC or ISBN.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub barcode: Option<String>,
+    /// Whether a tax is charged when the variant is sold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxable: Option<bool>,
+}
+
+/// An option value of a [`VariantInput`].
+///
+/// Maps to Shopify's `VariantOptionValueInput`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VariantOptionValueInput {
+    /// The name of the product option (e.g. `"Size"`).
+    pub option_name: String,
+    /// The option value (e.g. `"Small"`).
+    pub name: String,
+}
+
+/// A variant returned by [`bulk_create`] or [`bulk_update`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ProductVariant {
+    /// The variant GID.
+    pub id: String,
+    /// The variant title, built from its option values.
+    pub title: String,
+    /// The variant price.
+    #[serde(default)]
+    pub price: Option<String>,
+    /// The variant SKU.
+    #[serde(default)]
+    pub sku: Option<String>,
+}
+
+/// Creates variants for a product.
+///
+/// Wraps the `productVariantsBulkCreate` mutation.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the request
+/// * `product_id` - The product GID (e.g. `gid://shopify/Product/1`)
+/// * `variants` - The variants to create
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if Shopify rejects a variant
+/// (for example, a duplicate option combination).
+/// Returns [`GraphqlError::MissingData`] if the response cannot be parsed.
+pub async fn bulk_create(
+    client: &GraphqlClient,
+    product_id: &str,
+    variants: Vec<VariantInput>,
+) -> Result<Vec<ProductVariant>, GraphqlError> {
+    let variables = bulk_variables(product_id, &variants);
+    let response = client
+        .query(BULK_CREATE_MUTATION, Some(variables), None, None)
+        .await?;
+
+    parse_response(&response, |body| {
+        parse_variants(body, "productVariantsBulkCreate")
+    })
+}
+
+/// Updates variants of a product.
+///
+/// Wraps the `productVariantsBulkUpdate` mutation. Each input must set
+/// [`VariantInput::id`].
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the request
+/// * `product_id` - The product GID (e.g. `gid://shopify/Product/1`)
+/// * `variants` - The variant changes to apply
+///
+/// # Errors
+///
+/// Returns [`GraphqlError::Http`] for HTTP-level errors.
+/// Returns [`GraphqlError::UserErrors`] if Shopify rejects a change
+/// (for example, an unknown variant ID).
+/// Returns [`GraphqlError::MissingData`] if the response cannot be parsed.
+pub async fn bulk_update(
+    client: &GraphqlClient,
+    product_id: &str,
+    variants: Vec<VariantInput>,
+) -> Result<Vec<ProductVariant>, GraphqlError> {
+    let variables = bulk_variables(product_id, &variants);
+    let response = client
+        .query(BULK_UPDATE_MUTATION, Some(variables), None, None)
+        .await?;
+
+    parse_response(&response, |body| {
+        parse_variants(body, "productVariantsBulkUpdate")
+    })
+}
+
+/// Builds the variables shared by both bulk variant mutations.
+fn bulk_variables(product_id: &str, variants: &[VariantInput]) -> Value {
+    json!({
+        "productId": product_id,
+        "variants": variants,
+    })
+}
+
+/// Parses the `productVariants` list from a bulk variant mutation response.
+fn parse_variants(body: &Value, field: &str) -> Result<Vec<ProductVariant>, GraphqlError> {
+    let payload = root_payload(body, field)?;
+    serde_json::from_value(payload["productVariants"].clone()).map_err(|_| {
+        GraphqlError::MissingData {
+            field: format!("data.{field}.productVariants"),
+            request_id: None,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::graphql::UserError;
+
+    #[test]
+    fn test_bulk_variables_serialize_variant_inputs() {
+        let variables = bulk_variables(
+            "gid://shopify/Product/1",
+            &[
+                VariantInput {
+                    option_values: vec![VariantOptionValueInput {
+                        option_name: "Size".to_string(),
+                        name: "Small".to_string(),
+                    }],
+                    price: Some("19.99".to_string()),
+                    ..Default::default()
+                },
+                VariantInput {
+                    id: Some("gid://shopify/ProductVariant/2".to_string()),
+                    compare_at_price: Some("29.99".to_string()),
+                    taxable: Some(false),
+                    ..Default::default()
+                },
+            ],
+        );
+
+        assert_eq!(
+            variables,
+            json!({
+                "productId": "gid://shopify/Product/1",
+                "variants": [
+                    {
+                        "optionValues": [{ "optionName": "Size", "name": "Small" }],
+                        "price": "19.99"
+                    },
+                    {
+                        "id": "gid://shopify/ProductVariant/2",
+                        "compareAtPrice": "29.99",
+                        "taxable": false
+                    }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_variants() {
+        let body = json!({
+            "data": {
+                "productVariantsBulkCreate": {
+                    "productVariants": [
+                        { "id": "gid://shopify/ProductVariant/1", "title": "Small", "price": "19.99", "sku": null },
+                        { "id": "gid://shopify/ProductVariant/2", "title": "Medium", "price": "19.99", "sku": "TEE-M" }
+                    ],
+                    "userErrors": []
+                }
+            }
+        });
+
+        let variants = parse_variants(&body, "productVariantsBulkCreate").unwrap();
+
+        assert_eq!(variants.len(), 2);
+        assert_eq!(variants[0].title, "Small");
+        assert_eq!(variants[0].sku, None);
+        assert_eq!(variants[1].sku.as_deref(), Some("TEE-M"));
+    }
+
+    #[test]
+    fn test_parse_variants_surfaces_duplicate_option_error() {
+        let body = json!({
+            "data": {
+                "productVariantsBulkCreate": {
+                    "productVariants": [],
+                    "userErrors": [{
+                        "field": ["variants", "0"],
+                        "message": "The variant 'Small' already exists."
+                    }]
+                }
+            }
+        });
+
+        let result = parse_variants(&body, "productVariantsBulkCreate");
+
+        match result {
+            Err(GraphqlError::UserErrors { errors, .. }) => assert_eq!(
+                errors,
+                vec![UserError {
+                    field: Some(vec!["variants".to_string(), "0".to_string()]),
+                    message: "The variant 'Small' already exists.".to_string(),
+                }]
+            ),
+            other => panic!("Expected UserErrors, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,241 @@
+//! API version compatibility checks against the shop.
+//!
+//! [`ApiVersion::supported_versions`] is compiled into the SDK and can fall
+//! behind Shopify's actual support window. [`ApiVersion::fetch_supported`]
+//! asks Shopify instead, via the `publicApiVersions` query, and
+//! [`ShopifyConfig::warn_if_unsupported`] uses it to flag a configured
+//! version that is no longer supported.
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::payload::{parse_response, root_payload};
+use super::{GraphqlClient, GraphqlError};
+use crate::clients::{DeprecationNotice, DeprecationSource};
+use crate::config::{ApiVersion, ShopifyConfig};
+
+/// Query used by [`ApiVersion::fetch_supported`].
+const PUBLIC_API_VERSIONS_QUERY: &str = r"
+query publicApiVersions {
+    publicApiVersions {
+        handle
+        displayName
+        supported
+    }
+}
+";
+
+/// A single entry of the `publicApiVersions` list.
+#[derive(Debug, Deserialize)]
+struct PublicApiVersion {
+    handle: String,
+    supported: bool,
+}
+
+impl ApiVersion {
+    /// Fetches the API versions Shopify currently supports.
+    ///
+    /// Wraps the `publicApiVersions` query. Only versions marked as
+    /// supported are returned, oldest first. Handles that aren't valid
+    /// version strings are skipped.
+    ///
+    /// The list changes at most once a quarter, so fetch it once (for
+    /// example at startup) and keep the result rather than calling this
+    /// before every request.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphqlError::Http`] for HTTP-level errors.
+    /// Returns [`GraphqlError::UserErrors`] if the query is rejected.
+    /// Returns [`GraphqlError::MissingData`] if the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use shopify_sdk::ApiVersion;
+    ///
+    /// let supported = ApiVersion::fetch_supported(&client).await?;
+    /// assert!(supported.contains(&ApiVersion::latest()));
+    /// ```
+    pub async fn fetch_supported(client: &GraphqlClient) -> Result<Vec<Self>, GraphqlError> {
+        let response = client
+            .query(PUBLIC_API_VERSIONS_QUERY, None, None, None)
+            .await?;
+
+        parse_response(&response, parse_public_api_versions)
+    }
+}
+
+impl ShopifyConfig {
+    /// Logs a warning if the configured API version is no longer supported.
+    ///
+    /// The warning is also recorded as a
+    /// [`DeprecationSource::ApiVersion`]
+    /// notice if the config has a
+    /// [`DeprecationRegistry`](crate::clients::DeprecationRegistry).
+    ///
+    /// Fetches the supported versions with [`ApiVersion::fetch_supported`]
+    /// and checks the configured [`api_version`](Self::api_version) against
+    /// them. The `unstable` version is never reported.
+    ///
+    /// Returns the fetched list so it can be kept for later checks.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`ApiVersion::fetch_supported`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let supported = config.warn_if_unsupported(&client).await?;
+    /// ```
+    pub async fn warn_if_unsupported(
+        &self,
+        client: &GraphqlClient,
+    ) -> Result<Vec<ApiVersion>, GraphqlError> {
+        let supported = ApiVersion::fetch_supported(client).await?;
+
+        if !is_configured_version_supported(self.api_version(), &supported) {
+            tracing::warn!(
+                version = %self.api_version(),
+                "Configured API version '{}' is past Shopify's support window (supported: {}). Please upgrade.",
+                self.api_version(),
+                supported
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            if let Some(registry) = self.deprecation_registry() {
+                registry.record(DeprecationNotice {
+                    source: DeprecationSource::ApiVersion,
+                    reason: format!(
+                        "API version '{}' is past Shopify's support window",
+                        self.api_version()
+                    ),
+                    path: None,
+                });
+            }
+        }
+
+        Ok(supported)
+    }
+}
+
+/// Returns `true` if `version` is `unstable` or appears in `supported`.
+fn is_configured_version_supported(version: &ApiVersion, supported: &[ApiVersion]) -> bool {
+    *version == ApiVersion::Unstable || supported.contains(version)
+}
+
+/// Parses the supported versions from a `publicApiVersions` response.
+fn parse_public_api_versions(body: &Value) -> Result<Vec<ApiVersion>, GraphqlError> {
+    let payload = root_payload(body, "publicApiVersions")?;
+    let versions: Vec<PublicApiVersion> =
+        serde_json::from_value(payload.clone()).map_err(|_| GraphqlError::MissingData {
+            field: "data.publicApiVersions".to_string(),
+            request_id: None,
+        })?;
+
+    let mut supported: Vec<ApiVersion> = versions
+        .into_iter()
+        .filter(|v| v.supported)
+        .filter_map(|v| v.handle.parse().ok())
+        .collect();
+    supported.sort();
+    Ok(supported)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_public_api_versions() {
+        let body = json!({
+            "data": {
+                "publicApiVersions": [
+                    { "handle": "2025-04", "displayName": "2025-04", "supported": false },
+                    { "handle": "2025-10", "displayName": "2025-10", "supported": true },
+                    { "handle": "2025-07", "displayName": "2025-07", "supported": true },
+                    { "handle": "2026-01", "displayName": "2026-01", "supported": true },
+                    { "handle": "2026-04", "displayName": "2026-04 (Latest)", "supported": true },
+                    { "handle": "2026-07", "displayName": "2026-07 (Release candidate)", "supported": true },
+                    { "handle": "unstable", "displayName": "unstable", "supported": false }
+                ]
+            }
+        });
+
+        let versions = parse_public_api_versions(&body).unwrap();
+
+        assert_eq!(
+            versions,
+            vec![
+                ApiVersion::V2025_07,
+                ApiVersion::V2025_10,
+                ApiVersion::V2026_01,
+                ApiVersion::V2026_04,
+                ApiVersion::Custom("2026-07".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_configured_version_support_check() {
+        let supported = vec![ApiVersion::V2025_10, ApiVersion::V2026_01];
+
+        assert!(is_configured_version_supported(
+            &ApiVersion::V2026_01,
+            &supported
+        ));
+        assert!(!is_configured_version_supported(
+            &ApiVersion::V2025_04,
+            &supported
+        ));
+        assert!(is_configured_version_supported(
+            &ApiVersion::Unstable,
+            &supported
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_warn_if_unsupported_records_deprecation_notice() {
+        use crate::clients::DeprecationRegistry;
+        use crate::config::{ApiKey, ApiSecretKey};
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": {
+                    "publicApiVersions": [
+                        { "handle": "2025-10", "displayName": "2025-10", "supported": true }
+                    ]
+                }
+            })))
+            .mount(&server)
+            .await;
+        let registry = DeprecationRegistry::new();
+        let config = ShopifyConfig::builder()
+            .api_key(ApiKey::new("key").unwrap())
+            .api_secret_key(ApiSecretKey::new("secret").unwrap())
+            .api_version(ApiVersion::V2026_04)
+            .deprecation_registry(registry.clone())
+            .build()
+            .unwrap();
+        assert!(registry.is_empty());
+
+        let client = GraphqlClient::for_mock_server(&server.uri());
+        config.warn_if_unsupported(&client).await.unwrap();
+
+        assert_eq!(
+            registry.notices(),
+            vec![DeprecationNotice {
+                source: DeprecationSource::ApiVersion,
+                reason: "API version '2026-04' is past Shopify's support window".to_string(),
+                path: None,
+            }]
+        );
+    }
+}
@@ -3,17 +3,27 @@
 //! This module provides the [`HttpClient`] type for making authenticated
 //! requests to the Shopify API with automatic retry handling.
 
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
 
-use crate::auth::Session;
+use crate::auth::{ScopeDrift, Session};
+use crate::clients::deprecation::{DeprecationNotice, DeprecationRegistry};
 use crate::clients::errors::{HttpError, HttpResponseError, MaxHttpRetriesExceededError};
-use crate::clients::http_request::HttpRequest;
-use crate::clients::http_response::{ApiDeprecationInfo, HttpResponse};
+use crate::clients::http_request::{HttpMethod, HttpRequest, RedirectPolicy};
+use crate::clients::http_response::{retry_after_from_headers, ApiDeprecationInfo, HttpResponse};
+use crate::clients::metrics::{Metrics, NoopMetrics};
 use crate::config::{DeprecationCallback, ShopifyConfig};
 
 /// Fixed retry wait time in seconds (matching Ruby SDK).
 pub const RETRY_WAIT_TIME: u64 = 1;
 
+/// Callback that rewrites the URL of every request before it is sent.
+///
+/// Set with [`HttpClient::with_url_rewriter`].
+pub type UrlRewriter = Arc<dyn Fn(&mut reqwest::Url) + Send + Sync>;
+
 /// SDK version from Cargo.toml.
 pub const SDK_VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -23,6 +33,7 @@ pub const SDK_VERSION: &str = env!("CARGO_PKG_VERSION");
 /// - Base URI construction from session shop domain or `api_host`
 /// - Default headers including User-Agent and access token
 /// - Automatic retry logic for 429 and 500 responses
+/// - Redirects, per its [`RedirectPolicy`]
 /// - Shopify-specific header parsing
 ///
 /// # Thread Safety
@@ -62,6 +73,14 @@ pub struct HttpClient {
     default_headers: HashMap<String, String>,
     /// Optional callback for deprecation notices.
     deprecation_callback: Option<DeprecationCallback>,
+    /// Optional registry that collects deprecation notices.
+    deprecation_registry: Option<DeprecationRegistry>,
+    /// Hook that records each request.
+    metrics: Arc<dyn Metrics>,
+    /// Optional callback that rewrites each request URL before sending.
+    url_rewriter: Option<UrlRewriter>,
+    /// Redirect handling for requests that don't set their own.
+    redirect_policy: RedirectPolicy,
 }
 
 impl std::fmt::Debug for HttpClient {
@@ -75,10 +94,140 @@ impl std::fmt::Debug for HttpClient {
                 "deprecation_callback",
                 &self.deprecation_callback.as_ref().map(|_| "<callback>"),
             )
+            .field("deprecation_registry", &self.deprecation_registry)
+            .field("metrics", &"<metrics>")
+            .field(
+                "url_rewriter",
+                &self.url_rewriter.as_ref().map(|_| "<rewriter>"),
+            )
+            .field("redirect_policy", &self.redirect_policy)
             .finish()
     }
 }
 
+/// Returns a reqwest client builder configured the way the SDK's own clients are.
+///
+/// The builder uses rustls and applies the
+/// [`connect_timeout`](ShopifyConfig::connect_timeout) and
+/// [`request_timeout`](ShopifyConfig::request_timeout) from `config`. Start
+/// from it when building a reqwest client of your own for Shopify requests,
+/// so the configured timeouts are honored there too.
+///
+/// The builder does not follow redirects; [`HttpClient`] follows them itself
+/// according to its [`RedirectPolicy`].
+///
+/// # Example
+///
+/// ```rust
+/// use shopify_sdk::clients::reqwest_client_builder;
+/// use shopify_sdk::{ShopifyConfig, ApiKey, ApiSecretKey};
+/// use std::time::Duration;
+///
+/// let config = ShopifyConfig::builder()
+///     .api_key(ApiKey::new("key").unwrap())
+///     .api_secret_key(ApiSecretKey::new("secret").unwrap())
+///     .connect_timeout(Duration::from_secs(2))
+///     .build()
+///     .unwrap();
+///
+/// let client = reqwest_client_builder(Some(&config))
+///     .pool_max_idle_per_host(4)
+///     .build()
+///     .unwrap();
+/// ```
+pub fn reqwest_client_builder(config: Option<&ShopifyConfig>) -> reqwest::ClientBuilder {
+    let mut builder = reqwest::Client::builder()
+        .use_rustls_tls()
+        .redirect(reqwest::redirect::Policy::none());
+    if let Some(timeout) = config.and_then(ShopifyConfig::connect_timeout) {
+        builder = builder.connect_timeout(timeout);
+    }
+    if let Some(timeout) = config.and_then(ShopifyConfig::request_timeout) {
+        builder = builder.timeout(timeout);
+    }
+    builder
+}
+
+/// Builds the reqwest client used for Admin API, Storefront API and OAuth
+/// requests.
+///
+/// Redirects are never followed, so a token request answered with a `307`
+/// or `308` is not re-sent to another host.
+///
+/// # Panics
+///
+/// Panics if the TLS backend cannot be initialized.
+pub fn build_reqwest_client(config: Option<&ShopifyConfig>) -> reqwest::Client {
+    reqwest_client_builder(config)
+        .build()
+        .expect("Failed to create HTTP client")
+}
+
+/// Compares the session's scopes with the configured scopes if
+/// [`ShopifyConfig::check_scope_drift`] is enabled, logging a warning when
+/// they differ.
+pub(super) fn check_scope_drift(
+    session: &Session,
+    config: Option<&ShopifyConfig>,
+) -> Option<ScopeDrift> {
+    let config = config.filter(|c| c.check_scope_drift() && !c.scopes().is_empty())?;
+    let drift = session.scope_drift(config.scopes())?;
+    tracing::warn!(
+        shop = %session.shop.as_ref(),
+        missing = %drift.missing.join(","),
+        unexpected = %drift.unexpected.join(","),
+        "Session scopes differ from the configured scopes. Re-authorize the app to update them."
+    );
+    Some(drift)
+}
+
+/// Builds the `User-Agent` header sent with every request.
+///
+/// The format is `{prefix} shopify-api-rust/{SDK_VERSION} (rustc {rust_version})`,
+/// where the prefix comes from [`ShopifyConfig::user_agent_prefix`] and is
+/// omitted when unset. The Rust version is the crate's declared minimum.
+pub fn build_user_agent(config: Option<&ShopifyConfig>) -> String {
+    let rust_version = env!("CARGO_PKG_RUST_VERSION");
+    let sdk = format!("shopify-api-rust/{SDK_VERSION} (rustc {rust_version})");
+
+    match config.and_then(ShopifyConfig::user_agent_prefix) {
+        Some(prefix) if !prefix.trim().is_empty() => format!("{} {sdk}", prefix.trim()),
+        _ => sdk,
+    }
+}
+
+/// Recognizes a Shopify maintenance page and returns the matching error.
+///
+/// During maintenance Shopify answers with a 503 and an HTML body. A 503
+/// whose body doesn't look like JSON is reported as
+/// [`HttpError::ServiceUnavailable`] without attempting to parse it.
+pub fn maintenance_error(
+    code: u16,
+    headers: &HashMap<String, Vec<String>>,
+    body_text: &str,
+) -> Option<HttpError> {
+    let looks_like_json = matches!(body_text.trim_start().chars().next(), Some('{' | '['));
+    if code != 503 || looks_like_json {
+        return None;
+    }
+
+    let header = |name: &str| headers.get(name).and_then(|values| values.first());
+    Some(HttpError::ServiceUnavailable {
+        retry_after: retry_after_from_headers(headers),
+        error_reference: header("x-request-id").cloned(),
+    })
+}
+
+/// Returns the response status carried by an error, if a response was received.
+const fn error_status(error: &HttpError) -> Option<u16> {
+    match error {
+        HttpError::Response(e) => Some(e.code),
+        HttpError::MaxRetries(e) => Some(e.code),
+        HttpError::ServiceUnavailable { .. } => Some(503),
+        HttpError::InvalidRequest(_) | HttpError::Network(_) | HttpError::ConnectTimeout(_) => None,
+    }
+}
+
 // Verify HttpClient is Send + Sync at compile time
 const _: fn() = || {
     const fn assert_send_sync<T: Send + Sync>() {}
@@ -121,6 +270,17 @@ impl HttpClient {
         base_path: impl Into<String>,
         session: &Session,
         config: Option<&ShopifyConfig>,
+    ) -> Self {
+        Self::with_reqwest_client(base_path, session, config, build_reqwest_client(config))
+    }
+
+    /// Creates a new HTTP client that sends requests through an existing
+    /// reqwest client, sharing its connection pool.
+    pub(crate) fn with_reqwest_client(
+        base_path: impl Into<String>,
+        session: &Session,
+        config: Option<&ShopifyConfig>,
+        client: reqwest::Client,
     ) -> Self {
         let base_path = base_path.into();
 
@@ -132,17 +292,9 @@ impl HttpClient {
                 .map_or_else(default_shop_uri, |host_name| format!("https://{host_name}"))
         });
 
-        // Build User-Agent header
-        let user_agent_prefix = config
-            .and_then(ShopifyConfig::user_agent_prefix)
-            .map_or(String::new(), |prefix| format!("{prefix} | "));
-        let rust_version = env!("CARGO_PKG_RUST_VERSION");
-        let user_agent =
-            format!("{user_agent_prefix}Shopify API Library v{SDK_VERSION} | Rust {rust_version}");
-
         // Build default headers
         let mut default_headers = HashMap::new();
-        default_headers.insert("User-Agent".to_string(), user_agent);
+        default_headers.insert("User-Agent".to_string(), build_user_agent(config));
         default_headers.insert("Accept".to_string(), "application/json".to_string());
 
         // Add Host header when using api_host (proxy scenario)
@@ -150,6 +302,14 @@ impl HttpClient {
             default_headers.insert("Host".to_string(), session.shop.as_ref().to_string());
         }
 
+        // Add API preview features header when any are configured
+        if let Some(features) = config
+            .map(ShopifyConfig::api_features)
+            .filter(|features| !features.is_empty())
+        {
+            default_headers.insert("X-Shopify-Api-Features".to_string(), features.join(", "));
+        }
+
         // Add access token header if present
         if !session.access_token.is_empty() {
             default_headers.insert(
@@ -158,14 +318,13 @@ impl HttpClient {
             );
         }
 
-        // Create reqwest client
-        let client = reqwest::Client::builder()
-            .use_rustls_tls()
-            .build()
-            .expect("Failed to create HTTP client");
-
         // Get deprecation callback if configured
         let deprecation_callback = config.and_then(|c| c.deprecation_callback().cloned());
+        let deprecation_registry = config.and_then(|c| c.deprecation_registry().cloned());
+
+        let metrics = config
+            .and_then(|c| c.metrics().cloned())
+            .unwrap_or_else(|| Arc::new(NoopMetrics));
 
         Self {
             client,
@@ -173,9 +332,110 @@ impl HttpClient {
             base_path,
             default_headers,
             deprecation_callback,
+            deprecation_registry,
+            metrics,
+            url_rewriter: None,
+            redirect_policy: RedirectPolicy::default(),
         }
     }
 
+    /// Sets a callback that can inspect and change each request URL before
+    /// it is sent.
+    ///
+    /// The callback receives the fully resolved URL, including query
+    /// parameters, once per attempt. Use it to route requests through an API
+    /// gateway or to add a signature parameter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::{Session, ShopDomain, AuthScopes};
+    /// use shopify_sdk::clients::HttpClient;
+    ///
+    /// let session = Session::new(
+    ///     "session-id".to_string(),
+    ///     ShopDomain::new("my-store").unwrap(),
+    ///     "access-token".to_string(),
+    ///     AuthScopes::new(),
+    ///     false,
+    ///     None,
+    /// );
+    ///
+    /// let client = HttpClient::new("/admin/api/2024-10", &session, None)
+    ///     .with_url_rewriter(|url| {
+    ///         url.query_pairs_mut().append_pair("gateway_key", "abc123");
+    ///     });
+    /// ```
+    #[must_use]
+    pub fn with_url_rewriter<F>(mut self, rewriter: F) -> Self
+    where
+        F: Fn(&mut reqwest::Url) + Send + Sync + 'static,
+    {
+        self.url_rewriter = Some(Arc::new(rewriter));
+        self
+    }
+
+    /// Sets how redirects are handled for requests that don't set their own
+    /// [`redirect_policy`](HttpRequest::redirect_policy).
+    ///
+    /// Defaults to [`RedirectPolicy::None`]: a `3xx` response is returned as
+    /// an [`HttpError::Response`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::{Session, ShopDomain, AuthScopes};
+    /// use shopify_sdk::clients::{HttpClient, RedirectPolicy};
+    ///
+    /// let session = Session::new(
+    ///     "session-id".to_string(),
+    ///     ShopDomain::new("my-store").unwrap(),
+    ///     "access-token".to_string(),
+    ///     AuthScopes::new(),
+    ///     false,
+    ///     None,
+    /// );
+    ///
+    /// let client = HttpClient::new("/admin/api/2024-10", &session, None)
+    ///     .with_redirect_policy(RedirectPolicy::Follow(3));
+    /// assert_eq!(client.redirect_policy(), RedirectPolicy::Follow(3));
+    /// ```
+    #[must_use]
+    pub const fn with_redirect_policy(mut self, policy: RedirectPolicy) -> Self {
+        self.redirect_policy = policy;
+        self
+    }
+
+    /// Returns the redirect policy for requests that don't set their own.
+    #[must_use]
+    pub const fn redirect_policy(&self) -> RedirectPolicy {
+        self.redirect_policy
+    }
+
+    /// Returns the registry deprecation notices are recorded into, if the
+    /// config set one.
+    #[must_use]
+    pub const fn deprecation_registry(&self) -> Option<&DeprecationRegistry> {
+        self.deprecation_registry.as_ref()
+    }
+
+    /// Returns the URL a request will be sent to.
+    ///
+    /// Resolves the request against this client's base URI and path, applies
+    /// any per-request API version and query parameters, then runs the
+    /// [URL rewriter](Self::with_url_rewriter), if one is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HttpError::InvalidRequest`] if the resolved URL is invalid.
+    pub fn resolved_url(&self, request: &HttpRequest) -> Result<reqwest::Url, HttpError> {
+        let mut url = request.resolved_url(&self.base_uri, &self.base_path)?;
+        if let Some(rewriter) = &self.url_rewriter {
+            rewriter(&mut url);
+        }
+        Ok(url)
+    }
+
     /// Returns the base URI for this client.
     #[must_use]
     pub fn base_uri(&self) -> &str {
@@ -188,6 +448,39 @@ impl HttpClient {
         &self.base_path
     }
 
+    /// Returns the `User-Agent` header sent with every request.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::{AuthScopes, Session, ShopDomain, ShopifyConfig, ApiKey, ApiSecretKey};
+    /// use shopify_sdk::clients::{HttpClient, SDK_VERSION};
+    ///
+    /// let session = Session::new(
+    ///     "session-id".to_string(),
+    ///     ShopDomain::new("my-store").unwrap(),
+    ///     "access-token".to_string(),
+    ///     AuthScopes::new(),
+    ///     false,
+    ///     None,
+    /// );
+    /// let config = ShopifyConfig::builder()
+    ///     .api_key(ApiKey::new("key").unwrap())
+    ///     .api_secret_key(ApiSecretKey::new("secret").unwrap())
+    ///     .user_agent_prefix("MyApp/1.0")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let client = HttpClient::new("/admin/api/2024-10", &session, Some(&config));
+    /// assert!(client.user_agent().starts_with(&format!("MyApp/1.0 shopify-api-rust/{SDK_VERSION} ")));
+    /// ```
+    #[must_use]
+    pub fn user_agent(&self) -> &str {
+        self.default_headers
+            .get("User-Agent")
+            .map_or("", String::as_str)
+    }
+
     /// Returns the default headers for this client.
     #[must_use]
     pub const fn default_headers(&self) -> &HashMap<String, String> {
@@ -229,8 +522,35 @@ impl HttpClient {
         // Validate request first
         request.verify()?;
 
-        // Build full URL
-        let url = format!("{}{}/{}", self.base_uri, self.base_path, request.path);
+        let started = Instant::now();
+        let mut tries: u32 = 0;
+        let result = self.send_with_retries(&request, &mut tries).await;
+
+        let status = match &result {
+            Ok(response) => Some(response.code),
+            Err(error) => error_status(error),
+        };
+        self.metrics.record_request(
+            &request.path,
+            request.http_method,
+            status,
+            started.elapsed(),
+            tries.saturating_sub(1),
+        );
+
+        result
+    }
+
+    /// Sends a validated request, retrying per its `tries` setting.
+    ///
+    /// `tries` is incremented for each attempt.
+    async fn send_with_retries(
+        &self,
+        request: &HttpRequest,
+        tries: &mut u32,
+    ) -> Result<HttpResponse, HttpError> {
+        // Build full URL, including query parameters
+        let url = self.resolved_url(request)?;
 
         // Merge headers
         let mut headers = self.default_headers.clone();
@@ -246,42 +566,38 @@ impl HttpClient {
             }
         }
 
+        let max_redirects = request
+            .redirect_policy
+            .unwrap_or(self.redirect_policy)
+            .max_redirects();
+
         // Retry loop
-        let mut tries: u32 = 0;
         loop {
-            tries += 1;
-
-            // Build the reqwest request
-            let mut req_builder = match request.http_method {
-                crate::clients::http_request::HttpMethod::Get => self.client.get(&url),
-                crate::clients::http_request::HttpMethod::Post => self.client.post(&url),
-                crate::clients::http_request::HttpMethod::Put => self.client.put(&url),
-                crate::clients::http_request::HttpMethod::Delete => self.client.delete(&url),
-            };
-
-            // Add headers
-            for (key, value) in &headers {
-                req_builder = req_builder.header(key, value);
-            }
-
-            // Add query params
-            if let Some(query) = &request.query {
-                req_builder = req_builder.query(query);
-            }
-
-            // Add body
-            if let Some(body) = &request.body {
-                req_builder = req_builder.body(body.to_string());
-            }
+            *tries += 1;
 
-            // Send request
-            let res = req_builder.send().await?;
+            // Send request, following redirects the policy allows
+            let res = self
+                .send_following_redirects(request, &url, &headers, max_redirects)
+                .await?;
 
             // Parse response
             let code = res.status().as_u16();
             let res_headers = Self::parse_response_headers(res.headers());
             let body_text = res.text().await.unwrap_or_default();
 
+            // Maintenance pages are HTML; retry them without parsing the body
+            if let Some(error) = maintenance_error(code, &res_headers, &body_text) {
+                if *tries >= request.tries {
+                    return Err(error);
+                }
+                let delay = error.retry_after().map_or(
+                    std::time::Duration::from_secs(RETRY_WAIT_TIME),
+                    std::time::Duration::from_secs_f64,
+                );
+                tokio::time::sleep(delay).await;
+                continue;
+            }
+
             // Parse body as JSON
             let body = if body_text.is_empty() {
                 serde_json::json!({})
@@ -306,14 +622,18 @@ impl HttpClient {
                     reason
                 );
 
+                let info = ApiDeprecationInfo {
+                    reason: reason.to_string(),
+                    path: Some(request.path.clone()),
+                };
+
                 // Invoke deprecation callback if configured
                 if let Some(callback) = &self.deprecation_callback {
-                    let info = ApiDeprecationInfo {
-                        reason: reason.to_string(),
-                        path: Some(request.path.clone()),
-                    };
                     callback(&info);
                 }
+                if let Some(registry) = &self.deprecation_registry {
+                    registry.record(DeprecationNotice::from(&info));
+                }
             }
 
             // Check if response is OK
@@ -335,7 +655,7 @@ impl HttpClient {
             }
 
             // Check if we've exhausted retries
-            if tries >= request.tries {
+            if *tries >= request.tries {
                 if request.tries == 1 {
                     return Err(HttpError::Response(HttpResponseError {
                         code,
@@ -357,6 +677,76 @@ impl HttpClient {
         }
     }
 
+    /// Sends one attempt of a request, following up to `max_redirects`
+    /// redirects.
+    ///
+    /// `301`, `302` and `303` redirects are followed with a `GET` without a
+    /// body; `307` and `308` keep the method and body. The access token and
+    /// `Host` headers are dropped when a redirect leaves the original host.
+    async fn send_following_redirects(
+        &self,
+        request: &HttpRequest,
+        url: &reqwest::Url,
+        headers: &HashMap<String, String>,
+        max_redirects: u32,
+    ) -> Result<reqwest::Response, HttpError> {
+        let mut url = Cow::Borrowed(url);
+        let mut headers = Cow::Borrowed(headers);
+        let mut method = request.http_method;
+        let mut body = request.body.as_ref().map(ToString::to_string);
+        let mut redirects: u32 = 0;
+
+        loop {
+            // Build the reqwest request
+            let mut req_builder = match method {
+                HttpMethod::Get => self.client.get(url.as_ref().clone()),
+                HttpMethod::Post => self.client.post(url.as_ref().clone()),
+                HttpMethod::Put => self.client.put(url.as_ref().clone()),
+                HttpMethod::Delete => self.client.delete(url.as_ref().clone()),
+            };
+
+            // Add headers
+            for (key, value) in headers.iter() {
+                req_builder = req_builder.header(key, value);
+            }
+
+            // Apply per-request timeout
+            if let Some(timeout) = request.timeout {
+                req_builder = req_builder.timeout(timeout);
+            }
+
+            // Add body
+            if let Some(body) = &body {
+                req_builder = req_builder.body(body.clone());
+            }
+
+            let res = req_builder.send().await?;
+            if redirects >= max_redirects || !res.status().is_redirection() {
+                return Ok(res);
+            }
+            let Some(next) = res
+                .headers()
+                .get(reqwest::header::LOCATION)
+                .and_then(|location| location.to_str().ok())
+                .and_then(|location| url.join(location).ok())
+            else {
+                return Ok(res);
+            };
+
+            redirects += 1;
+            if !matches!(res.status().as_u16(), 307 | 308) {
+                method = HttpMethod::Get;
+                body = None;
+                headers.to_mut().remove("Content-Type");
+            }
+            if next.host_str() != url.host_str() || next.port() != url.port() {
+                headers.to_mut().remove("X-Shopify-Access-Token");
+                headers.to_mut().remove("Host");
+            }
+            url = Cow::Owned(next);
+        }
+    }
+
     /// Parses response headers into a `HashMap`.
     fn parse_response_headers(
         headers: &reqwest::header::HeaderMap,
@@ -415,7 +805,12 @@ impl HttpClient {
 mod tests {
     use super::*;
     use crate::auth::AuthScopes;
-    use crate::config::{ApiKey, ApiSecretKey, ShopDomain};
+    use crate::clients::HttpMethod;
+    use crate::config::{ApiKey, ApiSecretKey, ApiVersion, ShopDomain};
+    use serde_json::json;
+    use std::time::Duration;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
 
     fn create_test_session() -> Session {
         Session::new(
@@ -443,8 +838,14 @@ mod tests {
         let client = HttpClient::new("/admin/api/2024-10", &session, None);
 
         let user_agent = client.default_headers().get("User-Agent").unwrap();
-        assert!(user_agent.contains("Shopify API Library v"));
-        assert!(user_agent.contains("Rust"));
+        assert_eq!(
+            user_agent,
+            &format!(
+                "shopify-api-rust/{SDK_VERSION} (rustc {})",
+                env!("CARGO_PKG_RUST_VERSION")
+            )
+        );
+        assert_eq!(client.user_agent(), user_agent);
     }
 
     #[test]
@@ -513,8 +914,272 @@ mod tests {
 
         let client = HttpClient::new("/admin/api/2024-10", &session, Some(&config));
 
-        let user_agent = client.default_headers().get("User-Agent").unwrap();
-        assert!(user_agent.starts_with("MyApp/1.0 | "));
-        assert!(user_agent.contains("Shopify API Library"));
+        let user_agent = client.user_agent();
+        assert!(user_agent.starts_with("MyApp/1.0 shopify-api-rust/"));
+        assert!(user_agent.contains(SDK_VERSION));
+        assert!(user_agent.contains("(rustc "));
+    }
+
+    #[test]
+    fn test_api_features_header_joins_configured_features() {
+        let session = create_test_session();
+        let config = ShopifyConfig::builder()
+            .api_key(ApiKey::new("test-key").unwrap())
+            .api_secret_key(ApiSecretKey::new("test-secret").unwrap())
+            .api_features(vec![
+                "include-presentment-prices".to_string(),
+                "order-editing".to_string(),
+            ])
+            .build()
+            .unwrap();
+
+        let client = HttpClient::new("/admin/api/2024-10", &session, Some(&config));
+
+        assert_eq!(
+            client.default_headers().get("X-Shopify-Api-Features"),
+            Some(&"include-presentment-prices, order-editing".to_string())
+        );
+    }
+
+    #[test]
+    fn test_no_api_features_header_by_default() {
+        let session = create_test_session();
+        let client = HttpClient::new("/admin/api/2024-10", &session, None);
+
+        assert!(client
+            .default_headers()
+            .get("X-Shopify-Api-Features")
+            .is_none());
+    }
+
+    #[test]
+    fn test_maintenance_page_returns_service_unavailable() {
+        let headers = HashMap::from([
+            ("content-type".to_string(), vec!["text/html".to_string()]),
+            ("retry-after".to_string(), vec!["120".to_string()]),
+            ("x-request-id".to_string(), vec!["abc-123".to_string()]),
+        ]);
+        // Not valid JSON: parsing it would fail rather than yield this error
+        let body = "<!DOCTYPE html><html><body>Shopify is down for maintenance</body></html>";
+
+        let error = maintenance_error(503, &headers, body).unwrap();
+
+        match &error {
+            HttpError::ServiceUnavailable {
+                retry_after,
+                error_reference,
+            } => {
+                assert_eq!(*retry_after, Some(120.0));
+                assert_eq!(error_reference.as_deref(), Some("abc-123"));
+            }
+            other => panic!("Expected ServiceUnavailable, got {other:?}"),
+        }
+        assert!(error.kind().is_retryable());
+    }
+
+    #[test]
+    fn test_maintenance_error_ignores_json_and_other_statuses() {
+        let headers = HashMap::new();
+
+        assert!(maintenance_error(503, &headers, r#"{"errors":"Unavailable"}"#).is_none());
+        assert!(maintenance_error(500, &headers, "<html></html>").is_none());
+        assert!(maintenance_error(200, &headers, "<html></html>").is_none());
+    }
+
+    /// A recorded request: path, method, status and retries.
+    type Record = (String, HttpMethod, Option<u16>, u32);
+
+    /// Records every request into a shared list.
+    struct RecordingMetrics {
+        records: Arc<std::sync::Mutex<Vec<Record>>>,
+    }
+
+    impl Metrics for RecordingMetrics {
+        fn record_request(
+            &self,
+            path: &str,
+            method: HttpMethod,
+            status: Option<u16>,
+            _duration: std::time::Duration,
+            retries: u32,
+        ) {
+            self.records
+                .lock()
+                .unwrap()
+                .push((path.to_string(), method, status, retries));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_metrics_record_status_and_retries() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/admin/api/2024-10/products.json"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/admin/api/2024-10/products.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "products": [] })))
+            .mount(&server)
+            .await;
+
+        let records = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let config = ShopifyConfig::builder()
+            .api_key(ApiKey::new("key").unwrap())
+            .api_secret_key(ApiSecretKey::new("secret").unwrap())
+            .metrics(RecordingMetrics {
+                records: Arc::clone(&records),
+            })
+            .build()
+            .unwrap();
+        let mut client =
+            HttpClient::new("/admin/api/2024-10", &create_test_session(), Some(&config));
+        client.base_uri = server.uri();
+
+        let request = HttpRequest::builder(HttpMethod::Get, "products.json")
+            .tries(3)
+            .build()
+            .unwrap();
+        let response = client.request(request).await.unwrap();
+
+        assert_eq!(response.code, 200);
+        assert_eq!(
+            *records.lock().unwrap(),
+            vec![("products.json".to_string(), HttpMethod::Get, Some(200), 1)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_built_client_does_not_resend_post_on_redirect() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/admin/oauth/access_token"))
+            .respond_with(
+                ResponseTemplate::new(307)
+                    .insert_header("Location", format!("{}/elsewhere", server.uri()).as_str()),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(path("/elsewhere"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let response = build_reqwest_client(None)
+            .post(format!("{}/admin/oauth/access_token", server.uri()))
+            .json(&json!({ "client_id": "key" }))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(response.status().as_u16(), 307);
+    }
+
+    #[tokio::test]
+    async fn test_connect_timeout_is_distinct_error() {
+        let config = ShopifyConfig::builder()
+            .api_key(ApiKey::new("key").unwrap())
+            .api_secret_key(ApiSecretKey::new("secret").unwrap())
+            .connect_timeout(Duration::from_millis(100))
+            .request_timeout(Duration::from_secs(30))
+            .build()
+            .unwrap();
+        // A non-routable address, so the connection attempt hangs until the
+        // connect timeout fires. Sandboxes that reject or intercept outbound
+        // connections answer it instead, which leaves nothing to test.
+        let addr: std::net::SocketAddr = "10.255.255.1:80".parse().unwrap();
+        match std::net::TcpStream::connect_timeout(&addr, Duration::from_millis(50)) {
+            Err(error) if error.kind() == std::io::ErrorKind::TimedOut => {}
+            probe => {
+                eprintln!("Skipping connect timeout test: {addr} is reachable ({probe:?})");
+                return;
+            }
+        }
+
+        let mut client =
+            HttpClient::new("/admin/api/2024-10", &create_test_session(), Some(&config));
+        client.base_uri = format!("http://{addr}");
+
+        let request = HttpRequest::builder(HttpMethod::Get, "products.json")
+            .build()
+            .unwrap();
+        let started = Instant::now();
+        let error = client.request(request).await.unwrap_err();
+
+        assert!(
+            matches!(error, HttpError::ConnectTimeout(_)),
+            "Expected ConnectTimeout, got {error:?}"
+        );
+        assert!(started.elapsed() < Duration::from_secs(30));
+    }
+
+    #[tokio::test]
+    async fn test_api_version_override_targets_alternate_version_path() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/admin/api/2026-04/products.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "products": [] })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/admin/api/2024-10/products.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "products": [] })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut client = HttpClient::new("/admin/api/2024-10", &create_test_session(), None);
+        client.base_uri = server.uri();
+
+        let overridden = HttpRequest::builder(HttpMethod::Get, "products.json")
+            .api_version(ApiVersion::V2026_04)
+            .build()
+            .unwrap();
+        assert_eq!(client.request(overridden).await.unwrap().code, 200);
+
+        // The client's own version is unchanged for later requests
+        let default = HttpRequest::builder(HttpMethod::Get, "products.json")
+            .build()
+            .unwrap();
+        assert_eq!(client.request(default).await.unwrap().code, 200);
+        assert_eq!(client.base_path(), "/admin/api/2024-10");
+    }
+
+    #[tokio::test]
+    async fn test_url_rewriter_query_param_reaches_server() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/admin/api/2024-10/products.json"))
+            .and(query_param("limit", "5"))
+            .and(query_param("gateway_key", "abc123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "products": [] })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut client = HttpClient::new("/admin/api/2024-10", &create_test_session(), None)
+            .with_url_rewriter(|url| {
+                url.query_pairs_mut().append_pair("gateway_key", "abc123");
+            });
+        client.base_uri = server.uri();
+
+        let request = HttpRequest::builder(HttpMethod::Get, "products.json")
+            .query_param("limit", "5")
+            .build()
+            .unwrap();
+        assert_eq!(
+            client.resolved_url(&request).unwrap().as_str(),
+            format!(
+                "{}/admin/api/2024-10/products.json?limit=5&gateway_key=abc123",
+                server.uri()
+            )
+        );
+
+        let response = client.request(request).await.unwrap();
+        assert_eq!(response.code, 200);
     }
 }
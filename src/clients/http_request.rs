@@ -5,8 +5,10 @@
 
 use std::collections::HashMap;
 use std::fmt;
+use std::time::Duration;
 
 use crate::clients::errors::InvalidHttpRequestError;
+use crate::config::ApiVersion;
 
 /// HTTP methods supported by the Shopify API.
 ///
@@ -57,6 +59,35 @@ impl DataType {
     }
 }
 
+/// How many `3xx` redirects to follow for a request.
+///
+/// Admin API endpoints answer directly, so [`HttpClient`](crate::clients::HttpClient)
+/// returns redirects to the caller by default; a redirect there usually
+/// means a misconfigured shop domain or API host. A few documented
+/// endpoints, such as discount code lookup, answer with a redirect to the
+/// resource and need [`Follow`](Self::Follow).
+///
+/// A redirect to another host drops the access token header.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RedirectPolicy {
+    /// Return `3xx` responses without following them.
+    #[default]
+    None,
+    /// Follow up to this many redirects in a row.
+    Follow(u32),
+}
+
+impl RedirectPolicy {
+    /// Returns the number of redirects this policy follows.
+    #[must_use]
+    pub const fn max_redirects(self) -> u32 {
+        match self {
+            Self::None => 0,
+            Self::Follow(max) => max,
+        }
+    }
+}
+
 /// An HTTP request to be sent to the Shopify API.
 ///
 /// Use [`HttpRequest::builder`] to construct requests with the builder pattern.
@@ -95,9 +126,66 @@ pub struct HttpRequest {
     pub extra_headers: Option<HashMap<String, String>>,
     /// Number of times to attempt the request (default: 1).
     pub tries: u32,
+    /// Timeout for each attempt, overriding the client default if set.
+    pub timeout: Option<Duration>,
+    /// API version for this request, overriding the client's version if set.
+    pub api_version: Option<ApiVersion>,
+    /// Redirect handling for this request, overriding the client's policy if set.
+    pub redirect_policy: Option<RedirectPolicy>,
 }
 
 impl HttpRequest {
+    /// Resolves the full URL this request is sent to.
+    ///
+    /// Joins `base_uri`, `base_path` and the request path, switches the API
+    /// version segment of `base_path` if [`api_version`](Self::api_version)
+    /// is set, and appends the query parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidHttpRequestError::InvalidUrl`] if the result is not a
+    /// valid URL.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::clients::{HttpRequest, HttpMethod};
+    /// use shopify_sdk::ApiVersion;
+    ///
+    /// let request = HttpRequest::builder(HttpMethod::Get, "products.json")
+    ///     .query_param("limit", "5")
+    ///     .api_version(ApiVersion::V2026_04)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let url = request
+    ///     .resolved_url("https://my-store.myshopify.com", "/admin/api/2024-10")
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     url.as_str(),
+    ///     "https://my-store.myshopify.com/admin/api/2026-04/products.json?limit=5"
+    /// );
+    /// ```
+    pub fn resolved_url(
+        &self,
+        base_uri: &str,
+        base_path: &str,
+    ) -> Result<reqwest::Url, InvalidHttpRequestError> {
+        let base_path = self.api_version.as_ref().map_or_else(
+            || base_path.to_string(),
+            |version| versioned_base_path(base_path, version),
+        );
+        let raw = format!("{base_uri}{base_path}/{}", self.path);
+        let mut url = reqwest::Url::parse(&raw)
+            .map_err(|_| InvalidHttpRequestError::InvalidUrl { url: raw })?;
+
+        if let Some(query) = self.query.as_ref().filter(|q| !q.is_empty()) {
+            url.query_pairs_mut().extend_pairs(query);
+        }
+
+        Ok(url)
+    }
+
     /// Creates a new builder for constructing an `HttpRequest`.
     ///
     /// # Arguments
@@ -144,6 +232,21 @@ impl HttpRequest {
     }
 }
 
+/// Replaces the API version segment of a base path (e.g. `/admin/api/2024-10`).
+fn versioned_base_path(base_path: &str, version: &ApiVersion) -> String {
+    base_path
+        .split('/')
+        .map(|segment| {
+            if segment.parse::<ApiVersion>().is_ok() {
+                version.to_string()
+            } else {
+                segment.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
 /// Builder for constructing [`HttpRequest`] instances.
 ///
 /// Provides a fluent API for building requests with optional parameters.
@@ -156,6 +259,9 @@ pub struct HttpRequestBuilder {
     query: Option<HashMap<String, String>>,
     extra_headers: Option<HashMap<String, String>>,
     tries: u32,
+    timeout: Option<Duration>,
+    api_version: Option<ApiVersion>,
+    redirect_policy: Option<RedirectPolicy>,
 }
 
 impl HttpRequestBuilder {
@@ -169,6 +275,9 @@ impl HttpRequestBuilder {
             query: None,
             extra_headers: None,
             tries: 1,
+            timeout: None,
+            api_version: None,
+            redirect_policy: None,
         }
     }
 
@@ -230,6 +339,62 @@ impl HttpRequestBuilder {
         self
     }
 
+    /// Sets a timeout for each attempt of the request.
+    ///
+    /// Useful for requests with large bodies that may take longer than usual.
+    #[must_use]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sends this request to a different API version than the client's.
+    ///
+    /// The version segment of the client's base path is replaced for this
+    /// request only, e.g. to use a field that is not yet available in the
+    /// configured version. The client's default is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::clients::{HttpRequest, HttpMethod};
+    /// use shopify_sdk::ApiVersion;
+    ///
+    /// let request = HttpRequest::builder(HttpMethod::Get, "products.json")
+    ///     .api_version(ApiVersion::V2026_04)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(request.api_version, Some(ApiVersion::V2026_04));
+    /// ```
+    #[must_use]
+    pub fn api_version(mut self, version: ApiVersion) -> Self {
+        self.api_version = Some(version);
+        self
+    }
+
+    /// Sets how redirects are handled for this request, overriding the
+    /// client's [`RedirectPolicy`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::clients::{HttpRequest, HttpMethod, RedirectPolicy};
+    ///
+    /// let request = HttpRequest::builder(HttpMethod::Get, "discount_codes/lookup.json")
+    ///     .query_param("code", "SUMMER20")
+    ///     .redirect_policy(RedirectPolicy::Follow(3))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(request.redirect_policy, Some(RedirectPolicy::Follow(3)));
+    /// ```
+    #[must_use]
+    pub const fn redirect_policy(mut self, policy: RedirectPolicy) -> Self {
+        self.redirect_policy = Some(policy);
+        self
+    }
+
     /// Builds the [`HttpRequest`], validating it in the process.
     ///
     /// # Errors
@@ -244,6 +409,9 @@ impl HttpRequestBuilder {
             query: self.query,
             extra_headers: self.extra_headers,
             tries: self.tries,
+            timeout: self.timeout,
+            api_version: self.api_version,
+            redirect_policy: self.redirect_policy,
         };
         request.verify()?;
         Ok(request)
@@ -325,6 +493,9 @@ mod tests {
             query: None,
             extra_headers: None,
             tries: 1,
+            timeout: None,
+            api_version: None,
+            redirect_policy: None,
         };
 
         assert!(matches!(
@@ -366,5 +537,45 @@ mod tests {
             .build()
             .unwrap();
         assert_eq!(request.tries, 1);
+        assert_eq!(request.timeout, None);
+    }
+
+    #[test]
+    fn test_versioned_base_path_replaces_version_segment() {
+        assert_eq!(
+            versioned_base_path("/admin/api/2024-10", &ApiVersion::V2026_04),
+            "/admin/api/2026-04"
+        );
+        assert_eq!(
+            versioned_base_path("/api/2025-01", &ApiVersion::Unstable),
+            "/api/unstable"
+        );
+    }
+
+    #[test]
+    fn test_resolved_url_appends_query_to_versioned_path() {
+        let request = HttpRequest::builder(HttpMethod::Get, "orders.json")
+            .query_param("status", "any")
+            .api_version(ApiVersion::V2026_04)
+            .build()
+            .unwrap();
+
+        let url = request
+            .resolved_url("https://test-shop.myshopify.com", "/admin/api/2024-10")
+            .unwrap();
+
+        assert_eq!(
+            url.as_str(),
+            "https://test-shop.myshopify.com/admin/api/2026-04/orders.json?status=any"
+        );
+    }
+
+    #[test]
+    fn test_builder_sets_timeout() {
+        let request = HttpRequest::builder(HttpMethod::Get, "test")
+            .timeout(Duration::from_secs(90))
+            .build()
+            .unwrap();
+        assert_eq!(request.timeout, Some(Duration::from_secs(90)));
     }
 }
@@ -5,6 +5,9 @@
 
 use std::collections::HashMap;
 
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
 /// Information about a deprecated API endpoint or feature.
 ///
 /// When Shopify deprecates an API endpoint, they include the
@@ -80,6 +83,57 @@ impl ApiCallLimit {
     }
 }
 
+/// The cost of a GraphQL query, from the response's `extensions.cost`.
+///
+/// Shopify includes this object on GraphQL Admin API responses. It can be
+/// missing, for example when a proxy strips extensions.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryCost {
+    /// The cost Shopify estimated before running the query.
+    #[serde(default)]
+    pub requested_query_cost: Option<u64>,
+    /// The cost of the query as it ran, when it did.
+    #[serde(default)]
+    pub actual_query_cost: Option<u64>,
+    /// The state of the shop's cost bucket after the query.
+    #[serde(default)]
+    pub throttle_status: Option<ThrottleStatus>,
+}
+
+impl QueryCost {
+    /// Returns the actual cost, falling back to the requested cost for
+    /// queries that didn't run.
+    #[must_use]
+    pub fn cost(&self) -> Option<u64> {
+        self.actual_query_cost.or(self.requested_query_cost)
+    }
+}
+
+/// The state of a shop's GraphQL cost bucket.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThrottleStatus {
+    /// The bucket size.
+    pub maximum_available: f64,
+    /// The cost available to spend right now.
+    pub currently_available: f64,
+    /// The cost restored to the bucket per second.
+    pub restore_rate: f64,
+}
+
+/// Throttle information reported by a response.
+///
+/// Returned by [`HttpResponse::rate_limit_signal`], which prefers the query
+/// cost in a GraphQL body and falls back to the call limit header.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RateLimitSignal {
+    /// The query cost from a GraphQL response's `extensions.cost`.
+    QueryCost(QueryCost),
+    /// The request bucket from the `X-Shopify-Shop-Api-Call-Limit` header.
+    CallLimit(ApiCallLimit),
+}
+
 /// Pagination information parsed from the `Link` header.
 ///
 /// Shopify uses cursor-based pagination with `page_info` parameters in
@@ -179,6 +233,9 @@ pub struct HttpResponse {
     /// Rate limit information (from `X-Shopify-Shop-Api-Call-Limit` header).
     pub api_call_limit: Option<ApiCallLimit>,
     /// Seconds to wait before retrying (from `Retry-After` header).
+    ///
+    /// Both header forms are supported: a number of seconds, or an HTTP date
+    /// converted to the delay from the response's `Date` header.
     pub retry_request_after: Option<f64>,
 }
 
@@ -207,10 +264,7 @@ impl HttpResponse {
             .and_then(|value| ApiCallLimit::parse(value));
 
         // Parse Retry-After
-        let retry_request_after = headers
-            .get("retry-after")
-            .and_then(|values| values.first())
-            .and_then(|value| value.parse::<f64>().ok());
+        let retry_request_after = retry_after_from_headers(&headers);
 
         Self {
             code,
@@ -240,6 +294,16 @@ impl HttpResponse {
             .map(String::as_str)
     }
 
+    /// Returns the store's numeric ID from the `X-Sorting-Hat-ShopId` header,
+    /// if present.
+    #[must_use]
+    pub fn shop_id(&self) -> Option<u64> {
+        self.headers
+            .get("x-sorting-hat-shopid")
+            .and_then(|values| values.first())
+            .and_then(|value| value.trim().parse().ok())
+    }
+
     /// Returns the `X-Shopify-API-Deprecated-Reason` header value, if present.
     ///
     /// When present, this indicates the API endpoint is deprecated and
@@ -308,6 +372,126 @@ impl HttpResponse {
     pub fn is_deprecated(&self) -> bool {
         self.deprecation_reason().is_some()
     }
+
+    /// Returns the query cost from a GraphQL body's `extensions.cost`, if present.
+    #[must_use]
+    pub fn query_cost(&self) -> Option<QueryCost> {
+        let cost = self.body.get("extensions")?.get("cost")?;
+        QueryCost::deserialize(cost).ok()
+    }
+
+    /// Returns the cost bucket state from a GraphQL body's
+    /// `extensions.cost.throttleStatus`, if present.
+    ///
+    /// Both GraphQL APIs report it. The Admin API tracks one bucket per
+    /// app and shop; the Storefront API tracks buckets per buyer IP, so its
+    /// status describes the buyer the request was made for.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::HttpResponse;
+    /// use std::collections::HashMap;
+    /// use serde_json::json;
+    ///
+    /// let response = HttpResponse::new(200, HashMap::new(), json!({
+    ///     "data": {},
+    ///     "extensions": { "cost": { "throttleStatus": {
+    ///         "maximumAvailable": 1000.0,
+    ///         "currentlyAvailable": 990.0,
+    ///         "restoreRate": 50.0
+    ///     } } }
+    /// }));
+    ///
+    /// let status = response.throttle_status().unwrap();
+    /// assert_eq!(status.currently_available, 990.0);
+    /// ```
+    #[must_use]
+    pub fn throttle_status(&self) -> Option<ThrottleStatus> {
+        self.query_cost()?.throttle_status
+    }
+
+    /// Returns the throttle information this response carries.
+    ///
+    /// Uses the GraphQL query cost when the body reports one, and the
+    /// `X-Shopify-Shop-Api-Call-Limit` header otherwise, so callers get a
+    /// signal from GraphQL responses without `extensions.cost` as well as
+    /// from REST responses. Returns `None` if neither is present.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::clients::{ApiCallLimit, RateLimitSignal};
+    /// use shopify_sdk::HttpResponse;
+    /// use std::collections::HashMap;
+    /// use serde_json::json;
+    ///
+    /// let mut headers = HashMap::new();
+    /// headers.insert(
+    ///     "x-shopify-shop-api-call-limit".to_string(),
+    ///     vec!["32/40".to_string()],
+    /// );
+    /// let response = HttpResponse::new(200, headers, json!({ "data": {} }));
+    ///
+    /// assert_eq!(
+    ///     response.rate_limit_signal(),
+    ///     Some(RateLimitSignal::CallLimit(ApiCallLimit {
+    ///         request_count: 32,
+    ///         bucket_size: 40,
+    ///     }))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn rate_limit_signal(&self) -> Option<RateLimitSignal> {
+        self.query_cost()
+            .map(RateLimitSignal::QueryCost)
+            .or_else(|| self.api_call_limit.map(RateLimitSignal::CallLimit))
+    }
+}
+
+/// Longest `Retry-After` delay honoured, in seconds; larger values are capped
+/// so they still convert to a [`Duration`](std::time::Duration).
+const MAX_RETRY_AFTER_SECONDS: f64 = 86_400.0;
+
+/// Reads the `Retry-After` header as a delay in seconds.
+///
+/// An HTTP-date value is measured from the response's `Date` header.
+pub fn retry_after_from_headers(headers: &HashMap<String, Vec<String>>) -> Option<f64> {
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|values| values.first())
+            .map(String::as_str)
+    };
+    parse_retry_after(header("retry-after")?, header("date"), Utc::now())
+}
+
+/// Parses a `Retry-After` value into a delay in seconds.
+///
+/// The value is either a number of seconds (`"5"`) or an HTTP date
+/// (`"Wed, 21 Oct 2015 07:28:00 GMT"`). A date is measured from `date`, the
+/// response's `Date` header, falling back to `now` when that is missing or
+/// invalid. Dates in the past yield a delay of zero, and delays longer than
+/// a day are capped at a day.
+fn parse_retry_after(value: &str, date: Option<&str>, now: DateTime<Utc>) -> Option<f64> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<f64>() {
+        return (seconds.is_finite() && seconds >= 0.0)
+            .then(|| seconds.min(MAX_RETRY_AFTER_SECONDS));
+    }
+
+    let retry_at = DateTime::parse_from_rfc2822(value).ok()?;
+    let reference = date
+        .and_then(|date| DateTime::parse_from_rfc2822(date.trim()).ok())
+        .map_or(now, |date| date.with_timezone(&Utc));
+
+    #[allow(clippy::cast_precision_loss)]
+    let delay = retry_at
+        .with_timezone(&Utc)
+        .signed_duration_since(reference)
+        .num_milliseconds() as f64
+        / 1000.0;
+    Some(delay.clamp(0.0, MAX_RETRY_AFTER_SECONDS))
 }
 
 #[cfg(test)]
@@ -359,6 +543,62 @@ mod tests {
         assert!(ApiCallLimit::parse("abc/def").is_none());
     }
 
+    #[test]
+    fn test_rate_limit_signal_prefers_query_cost() {
+        let mut headers = HashMap::new();
+        headers.insert(
+            "x-shopify-shop-api-call-limit".to_string(),
+            vec!["1/40".to_string()],
+        );
+        let body = json!({
+            "data": {},
+            "extensions": {
+                "cost": {
+                    "requestedQueryCost": 12,
+                    "actualQueryCost": 4,
+                    "throttleStatus": {
+                        "maximumAvailable": 2000.0,
+                        "currentlyAvailable": 1996.0,
+                        "restoreRate": 100.0
+                    }
+                }
+            }
+        });
+        let response = HttpResponse::new(200, headers, body);
+
+        let Some(RateLimitSignal::QueryCost(cost)) = response.rate_limit_signal() else {
+            panic!("Expected a query cost signal");
+        };
+        assert_eq!(cost.cost(), Some(4));
+        assert_eq!(
+            cost.throttle_status
+                .map(|status| status.currently_available),
+            Some(1996.0)
+        );
+    }
+
+    #[test]
+    fn test_rate_limit_signal_falls_back_to_call_limit_header() {
+        let mut headers = HashMap::new();
+        headers.insert(
+            "x-shopify-shop-api-call-limit".to_string(),
+            vec!["39/40".to_string()],
+        );
+        let response = HttpResponse::new(200, headers, json!({ "data": {} }));
+
+        assert_eq!(response.query_cost(), None);
+        assert_eq!(
+            response.rate_limit_signal(),
+            Some(RateLimitSignal::CallLimit(ApiCallLimit {
+                request_count: 39,
+                bucket_size: 40,
+            }))
+        );
+
+        let response = HttpResponse::new(200, HashMap::new(), json!({ "data": {} }));
+        assert_eq!(response.rate_limit_signal(), None);
+    }
+
     #[test]
     fn test_link_header_parsing() {
         // Both prev and next
@@ -389,6 +629,80 @@ mod tests {
         assert!((response.retry_request_after.unwrap() - 2.5).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn test_retry_after_seconds() {
+        let mut headers = HashMap::new();
+        headers.insert("retry-after".to_string(), vec!["5".to_string()]);
+
+        let response = HttpResponse::new(429, headers, json!({}));
+        assert_eq!(response.retry_request_after, Some(5.0));
+    }
+
+    #[test]
+    fn test_retry_after_http_date_is_relative_to_date_header() {
+        let mut headers = HashMap::new();
+        headers.insert(
+            "retry-after".to_string(),
+            vec!["Wed, 21 Oct 2015 07:28:30 GMT".to_string()],
+        );
+        headers.insert(
+            "date".to_string(),
+            vec!["Wed, 21 Oct 2015 07:28:00 GMT".to_string()],
+        );
+
+        let response = HttpResponse::new(429, headers, json!({}));
+        assert_eq!(response.retry_request_after, Some(30.0));
+    }
+
+    #[test]
+    fn test_retry_after_http_date_without_date_header_uses_now() {
+        let now = DateTime::parse_from_rfc3339("2015-10-21T07:28:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:12 GMT", None, now),
+            Some(12.0)
+        );
+        // An unparseable Date header also falls back to now
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:12 GMT", Some("soon"), now),
+            Some(12.0)
+        );
+        // A date already in the past means retry immediately
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:27:00 GMT", None, now),
+            Some(0.0)
+        );
+    }
+
+    #[test]
+    fn test_retry_after_rejects_invalid_values() {
+        let now = Utc::now();
+
+        assert_eq!(parse_retry_after("soon", None, now), None);
+        assert_eq!(parse_retry_after("-5", None, now), None);
+        assert_eq!(parse_retry_after("NaN", None, now), None);
+    }
+
+    #[test]
+    fn test_retry_after_caps_huge_values() {
+        let now = DateTime::parse_from_rfc2822("Wed, 21 Oct 2015 07:28:00 GMT")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let capped = parse_retry_after("1e300", None, now).unwrap();
+        assert!((capped - MAX_RETRY_AFTER_SECONDS).abs() < f64::EPSILON);
+        let far_future = parse_retry_after("Fri, 31 Dec 9999 23:59:59 GMT", None, now).unwrap();
+        assert!((far_future - MAX_RETRY_AFTER_SECONDS).abs() < f64::EPSILON);
+
+        // The capped delay converts without panicking
+        assert_eq!(
+            std::time::Duration::from_secs_f64(capped),
+            std::time::Duration::from_secs(86_400)
+        );
+    }
+
     #[test]
     fn test_empty_body_returns_empty_json() {
         let response = HttpResponse::new(200, HashMap::new(), json!({}));
@@ -404,6 +718,22 @@ mod tests {
         assert_eq!(response.request_id(), Some("abc-123-xyz"));
     }
 
+    #[test]
+    fn test_shop_id_extraction() {
+        let mut headers = HashMap::new();
+        headers.insert(
+            "x-sorting-hat-shopid".to_string(),
+            vec!["548380009".to_string()],
+        );
+
+        let response = HttpResponse::new(200, headers, json!({}));
+        assert_eq!(response.shop_id(), Some(548_380_009));
+        assert_eq!(
+            HttpResponse::new(200, HashMap::new(), json!({})).shop_id(),
+            None
+        );
+    }
+
     #[test]
     fn test_deprecation_reason_extraction() {
         let mut headers = HashMap::new();
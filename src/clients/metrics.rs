@@ -0,0 +1,98 @@
+//! Request metrics hook for HTTP clients.
+//!
+//! Implement [`Metrics`] and register it with
+//! [`ShopifyConfigBuilder::metrics`](crate::ShopifyConfigBuilder::metrics) to
+//! record a measurement for every Admin API request, for example to export
+//! request counts, latencies and error rates per endpoint. Without one,
+//! [`NoopMetrics`] is used and nothing is recorded.
+//!
+//! # Example
+//!
+//! An implementation over the [`metrics`](https://docs.rs/metrics) crate:
+//!
+//! ```rust,ignore
+//! use std::time::Duration;
+//! use shopify_sdk::clients::{HttpMethod, Metrics};
+//!
+//! struct ShopifyMetrics;
+//!
+//! impl Metrics for ShopifyMetrics {
+//!     fn record_request(
+//!         &self,
+//!         path: &str,
+//!         method: HttpMethod,
+//!         status: Option<u16>,
+//!         duration: Duration,
+//!         retries: u32,
+//!     ) {
+//!         let labels = [
+//!             ("path", path.to_string()),
+//!             ("method", method.to_string()),
+//!             ("status", status.map_or_else(|| "error".to_string(), |s| s.to_string())),
+//!         ];
+//!         metrics::counter!("shopify_requests_total", &labels).increment(1);
+//!         metrics::counter!("shopify_request_retries_total", &labels).increment(u64::from(retries));
+//!         metrics::histogram!("shopify_request_duration_seconds", &labels).record(duration);
+//!     }
+//! }
+//!
+//! let config = ShopifyConfig::builder()
+//!     .api_key(api_key)
+//!     .api_secret_key(api_secret_key)
+//!     .metrics(ShopifyMetrics)
+//!     .build()?;
+//! ```
+//!
+//! An OpenTelemetry implementation records the same values on a `Counter`
+//! and a `Histogram` built from the app's `Meter`.
+//!
+//! Paths include resource IDs (e.g. `products/123.json`); normalize them
+//! before using them as labels to keep cardinality bounded.
+
+use std::time::Duration;
+
+use crate::clients::HttpMethod;
+
+/// Receives a measurement for each request sent by an
+/// [`HttpClient`](crate::clients::HttpClient).
+///
+/// Implementations are called inline after every request, so they should
+/// be cheap and must not block.
+pub trait Metrics: Send + Sync {
+    /// Records a completed request.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The request path, relative to the API base path (e.g. `products.json`)
+    /// * `method` - The HTTP method
+    /// * `status` - The status of the final response, or `None` if no
+    ///   response was received (for example, a network error)
+    /// * `duration` - Time spent on the request, including retries and waits
+    /// * `retries` - Number of retries after the first attempt
+    fn record_request(
+        &self,
+        path: &str,
+        method: HttpMethod,
+        status: Option<u16>,
+        duration: Duration,
+        retries: u32,
+    );
+}
+
+/// A [`Metrics`] implementation that records nothing.
+///
+/// Used when no metrics hook is configured.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NoopMetrics;
+
+impl Metrics for NoopMetrics {
+    fn record_request(
+        &self,
+        _path: &str,
+        _method: HttpMethod,
+        _status: Option<u16>,
+        _duration: Duration,
+        _retries: u32,
+    ) {
+    }
+}
@@ -9,10 +9,14 @@
 //! The main types in this module are:
 //!
 //! - [`HttpClient`]: The async HTTP client for API communication
+//! - [`ClientFactory`]: Per-shop client cache for multi-tenant apps
 //! - [`HttpRequest`]: A request to be sent to the API
 //! - [`HttpResponse`]: A parsed response from the API
 //! - [`HttpMethod`]: Supported HTTP methods (GET, POST, PUT, DELETE)
 //! - [`DataType`]: Content types for request bodies
+//! - [`Page`]: A page of results shared by REST and GraphQL pagination
+//! - [`Metrics`]: Hook for recording request counts, latencies and retries
+//! - [`DeprecationRegistry`]: Collects deprecation notices encountered during a run
 //! - [`rest::RestClient`]: Higher-level REST API client
 //! - [`rest::RestError`]: REST-specific error types
 //! - [`graphql::GraphqlClient`]: Higher-level GraphQL API client (Admin API)
@@ -52,25 +56,52 @@
 //!
 //! - **429 (Rate Limited)**: Retries using `Retry-After` header value, or 1 second if not present
 //! - **500 (Server Error)**: Retries with fixed 1-second delay
+//! - **503 (Maintenance)**: Retries using `Retry-After` header value, or 1 second if not present
 //! - **Other errors (4xx)**: Returns immediately without retry
 //!
+//! `Retry-After` may be given in seconds or as an HTTP date, which is
+//! converted to a delay relative to the response's `Date` header.
+//!
 //! The default `tries` is 1, meaning no automatic retries. Configure via
 //! [`HttpRequest::builder`] with `.tries(n)` to enable retries.
+//!
+//! # Timeouts
+//!
+//! No timeouts are applied by default. Set
+//! [`ShopifyConfigBuilder::connect_timeout`](crate::ShopifyConfigBuilder::connect_timeout)
+//! to bound connection setup (failing with [`HttpError::ConnectTimeout`]) and
+//! [`ShopifyConfigBuilder::request_timeout`](crate::ShopifyConfigBuilder::request_timeout)
+//! to bound each attempt. Use [`reqwest_client_builder`] to build reqwest
+//! clients of your own with the same settings.
 
+mod deprecation;
 mod errors;
+mod factory;
 pub mod graphql;
 mod http_client;
 mod http_request;
 mod http_response;
+mod metrics;
+mod page;
+mod rate_limit;
 pub mod rest;
 pub mod storefront;
 
+pub use deprecation::{DeprecationNotice, DeprecationRegistry, DeprecationSource};
 pub use errors::{
-    HttpError, HttpResponseError, InvalidHttpRequestError, MaxHttpRetriesExceededError,
+    ErrorKind, HttpError, HttpResponseError, InvalidHttpRequestError, MaxHttpRetriesExceededError,
+};
+pub use factory::{ClientFactory, DEFAULT_CLIENT_CACHE_CAPACITY};
+pub(crate) use http_client::build_reqwest_client;
+pub use http_client::{reqwest_client_builder, HttpClient, UrlRewriter, SDK_VERSION};
+pub use http_request::{DataType, HttpMethod, HttpRequest, HttpRequestBuilder, RedirectPolicy};
+pub use http_response::{
+    ApiCallLimit, ApiDeprecationInfo, HttpResponse, PaginationInfo, QueryCost, RateLimitSignal,
+    ThrottleStatus,
 };
-pub use http_client::{HttpClient, SDK_VERSION};
-pub use http_request::{DataType, HttpMethod, HttpRequest, HttpRequestBuilder};
-pub use http_response::{ApiCallLimit, ApiDeprecationInfo, HttpResponse, PaginationInfo};
+pub use metrics::{Metrics, NoopMetrics};
+pub use page::Page;
+pub use rate_limit::RateLimiter;
 
 // Re-export REST client types at the clients module level
 pub use rest::{RestClient, RestError};
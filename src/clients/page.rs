@@ -0,0 +1,114 @@
+//! Transport-agnostic page of results.
+//!
+//! REST list endpoints paginate with `page_info` tokens from the `Link`
+//! header, while GraphQL connections use `pageInfo` cursors. [`Page`] gives
+//! both the same shape so pagination logic can be shared:
+//!
+//! - [`ResourceResponse::into_page`](crate::rest::ResourceResponse::into_page)
+//!   converts a REST list response
+//! - [`Connection::into_page`](crate::clients::graphql::Connection::into_page)
+//!   converts a GraphQL connection
+//!
+//! # Example
+//!
+//! ```rust
+//! use shopify_sdk::clients::Page;
+//!
+//! fn process<T>(page: Page<T>) -> Option<String> {
+//!     println!("Got {} items", page.items.len());
+//!     page.next_cursor
+//! }
+//! ```
+
+/// A page of results with the cursor needed to fetch the next page.
+///
+/// For REST responses the cursor is the `page_info` token; for GraphQL
+/// connections it is the `endCursor`. Pass it back through the same
+/// transport it came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page<T> {
+    /// The items on this page.
+    pub items: Vec<T>,
+    /// The cursor for the next page, if there is one.
+    pub next_cursor: Option<String>,
+    /// Whether another page follows this one.
+    pub has_next: bool,
+}
+
+impl<T> Page<T> {
+    /// Maps the items to a new type, keeping the pagination state.
+    #[must_use]
+    pub fn map<U, F>(self, f: F) -> Page<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            next_cursor: self.next_cursor,
+            has_next: self.has_next,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::graphql::Connection;
+    use crate::clients::PaginationInfo;
+    use crate::rest::ResourceResponse;
+    use serde_json::json;
+
+    #[test]
+    fn test_rest_and_graphql_convert_to_same_page_shape() {
+        let rest = ResourceResponse::new(
+            vec!["a".to_string(), "b".to_string()],
+            Some(PaginationInfo {
+                prev_page_info: None,
+                next_page_info: Some("next".to_string()),
+            }),
+            None,
+            None,
+        )
+        .into_page();
+
+        let connection: Connection<String> = serde_json::from_value(json!({
+            "nodes": ["a", "b"],
+            "pageInfo": { "hasNextPage": true, "endCursor": "next" }
+        }))
+        .unwrap();
+        let graphql = connection.into_page();
+
+        assert_eq!(rest, graphql);
+        assert_eq!(
+            rest,
+            Page {
+                items: vec!["a".to_string(), "b".to_string()],
+                next_cursor: Some("next".to_string()),
+                has_next: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_rest_last_page_has_no_next() {
+        let page = ResourceResponse::new(vec![1], None, None, None).into_page();
+
+        assert_eq!(page.next_cursor, None);
+        assert!(!page.has_next);
+    }
+
+    #[test]
+    fn test_page_map_keeps_cursor() {
+        let page = Page {
+            items: vec![1, 2],
+            next_cursor: Some("abc".to_string()),
+            has_next: true,
+        };
+
+        let mapped = page.map(|n| n * 10);
+
+        assert_eq!(mapped.items, vec![10, 20]);
+        assert_eq!(mapped.next_cursor.as_deref(), Some("abc"));
+        assert!(mapped.has_next);
+    }
+}
@@ -0,0 +1,190 @@
+//! Client-side pacing for the REST Admin API call limit.
+//!
+//! Shopify limits REST requests with a leaky bucket: each request adds one
+//! to the bucket, the bucket drains at a fixed rate, and requests made while
+//! it is full are rejected with a 429. The current level is reported on every
+//! response in the `X-Shopify-Shop-Api-Call-Limit` header.
+//!
+//! A [`RateLimiter`] mirrors that bucket locally. Every
+//! [`RestClient`](crate::clients::RestClient) holds one and updates it from
+//! the call limit header of each response; batch operations such as
+//! [`RestResource::create_all`](crate::rest::RestResource::create_all) call
+//! [`RateLimiter::acquire`] before each request so they wait for room
+//! instead of being throttled.
+//!
+//! The bucket belongs to the store, not to a client. Clients built by
+//! [`ClientFactory`](crate::clients::ClientFactory) share their shop's
+//! limiter; a client built with `RestClient::new` starts with a bucket of
+//! its own unless one is passed to `RestClient::with_rate_limiter`.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use shopify_sdk::clients::RateLimiter;
+//!
+//! // Shopify Plus stores have a 400 request bucket that drains 20 per second
+//! let limiter = RateLimiter::new(400, 20.0);
+//!
+//! // Share the bucket between clients for the same store
+//! let products = RestClient::new(&session, None)?.with_rate_limiter(limiter.clone());
+//! let orders = RestClient::new(&session, None)?.with_rate_limiter(limiter);
+//! ```
+
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use crate::clients::ApiCallLimit;
+
+/// Bucket size of the standard REST Admin API call limit.
+const DEFAULT_BUCKET_SIZE: u32 = 40;
+
+/// Requests drained per second from the standard REST Admin API bucket.
+const DEFAULT_LEAK_RATE: f64 = 2.0;
+
+/// A local model of Shopify's leaky bucket call limit.
+///
+/// Cloning a `RateLimiter` shares the same bucket, so clones can be handed
+/// to several clients talking to the same store.
+#[derive(Clone, Debug)]
+pub struct RateLimiter {
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+/// The state of the bucket.
+#[derive(Debug)]
+struct Bucket {
+    /// Maximum number of requests the bucket holds.
+    capacity: f64,
+    /// Requests drained per second.
+    leak_rate: f64,
+    /// Requests currently in the bucket.
+    level: f64,
+    /// When `level` was last brought up to date.
+    updated_at: Instant,
+}
+
+impl Bucket {
+    /// Drains the requests that leaked out since the last update.
+    fn leak(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.level = elapsed.mul_add(-self.leak_rate, self.level).max(0.0);
+        self.updated_at = now;
+    }
+}
+
+impl RateLimiter {
+    /// Creates a limiter for a bucket of `bucket_size` requests that drains
+    /// `leak_rate` requests per second.
+    ///
+    /// A bucket size of zero is treated as one, and the leak rate is clamped
+    /// to a small positive value so waits always end.
+    #[must_use]
+    pub fn new(bucket_size: u32, leak_rate: f64) -> Self {
+        Self {
+            bucket: Arc::new(Mutex::new(Bucket {
+                capacity: f64::from(bucket_size.max(1)),
+                leak_rate: leak_rate.max(0.001),
+                level: 0.0,
+                updated_at: Instant::now(),
+            })),
+        }
+    }
+
+    /// Waits until the bucket has room for one request, then claims it.
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.lock();
+                bucket.leak(Instant::now());
+                if bucket.level + 1.0 <= bucket.capacity {
+                    bucket.level += 1.0;
+                    return;
+                }
+                (bucket.level + 1.0 - bucket.capacity) / bucket.leak_rate
+            };
+            tokio::time::sleep(Duration::from_secs_f64(wait)).await;
+        }
+    }
+
+    /// Updates the bucket from a response's call limit header.
+    ///
+    /// The bucket size is taken from the header. The level only ever rises,
+    /// since responses to earlier requests can report a count that doesn't
+    /// yet include requests still in flight.
+    pub fn observe(&self, limit: ApiCallLimit) {
+        let mut bucket = self.lock();
+        bucket.leak(Instant::now());
+        bucket.capacity = f64::from(limit.bucket_size.max(1));
+        bucket.level = bucket.level.max(f64::from(limit.request_count));
+    }
+
+    /// Returns `true` if both limiters share the same bucket.
+    #[cfg(test)]
+    #[must_use]
+    pub fn shares_bucket_with(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.bucket, &other.bucket)
+    }
+
+    /// Locks the bucket, recovering it if a previous holder panicked.
+    fn lock(&self) -> MutexGuard<'_, Bucket> {
+        self.bucket.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for RateLimiter {
+    /// Creates a limiter for the standard 40 request bucket draining 2 per second.
+    fn default() -> Self {
+        Self::new(DEFAULT_BUCKET_SIZE, DEFAULT_LEAK_RATE)
+    }
+}
+
+// Verify RateLimiter is Send + Sync at compile time
+const _: fn() = || {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<RateLimiter>();
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_acquire_is_immediate_while_bucket_has_room() {
+        let limiter = RateLimiter::new(3, 1.0);
+        let started = Instant::now();
+
+        for _ in 0..3 {
+            limiter.acquire().await;
+        }
+
+        assert!(started.elapsed() < Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn test_acquire_waits_for_bucket_to_drain() {
+        let limiter = RateLimiter::new(2, 20.0);
+        let started = Instant::now();
+
+        for _ in 0..4 {
+            limiter.acquire().await;
+        }
+
+        // Two requests fit, the other two wait 50ms each for a slot to drain
+        assert!(started.elapsed() >= Duration::from_millis(90));
+    }
+
+    #[tokio::test]
+    async fn test_observe_adopts_bucket_size_and_reported_level() {
+        let limiter = RateLimiter::new(40, 20.0);
+        limiter.observe(ApiCallLimit {
+            request_count: 2,
+            bucket_size: 2,
+        });
+
+        let started = Instant::now();
+        limiter.acquire().await;
+
+        // The reported full bucket has to drain before the next request
+        assert!(started.elapsed() >= Duration::from_millis(40));
+    }
+}
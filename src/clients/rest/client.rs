@@ -4,10 +4,16 @@
 //! to the Shopify Admin API with automatic path normalization and retry handling.
 
 use std::collections::HashMap;
+use std::sync::OnceLock;
+use std::time::Duration;
 
-use crate::auth::Session;
+use crate::auth::{ScopeDrift, Session};
+use crate::clients::http_client::{build_reqwest_client, check_scope_drift};
 use crate::clients::rest::RestError;
-use crate::clients::{DataType, HttpClient, HttpMethod, HttpRequest, HttpResponse};
+use crate::clients::{
+    DataType, DeprecationNotice, DeprecationRegistry, DeprecationSource, HttpClient, HttpMethod,
+    HttpRequest, HttpResponse, RateLimiter, RedirectPolicy,
+};
 use crate::config::{ApiVersion, ShopifyConfig};
 
 /// REST API client for Shopify Admin API.
@@ -53,6 +59,14 @@ pub struct RestClient {
     http_client: HttpClient,
     /// The API version being used.
     api_version: ApiVersion,
+    /// The `limit` applied to list requests that don't set one.
+    default_page_size: Option<u32>,
+    /// Local model of the store's call limit bucket.
+    rate_limiter: RateLimiter,
+    /// The store's numeric ID, once resolved.
+    shop_id: OnceLock<u64>,
+    /// How the session's scopes differ from the configured scopes, if checked.
+    scope_drift: Option<ScopeDrift>,
 }
 
 // Verify RestClient is Send + Sync at compile time
@@ -96,7 +110,7 @@ impl RestClient {
     pub fn new(session: &Session, config: Option<&ShopifyConfig>) -> Result<Self, RestError> {
         let api_version = config.map_or_else(ApiVersion::latest, |c| c.api_version().clone());
 
-        Self::create_client(session, config, api_version)
+        Self::create_client(session, config, api_version, build_reqwest_client(config))
     }
 
     /// Creates a new REST client with a specific API version override.
@@ -153,7 +167,19 @@ impl RestClient {
             }
         }
 
-        Self::create_client(session, config, version)
+        Self::create_client(session, config, version, build_reqwest_client(config))
+    }
+
+    /// Creates a REST client for the configured API version that shares an
+    /// existing reqwest client, as used by
+    /// [`ClientFactory`](crate::clients::ClientFactory).
+    pub(crate) fn with_reqwest_client(
+        session: &Session,
+        config: Option<&ShopifyConfig>,
+        reqwest_client: reqwest::Client,
+    ) -> Result<Self, RestError> {
+        let api_version = config.map_or_else(ApiVersion::latest, |c| c.api_version().clone());
+        Self::create_client(session, config, api_version, reqwest_client)
     }
 
     /// Internal helper to create the client with shared logic.
@@ -165,30 +191,159 @@ impl RestClient {
         session: &Session,
         config: Option<&ShopifyConfig>,
         api_version: ApiVersion,
+        reqwest_client: reqwest::Client,
     ) -> Result<Self, RestError> {
         // Log deprecation warning (matching Ruby SDK pattern)
         tracing::warn!(
             "The REST Admin API is deprecated. Consider migrating to GraphQL. See: https://www.shopify.com/ca/partners/blog/all-in-on-graphql"
         );
+        if let Some(registry) = config.and_then(ShopifyConfig::deprecation_registry) {
+            registry.record(DeprecationNotice {
+                source: DeprecationSource::RestApi,
+                reason: "The REST Admin API is deprecated. Consider migrating to GraphQL."
+                    .to_string(),
+                path: None,
+            });
+        }
 
         // Construct base path: /admin/api/{version}
         let base_path = format!("/admin/api/{api_version}");
 
         // Create internal HTTP client
-        let http_client = HttpClient::new(base_path, session, config);
+        let http_client =
+            HttpClient::with_reqwest_client(base_path, session, config, reqwest_client);
 
         Ok(Self {
             http_client,
             api_version,
+            default_page_size: config.and_then(ShopifyConfig::default_page_size),
+            rate_limiter: RateLimiter::default(),
+            shop_id: OnceLock::new(),
+            scope_drift: check_scope_drift(session, config),
+        })
+    }
+
+    /// Replaces the client's rate limiter, e.g. with one shared by other
+    /// clients for the same store.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use shopify_sdk::clients::RateLimiter;
+    ///
+    /// let limiter = RateLimiter::default();
+    /// let client = RestClient::new(&session, None)?.with_rate_limiter(limiter.clone());
+    /// ```
+    #[must_use]
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// Rewrites the URL of every request this client sends.
+    ///
+    /// See [`HttpClient::with_url_rewriter`](crate::clients::HttpClient::with_url_rewriter).
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let client = RestClient::new(&session, None)?.with_url_rewriter(|url| {
+    ///     url.query_pairs_mut().append_pair("gateway_key", "abc123");
+    /// });
+    /// ```
+    #[must_use]
+    pub fn with_url_rewriter<F>(mut self, rewriter: F) -> Self
+    where
+        F: Fn(&mut reqwest::Url) + Send + Sync + 'static,
+    {
+        self.http_client = self.http_client.with_url_rewriter(rewriter);
+        self
+    }
+
+    /// Creates a client for `test-shop` whose requests go to a local mock
+    /// server at `server_uri` instead.
+    #[cfg(test)]
+    #[must_use]
+    pub fn for_mock_server(server_uri: &str, api_version: ApiVersion) -> Self {
+        let session = Session::new(
+            "test-session".to_string(),
+            crate::config::ShopDomain::new("test-shop").unwrap(),
+            "test-access-token".to_string(),
+            crate::auth::AuthScopes::new(),
+            false,
+            None,
+        );
+        Self::with_version(&session, None, api_version)
+            .unwrap()
+            .with_mock_server(server_uri)
+    }
+
+    /// Sends this client's requests to the mock server at `server_uri`.
+    #[cfg(test)]
+    #[must_use]
+    pub fn with_mock_server(self, server_uri: &str) -> Self {
+        let server_url = reqwest::Url::parse(server_uri).unwrap();
+        self.with_url_rewriter(move |url| {
+            url.set_scheme("http").unwrap();
+            url.set_host(server_url.host_str()).unwrap();
+            url.set_port(server_url.port()).unwrap();
         })
     }
 
+    /// Returns the registry deprecation notices are recorded into, if the
+    /// config set one.
+    #[must_use]
+    pub const fn deprecation_registry(&self) -> Option<&DeprecationRegistry> {
+        self.http_client.deprecation_registry()
+    }
+
+    /// Returns the client's rate limiter.
+    ///
+    /// It is updated from the call limit header of every response. Batch
+    /// operations acquire from it before each request. Clients from a
+    /// [`ClientFactory`](crate::clients::ClientFactory) return their shop's
+    /// shared limiter.
+    #[must_use]
+    pub const fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
     /// Returns the API version being used by this client.
     #[must_use]
     pub const fn api_version(&self) -> &ApiVersion {
         &self.api_version
     }
 
+    /// Returns how the session's scopes differ from the configured scopes.
+    ///
+    /// Only checked when [`ShopifyConfigBuilder::check_scope_drift`](crate::ShopifyConfigBuilder::check_scope_drift)
+    /// is enabled; `None` otherwise or if the scopes match.
+    #[must_use]
+    pub const fn scope_drift(&self) -> Option<&ScopeDrift> {
+        self.scope_drift.as_ref()
+    }
+
+    /// Returns the store's numeric ID, if it has been resolved with
+    /// [`resolve_shop_id`](crate::rest::resources::v2026_04::resolve_shop_id)
+    /// or seen in a response's `X-Sorting-Hat-ShopId` header.
+    #[must_use]
+    pub fn shop_id(&self) -> Option<u64> {
+        self.shop_id.get().copied()
+    }
+
+    /// Remembers the store's numeric ID for [`shop_id`](Self::shop_id).
+    pub(crate) fn set_shop_id(&self, id: u64) {
+        let _ = self.shop_id.set(id);
+    }
+
+    /// Returns the page size applied to list requests that don't set `limit`.
+    ///
+    /// See [`ShopifyConfigBuilder::default_page_size`](crate::ShopifyConfigBuilder::default_page_size).
+    #[must_use]
+    pub const fn default_page_size(&self) -> Option<u32> {
+        self.default_page_size
+    }
+
     /// Sends a GET request to the specified path.
     ///
     /// # Arguments
@@ -217,7 +372,7 @@ impl RestClient {
         path: &str,
         query: Option<HashMap<String, String>>,
     ) -> Result<HttpResponse, RestError> {
-        self.make_request(HttpMethod::Get, path, None, query, None)
+        self.make_request(HttpMethod::Get, path, None, query, None, None)
             .await
     }
 
@@ -239,10 +394,48 @@ impl RestClient {
         query: Option<HashMap<String, String>>,
         tries: u32,
     ) -> Result<HttpResponse, RestError> {
-        self.make_request(HttpMethod::Get, path, None, query, Some(tries))
+        self.make_request(HttpMethod::Get, path, None, query, Some(tries), None)
             .await
     }
 
+    /// Sends a GET request, following up to `max_redirects` redirects.
+    ///
+    /// Admin API requests don't follow redirects; a `3xx` response from
+    /// [`get`](Self::get) is returned as an error. Use this method for the
+    /// few endpoints documented to answer with a redirect, such as discount
+    /// code lookup.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RestError::InvalidPath`] if the path is invalid.
+    /// Returns [`RestError::Http`] for HTTP-level errors, including a
+    /// redirect beyond `max_redirects`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut query = HashMap::new();
+    /// query.insert("code".to_string(), "SUMMER20".to_string());
+    /// let response = client
+    ///     .get_following_redirects("discount_codes/lookup", Some(query), 3)
+    ///     .await?;
+    /// ```
+    pub async fn get_following_redirects(
+        &self,
+        path: &str,
+        query: Option<HashMap<String, String>>,
+        max_redirects: u32,
+    ) -> Result<HttpResponse, RestError> {
+        let normalized_path = normalize_path(path)?;
+        let mut builder = HttpRequest::builder(HttpMethod::Get, &normalized_path)
+            .redirect_policy(RedirectPolicy::Follow(max_redirects));
+        if let Some(query_params) = query {
+            builder = builder.query(query_params);
+        }
+        let request = builder.build().map_err(|e| RestError::Http(e.into()))?;
+        self.send(request).await
+    }
+
     /// Sends a POST request to the specified path.
     ///
     /// # Arguments
@@ -273,7 +466,7 @@ impl RestClient {
         body: serde_json::Value,
         query: Option<HashMap<String, String>>,
     ) -> Result<HttpResponse, RestError> {
-        self.make_request(HttpMethod::Post, path, Some(body), query, None)
+        self.make_request(HttpMethod::Post, path, Some(body), query, None, None)
             .await
     }
 
@@ -290,7 +483,7 @@ impl RestClient {
         query: Option<HashMap<String, String>>,
         tries: u32,
     ) -> Result<HttpResponse, RestError> {
-        self.make_request(HttpMethod::Post, path, Some(body), query, Some(tries))
+        self.make_request(HttpMethod::Post, path, Some(body), query, Some(tries), None)
             .await
     }
 
@@ -323,7 +516,7 @@ impl RestClient {
         body: serde_json::Value,
         query: Option<HashMap<String, String>>,
     ) -> Result<HttpResponse, RestError> {
-        self.make_request(HttpMethod::Put, path, Some(body), query, None)
+        self.make_request(HttpMethod::Put, path, Some(body), query, None, None)
             .await
     }
 
@@ -340,10 +533,37 @@ impl RestClient {
         query: Option<HashMap<String, String>>,
         tries: u32,
     ) -> Result<HttpResponse, RestError> {
-        self.make_request(HttpMethod::Put, path, Some(body), query, Some(tries))
+        self.make_request(HttpMethod::Put, path, Some(body), query, Some(tries), None)
             .await
     }
 
+    /// Sends a PUT request with a timeout for each attempt.
+    ///
+    /// Use this for requests with large bodies, such as binary theme assets,
+    /// that may take longer than the client's default timeout.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RestError::InvalidPath`] if the path is invalid.
+    /// Returns [`RestError::Http`] for HTTP-level errors, including timeouts.
+    pub async fn put_with_timeout(
+        &self,
+        path: &str,
+        body: serde_json::Value,
+        query: Option<HashMap<String, String>>,
+        timeout: Duration,
+    ) -> Result<HttpResponse, RestError> {
+        self.make_request(
+            HttpMethod::Put,
+            path,
+            Some(body),
+            query,
+            None,
+            Some(timeout),
+        )
+        .await
+    }
+
     /// Sends a DELETE request to the specified path.
     ///
     /// # Arguments
@@ -366,7 +586,7 @@ impl RestClient {
         path: &str,
         query: Option<HashMap<String, String>>,
     ) -> Result<HttpResponse, RestError> {
-        self.make_request(HttpMethod::Delete, path, None, query, None)
+        self.make_request(HttpMethod::Delete, path, None, query, None, None)
             .await
     }
 
@@ -382,7 +602,7 @@ impl RestClient {
         query: Option<HashMap<String, String>>,
         tries: u32,
     ) -> Result<HttpResponse, RestError> {
-        self.make_request(HttpMethod::Delete, path, None, query, Some(tries))
+        self.make_request(HttpMethod::Delete, path, None, query, Some(tries), None)
             .await
     }
 
@@ -394,6 +614,7 @@ impl RestClient {
         body: Option<serde_json::Value>,
         query: Option<HashMap<String, String>>,
         tries: Option<u32>,
+        timeout: Option<Duration>,
     ) -> Result<HttpResponse, RestError> {
         // Normalize the path
         let normalized_path = normalize_path(path)?;
@@ -416,10 +637,27 @@ impl RestClient {
             builder = builder.tries(t);
         }
 
+        // Set per-request timeout if provided
+        if let Some(t) = timeout {
+            builder = builder.timeout(t);
+        }
+
         // Build and send the request
         let request = builder.build().map_err(|e| RestError::Http(e.into()))?;
+        self.send(request).await
+    }
 
-        self.http_client.request(request).await.map_err(Into::into)
+    /// Sends a built request, feeding its call limit to the rate limiter and
+    /// remembering the shop ID it reports.
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, RestError> {
+        let response = self.http_client.request(request).await?;
+        if let Some(id) = response.shop_id() {
+            self.set_shop_id(id);
+        }
+        if let Some(limit) = response.api_call_limit {
+            self.rate_limiter.observe(limit);
+        }
+        Ok(response)
     }
 }
 
@@ -587,4 +825,324 @@ mod tests {
         // We can verify this indirectly through the api_version
         assert_eq!(client.api_version(), &ApiVersion::V2024_10);
     }
+
+    #[test]
+    fn test_rest_client_uses_config_default_page_size() {
+        use crate::config::{ApiKey, ApiSecretKey};
+
+        let session = create_test_session();
+        let config = ShopifyConfig::builder()
+            .api_key(ApiKey::new("test-key").unwrap())
+            .api_secret_key(ApiSecretKey::new("test-secret").unwrap())
+            .default_page_size(250)
+            .build()
+            .unwrap();
+
+        let client = RestClient::new(&session, Some(&config)).unwrap();
+        assert_eq!(client.default_page_size(), Some(250));
+
+        let client = RestClient::new(&session, None).unwrap();
+        assert_eq!(client.default_page_size(), None);
+    }
+
+    #[test]
+    fn test_rest_client_reports_scope_drift_when_enabled() {
+        use crate::config::{ApiKey, ApiSecretKey};
+
+        let mut session = create_test_session();
+        session.scopes = "read_products".parse().unwrap();
+        let config = ShopifyConfig::builder()
+            .api_key(ApiKey::new("test-key").unwrap())
+            .api_secret_key(ApiSecretKey::new("test-secret").unwrap())
+            .scopes("read_products,write_orders".parse().unwrap())
+            .check_scope_drift(true)
+            .build()
+            .unwrap();
+
+        let client = RestClient::new(&session, Some(&config)).unwrap();
+        let drift = client.scope_drift().unwrap();
+        assert_eq!(drift.missing, vec!["read_orders", "write_orders"]);
+        assert!(drift.requires_reauth());
+    }
+
+    #[tokio::test]
+    async fn test_admin_requests_do_not_follow_redirects_but_lookup_does() {
+        use crate::rest::resources::v2026_04::DiscountCodeResource;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/admin/api/2026-04/discount_codes/lookup.json"))
+            .and(query_param("code", "SUMMER20"))
+            .respond_with(ResponseTemplate::new(303).insert_header(
+                "Location",
+                "/admin/api/2026-04/price_rules/507328175/discount_codes/1002.json",
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(
+                "/admin/api/2026-04/price_rules/507328175/discount_codes/1002.json",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "discount_code": {
+                    "id": 1002,
+                    "price_rule_id": 507_328_175,
+                    "code": "SUMMER20"
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let server_url = reqwest::Url::parse(&server.uri()).unwrap();
+        let mut client =
+            RestClient::with_version(&create_test_session(), None, ApiVersion::V2026_04).unwrap();
+        client.http_client = client.http_client.with_url_rewriter(move |url| {
+            url.set_scheme("http").unwrap();
+            url.set_host(server_url.host_str()).unwrap();
+            url.set_port(server_url.port()).unwrap();
+        });
+        let query = HashMap::from([("code".to_string(), "SUMMER20".to_string())]);
+
+        let error = client
+            .get("discount_codes/lookup", Some(query.clone()))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            RestError::Http(crate::clients::HttpError::Response(ref e)) if e.code == 303
+        ));
+
+        let response = client
+            .get_following_redirects("discount_codes/lookup", Some(query), 1)
+            .await
+            .unwrap();
+        assert_eq!(response.code, 200);
+
+        let found = DiscountCodeResource::lookup(&client, "SUMMER20")
+            .await
+            .unwrap();
+        assert_eq!(found.id, Some(1002));
+        assert_eq!(found.price_rule_id, Some(507_328_175));
+    }
+
+    #[tokio::test]
+    async fn test_all_by_ids_splits_ids_across_requests() {
+        use crate::rest::resources::v2026_04::Product;
+        use crate::rest::{IdChunkOptions, RestResource};
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/admin/api/2026-04/products.json"))
+            .respond_with(|request: &Request| {
+                let ids = request
+                    .url
+                    .query_pairs()
+                    .find(|(key, _)| key == "ids")
+                    .map(|(_, ids)| ids.into_owned())
+                    .unwrap_or_default();
+                let products: Vec<serde_json::Value> = ids
+                    .split(',')
+                    .map(|id| serde_json::json!({ "id": id.parse::<u64>().unwrap() }))
+                    .collect();
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "products": products }))
+            })
+            .mount(&server)
+            .await;
+
+        let server_url = reqwest::Url::parse(&server.uri()).unwrap();
+        let mut client =
+            RestClient::with_version(&create_test_session(), None, ApiVersion::V2026_04).unwrap();
+        client.http_client = client.http_client.with_url_rewriter(move |url| {
+            url.set_scheme("http").unwrap();
+            url.set_host(server_url.host_str()).unwrap();
+            url.set_port(server_url.port()).unwrap();
+        });
+
+        let ids: Vec<u64> = (1..=500).collect();
+        let options = IdChunkOptions {
+            chunk_size: 50,
+            concurrency: 4,
+        };
+        let products = Product::all_by_ids(&client, &ids, None, options)
+            .await
+            .unwrap();
+
+        let found: Vec<u64> = products.iter().filter_map(|p| p.id).collect();
+        assert_eq!(found, ids);
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 10);
+        assert!(requests.iter().all(|request| request
+            .url
+            .query_pairs()
+            .any(|(k, v)| k == "limit" && v == "50")));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_shop_id_caches_the_id_on_the_client() {
+        use crate::rest::resources::v2026_04::resolve_shop_id;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/admin/api/2026-04/shop.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "shop": { "id": 548_380_009, "name": "John Smith Test Store" }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let server_url = reqwest::Url::parse(&server.uri()).unwrap();
+        let mut client =
+            RestClient::with_version(&create_test_session(), None, ApiVersion::V2026_04).unwrap();
+        client.http_client = client.http_client.with_url_rewriter(move |url| {
+            url.set_scheme("http").unwrap();
+            url.set_host(server_url.host_str()).unwrap();
+            url.set_port(server_url.port()).unwrap();
+        });
+
+        assert_eq!(client.shop_id(), None);
+        assert_eq!(resolve_shop_id(&client).await.unwrap(), 548_380_009);
+        assert_eq!(resolve_shop_id(&client).await.unwrap(), 548_380_009);
+        assert_eq!(client.shop_id(), Some(548_380_009));
+    }
+
+    #[tokio::test]
+    async fn test_export_jsonl_follows_link_header_across_pages() {
+        use crate::rest::resources::v2026_04::{Product, ProductListParams};
+        use crate::rest::RestResource;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        // Mounted first so it takes precedence for the second page
+        Mock::given(method("GET"))
+            .and(path("/admin/api/2026-04/products.json"))
+            .and(query_param("page_info", "page-2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "products": [{ "id": 3, "title": "Third" }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/admin/api/2026-04/products.json"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(
+                        "Link",
+                        r#"<https://test-shop.myshopify.com/admin/api/2026-04/products.json?limit=2&page_info=page-2>; rel="next""#,
+                    )
+                    .set_body_json(serde_json::json!({
+                        "products": [
+                            { "id": 1, "title": "First" },
+                            { "id": 2, "title": "Second" }
+                        ]
+                    })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let server_url = reqwest::Url::parse(&server.uri()).unwrap();
+        let mut client =
+            RestClient::with_version(&create_test_session(), None, ApiVersion::V2026_04).unwrap();
+        client.http_client = client.http_client.with_url_rewriter(move |url| {
+            url.set_scheme("http").unwrap();
+            url.set_host(server_url.host_str()).unwrap();
+            url.set_port(server_url.port()).unwrap();
+        });
+
+        let params = ProductListParams {
+            limit: Some(2),
+            vendor: Some("Acme".to_string()),
+            ..Default::default()
+        };
+        let mut output = Vec::new();
+        let summary = Product::export_jsonl(&client, Some(params), &mut output)
+            .await
+            .unwrap();
+
+        assert!(summary.is_complete());
+        assert_eq!(summary.pages, 2);
+        assert_eq!(summary.records, 3);
+        let titles: Vec<String> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| {
+                let product: serde_json::Value = serde_json::from_str(line).unwrap();
+                product["title"].as_str().unwrap().to_string()
+            })
+            .collect();
+        assert_eq!(titles, ["First", "Second", "Third"]);
+
+        // Filters only apply to the first page; the cursor replaces them
+        let requests = server.received_requests().await.unwrap();
+        let second: HashMap<String, String> = requests[1].url.query_pairs().into_owned().collect();
+        assert_eq!(second.get("limit").map(String::as_str), Some("2"));
+        assert!(!second.contains_key("vendor"));
+    }
+
+    #[tokio::test]
+    async fn test_export_jsonl_keeps_written_pages_when_a_page_fails() {
+        use crate::rest::resources::v2026_04::Product;
+        use crate::rest::{ResourceError, RestResource};
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/admin/api/2026-04/products.json"))
+            .and(query_param("page_info", "page-2"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "errors": "Not Found"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/admin/api/2026-04/products.json"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(
+                        "Link",
+                        r#"<https://test-shop.myshopify.com/admin/api/2026-04/products.json?page_info=page-2>; rel="next""#,
+                    )
+                    .set_body_json(serde_json::json!({
+                        "products": [{ "id": 1 }, { "id": 2 }]
+                    })),
+            )
+            .mount(&server)
+            .await;
+
+        let server_url = reqwest::Url::parse(&server.uri()).unwrap();
+        let mut client =
+            RestClient::with_version(&create_test_session(), None, ApiVersion::V2026_04).unwrap();
+        client.http_client = client.http_client.with_url_rewriter(move |url| {
+            url.set_scheme("http").unwrap();
+            url.set_host(server_url.host_str()).unwrap();
+            url.set_port(server_url.port()).unwrap();
+        });
+
+        let mut output = Vec::new();
+        let summary = Product::export_jsonl(&client, None, &mut output)
+            .await
+            .unwrap();
+
+        assert_eq!(summary.pages, 1);
+        assert_eq!(summary.records, 2);
+        assert!(matches!(
+            summary.error,
+            Some(ResourceError::Rest(RestError::Http(crate::clients::HttpError::Response(ref e))))
+                if e.code == 404
+        ));
+        assert!(!summary.is_complete());
+        assert_eq!(String::from_utf8(output).unwrap().lines().count(), 2);
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    }
 }
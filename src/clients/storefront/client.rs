@@ -39,11 +39,14 @@
 //! ```
 
 use std::collections::HashMap;
+use std::time::Duration;
 
-use crate::clients::graphql::GraphqlError;
-use crate::clients::storefront::storefront_http::StorefrontHttpClient;
+use crate::clients::graphql::payload::reject_incremental_delivery;
+use crate::clients::graphql::{GraphqlError, UserError};
+use crate::clients::storefront::storefront_http::{RequestFailure, StorefrontHttpClient};
+use crate::clients::storefront::token::BUYER_IP_HEADER_NAME;
 use crate::clients::storefront::StorefrontToken;
-use crate::clients::{DataType, HttpMethod, HttpRequest, HttpResponse};
+use crate::clients::{DataType, HttpError, HttpMethod, HttpRequest, HttpResponse};
 use crate::config::{ApiVersion, ShopDomain, ShopifyConfig};
 
 /// GraphQL client for Shopify Storefront API.
@@ -70,6 +73,12 @@ use crate::config::{ApiVersion, ShopDomain, ShopifyConfig};
 /// - **Private token**: Uses `Shopify-Storefront-Private-Token` header
 /// - **Tokenless**: No authentication header (limited access)
 ///
+/// Prefer [`new_public`](Self::new_public) and [`new_private`](Self::new_private),
+/// which make the token type explicit at the call site. Requests made with a
+/// private token should forward the buyer's IP in the
+/// `Shopify-Storefront-Buyer-IP` header; the client logs a warning when it is
+/// missing.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -104,8 +113,19 @@ pub struct StorefrontClient {
     http_client: StorefrontHttpClient,
     /// The API version being used.
     api_version: ApiVersion,
+    /// Whether the client authenticates with a private token.
+    private_token: bool,
+    /// How many times to retry a throttled query.
+    throttle_retries: u32,
 }
 
+/// Wait before the first retry of a throttled query when the response
+/// doesn't say how long to wait.
+const THROTTLE_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Longest wait between retries of a throttled query.
+const MAX_THROTTLE_BACKOFF: Duration = Duration::from_secs(30);
+
 // Verify StorefrontClient is Send + Sync at compile time
 const _: fn() = || {
     const fn assert_send_sync<T: Send + Sync>() {}
@@ -153,6 +173,51 @@ impl StorefrontClient {
         Self::create_client(shop, token.as_ref(), config, api_version)
     }
 
+    /// Creates a new Storefront client authenticated with a public token.
+    ///
+    /// Public tokens are sent in the `X-Shopify-Storefront-Access-Token`
+    /// header and are safe to embed in client-side code.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::{StorefrontClient, ShopDomain};
+    ///
+    /// let shop = ShopDomain::new("my-store").unwrap();
+    /// let client = StorefrontClient::new_public(&shop, "public-access-token", None);
+    /// ```
+    #[must_use]
+    pub fn new_public(
+        shop: &ShopDomain,
+        token: impl Into<String>,
+        config: Option<&ShopifyConfig>,
+    ) -> Self {
+        Self::new(shop, Some(StorefrontToken::Public(token.into())), config)
+    }
+
+    /// Creates a new Storefront client authenticated with a private token.
+    ///
+    /// Private tokens are sent in the `Shopify-Storefront-Private-Token`
+    /// header and must only be used server-side. Each request should include
+    /// the buyer's IP in the `Shopify-Storefront-Buyer-IP` header.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::{StorefrontClient, ShopDomain};
+    ///
+    /// let shop = ShopDomain::new("my-store").unwrap();
+    /// let client = StorefrontClient::new_private(&shop, "private-access-token", None);
+    /// ```
+    #[must_use]
+    pub fn new_private(
+        shop: &ShopDomain,
+        token: impl Into<String>,
+        config: Option<&ShopifyConfig>,
+    ) -> Self {
+        Self::new(shop, Some(StorefrontToken::Private(token.into())), config)
+    }
+
     /// Creates a new Storefront client with a specific API version override.
     ///
     /// This constructor allows overriding the API version from configuration.
@@ -218,9 +283,43 @@ impl StorefrontClient {
         Self {
             http_client,
             api_version,
+            private_token: token.is_some_and(StorefrontToken::is_private),
+            throttle_retries: 0,
         }
     }
 
+    /// Retries queries the Storefront API throttles, up to `retries` times.
+    ///
+    /// The Storefront API limits query cost per buyer IP, separately from
+    /// the Admin API's per-shop limits. A throttled query is answered with
+    /// a `THROTTLED` error in the body, or with HTTP 429. With retries set,
+    /// the client waits until the cost bucket reported in
+    /// `extensions.cost` has room for the query, or as long as a 429's
+    /// `Retry-After` header asks, and otherwise backs off exponentially from
+    /// one second, then sends the query again. The default is `0`, which returns throttled responses
+    /// as they are.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::{StorefrontClient, ShopDomain};
+    ///
+    /// let shop = ShopDomain::new("my-store").unwrap();
+    /// let client = StorefrontClient::new(&shop, None, None).with_throttle_retries(3);
+    /// assert_eq!(client.throttle_retries(), 3);
+    /// ```
+    #[must_use]
+    pub const fn with_throttle_retries(mut self, retries: u32) -> Self {
+        self.throttle_retries = retries;
+        self
+    }
+
+    /// Returns how many times a throttled query is retried.
+    #[must_use]
+    pub const fn throttle_retries(&self) -> u32 {
+        self.throttle_retries
+    }
+
     /// Returns the API version being used by this client.
     #[must_use]
     pub const fn api_version(&self) -> &ApiVersion {
@@ -345,6 +444,52 @@ impl StorefrontClient {
             .await
     }
 
+    /// Executes a GraphQL query and returns its `data`, treating top-level
+    /// `errors` as a failure.
+    ///
+    /// Unlike [`query`](Self::query), which leaves the response body for the
+    /// caller to inspect, this method reports Storefront API errors (such as
+    /// query validation failures or throttling) as
+    /// [`GraphqlError::StorefrontErrors`].
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The GraphQL query string
+    /// * `variables` - Optional variables for the query
+    /// * `headers` - Optional extra headers to include in the request
+    /// * `tries` - Optional number of retry attempts (default: 1, no retries)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphqlError::Http`] for HTTP-level errors.
+    /// Returns [`GraphqlError::StorefrontErrors`] if the response contains `errors`.
+    /// Returns [`GraphqlError::MissingData`] if the response has no `data`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use shopify_sdk::GraphqlError;
+    ///
+    /// match client.query_data("query { shop { name } }", None, None, None).await {
+    ///     Ok(data) => println!("Shop: {}", data["shop"]["name"]),
+    ///     Err(GraphqlError::StorefrontErrors { errors }) => println!("Rejected: {errors:?}"),
+    ///     Err(e) => println!("Request failed: {e}"),
+    /// }
+    /// ```
+    pub async fn query_data(
+        &self,
+        query: &str,
+        variables: Option<serde_json::Value>,
+        headers: Option<HashMap<String, String>>,
+        tries: Option<u32>,
+    ) -> Result<serde_json::Value, GraphqlError> {
+        let response = self
+            .execute_query(query, variables, headers, tries, false)
+            .await?;
+        let request_id = response.request_id().map(String::from);
+        response_data(response.body).map_err(|error| error.with_request_id(request_id.as_deref()))
+    }
+
     /// Internal helper to execute a GraphQL query with shared logic.
     async fn execute_query(
         &self,
@@ -354,6 +499,14 @@ impl StorefrontClient {
         tries: Option<u32>,
         debug: bool,
     ) -> Result<HttpResponse, GraphqlError> {
+        if self.private_token && !has_buyer_ip(headers.as_ref()) {
+            tracing::warn!(
+                "Storefront request uses a private token without a {} header; \
+                 Shopify will attribute the request to the server rather than the buyer",
+                BUYER_IP_HEADER_NAME
+            );
+        }
+
         // Construct the request body
         let body = serde_json::json!({
             "query": query,
@@ -376,15 +529,112 @@ impl StorefrontClient {
             builder = builder.extra_headers(extra_headers);
         }
 
-        // Build and execute the request
+        // Build and execute the request, retrying while throttled
         let request = builder.build().map_err(|e| GraphqlError::Http(e.into()))?;
-        self.http_client.request(request).await.map_err(Into::into)
+        let mut attempt = 0;
+        loop {
+            let result = self.http_client.request(request.clone()).await;
+            match throttle_delay(&result, attempt) {
+                Some(delay) if attempt < self.throttle_retries => {
+                    tracing::debug!("Storefront query throttled; retrying in {delay:?}");
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                _ => return reject_incremental_delivery(result.map_err(|failure| failure.error)?),
+            }
+        }
+    }
+}
+
+/// Returns how long to wait before retrying a throttled query, or `None`
+/// if the query wasn't throttled.
+///
+/// Uses the bucket state in `extensions.cost` to wait until the requested
+/// cost has been restored, or the `Retry-After` header of a 429, and
+/// exponential backoff otherwise.
+#[allow(clippy::cast_precision_loss)] // Query costs are far below 2^52
+fn throttle_delay(result: &Result<HttpResponse, RequestFailure>, attempt: u32) -> Option<Duration> {
+    let backoff = THROTTLE_BACKOFF
+        .saturating_mul(2_u32.saturating_pow(attempt))
+        .min(MAX_THROTTLE_BACKOFF);
+
+    match result {
+        Ok(response) if is_throttled(&response.body) => {
+            let restored = response.query_cost().and_then(|cost| {
+                let status = cost.throttle_status?;
+                let missing = cost.requested_query_cost? as f64 - status.currently_available;
+                // Capped before converting, as a tiny restore rate would
+                // overflow the duration
+                (status.restore_rate > 0.0).then(|| {
+                    let seconds = missing.max(0.0) / status.restore_rate;
+                    Duration::from_secs_f64(seconds.min(MAX_THROTTLE_BACKOFF.as_secs_f64()))
+                })
+            });
+            Some(restored.unwrap_or(backoff))
+        }
+        Err(RequestFailure {
+            error: HttpError::Response(error),
+            retry_after,
+        }) if error.code == 429 => Some(retry_after.map_or(backoff, Duration::from_secs_f64)),
+        _ => None,
+    }
+}
+
+/// Returns `true` if a response body reports a `THROTTLED` error.
+fn is_throttled(body: &serde_json::Value) -> bool {
+    body["errors"].as_array().is_some_and(|errors| {
+        errors
+            .iter()
+            .any(|error| error["extensions"]["code"] == "THROTTLED")
+    })
+}
+
+/// Returns `true` if the extra headers include the buyer IP header.
+fn has_buyer_ip(headers: Option<&HashMap<String, String>>) -> bool {
+    headers.is_some_and(|headers| {
+        headers
+            .keys()
+            .any(|name| name.eq_ignore_ascii_case(BUYER_IP_HEADER_NAME))
+    })
+}
+
+/// Extracts `data` from a Storefront API response body.
+///
+/// Top-level `errors` take precedence over any partial `data`.
+fn response_data(mut body: serde_json::Value) -> Result<serde_json::Value, GraphqlError> {
+    if let Some(errors) = body["errors"].as_array().filter(|e| !e.is_empty()) {
+        return Err(GraphqlError::StorefrontErrors {
+            errors: errors
+                .iter()
+                .map(|e| UserError {
+                    field: e["path"].as_array().map(|path| {
+                        path.iter()
+                            .map(|p| {
+                                p.as_str()
+                                    .map_or_else(|| p.to_string(), ToString::to_string)
+                            })
+                            .collect()
+                    }),
+                    message: e["message"].as_str().unwrap_or("Unknown error").to_string(),
+                })
+                .collect(),
+        });
+    }
+
+    match body["data"].take() {
+        serde_json::Value::Null => Err(GraphqlError::MissingData {
+            field: "data".to_string(),
+            request_id: None,
+        }),
+        data => Ok(data),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clients::storefront::token::{PRIVATE_HEADER_NAME, PUBLIC_HEADER_NAME};
+    use serde_json::json;
 
     // === Construction Tests ===
 
@@ -494,4 +744,381 @@ mod tests {
         assert_eq!(client_2024_07.api_version(), &ApiVersion::V2024_07);
         assert_eq!(client_latest.api_version(), &ApiVersion::latest());
     }
+
+    // === Token Type Tests ===
+
+    #[test]
+    fn test_new_public_sets_public_token_header() {
+        let shop = ShopDomain::new("test-shop").unwrap();
+        let client = StorefrontClient::new_public(&shop, "public-token", None);
+        let headers = client.http_client.default_headers();
+
+        assert_eq!(
+            headers.get("X-Shopify-Storefront-Access-Token"),
+            Some(&"public-token".to_string())
+        );
+        assert!(!headers.contains_key(PRIVATE_HEADER_NAME));
+        assert!(!client.private_token);
+    }
+
+    #[test]
+    fn test_new_private_sets_private_token_header() {
+        let shop = ShopDomain::new("test-shop").unwrap();
+        let client = StorefrontClient::new_private(&shop, "private-token", None);
+        let headers = client.http_client.default_headers();
+
+        assert_eq!(
+            headers.get("Shopify-Storefront-Private-Token"),
+            Some(&"private-token".to_string())
+        );
+        assert!(!headers.contains_key(PUBLIC_HEADER_NAME));
+        assert!(client.private_token);
+    }
+
+    #[test]
+    fn test_tokenless_client_is_not_private() {
+        let shop = ShopDomain::new("test-shop").unwrap();
+        let client = StorefrontClient::new(&shop, None, None);
+
+        assert!(!client.private_token);
+    }
+
+    #[test]
+    fn test_has_buyer_ip_matches_header_case_insensitively() {
+        let headers = HashMap::from([(
+            "shopify-storefront-buyer-ip".to_string(),
+            "192.0.2.1".to_string(),
+        )]);
+
+        assert!(has_buyer_ip(Some(&headers)));
+        assert!(!has_buyer_ip(Some(&HashMap::new())));
+        assert!(!has_buyer_ip(None));
+    }
+
+    // === Response Data Tests ===
+
+    #[test]
+    fn test_response_data_returns_data() {
+        let data = response_data(json!({ "data": { "shop": { "name": "My Store" } } })).unwrap();
+
+        assert_eq!(data["shop"]["name"], "My Store");
+    }
+
+    #[test]
+    fn test_response_data_surfaces_storefront_errors() {
+        let result = response_data(json!({
+            "data": { "product": null },
+            "errors": [{
+                "message": "Field 'titel' doesn't exist on type 'Product'",
+                "path": ["query", "product", 0, "titel"]
+            }]
+        }));
+
+        let Err(GraphqlError::StorefrontErrors { errors }) = result else {
+            panic!("expected StorefrontErrors, got {result:?}");
+        };
+        assert_eq!(
+            errors[0].field,
+            Some(vec![
+                "query".to_string(),
+                "product".to_string(),
+                "0".to_string(),
+                "titel".to_string()
+            ])
+        );
+        assert!(errors[0].message.contains("titel"));
+    }
+
+    #[test]
+    fn test_response_data_without_data_is_missing_data() {
+        assert!(matches!(
+            response_data(json!({})),
+            Err(GraphqlError::MissingData { .. })
+        ));
+    }
+
+    #[test]
+    fn test_storefront_throttle_response_is_parsed() {
+        let response = HttpResponse::new(
+            200,
+            HashMap::new(),
+            json!({
+                "errors": [{
+                    "message": "Throttled",
+                    "extensions": { "code": "THROTTLED" }
+                }],
+                "extensions": {
+                    "cost": {
+                        "requestedQueryCost": 120,
+                        "actualQueryCost": null,
+                        "throttleStatus": {
+                            "maximumAvailable": 1000.0,
+                            "currentlyAvailable": 20.0,
+                            "restoreRate": 50.0
+                        }
+                    }
+                }
+            }),
+        );
+
+        let status = response.throttle_status().unwrap();
+        assert!((status.maximum_available - 1000.0).abs() < f64::EPSILON);
+        assert!((status.currently_available - 20.0).abs() < f64::EPSILON);
+        assert!((status.restore_rate - 50.0).abs() < f64::EPSILON);
+        assert!(is_throttled(&response.body));
+
+        // 100 points missing at 50 points per second
+        assert_eq!(
+            throttle_delay(&Ok(response), 0),
+            Some(Duration::from_secs(2))
+        );
+    }
+
+    #[test]
+    fn test_throttle_delay_backs_off_without_cost_information() {
+        let throttled = Ok(HttpResponse::new(
+            200,
+            HashMap::new(),
+            json!({ "errors": [{ "message": "Throttled", "extensions": { "code": "THROTTLED" } }] }),
+        ));
+        assert_eq!(throttle_delay(&throttled, 0), Some(Duration::from_secs(1)));
+        assert_eq!(throttle_delay(&throttled, 2), Some(Duration::from_secs(4)));
+        assert_eq!(throttle_delay(&throttled, 10), Some(MAX_THROTTLE_BACKOFF));
+
+        let too_many_requests = |retry_after| {
+            Err(RequestFailure {
+                error: HttpError::Response(crate::clients::HttpResponseError {
+                    code: 429,
+                    message: "Too Many Requests".to_string(),
+                    error_reference: None,
+                }),
+                retry_after,
+            })
+        };
+        assert_eq!(
+            throttle_delay(&too_many_requests(None), 1),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            throttle_delay(&too_many_requests(Some(5.0)), 1),
+            Some(Duration::from_secs(5))
+        );
+
+        let ok = Ok(HttpResponse::new(
+            200,
+            HashMap::new(),
+            json!({ "data": { "shop": { "name": "Test" } } }),
+        ));
+        assert_eq!(throttle_delay(&ok, 0), None);
+    }
+
+    #[test]
+    fn test_throttle_delay_caps_tiny_restore_rate() {
+        let response = HttpResponse::new(
+            200,
+            HashMap::new(),
+            json!({
+                "errors": [{ "message": "Throttled", "extensions": { "code": "THROTTLED" } }],
+                "extensions": {
+                    "cost": {
+                        "requestedQueryCost": 120,
+                        "throttleStatus": {
+                            "maximumAvailable": 1000.0,
+                            "currentlyAvailable": 20.0,
+                            "restoreRate": 1e-300
+                        }
+                    }
+                }
+            }),
+        );
+
+        assert_eq!(throttle_delay(&Ok(response), 0), Some(MAX_THROTTLE_BACKOFF));
+    }
+
+    /// Returns a client with throttle retries that sends to `server`.
+    fn mock_server_client(server: &wiremock::MockServer) -> StorefrontClient {
+        let shop = ShopDomain::new("test-shop").unwrap();
+        let mut client = StorefrontClient::new(&shop, None, None).with_throttle_retries(1);
+        client.http_client = client.http_client.with_base_uri(server.uri());
+        client
+    }
+
+    #[tokio::test]
+    async fn test_query_retries_429_after_retry_after_delay() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "1.5"))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "data": { "shop": { "name": "Test" } } })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = mock_server_client(&server);
+        let started = std::time::Instant::now();
+        let response = client.query("{ shop { name } }", None, None, None).await;
+
+        assert_eq!(response.unwrap().body["data"]["shop"]["name"], "Test");
+        // The 1.5 second Retry-After is longer than the first backoff step
+        assert!(started.elapsed() >= Duration::from_millis(1500));
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_query_retries_throttled_response_once_cost_is_restored() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "errors": [{ "message": "Throttled", "extensions": { "code": "THROTTLED" } }],
+                "extensions": {
+                    "cost": {
+                        "requestedQueryCost": 30,
+                        "throttleStatus": {
+                            "maximumAvailable": 1000.0,
+                            "currentlyAvailable": 20.0,
+                            "restoreRate": 100.0
+                        }
+                    }
+                }
+            })))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "data": { "shop": { "name": "Test" } } })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = mock_server_client(&server);
+        let started = std::time::Instant::now();
+        let response = client.query("{ shop { name } }", None, None, None).await;
+
+        assert_eq!(response.unwrap().body["data"]["shop"]["name"], "Test");
+        // 10 points missing at 100 per second, well under the backoff
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_query_gives_up_after_throttle_retries() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .mount(&server)
+            .await;
+
+        let client = mock_server_client(&server);
+        let result = client.query("{ shop { name } }", None, None, None).await;
+
+        assert!(matches!(
+            result,
+            Err(GraphqlError::Http(HttpError::Response(ref e))) if e.code == 429
+        ));
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_query_honours_configured_request_timeout() {
+        use crate::config::{ApiKey, ApiSecretKey};
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "data": {} }))
+                    .set_delay(Duration::from_secs(5)),
+            )
+            .mount(&server)
+            .await;
+        let config = ShopifyConfig::builder()
+            .api_key(ApiKey::new("key").unwrap())
+            .api_secret_key(ApiSecretKey::new("secret").unwrap())
+            .request_timeout(Duration::from_millis(200))
+            .build()
+            .unwrap();
+        let shop = ShopDomain::new("test-shop").unwrap();
+        let mut client = StorefrontClient::new(&shop, None, Some(&config));
+        client.http_client = client.http_client.with_base_uri(server.uri());
+
+        let started = std::time::Instant::now();
+        let result = client.query("{ shop { name } }", None, None, None).await;
+
+        assert!(matches!(
+            result,
+            Err(GraphqlError::Http(HttpError::Network(_)))
+        ));
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_deprecated_response_registers_notice() {
+        use crate::clients::{DeprecationNotice, DeprecationRegistry, DeprecationSource};
+        use crate::config::{ApiKey, ApiSecretKey};
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(
+                        "X-Shopify-API-Deprecated-Reason",
+                        "Field `Product.descriptionHtml` is deprecated",
+                    )
+                    .set_body_json(json!({ "data": {} })),
+            )
+            .mount(&server)
+            .await;
+        let registry = DeprecationRegistry::new();
+        let config = ShopifyConfig::builder()
+            .api_key(ApiKey::new("key").unwrap())
+            .api_secret_key(ApiSecretKey::new("secret").unwrap())
+            .deprecation_registry(registry.clone())
+            .build()
+            .unwrap();
+        let shop = ShopDomain::new("test-shop").unwrap();
+        let mut client = StorefrontClient::new(&shop, None, Some(&config));
+        client.http_client = client.http_client.with_base_uri(server.uri());
+
+        client
+            .query("{ product(id: 1) { descriptionHtml } }", None, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            registry.notices(),
+            vec![DeprecationNotice {
+                source: DeprecationSource::Response,
+                reason: "Field `Product.descriptionHtml` is deprecated".to_string(),
+                path: Some("graphql.json".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_throttle_retries_default_to_zero() {
+        let shop = ShopDomain::new("test-shop").unwrap();
+        let client = StorefrontClient::new(&shop, None, None);
+        assert_eq!(client.throttle_retries(), 0);
+        assert_eq!(client.with_throttle_retries(2).throttle_retries(), 2);
+    }
 }
@@ -5,12 +5,14 @@
 
 use std::collections::HashMap;
 
+use crate::clients::deprecation::{DeprecationNotice, DeprecationRegistry};
 use crate::clients::errors::{HttpError, HttpResponseError, MaxHttpRetriesExceededError};
-use crate::clients::http_client::RETRY_WAIT_TIME;
+use crate::clients::http_client::{
+    build_reqwest_client, build_user_agent, maintenance_error, RETRY_WAIT_TIME,
+};
 use crate::clients::http_request::HttpRequest;
-use crate::clients::http_response::HttpResponse;
+use crate::clients::http_response::{ApiDeprecationInfo, HttpResponse};
 use crate::clients::storefront::StorefrontToken;
-use crate::clients::SDK_VERSION;
 use crate::config::{ApiVersion, ShopDomain, ShopifyConfig};
 
 /// Internal HTTP client for Storefront API requests.
@@ -32,6 +34,27 @@ pub(super) struct StorefrontHttpClient {
     base_path: String,
     /// Default headers to include in all requests.
     default_headers: HashMap<String, String>,
+    /// Optional registry that collects deprecation notices.
+    deprecation_registry: Option<DeprecationRegistry>,
+}
+
+/// A failed Storefront request.
+#[derive(Debug)]
+pub(super) struct RequestFailure {
+    /// The error the request failed with.
+    pub(super) error: HttpError,
+    /// The `Retry-After` delay in seconds of the final response, if it had
+    /// one.
+    pub(super) retry_after: Option<f64>,
+}
+
+impl From<HttpError> for RequestFailure {
+    fn from(error: HttpError) -> Self {
+        Self {
+            error,
+            retry_after: None,
+        }
+    }
 }
 
 // Verify StorefrontHttpClient is Send + Sync at compile time
@@ -67,17 +90,9 @@ impl StorefrontHttpClient {
                 .map_or_else(default_shop_uri, |host_name| format!("https://{host_name}"))
         });
 
-        // Build User-Agent header
-        let user_agent_prefix = config
-            .and_then(ShopifyConfig::user_agent_prefix)
-            .map_or(String::new(), |prefix| format!("{prefix} | "));
-        let rust_version = env!("CARGO_PKG_RUST_VERSION");
-        let user_agent =
-            format!("{user_agent_prefix}Shopify API Library v{SDK_VERSION} | Rust {rust_version}");
-
         // Build default headers
         let mut default_headers = HashMap::new();
-        default_headers.insert("User-Agent".to_string(), user_agent);
+        default_headers.insert("User-Agent".to_string(), build_user_agent(config));
         default_headers.insert("Accept".to_string(), "application/json".to_string());
 
         // Add Host header when using api_host (proxy scenario)
@@ -94,17 +109,15 @@ impl StorefrontHttpClient {
             );
         }
 
-        // Create reqwest client
-        let client = reqwest::Client::builder()
-            .use_rustls_tls()
-            .build()
-            .expect("Failed to create HTTP client");
+        // Create reqwest client with the configured timeouts
+        let client = build_reqwest_client(config);
 
         Self {
             client,
             base_uri,
             base_path,
             default_headers,
+            deprecation_registry: config.and_then(|c| c.deprecation_registry().cloned()),
         }
     }
 
@@ -116,9 +129,15 @@ impl StorefrontHttpClient {
     /// - Header merging
     /// - Response parsing
     /// - Retry logic for 429 and 500 responses
-    pub(super) async fn request(&self, request: HttpRequest) -> Result<HttpResponse, HttpError> {
+    ///
+    /// A failure carries the final response's `Retry-After` delay, so callers
+    /// retrying on their own can honour it.
+    pub(super) async fn request(
+        &self,
+        request: HttpRequest,
+    ) -> Result<HttpResponse, RequestFailure> {
         // Validate request first
-        request.verify()?;
+        request.verify().map_err(HttpError::from)?;
 
         // Build full URL
         let url = format!("{}{}/{}", self.base_uri, self.base_path, request.path);
@@ -166,13 +185,26 @@ impl StorefrontHttpClient {
             }
 
             // Send request
-            let res = req_builder.send().await?;
+            let res = req_builder.send().await.map_err(HttpError::from)?;
 
             // Parse response
             let code = res.status().as_u16();
             let res_headers = Self::parse_response_headers(res.headers());
             let body_text = res.text().await.unwrap_or_default();
 
+            // Maintenance pages are HTML; retry them without parsing the body
+            if let Some(error) = maintenance_error(code, &res_headers, &body_text) {
+                if tries >= request.tries {
+                    return Err(error.into());
+                }
+                let delay = error.retry_after().map_or(
+                    std::time::Duration::from_secs(RETRY_WAIT_TIME),
+                    std::time::Duration::from_secs_f64,
+                );
+                tokio::time::sleep(delay).await;
+                continue;
+            }
+
             // Parse body as JSON
             let body = if body_text.is_empty() {
                 serde_json::json!({})
@@ -189,6 +221,8 @@ impl StorefrontHttpClient {
 
             let response = HttpResponse::new(code, res_headers, body);
 
+            self.handle_deprecation(&request.path, &response);
+
             // Check if response is OK
             if response.is_ok() {
                 return Ok(response);
@@ -204,24 +238,30 @@ impl StorefrontHttpClient {
                     code,
                     message: error_message,
                     error_reference: response.request_id().map(String::from),
-                }));
+                })
+                .into());
             }
 
             // Check if we've exhausted retries
             if tries >= request.tries {
-                if request.tries == 1 {
-                    return Err(HttpError::Response(HttpResponseError {
+                let error = if request.tries == 1 {
+                    HttpError::Response(HttpResponseError {
                         code,
                         message: error_message,
                         error_reference: response.request_id().map(String::from),
-                    }));
-                }
-                return Err(HttpError::MaxRetries(MaxHttpRetriesExceededError {
-                    code,
-                    tries: request.tries,
-                    message: error_message,
-                    error_reference: response.request_id().map(String::from),
-                }));
+                    })
+                } else {
+                    HttpError::MaxRetries(MaxHttpRetriesExceededError {
+                        code,
+                        tries: request.tries,
+                        message: error_message,
+                        error_reference: response.request_id().map(String::from),
+                    })
+                };
+                return Err(RequestFailure {
+                    error,
+                    retry_after: response.retry_request_after,
+                });
             }
 
             // Calculate retry delay
@@ -230,6 +270,24 @@ impl StorefrontHttpClient {
         }
     }
 
+    /// Logs and records the deprecation reason a response carries, if any.
+    fn handle_deprecation(&self, path: &str, response: &HttpResponse) {
+        let Some(reason) = response.deprecation_reason() else {
+            return;
+        };
+        tracing::warn!(
+            "Deprecated request to Shopify Storefront API at {}, received reason: {}",
+            path,
+            reason
+        );
+        if let Some(registry) = &self.deprecation_registry {
+            registry.record(DeprecationNotice::from(&ApiDeprecationInfo {
+                reason: reason.to_string(),
+                path: Some(path.to_string()),
+            }));
+        }
+    }
+
     /// Parses response headers into a `HashMap`.
     fn parse_response_headers(
         headers: &reqwest::header::HeaderMap,
@@ -297,9 +355,15 @@ impl StorefrontHttpClient {
     }
 
     /// Returns the default headers for this client (test helper).
-    fn default_headers(&self) -> &HashMap<String, String> {
+    pub(super) fn default_headers(&self) -> &HashMap<String, String> {
         &self.default_headers
     }
+
+    /// Sends requests to `base_uri` instead of the shop (test helper).
+    pub(super) fn with_base_uri(mut self, base_uri: String) -> Self {
+        self.base_uri = base_uri;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -350,8 +414,8 @@ mod tests {
         let client = StorefrontHttpClient::new(&shop, None, None, &ApiVersion::V2024_10);
 
         let user_agent = client.default_headers().get("User-Agent").unwrap();
-        assert!(user_agent.contains("Shopify API Library v"));
-        assert!(user_agent.contains("Rust"));
+        assert!(user_agent.starts_with("shopify-api-rust/"));
+        assert!(user_agent.contains(crate::clients::SDK_VERSION));
     }
 
     #[test]
@@ -454,7 +518,6 @@ mod tests {
         let client = StorefrontHttpClient::new(&shop, None, Some(&config), &ApiVersion::V2024_10);
 
         let user_agent = client.default_headers().get("User-Agent").unwrap();
-        assert!(user_agent.starts_with("MyApp/1.0 | "));
-        assert!(user_agent.contains("Shopify API Library"));
+        assert!(user_agent.starts_with("MyApp/1.0 shopify-api-rust/"));
     }
 }
@@ -45,6 +45,13 @@ pub const PUBLIC_HEADER_NAME: &str = "X-Shopify-Storefront-Access-Token";
 /// HTTP header name for private storefront access tokens.
 pub const PRIVATE_HEADER_NAME: &str = "Shopify-Storefront-Private-Token";
 
+/// HTTP header name carrying the buyer's IP address.
+///
+/// Shopify expects this header on every request made with a private token,
+/// so that bot protection and rate limits apply to the buyer rather than to
+/// the server making the request.
+pub const BUYER_IP_HEADER_NAME: &str = "Shopify-Storefront-Buyer-IP";
+
 /// A Shopify Storefront API access token.
 ///
 /// This enum provides type-safe representation of storefront access tokens,
@@ -120,6 +127,21 @@ impl StorefrontToken {
         }
     }
 
+    /// Returns `true` for [`Private`](Self::Private) tokens.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::StorefrontToken;
+    ///
+    /// assert!(StorefrontToken::Private("token".to_string()).is_private());
+    /// assert!(!StorefrontToken::Public("token".to_string()).is_private());
+    /// ```
+    #[must_use]
+    pub const fn is_private(&self) -> bool {
+        matches!(self, Self::Private(_))
+    }
+
     /// Returns the token value as a string slice.
     ///
     /// This is used to set the HTTP header value when making requests.
@@ -232,4 +254,10 @@ mod tests {
     fn test_private_header_name_constant() {
         assert_eq!(PRIVATE_HEADER_NAME, "Shopify-Storefront-Private-Token");
     }
+
+    #[test]
+    fn test_is_private_distinguishes_token_types() {
+        assert!(StorefrontToken::Private("token".to_string()).is_private());
+        assert!(!StorefrontToken::Public("token".to_string()).is_private());
+    }
 }
@@ -38,9 +38,15 @@ pub use version::ApiVersion;
 // Re-export DeprecationCallback type (defined in this module)
 
 use crate::auth::AuthScopes;
-use crate::clients::ApiDeprecationInfo;
+use crate::clients::{
+    ApiDeprecationInfo, DeprecationNotice, DeprecationRegistry, DeprecationSource, Metrics,
+};
 use crate::error::ConfigError;
 use std::sync::Arc;
+use std::time::Duration;
+
+/// Largest page size Shopify accepts for REST list requests.
+const MAX_PAGE_SIZE: u32 = 250;
 
 /// Callback type for handling API deprecation notices.
 ///
@@ -118,7 +124,14 @@ pub struct ShopifyConfig {
     api_version: ApiVersion,
     is_embedded: bool,
     user_agent_prefix: Option<String>,
+    api_features: Vec<String>,
+    default_page_size: Option<u32>,
     deprecation_callback: Option<DeprecationCallback>,
+    deprecation_registry: Option<DeprecationRegistry>,
+    metrics: Option<Arc<dyn Metrics>>,
+    connect_timeout: Option<Duration>,
+    request_timeout: Option<Duration>,
+    check_scope_drift: bool,
 }
 
 impl std::fmt::Debug for ShopifyConfig {
@@ -132,10 +145,17 @@ impl std::fmt::Debug for ShopifyConfig {
             .field("api_version", &self.api_version)
             .field("is_embedded", &self.is_embedded)
             .field("user_agent_prefix", &self.user_agent_prefix)
+            .field("api_features", &self.api_features)
+            .field("default_page_size", &self.default_page_size)
             .field(
                 "deprecation_callback",
                 &self.deprecation_callback.as_ref().map(|_| "<callback>"),
             )
+            .field("deprecation_registry", &self.deprecation_registry)
+            .field("metrics", &self.metrics.as_ref().map(|_| "<metrics>"))
+            .field("connect_timeout", &self.connect_timeout)
+            .field("request_timeout", &self.request_timeout)
+            .field("check_scope_drift", &self.check_scope_drift)
             .finish()
     }
 }
@@ -210,6 +230,24 @@ impl ShopifyConfig {
         self.user_agent_prefix.as_deref()
     }
 
+    /// Returns the API preview features opted into, if any.
+    ///
+    /// These are sent in the `X-Shopify-Api-Features` header on every
+    /// Admin API request.
+    #[must_use]
+    pub fn api_features(&self) -> &[String] {
+        &self.api_features
+    }
+
+    /// Returns the default page size for REST list requests, if configured.
+    ///
+    /// Applied as the `limit` query parameter when a list request does not
+    /// set one.
+    #[must_use]
+    pub const fn default_page_size(&self) -> Option<u32> {
+        self.default_page_size
+    }
+
     /// Returns the deprecation callback, if configured.
     ///
     /// This callback is invoked when the SDK receives a response indicating
@@ -218,6 +256,40 @@ impl ShopifyConfig {
     pub fn deprecation_callback(&self) -> Option<&DeprecationCallback> {
         self.deprecation_callback.as_ref()
     }
+
+    /// Returns the registry that collects deprecation notices, if configured.
+    #[must_use]
+    pub const fn deprecation_registry(&self) -> Option<&DeprecationRegistry> {
+        self.deprecation_registry.as_ref()
+    }
+
+    /// Returns the request metrics hook, if configured.
+    #[must_use]
+    pub fn metrics(&self) -> Option<&Arc<dyn Metrics>> {
+        self.metrics.as_ref()
+    }
+
+    /// Returns the timeout for establishing a connection, if configured.
+    #[must_use]
+    pub const fn connect_timeout(&self) -> Option<Duration> {
+        self.connect_timeout
+    }
+
+    /// Returns the timeout for each request attempt, if configured.
+    ///
+    /// Covers the whole attempt, from connecting until the response body has
+    /// been read.
+    #[must_use]
+    pub const fn request_timeout(&self) -> Option<Duration> {
+        self.request_timeout
+    }
+
+    /// Returns `true` if clients compare session scopes with the configured
+    /// scopes when they are constructed.
+    #[must_use]
+    pub const fn check_scope_drift(&self) -> bool {
+        self.check_scope_drift
+    }
 }
 
 // Verify ShopifyConfig is Send + Sync at compile time
@@ -238,8 +310,13 @@ const _: fn() = || {
 /// - `scopes`: Empty
 /// - `host`: `None`
 /// - `user_agent_prefix`: `None`
+/// - `api_features`: Empty
+/// - `default_page_size`: `None` (Shopify's default of 50)
 /// - `old_api_secret_key`: `None`
 /// - `reject_deprecated_versions`: `false`
+/// - `metrics`: `None` (nothing is recorded)
+/// - `connect_timeout`: `None` (no limit beyond the operating system's)
+/// - `request_timeout`: `None` (no limit)
 ///
 /// # Example
 ///
@@ -266,8 +343,15 @@ pub struct ShopifyConfigBuilder {
     api_version: Option<ApiVersion>,
     is_embedded: Option<bool>,
     user_agent_prefix: Option<String>,
+    api_features: Vec<String>,
+    default_page_size: Option<u32>,
     reject_deprecated_versions: bool,
     deprecation_callback: Option<DeprecationCallback>,
+    deprecation_registry: Option<DeprecationRegistry>,
+    metrics: Option<Arc<dyn Metrics>>,
+    connect_timeout: Option<Duration>,
+    request_timeout: Option<Duration>,
+    check_scope_drift: bool,
 }
 
 impl std::fmt::Debug for ShopifyConfigBuilder {
@@ -281,6 +365,8 @@ impl std::fmt::Debug for ShopifyConfigBuilder {
             .field("api_version", &self.api_version)
             .field("is_embedded", &self.is_embedded)
             .field("user_agent_prefix", &self.user_agent_prefix)
+            .field("api_features", &self.api_features)
+            .field("default_page_size", &self.default_page_size)
             .field(
                 "reject_deprecated_versions",
                 &self.reject_deprecated_versions,
@@ -289,6 +375,11 @@ impl std::fmt::Debug for ShopifyConfigBuilder {
                 "deprecation_callback",
                 &self.deprecation_callback.as_ref().map(|_| "<callback>"),
             )
+            .field("deprecation_registry", &self.deprecation_registry)
+            .field("metrics", &self.metrics.as_ref().map(|_| "<metrics>"))
+            .field("connect_timeout", &self.connect_timeout)
+            .field("request_timeout", &self.request_timeout)
+            .field("check_scope_drift", &self.check_scope_drift)
             .finish()
     }
 }
@@ -368,12 +459,68 @@ impl ShopifyConfigBuilder {
     }
 
     /// Sets the user agent prefix for HTTP requests.
+    ///
+    /// The `User-Agent` header becomes
+    /// `"{prefix} shopify-api-rust/{version} (rustc {rust_version})"`, so
+    /// Shopify can attribute SDK traffic to your app.
     #[must_use]
     pub fn user_agent_prefix(mut self, prefix: impl Into<String>) -> Self {
         self.user_agent_prefix = Some(prefix.into());
         self
     }
 
+    /// Opts into API preview features.
+    ///
+    /// The features are sent as a comma-separated `X-Shopify-Api-Features`
+    /// header on every Admin API request (REST and GraphQL), so previews can
+    /// be enabled without building a separate client.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::{ShopifyConfig, ApiKey, ApiSecretKey};
+    ///
+    /// let config = ShopifyConfig::builder()
+    ///     .api_key(ApiKey::new("key").unwrap())
+    ///     .api_secret_key(ApiSecretKey::new("secret").unwrap())
+    ///     .api_features(vec!["include-presentment-prices".to_string()])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(config.api_features(), ["include-presentment-prices"]);
+    /// ```
+    #[must_use]
+    pub fn api_features(mut self, features: Vec<String>) -> Self {
+        self.api_features = features;
+        self
+    }
+
+    /// Sets the page size used by REST list requests that don't set `limit`.
+    ///
+    /// Shopify returns 50 resources per page by default and allows at most
+    /// 250. The value is clamped to `1..=250`. An explicit `limit` in a
+    /// `*ListParams` struct still takes precedence.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::{ShopifyConfig, ApiKey, ApiSecretKey};
+    ///
+    /// let config = ShopifyConfig::builder()
+    ///     .api_key(ApiKey::new("key").unwrap())
+    ///     .api_secret_key(ApiSecretKey::new("secret").unwrap())
+    ///     .default_page_size(1000)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(config.default_page_size(), Some(250));
+    /// ```
+    #[must_use]
+    pub fn default_page_size(mut self, page_size: u32) -> Self {
+        self.default_page_size = Some(page_size.clamp(1, MAX_PAGE_SIZE));
+        self
+    }
+
     /// Sets whether to reject deprecated API versions.
     ///
     /// When `true`, [`build()`](Self::build) will return a
@@ -439,6 +586,140 @@ impl ShopifyConfigBuilder {
         self
     }
 
+    /// Sets a registry that collects every deprecation notice the SDK
+    /// encounters.
+    ///
+    /// The registry records deprecated API versions, REST client creation,
+    /// and deprecation headers on responses. Keep a clone to read the notices
+    /// with [`DeprecationRegistry::notices`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::{ShopifyConfig, ApiKey, ApiSecretKey};
+    /// use shopify_sdk::clients::DeprecationRegistry;
+    ///
+    /// let registry = DeprecationRegistry::new();
+    ///
+    /// let config = ShopifyConfig::builder()
+    ///     .api_key(ApiKey::new("key").unwrap())
+    ///     .api_secret_key(ApiSecretKey::new("secret").unwrap())
+    ///     .deprecation_registry(registry.clone())
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert!(registry.notices().is_empty());
+    /// ```
+    #[must_use]
+    pub fn deprecation_registry(mut self, registry: DeprecationRegistry) -> Self {
+        self.deprecation_registry = Some(registry);
+        self
+    }
+
+    /// Sets a hook that records a measurement for every Admin API request.
+    ///
+    /// See [`Metrics`] for what is recorded. Without a hook, nothing is
+    /// recorded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::{ShopifyConfig, ApiKey, ApiSecretKey, HttpMethod, Metrics};
+    /// use std::time::Duration;
+    ///
+    /// struct LogMetrics;
+    ///
+    /// impl Metrics for LogMetrics {
+    ///     fn record_request(
+    ///         &self,
+    ///         path: &str,
+    ///         method: HttpMethod,
+    ///         status: Option<u16>,
+    ///         duration: Duration,
+    ///         retries: u32,
+    ///     ) {
+    ///         println!("{method} {path} -> {status:?} in {duration:?} ({retries} retries)");
+    ///     }
+    /// }
+    ///
+    /// let config = ShopifyConfig::builder()
+    ///     .api_key(ApiKey::new("key").unwrap())
+    ///     .api_secret_key(ApiSecretKey::new("secret").unwrap())
+    ///     .metrics(LogMetrics)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert!(config.metrics().is_some());
+    /// ```
+    #[must_use]
+    pub fn metrics(mut self, metrics: impl Metrics + 'static) -> Self {
+        self.metrics = Some(Arc::new(metrics));
+        self
+    }
+
+    /// Sets the timeout for establishing a connection to Shopify.
+    ///
+    /// A connection that is not established in time fails with
+    /// [`HttpError::ConnectTimeout`](crate::clients::HttpError::ConnectTimeout).
+    /// Combine a short connect timeout with a longer
+    /// [`request_timeout`](Self::request_timeout) to fail fast on unreachable
+    /// hosts without cutting off slow responses.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::{ShopifyConfig, ApiKey, ApiSecretKey};
+    /// use std::time::Duration;
+    ///
+    /// let config = ShopifyConfig::builder()
+    ///     .api_key(ApiKey::new("key").unwrap())
+    ///     .api_secret_key(ApiSecretKey::new("secret").unwrap())
+    ///     .connect_timeout(Duration::from_secs(2))
+    ///     .request_timeout(Duration::from_secs(30))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(config.connect_timeout(), Some(Duration::from_secs(2)));
+    /// ```
+    #[must_use]
+    pub const fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for each request attempt.
+    ///
+    /// The timeout covers connecting, sending the request and reading the
+    /// response. A per-request [`HttpRequest::timeout`](crate::clients::HttpRequest)
+    /// overrides it.
+    #[must_use]
+    pub const fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether Admin API clients check sessions for scope drift.
+    ///
+    /// When `true`, [`GraphqlClient`](crate::GraphqlClient) and
+    /// [`RestClient`](crate::RestClient) compare the session's scopes with
+    /// the configured [`scopes`](Self::scopes) when they are constructed. If
+    /// they differ, a warning suggesting re-authorization is logged via
+    /// `tracing` and the difference is available from the client's
+    /// `scope_drift()`. Nothing is checked when no scopes are configured.
+    ///
+    /// The check runs only when a client is constructed. A client reused
+    /// for later requests, such as one cached by
+    /// [`ClientFactory`](crate::clients::ClientFactory), is never re-checked,
+    /// so scopes changed after it was built go unnoticed until a new client
+    /// is constructed.
+    ///
+    /// Defaults to `false`.
+    #[must_use]
+    pub const fn check_scope_drift(mut self, check: bool) -> Self {
+        self.check_scope_drift = check;
+        self
+    }
+
     /// Builds the [`ShopifyConfig`], validating that required fields are set.
     ///
     /// # Errors
@@ -476,6 +757,16 @@ impl ShopifyConfigBuilder {
                 api_version,
                 ApiVersion::latest()
             );
+            if let Some(registry) = &self.deprecation_registry {
+                registry.record(DeprecationNotice {
+                    source: DeprecationSource::ApiVersion,
+                    reason: format!(
+                        "API version '{api_version}' is deprecated; upgrade to '{}'",
+                        ApiVersion::latest()
+                    ),
+                    path: None,
+                });
+            }
         }
 
         Ok(ShopifyConfig {
@@ -487,7 +778,14 @@ impl ShopifyConfigBuilder {
             api_version,
             is_embedded: self.is_embedded.unwrap_or(true),
             user_agent_prefix: self.user_agent_prefix,
+            api_features: self.api_features,
+            default_page_size: self.default_page_size,
             deprecation_callback: self.deprecation_callback,
+            deprecation_registry: self.deprecation_registry,
+            metrics: self.metrics,
+            connect_timeout: self.connect_timeout,
+            request_timeout: self.request_timeout,
+            check_scope_drift: self.check_scope_drift,
         })
     }
 }
@@ -535,7 +833,11 @@ mod tests {
         assert!(config.scopes().is_empty());
         assert!(config.host().is_none());
         assert!(config.user_agent_prefix().is_none());
+        assert!(config.api_features().is_empty());
+        assert!(config.default_page_size().is_none());
         assert!(config.old_api_secret_key().is_none());
+        assert!(config.connect_timeout().is_none());
+        assert!(config.request_timeout().is_none());
     }
 
     #[test]
@@ -658,4 +960,21 @@ mod tests {
 
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_default_page_size_is_clamped() {
+        let build = |page_size| {
+            ShopifyConfig::builder()
+                .api_key(ApiKey::new("key").unwrap())
+                .api_secret_key(ApiSecretKey::new("secret").unwrap())
+                .default_page_size(page_size)
+                .build()
+                .unwrap()
+        };
+
+        assert_eq!(build(250).default_page_size(), Some(250));
+        assert_eq!(build(100).default_page_size(), Some(100));
+        assert_eq!(build(1000).default_page_size(), Some(250));
+        assert_eq!(build(0).default_page_size(), Some(1));
+    }
 }
@@ -204,6 +204,29 @@ impl AsRef<str> for ShopDomain {
     }
 }
 
+/// Compares against the normalized form of the string, so both the short and
+/// full formats match.
+///
+/// ```rust
+/// use shopify_sdk::ShopDomain;
+///
+/// let domain = ShopDomain::new("my-store").unwrap();
+/// assert!(domain == "my-store");
+/// assert!(domain == "my-store.myshopify.com");
+/// assert!(domain != "other-store");
+/// ```
+impl PartialEq<str> for ShopDomain {
+    fn eq(&self, other: &str) -> bool {
+        Self::new(other).is_ok_and(|other| *self == other)
+    }
+}
+
+impl PartialEq<&str> for ShopDomain {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
 impl Serialize for ShopDomain {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -313,6 +336,27 @@ impl AsRef<str> for HostUrl {
     }
 }
 
+/// Compares against the normalized (trimmed) form of the string.
+///
+/// ```rust
+/// use shopify_sdk::HostUrl;
+///
+/// let url = HostUrl::new("https://myapp.example.com").unwrap();
+/// assert!(url == "https://myapp.example.com");
+/// assert!(url != "https://other.example.com");
+/// ```
+impl PartialEq<str> for HostUrl {
+    fn eq(&self, other: &str) -> bool {
+        Self::new(other).is_ok_and(|other| *self == other)
+    }
+}
+
+impl PartialEq<&str> for HostUrl {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -393,6 +437,28 @@ mod tests {
     }
 
     // ShopDomain serialization tests
+    #[test]
+    fn test_shop_domain_eq_str_uses_normalized_form() {
+        let domain = ShopDomain::new("example").unwrap();
+
+        assert!(domain == "example");
+        assert!(domain == "example.myshopify.com");
+        assert!(domain == " Example ");
+        assert_eq!(domain, *"example");
+        assert!(domain != "other-shop");
+        assert!(domain != "not a shop!");
+    }
+
+    #[test]
+    fn test_host_url_eq_str() {
+        let url = HostUrl::new("https://myapp.example.com").unwrap();
+
+        assert!(url == "https://myapp.example.com");
+        assert!(url == "  https://myapp.example.com  ");
+        assert!(url != "https://other.example.com");
+        assert!(url != "not a url");
+    }
+
     #[test]
     fn test_shop_domain_serializes_to_string() {
         let domain = ShopDomain::new("my-store").unwrap();
@@ -84,6 +84,9 @@ impl ApiVersion {
     /// This includes versions within Shopify's approximately 12-month support window.
     /// Versions are ordered from oldest to newest.
     ///
+    /// The list is compiled into the SDK. Use
+    /// [`fetch_supported`](Self::fetch_supported) to ask Shopify instead.
+    ///
     /// # Example
     ///
     /// ```rust
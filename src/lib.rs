@@ -351,7 +351,7 @@ pub mod rest;
 pub mod webhooks;
 
 // Re-export public types at crate root for convenience
-pub use auth::{AssociatedUser, AuthScopes, Session};
+pub use auth::{AssociatedUser, AuthScopes, ScopeDrift, Session, SessionBuilder};
 pub use config::{
     ApiKey, ApiSecretKey, ApiVersion, DeprecationCallback, HostUrl, ShopDomain, ShopifyConfig,
     ShopifyConfigBuilder,
@@ -360,9 +360,10 @@ pub use error::ConfigError;
 
 // Re-export HTTP client types
 pub use clients::{
-    ApiCallLimit, ApiDeprecationInfo, DataType, HttpClient, HttpError, HttpMethod, HttpRequest,
-    HttpRequestBuilder, HttpResponse, HttpResponseError, InvalidHttpRequestError,
-    MaxHttpRetriesExceededError, PaginationInfo,
+    ApiCallLimit, ApiDeprecationInfo, ClientFactory, DataType, ErrorKind, HttpClient, HttpError,
+    HttpMethod, HttpRequest, HttpRequestBuilder, HttpResponse, HttpResponseError,
+    InvalidHttpRequestError, MaxHttpRetriesExceededError, Metrics, NoopMetrics, Page,
+    PaginationInfo, RedirectPolicy,
 };
 
 // Re-export REST client types
@@ -383,7 +384,8 @@ pub use auth::oauth::{
 
 // Re-export REST resource types for convenience
 pub use rest::{
-    ResourceError, ResourceOperation, ResourcePath, ResourceResponse, RestResource, TrackedResource,
+    ResourceError, ResourceOperation, ResourcePath, ResourceResponse, RestResource,
+    SerializationMode, TrackedResource,
 };
 
 // Re-export webhook types for convenience
@@ -0,0 +1,394 @@
+//! Paced batch operations for REST resources.
+//!
+//! This module provides the types behind
+//! [`RestResource::create_all`](crate::rest::RestResource::create_all), which
+//! creates many resources while keeping under the store's call limit, and
+//! [`RestResource::all_by_ids`](crate::rest::RestResource::all_by_ids), which
+//! splits a long `ids` filter across several list requests.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use shopify_sdk::rest::{BatchOptions, RestResource};
+//! use shopify_sdk::rest::resources::v2026_04::Product;
+//!
+//! let products: Vec<Product> = titles
+//!     .iter()
+//!     .map(|title| Product {
+//!         title: Some(title.to_string()),
+//!         ..Default::default()
+//!     })
+//!     .collect();
+//!
+//! let results = Product::create_all(&client, products, BatchOptions::default()).await;
+//! for (title, result) in titles.iter().zip(&results) {
+//!     if let Err(error) = result {
+//!         eprintln!("Failed to create {title}: {error}");
+//!     }
+//! }
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::hash::Hash;
+use std::pin::Pin;
+use std::task::Poll;
+
+/// The most IDs sent in one request; Shopify returns at most 250 items per
+/// page.
+const MAX_IDS_PER_REQUEST: usize = 250;
+
+/// Options for [`RestResource::create_all`](crate::rest::RestResource::create_all).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BatchOptions {
+    /// Maximum number of requests in flight at once. Zero is treated as one.
+    pub concurrency: usize,
+
+    /// Whether to keep going after an item fails. When `false`, no new items
+    /// are started after the first failure; requests already in flight still
+    /// complete.
+    pub continue_on_error: bool,
+}
+
+impl Default for BatchOptions {
+    /// Four requests at a time, continuing past failures.
+    fn default() -> Self {
+        Self {
+            concurrency: 4,
+            continue_on_error: true,
+        }
+    }
+}
+
+/// Options for [`RestResource::all_by_ids`](crate::rest::RestResource::all_by_ids).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IdChunkOptions {
+    /// Maximum number of IDs per request. Values are clamped to 1–250, as a
+    /// single page holds at most 250 items.
+    pub chunk_size: usize,
+
+    /// Maximum number of requests in flight at once. Zero is treated as one.
+    pub concurrency: usize,
+}
+
+impl Default for IdChunkOptions {
+    /// 100 IDs per request, which keeps the URL well within Shopify's
+    /// length limit, four requests at a time.
+    fn default() -> Self {
+        Self {
+            chunk_size: 100,
+            concurrency: 4,
+        }
+    }
+}
+
+/// Fetches the items for `ids` in chunks, keeping up to
+/// `options.concurrency` requests in flight.
+///
+/// Duplicate IDs are requested once. The merged items follow the order of
+/// `ids`, with one item per ID; items whose ID wasn't requested are dropped.
+/// The first failed chunk fails the whole call, and no further chunks are
+/// started.
+pub(super) async fn run_id_chunks<Id, T, E, F, Fut>(
+    ids: &[Id],
+    options: IdChunkOptions,
+    fetch: F,
+    id_of: impl Fn(&T) -> Option<Id> + Send,
+) -> Result<Vec<T>, E>
+where
+    Id: Clone + Eq + Hash + Send + Sync,
+    T: Send,
+    E: Send,
+    F: FnMut(Vec<Id>) -> Fut + Send,
+    Fut: Future<Output = Result<Vec<T>, E>> + Send,
+{
+    let mut seen = HashSet::new();
+    let unique: Vec<Id> = ids.iter().filter(|id| seen.insert(*id)).cloned().collect();
+    let chunk_size = options.chunk_size.clamp(1, MAX_IDS_PER_REQUEST);
+    let chunks: Vec<Vec<Id>> = unique.chunks(chunk_size).map(<[Id]>::to_vec).collect();
+
+    let batch = BatchOptions {
+        concurrency: options.concurrency,
+        continue_on_error: false,
+    };
+    let results = run_batch(chunks, batch, fetch).await;
+
+    let positions: HashMap<&Id, usize> = unique
+        .iter()
+        .enumerate()
+        .map(|(position, id)| (id, position))
+        .collect();
+    let mut slots: Vec<Option<T>> = std::iter::repeat_with(|| None).take(unique.len()).collect();
+    for result in results {
+        for item in result? {
+            let position = id_of(&item).and_then(|id| positions.get(&id).copied());
+            if let Some(slot) = position.map(|position| &mut slots[position]) {
+                slot.get_or_insert(item);
+            }
+        }
+    }
+    Ok(slots.into_iter().flatten().collect())
+}
+
+/// Runs `operation` over `items`, keeping up to `options.concurrency` of them
+/// in flight.
+///
+/// Items are started in order, so the returned results always cover a prefix
+/// of `items`, in input order. The results are shorter than `items` only
+/// when the batch stopped early on an error.
+pub async fn run_batch<T, R, E, F, Fut>(
+    items: Vec<T>,
+    options: BatchOptions,
+    mut operation: F,
+) -> Vec<Result<R, E>>
+where
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = Result<R, E>>,
+{
+    let concurrency = options.concurrency.max(1);
+    let mut pending = items.into_iter();
+    let mut results: Vec<Option<Result<R, E>>> = Vec::with_capacity(pending.len());
+    let mut in_flight: Vec<(usize, Pin<Box<Fut>>)> = Vec::with_capacity(concurrency);
+    let mut stopped = false;
+
+    std::future::poll_fn(|cx| loop {
+        while !stopped && in_flight.len() < concurrency {
+            let Some(item) = pending.next() else { break };
+            in_flight.push((results.len(), Box::pin(operation(item))));
+            results.push(None);
+        }
+
+        let mut progressed = false;
+        let mut i = 0;
+        while i < in_flight.len() {
+            if let Poll::Ready(result) = in_flight[i].1.as_mut().poll(cx) {
+                let (index, _) = in_flight.swap_remove(i);
+                stopped |= result.is_err() && !options.continue_on_error;
+                results[index] = Some(result);
+                progressed = true;
+            } else {
+                i += 1;
+            }
+        }
+
+        if in_flight.is_empty() && (stopped || pending.len() == 0) {
+            return Poll::Ready(());
+        }
+        if !progressed {
+            return Poll::Pending;
+        }
+    })
+    .await;
+
+    results.into_iter().flatten().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::RateLimiter;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+
+    /// A server-side leaky bucket that rejects requests once full.
+    struct MockBucket {
+        capacity: f64,
+        leak_rate: f64,
+        state: Mutex<(f64, Instant)>,
+        peak: Mutex<f64>,
+    }
+
+    impl MockBucket {
+        fn new(capacity: u32, leak_rate: f64) -> Self {
+            Self {
+                capacity: f64::from(capacity),
+                leak_rate,
+                state: Mutex::new((0.0, Instant::now())),
+                peak: Mutex::new(0.0),
+            }
+        }
+
+        fn request(&self) -> Result<(), &'static str> {
+            let mut state = self.state.lock().unwrap();
+            let now = Instant::now();
+            let leaked = now.duration_since(state.1).as_secs_f64() * self.leak_rate;
+            state.0 = (state.0 - leaked).max(0.0);
+            state.1 = now;
+            if state.0 + 1.0 > self.capacity + 1e-9 {
+                return Err("429 Too Many Requests");
+            }
+            state.0 += 1.0;
+            let mut peak = self.peak.lock().unwrap();
+            *peak = peak.max(state.0);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_paces_requests_within_bucket() {
+        let bucket = MockBucket::new(3, 50.0);
+        let limiter = RateLimiter::new(3, 50.0);
+        let started = Instant::now();
+
+        let results = run_batch(
+            (0..12).collect(),
+            BatchOptions {
+                concurrency: 4,
+                continue_on_error: true,
+            },
+            |item: u32| {
+                let bucket = &bucket;
+                let limiter = &limiter;
+                async move {
+                    limiter.acquire().await;
+                    bucket.request().map(|()| item)
+                }
+            },
+        )
+        .await;
+
+        let created: Vec<u32> = results.into_iter().map(Result::unwrap).collect();
+        assert_eq!(created, (0..12).collect::<Vec<_>>());
+        assert!(*bucket.peak.lock().unwrap() <= 3.0);
+        // Nine requests beyond the bucket size drain at 50 per second
+        assert!(started.elapsed() >= Duration::from_millis(170));
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_batches_sharing_a_limiter_stay_within_bucket() {
+        let bucket = MockBucket::new(3, 50.0);
+        let limiter = RateLimiter::new(3, 50.0);
+        let shared = limiter.clone();
+        let options = BatchOptions {
+            concurrency: 3,
+            continue_on_error: true,
+        };
+        let batch = |limiter: &RateLimiter| {
+            run_batch((0..6).collect(), options, {
+                let bucket = &bucket;
+                let limiter = limiter.clone();
+                move |item: u32| {
+                    let limiter = limiter.clone();
+                    async move {
+                        limiter.acquire().await;
+                        bucket.request().map(|()| item)
+                    }
+                }
+            })
+        };
+
+        let (first, second) = tokio::join!(batch(&limiter), batch(&shared));
+
+        assert!(first.iter().chain(&second).all(Result::is_ok));
+        assert!(*bucket.peak.lock().unwrap() <= 3.0);
+    }
+
+    #[tokio::test]
+    async fn test_batch_stops_on_first_error() {
+        let calls = AtomicUsize::new(0);
+
+        let results = run_batch(
+            vec![1, 2, 3, 4, 5],
+            BatchOptions {
+                concurrency: 1,
+                continue_on_error: false,
+            },
+            |item: i32| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    if item == 2 {
+                        Err("invalid")
+                    } else {
+                        Ok(item)
+                    }
+                }
+            },
+        )
+        .await;
+
+        assert_eq!(results, vec![Ok(1), Err("invalid")]);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_batch_continues_past_errors_and_keeps_order() {
+        let results = run_batch(
+            vec![30_u64, 10, 20],
+            BatchOptions::default(),
+            |delay| async move {
+                tokio::time::sleep(Duration::from_millis(delay)).await;
+                if delay == 10 {
+                    Err(delay)
+                } else {
+                    Ok(delay)
+                }
+            },
+        )
+        .await;
+
+        assert_eq!(results, vec![Ok(30), Err(10), Ok(20)]);
+    }
+
+    #[tokio::test]
+    async fn test_id_chunks_merge_all_items_in_requested_order() {
+        let requests = Mutex::new(Vec::new());
+        // Request the IDs in descending order, with a duplicate
+        let mut ids: Vec<u64> = (1..=500).rev().collect();
+        ids.push(250);
+
+        let items = run_id_chunks(
+            &ids,
+            IdChunkOptions {
+                chunk_size: 40,
+                concurrency: 3,
+            },
+            |chunk: Vec<u64>| {
+                requests.lock().unwrap().push(chunk.len());
+                async move {
+                    // Shopify returns matches in its own order
+                    let mut found = chunk;
+                    found.sort_unstable();
+                    Ok::<_, &str>(found)
+                }
+            },
+            |item| Some(*item),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(items, (1..=500).rev().collect::<Vec<_>>());
+        let requests = requests.into_inner().unwrap();
+        assert_eq!(requests.len(), 13);
+        assert!(requests[..12].iter().all(|&size| size == 40));
+        assert_eq!(requests[12], 20);
+    }
+
+    #[tokio::test]
+    async fn test_id_chunks_fail_on_first_error() {
+        let calls = AtomicUsize::new(0);
+
+        let result = run_id_chunks(
+            &(1..=10).collect::<Vec<u64>>(),
+            IdChunkOptions {
+                chunk_size: 2,
+                concurrency: 1,
+            },
+            |chunk: Vec<u64>| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    if chunk.contains(&3) {
+                        Err("too long")
+                    } else {
+                        Ok(chunk)
+                    }
+                }
+            },
+            |item| Some(*item),
+        )
+        .await;
+
+        assert_eq!(result, Err("too long"));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
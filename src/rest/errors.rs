@@ -12,6 +12,11 @@
 //! - **422**: [`ResourceError::ValidationFailed`] - Validation errors from the API
 //! - **Other 4xx/5xx**: [`ResourceError::Http`] - Wrapped HTTP error
 //!
+//! [`ResourceError::Conflict`] is raised client-side when a conditional
+//! update finds that the resource changed on the server,
+//! [`ResourceError::InvalidInput`] when an operation is rejected before it is
+//! sent, and [`ResourceError::Read`] when content to upload can't be read.
+//!
 //! # Example
 //!
 //! ```rust,ignore
@@ -33,7 +38,7 @@
 
 use std::collections::HashMap;
 
-use crate::clients::{HttpError, RestError};
+use crate::clients::{ErrorKind, HttpError, RestError};
 use thiserror::Error;
 
 /// Error type for REST resource operations.
@@ -103,6 +108,52 @@ pub enum ResourceError {
         operation: &'static str,
     },
 
+    /// The resource was modified on the server since it was fetched.
+    ///
+    /// Returned by [`TrackedResource::save_if_unchanged`](crate::rest::TrackedResource::save_if_unchanged)
+    /// when the server's `updated_at` no longer matches the tracked copy.
+    /// No update is sent.
+    #[error("{resource} with id {id} was modified concurrently (expected updated_at {expected}, found {actual})")]
+    Conflict {
+        /// The type name of the resource.
+        resource: &'static str,
+        /// The ID of the resource.
+        id: String,
+        /// The `updated_at` of the tracked copy.
+        expected: String,
+        /// The `updated_at` currently on the server.
+        actual: String,
+    },
+
+    /// The operation can't be performed on the resource as given.
+    ///
+    /// Returned when a precondition is checked client-side, such as
+    /// [`SmartCollection::order`](crate::rest::resources::v2026_04::SmartCollection::order)
+    /// on a collection that isn't sorted manually. No request is sent.
+    #[error("Invalid {resource}::{operation}: {message}")]
+    InvalidInput {
+        /// The type name of the resource.
+        resource: &'static str,
+        /// The operation being attempted.
+        operation: &'static str,
+        /// Why the operation was rejected.
+        message: String,
+    },
+
+    /// Reading the content of an upload failed.
+    ///
+    /// Returned when the reader passed to an upload such as
+    /// [`Asset::upload_stream`](crate::rest::resources::v2026_04::Asset::upload_stream)
+    /// fails. No request is sent.
+    #[error("Failed to read {resource} content: {source}")]
+    Read {
+        /// The type name of the resource being uploaded.
+        resource: &'static str,
+        /// The error returned by the reader.
+        #[source]
+        source: std::io::Error,
+    },
+
     /// An HTTP-level error occurred.
     ///
     /// This variant wraps [`HttpError`] for errors that don't map to
@@ -187,11 +238,44 @@ impl ResourceError {
     pub fn request_id(&self) -> Option<&str> {
         match self {
             Self::ValidationFailed { request_id, .. } => request_id.as_deref(),
-            Self::Http(HttpError::Response(e)) => e.error_reference.as_deref(),
-            Self::Http(HttpError::MaxRetries(e)) => e.error_reference.as_deref(),
+            Self::Http(e) | Self::Rest(RestError::Http(e)) => e.request_id(),
             _ => None,
         }
     }
+
+    /// Classifies this error into an [`ErrorKind`].
+    ///
+    /// Not-found, validation, path, and read errors are [`ErrorKind::Client`].
+    /// Wrapped HTTP errors are classified by [`HttpError::kind`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::rest::ResourceError;
+    /// use shopify_sdk::ErrorKind;
+    /// use serde_json::json;
+    ///
+    /// let error = ResourceError::from_http_response(429, &json!({}), "Product", None, None);
+    /// assert_eq!(error.kind(), ErrorKind::RateLimited);
+    ///
+    /// let error = ResourceError::from_http_response(404, &json!({}), "Product", Some("1"), None);
+    /// assert_eq!(error.kind(), ErrorKind::Client);
+    /// ```
+    #[must_use]
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            Self::NotFound { .. }
+            | Self::ValidationFailed { .. }
+            | Self::PathResolutionFailed { .. }
+            | Self::Conflict { .. }
+            | Self::InvalidInput { .. }
+            | Self::Read { .. }
+            | Self::Rest(RestError::RestApiDisabled | RestError::InvalidPath { .. }) => {
+                ErrorKind::Client
+            }
+            Self::Http(e) | Self::Rest(RestError::Http(e)) => e.kind(),
+        }
+    }
 }
 
 /// Parses validation errors from an API response body.
@@ -382,6 +466,15 @@ mod tests {
         };
         let _ = path_error;
 
+        // Conflict
+        let conflict_error: &dyn std::error::Error = &ResourceError::Conflict {
+            resource: "Product",
+            id: "123".to_string(),
+            expected: "2024-01-01T00:00:00+00:00".to_string(),
+            actual: "2024-01-02T00:00:00+00:00".to_string(),
+        };
+        let _ = conflict_error;
+
         // Http
         let http_error: &dyn std::error::Error =
             &ResourceError::Http(HttpError::Response(crate::clients::HttpResponseError {
@@ -494,4 +587,54 @@ mod tests {
         };
         assert_eq!(error.request_id(), None);
     }
+
+    #[test]
+    fn test_kind_maps_resource_errors_to_client() {
+        let error = ResourceError::NotFound {
+            resource: "Product",
+            id: "1".to_string(),
+        };
+        assert_eq!(error.kind(), ErrorKind::Client);
+
+        let error = ResourceError::ValidationFailed {
+            errors: HashMap::new(),
+            request_id: None,
+        };
+        assert_eq!(error.kind(), ErrorKind::Client);
+
+        let error = ResourceError::PathResolutionFailed {
+            resource: "Product",
+            operation: "find",
+        };
+        assert_eq!(error.kind(), ErrorKind::Client);
+
+        let error = ResourceError::Conflict {
+            resource: "Product",
+            id: "1".to_string(),
+            expected: "unknown".to_string(),
+            actual: "unknown".to_string(),
+        };
+        assert_eq!(error.kind(), ErrorKind::Client);
+
+        let error = ResourceError::Rest(RestError::RestApiDisabled);
+        assert_eq!(error.kind(), ErrorKind::Client);
+    }
+
+    #[test]
+    fn test_kind_delegates_to_wrapped_http_error() {
+        let error = ResourceError::from_http_response(401, &json!({}), "Product", None, None);
+        assert_eq!(error.kind(), ErrorKind::Auth);
+
+        let error = ResourceError::from_http_response(502, &json!({}), "Product", None, None);
+        assert_eq!(error.kind(), ErrorKind::Retryable);
+
+        let error = ResourceError::Rest(RestError::Http(HttpError::Response(
+            crate::clients::HttpResponseError {
+                code: 429,
+                message: "throttled".to_string(),
+                error_reference: None,
+            },
+        )));
+        assert_eq!(error.kind(), ErrorKind::RateLimited);
+    }
 }
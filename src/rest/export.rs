@@ -0,0 +1,142 @@
+//! Streaming JSONL export for REST resources.
+//!
+//! This module provides the types behind
+//! [`RestResource::export_jsonl`](crate::rest::RestResource::export_jsonl),
+//! which pages through a list endpoint and writes each resource as one line
+//! of JSON, so large collections can be dumped without holding them in memory.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use shopify_sdk::rest::RestResource;
+//! use shopify_sdk::rest::resources::v2026_04::Product;
+//!
+//! let file = tokio::fs::File::create("products.jsonl").await?;
+//! let summary = Product::export_jsonl(&client, None, file).await?;
+//!
+//! println!("Wrote {} products over {} pages", summary.records, summary.pages);
+//! if let Some(error) = &summary.error {
+//!     eprintln!("Export stopped early: {error}");
+//! }
+//! ```
+
+use serde::Serialize;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::rest::ResourceError;
+
+/// The outcome of a JSONL export.
+///
+/// API failures do not abort the export with an `Err`; everything written up
+/// to that point is kept and the failure is reported here instead.
+#[derive(Debug, Default)]
+pub struct ExportSummary {
+    /// Number of pages successfully fetched and written.
+    pub pages: usize,
+
+    /// Number of resources written.
+    pub records: usize,
+
+    /// Number of resources that could not be serialized and were skipped.
+    pub skipped: usize,
+
+    /// The error that ended the export early, if any.
+    ///
+    /// A failed page ends the export because the cursor for the following
+    /// page is only known from a successful response.
+    pub error: Option<ResourceError>,
+}
+
+impl ExportSummary {
+    /// Returns `true` if every page was fetched and every resource written.
+    #[must_use]
+    pub const fn is_complete(&self) -> bool {
+        self.error.is_none() && self.skipped == 0
+    }
+}
+
+/// Writes one page of resources as JSON lines and flushes the writer.
+///
+/// Resources that fail to serialize are counted in `summary.skipped`.
+///
+/// # Errors
+///
+/// Returns an I/O error if writing or flushing fails.
+pub async fn write_jsonl_page<T, W>(
+    writer: &mut W,
+    items: &[T],
+    summary: &mut ExportSummary,
+) -> std::io::Result<()>
+where
+    T: Serialize + Sync,
+    W: AsyncWrite + Unpin + Send,
+{
+    for item in items {
+        match serde_json::to_vec(item) {
+            Ok(mut line) => {
+                line.push(b'\n');
+                writer.write_all(&line).await?;
+                summary.records += 1;
+            }
+            Err(e) => {
+                tracing::warn!("Skipping resource that failed to serialize: {e}");
+                summary.skipped += 1;
+            }
+        }
+    }
+
+    writer.flush().await?;
+    summary.pages += 1;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_write_jsonl_page_writes_one_line_per_resource_across_pages() {
+        let mut buffer: Vec<u8> = Vec::new();
+        let mut summary = ExportSummary::default();
+
+        let first_page = vec![
+            json!({"id": 1, "title": "A"}),
+            json!({"id": 2, "title": "B"}),
+        ];
+        let second_page = vec![json!({"id": 3, "title": "C"})];
+
+        write_jsonl_page(&mut buffer, &first_page, &mut summary)
+            .await
+            .unwrap();
+        write_jsonl_page(&mut buffer, &second_page, &mut summary)
+            .await
+            .unwrap();
+
+        let output = String::from_utf8(buffer).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(summary.pages, 2);
+        assert_eq!(summary.records, 3);
+        assert!(summary.is_complete());
+
+        let third: serde_json::Value = serde_json::from_str(lines[2]).unwrap();
+        assert_eq!(third["title"], "C");
+    }
+
+    #[test]
+    fn test_export_summary_with_error_is_incomplete() {
+        let summary = ExportSummary {
+            pages: 1,
+            records: 50,
+            skipped: 0,
+            error: Some(ResourceError::PathResolutionFailed {
+                resource: "Product",
+                operation: "all",
+            }),
+        };
+
+        assert!(!summary.is_complete());
+    }
+}
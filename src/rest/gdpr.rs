@@ -0,0 +1,307 @@
+//! Helpers for answering GDPR customer data requests.
+//!
+//! When a customer asks for their data, Shopify sends the
+//! `customers/data_request` webhook and the app has to hand the store owner
+//! everything it holds about that customer. [`assemble_customer_data`]
+//! gathers the customer record, their addresses and their full order history
+//! into a single serializable [`CustomerDataExport`].
+//!
+//! Records are kept exactly as Shopify returns them, so the export includes
+//! read-only fields such as `id`, `name` and `order_number` as well as fields
+//! the SDK doesn't model.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use shopify_sdk::rest::gdpr;
+//!
+//! let export = gdpr::assemble_customer_data(&client, 207119551).await?;
+//! let json = serde_json::to_string_pretty(&export)?;
+//! ```
+
+use std::collections::HashMap;
+use std::future::Future;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::clients::{HttpError, HttpResponse, RestClient, RestError};
+use crate::rest::resources::v2026_04::{Customer, Order};
+use crate::rest::{ResourceError, ResourceResponse, RestResource};
+
+/// Page size used when collecting orders and addresses.
+const PAGE_SIZE: &str = "250";
+
+/// Everything the SDK can fetch about a single customer.
+///
+/// Each record is the JSON object from Shopify's response, unchanged, so no
+/// field is lost to the typed resources' request representation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CustomerDataExport {
+    /// The customer record.
+    pub customer: Value,
+
+    /// Every address saved for the customer.
+    pub addresses: Vec<Value>,
+
+    /// Every order placed by the customer, in any status.
+    pub orders: Vec<Value>,
+}
+
+/// Fetches a customer with their addresses and orders.
+///
+/// Orders are requested with `status=any` so that closed and cancelled
+/// orders are included, and both orders and addresses are paged through
+/// until the last page.
+///
+/// # Arguments
+///
+/// * `client` - The REST client to use for the requests
+/// * `customer_id` - The ID of the customer to export
+///
+/// # Errors
+///
+/// Returns [`ResourceError::NotFound`] if the customer does not exist.
+/// Returns [`ResourceError::Http`] or [`ResourceError::Rest`] if any request
+/// fails; no partial export is returned.
+pub async fn assemble_customer_data(
+    client: &RestClient,
+    customer_id: u64,
+) -> Result<CustomerDataExport, ResourceError> {
+    let customer = fetch_customer(client, customer_id).await?;
+
+    let addresses_path = format!("customers/{customer_id}/addresses");
+    let addresses = collect_pages("CustomerAddress", "addresses", HashMap::new(), |query| {
+        client.get(&addresses_path, Some(query))
+    })
+    .await?;
+
+    let orders_path = format!("customers/{customer_id}/orders");
+    let orders_query = HashMap::from([("status".to_string(), "any".to_string())]);
+    let orders = collect_pages(Order::NAME, "orders", orders_query, |query| {
+        client.get(&orders_path, Some(query))
+    })
+    .await?;
+
+    Ok(CustomerDataExport {
+        customer,
+        addresses,
+        orders,
+    })
+}
+
+/// Fetches the customer record as returned by Shopify.
+async fn fetch_customer(client: &RestClient, customer_id: u64) -> Result<Value, ResourceError> {
+    let not_found = || ResourceError::NotFound {
+        resource: Customer::NAME,
+        id: customer_id.to_string(),
+    };
+    let response = match client.get(&format!("customers/{customer_id}"), None).await {
+        Ok(response) => response,
+        Err(RestError::Http(HttpError::Response(error))) if error.code == 404 => {
+            return Err(not_found());
+        }
+        Err(error) => return Err(error.into()),
+    };
+    if !response.is_ok() {
+        return Err(ResourceError::from_http_response(
+            response.code,
+            &response.body,
+            Customer::NAME,
+            Some(&customer_id.to_string()),
+            response.request_id(),
+        ));
+    }
+
+    Ok(ResourceResponse::<Value>::from_http_response(response, "customer")?.into_inner())
+}
+
+/// Pages through a list endpoint, collecting the items under `key`.
+///
+/// `resource` names the resource in errors.
+///
+/// `filters` are only sent with the first request, since Shopify rejects
+/// them alongside `page_info`.
+async fn collect_pages<T, E, F, Fut>(
+    resource: &'static str,
+    key: &str,
+    filters: HashMap<String, String>,
+    mut fetch: F,
+) -> Result<Vec<T>, ResourceError>
+where
+    T: DeserializeOwned,
+    E: Into<ResourceError>,
+    F: FnMut(HashMap<String, String>) -> Fut,
+    Fut: Future<Output = Result<HttpResponse, E>>,
+{
+    let mut items = Vec::new();
+    let mut query = filters;
+    query.insert("limit".to_string(), PAGE_SIZE.to_string());
+
+    loop {
+        let response = fetch(query).await.map_err(Into::into)?;
+        if !response.is_ok() {
+            return Err(ResourceError::from_http_response(
+                response.code,
+                &response.body,
+                resource,
+                None,
+                response.request_id(),
+            ));
+        }
+
+        let page: ResourceResponse<Vec<T>> = ResourceResponse::from_http_response(response, key)?;
+        let next_page_info = page.next_page_info().map(ToString::to_string);
+        items.extend(page.into_inner());
+
+        let Some(page_info) = next_page_info else {
+            return Ok(items);
+        };
+        query = HashMap::from([
+            ("limit".to_string(), PAGE_SIZE.to_string()),
+            ("page_info".to_string(), page_info),
+        ]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ApiVersion;
+    use serde_json::json;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn response(body: Value, next_page_info: Option<&str>) -> HttpResponse {
+        let mut headers = HashMap::new();
+        if let Some(page_info) = next_page_info {
+            headers.insert(
+                "link".to_string(),
+                vec![format!(
+                    "<https://shop.myshopify.com/admin/api/2026-04/customers/1/orders.json?page_info={page_info}>; rel=\"next\""
+                )],
+            );
+        }
+        HttpResponse::new(200, headers, body)
+    }
+
+    #[tokio::test]
+    async fn test_collect_pages_follows_next_page_info() {
+        let mut queries = Vec::new();
+        let mut responses = vec![
+            response(
+                json!({ "orders": [{ "id": 1, "name": "#1001" }] }),
+                Some("next-token"),
+            ),
+            response(json!({ "orders": [{ "id": 2, "name": "#1002" }] }), None),
+        ]
+        .into_iter();
+
+        let filters = HashMap::from([("status".to_string(), "any".to_string())]);
+        let orders: Vec<Order> = collect_pages(Order::NAME, "orders", filters, |query| {
+            queries.push(query);
+            let next = responses.next().unwrap();
+            async move { Ok::<_, RestError>(next) }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            orders.iter().map(|o| o.id).collect::<Vec<_>>(),
+            [Some(1), Some(2)]
+        );
+        assert_eq!(queries[0]["status"], "any");
+        assert_eq!(queries[0]["limit"], "250");
+        assert_eq!(queries[1]["page_info"], "next-token");
+        assert!(!queries[1].contains_key("status"));
+    }
+
+    #[tokio::test]
+    async fn test_collect_pages_maps_error_responses() {
+        let result: Result<Vec<Order>, _> =
+            collect_pages(Order::NAME, "orders", HashMap::new(), |_| async {
+                Ok::<_, RestError>(HttpResponse::new(
+                    404,
+                    HashMap::new(),
+                    json!({ "errors": "Not Found" }),
+                ))
+            })
+            .await;
+
+        assert!(matches!(result, Err(ResourceError::NotFound { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_assemble_customer_data_keeps_read_only_fields() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/admin/api/2026-04/customers/207119551.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "customer": {
+                    "id": 207119551,
+                    "email": "bob.norman@example.com",
+                    "first_name": "Bob",
+                    "created_at": "2024-01-01T12:00:00-05:00",
+                    "admin_graphql_api_id": "gid://shopify/Customer/207119551"
+                }
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(
+                "/admin/api/2026-04/customers/207119551/addresses.json",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "addresses": [{ "id": 207119551, "city": "Louisville", "default": true }]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/admin/api/2026-04/customers/207119551/orders.json"))
+            .and(query_param("status", "any"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "orders": [
+                    { "id": 450789469, "name": "#1001", "order_number": 1001 },
+                    { "id": 450789470, "name": "#1002", "order_number": 1002 }
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = RestClient::for_mock_server(&server.uri(), ApiVersion::V2026_04);
+        let export = assemble_customer_data(&client, 207119551).await.unwrap();
+        let json = serde_json::to_value(&export).unwrap();
+
+        assert_eq!(json["customer"]["id"], 207119551);
+        assert_eq!(json["customer"]["created_at"], "2024-01-01T12:00:00-05:00");
+        assert_eq!(
+            json["customer"]["admin_graphql_api_id"],
+            "gid://shopify/Customer/207119551"
+        );
+        assert_eq!(json["addresses"][0]["id"], 207119551);
+        assert_eq!(json["orders"][0]["id"], 450789469);
+        assert_eq!(json["orders"][1]["name"], "#1002");
+        assert_eq!(json["orders"][1]["order_number"], 1002);
+    }
+
+    #[tokio::test]
+    async fn test_assemble_customer_data_reports_missing_customer() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/admin/api/2026-04/customers/1.json"))
+            .respond_with(
+                ResponseTemplate::new(404).set_body_json(json!({ "errors": "Not Found" })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = RestClient::for_mock_server(&server.uri(), ApiVersion::V2026_04);
+        let result = assemble_customer_data(&client, 1).await;
+
+        assert!(matches!(
+            result,
+            Err(ResourceError::NotFound { resource: "Customer", ref id }) if id == "1"
+        ));
+    }
+}
@@ -8,6 +8,8 @@
 //! - **[`TrackedResource<T>`]**: Dirty tracking for efficient partial updates
 //! - **Path building**: Multiple path support for nested resources
 //! - **[`ResourceError`]**: Semantic error types for resource operations
+//! - **[`ExportSummary`]**: Outcome of streaming a collection to JSON lines
+//! - **[`gdpr`]**: Assembling customer data exports for GDPR data requests
 //!
 //! # Overview
 //!
@@ -71,21 +73,29 @@
 //! - [`ResourcePath`] and [`ResourceOperation`]: Path building infrastructure
 //! - [`ResourceResponse`]: Response wrapper with Deref for transparent data access
 //! - [`TrackedResource`]: Dirty tracking wrapper for partial updates
+//! - [`BatchOptions`]: Options for paced batch creates with [`RestResource::create_all`]
+//! - [`IdChunkOptions`]: Options for splitting `ids` filters with [`RestResource::all_by_ids`]
 //! - [`RestResource`]: Trait defining CRUD operations for resources
 //! - [`ReadOnlyResource`]: Marker trait for read-only resources
 //! - [`resources`]: Version-specific resource implementations (e.g., Product, Order)
 
+mod batch;
 mod errors;
+mod export;
+pub mod gdpr;
 mod path;
 mod resource;
 mod response;
+mod tags;
 mod tracking;
 
 pub mod resources;
 
 // Public exports
+pub use batch::{BatchOptions, IdChunkOptions};
 pub use errors::ResourceError;
+pub use export::ExportSummary;
 pub use path::{build_path, get_path, ResourceOperation, ResourcePath};
 pub use resource::{ReadOnlyResource, RestResource};
 pub use response::ResourceResponse;
-pub use tracking::TrackedResource;
+pub use tracking::{SerializationMode, TrackedResource};
@@ -58,6 +58,65 @@
 //! let products = Product::all(&client, None).await?;
 //! ```
 //!
+//! # Custom Resources
+//!
+//! The trait and path types are public, so apps can describe their own
+//! endpoints (for example, app-specific Admin endpoints that have no built-in
+//! resource) and get the same `find()`/`all()` behavior. Only the paths for
+//! the supported operations need to be listed; everything else resolves to
+//! [`ResourceError::PathResolutionFailed`] without sending a request.
+//!
+//! ```rust
+//! use serde::{Deserialize, Serialize};
+//! use shopify_sdk::rest::{
+//!     get_path, ReadOnlyResource, ResourceOperation, ResourcePath, RestResource,
+//! };
+//! use shopify_sdk::HttpMethod;
+//!
+//! #[derive(Debug, Clone, Serialize, Deserialize)]
+//! struct LoyaltyMember {
+//!     id: Option<u64>,
+//!     points: u32,
+//! }
+//!
+//! impl RestResource for LoyaltyMember {
+//!     type Id = u64;
+//!     type FindParams = ();
+//!     type AllParams = ();
+//!     type CountParams = ();
+//!
+//!     const NAME: &'static str = "LoyaltyMember";
+//!     const PLURAL: &'static str = "members";
+//!     const PATHS: &'static [ResourcePath] = &[
+//!         ResourcePath::new(HttpMethod::Get, ResourceOperation::Find, &["id"], "members/{id}"),
+//!         ResourcePath::new(HttpMethod::Get, ResourceOperation::All, &[], "members"),
+//!     ];
+//!     // Every path lives under this prefix
+//!     const PREFIX: Option<&'static str> = Some("apps/loyalty");
+//!
+//!     fn get_id(&self) -> Option<Self::Id> {
+//!         self.id
+//!     }
+//!
+//!     // Single responses are `{"member": {...}}`, lists are `{"members": [...]}`
+//!     fn resource_key() -> String {
+//!         "member".to_string()
+//!     }
+//! }
+//!
+//! impl ReadOnlyResource for LoyaltyMember {}
+//!
+//! // LoyaltyMember::find(&client, 42, None) requests `apps/loyalty/members/42.json`
+//! assert_eq!(
+//!     get_path(LoyaltyMember::PATHS, ResourceOperation::Find, &["id"]).unwrap().template,
+//!     "members/{id}"
+//! );
+//! assert_eq!(
+//!     LoyaltyMember::available_operations(),
+//!     vec![ResourceOperation::Find, ResourceOperation::All]
+//! );
+//! ```
+//!
 //! # Read-Only Resources
 //!
 //! For resources that only support read operations (like Event, Policy, Location),
@@ -72,11 +131,19 @@
 
 use std::collections::HashMap;
 use std::fmt::Display;
+use std::hash::Hash;
 
+use chrono::{DateTime, Utc};
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::Value;
+use tokio::io::AsyncWrite;
 
-use crate::clients::RestClient;
+use crate::clients::{
+    DeprecationNotice, DeprecationSource, HttpError, HttpResponse, RestClient, RestError,
+};
+use crate::config::ShopDomain;
+use crate::rest::batch::{run_batch, run_id_chunks, BatchOptions, IdChunkOptions};
+use crate::rest::export::{write_jsonl_page, ExportSummary};
 use crate::rest::{
     build_path, get_path, ResourceError, ResourceOperation, ResourcePath, ResourceResponse,
 };
@@ -185,17 +252,126 @@ pub trait RestResource: Serialize + DeserializeOwned + Clone + Send + Sync + Siz
     /// Override this for resources that always appear under a parent path.
     const PREFIX: Option<&'static str> = None;
 
+    /// Why the resource is deprecated, if it is.
+    ///
+    /// Operations on a deprecated resource log a warning and record a
+    /// [`DeprecationSource::Resource`](crate::clients::DeprecationSource::Resource)
+    /// notice in the client's
+    /// [`DeprecationRegistry`](crate::clients::DeprecationRegistry).
+    const DEPRECATION: Option<&'static str> = None;
+
     /// Returns the resource's ID if it exists.
     ///
     /// Returns `None` for new resources that haven't been saved yet.
     fn get_id(&self) -> Option<Self::Id>;
 
+    /// Returns when the resource was last updated on the server, if known.
+    ///
+    /// Used by [`TrackedResource::save_if_unchanged`](crate::rest::TrackedResource::save_if_unchanged)
+    /// to detect concurrent modifications. Resources with an `updated_at`
+    /// field override this; the default returns `None`.
+    fn updated_at(&self) -> Option<DateTime<Utc>> {
+        None
+    }
+
+    /// Returns the URL of this resource in the merchant's Shopify admin.
+    ///
+    /// The URL is `https://admin.shopify.com/store/{shop}/{PLURAL}/{id}`,
+    /// which suits top-level resources such as products, orders and
+    /// customers. Returns `None` for resources that haven't been saved yet.
+    ///
+    /// Resources whose admin page lives elsewhere override this, such as
+    /// collections (`/collections/{id}`) and price rules
+    /// (`/discounts/{id}`). Resources with no admin page of their own, such
+    /// as refunds and transactions, return `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::rest::RestResource;
+    /// use shopify_sdk::rest::resources::v2026_04::Product;
+    /// use shopify_sdk::ShopDomain;
+    ///
+    /// let shop = ShopDomain::new("my-store").unwrap();
+    /// let product = Product {
+    ///     id: Some(632910392),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     product.admin_url(&shop).as_deref(),
+    ///     Some("https://admin.shopify.com/store/my-store/products/632910392")
+    /// );
+    /// ```
+    #[must_use]
+    fn admin_url(&self, shop: &ShopDomain) -> Option<String> {
+        self.get_id().map(|id| {
+            format!(
+                "https://admin.shopify.com/store/{}/{}/{id}",
+                shop.shop_name(),
+                Self::PLURAL
+            )
+        })
+    }
+
     /// Returns the lowercase key used in JSON request/response bodies.
     #[must_use]
     fn resource_key() -> String {
         Self::NAME.to_lowercase()
     }
 
+    /// Returns the IDs required to resolve a path for the given operation.
+    ///
+    /// When several paths support the operation, the one needing the fewest
+    /// IDs is used, so the result is the minimum set of IDs that must be
+    /// supplied. Returns `None` if the resource does not support the
+    /// operation at all.
+    ///
+    /// Useful for diagnosing [`ResourceError::PathResolutionFailed`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use shopify_sdk::rest::{ResourceOperation, RestResource};
+    ///
+    /// assert_eq!(
+    ///     FulfillmentOrder::required_params(ResourceOperation::All),
+    ///     Some(&["order_id"][..])
+    /// );
+    /// ```
+    #[must_use]
+    fn required_params(operation: ResourceOperation) -> Option<&'static [&'static str]> {
+        Self::PATHS
+            .iter()
+            .filter(|path| path.operation == operation)
+            .min_by_key(|path| path.id_count())
+            .map(|path| path.ids)
+    }
+
+    /// Returns the operations this resource has at least one path for.
+    ///
+    /// Operations are listed in the order they first appear in
+    /// [`PATHS`](Self::PATHS), without duplicates.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use shopify_sdk::rest::{ResourceOperation, RestResource};
+    ///
+    /// let operations = FulfillmentOrder::available_operations();
+    /// assert!(!operations.contains(&ResourceOperation::Create));
+    /// ```
+    #[must_use]
+    fn available_operations() -> Vec<ResourceOperation> {
+        let mut operations = Vec::new();
+        for path in Self::PATHS {
+            if !operations.contains(&path.operation) {
+                operations.push(path.operation);
+            }
+        }
+        operations
+    }
+
     /// Finds a single resource by ID.
     ///
     /// # Arguments
@@ -220,6 +396,7 @@ pub trait RestResource: Serialize + DeserializeOwned + Clone + Send + Sync + Siz
         id: Self::Id,
         params: Option<Self::FindParams>,
     ) -> Result<ResourceResponse<Self>, ResourceError> {
+        record_deprecated_use::<Self>(client);
         // Build the path
         let mut ids: HashMap<&str, String> = HashMap::new();
         ids.insert("id", id.to_string());
@@ -260,6 +437,48 @@ pub trait RestResource: Serialize + DeserializeOwned + Clone + Send + Sync + Siz
         ResourceResponse::from_http_response(response, &key)
     }
 
+    /// Checks whether a resource with the given ID exists.
+    ///
+    /// Shopify doesn't reliably support `HEAD`, so this sends a `GET` with
+    /// `fields=id` to keep the response small, and maps a 404 to `false`.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The REST client to use for the request
+    /// * `id` - The resource ID to check
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResourceError::PathResolutionFailed`] if no valid path matches.
+    /// Returns any error other than a 404 from the request.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// if !Product::exists(&client, 123).await? {
+    ///     println!("Product 123 was deleted");
+    /// }
+    /// ```
+    async fn exists(client: &RestClient, id: Self::Id) -> Result<bool, ResourceError> {
+        record_deprecated_use::<Self>(client);
+        let mut ids: HashMap<&str, String> = HashMap::new();
+        ids.insert("id", id.to_string());
+
+        let available_ids: Vec<&str> = ids.keys().copied().collect();
+        let path = get_path(Self::PATHS, ResourceOperation::Find, &available_ids).ok_or(
+            ResourceError::PathResolutionFailed {
+                resource: Self::NAME,
+                operation: "exists",
+            },
+        )?;
+
+        let url = build_path(path.template, &ids);
+        let full_path = Self::build_full_path(&url);
+
+        let result = client.get(&full_path, Some(exists_query())).await;
+        exists_from_result(result, Self::NAME, &id.to_string())
+    }
+
     /// Lists all resources matching the given parameters.
     ///
     /// Returns a paginated response. Use `has_next_page()` and `next_page_info()`
@@ -270,9 +489,22 @@ pub trait RestResource: Serialize + DeserializeOwned + Clone + Send + Sync + Siz
     /// * `client` - The REST client to use for the request
     /// * `params` - Optional parameters for filtering/pagination
     ///
+    /// A successful response with an empty array is an empty list, not an
+    /// error.
+    ///
+    /// # Long `ids` filters
+    ///
+    /// `params` is sent as a single request, whatever its size. An `ids`
+    /// filter with hundreds of IDs makes a URL Shopify rejects, and a filter
+    /// longer than the page size is cut to one page. To fetch more IDs than
+    /// fit in one request, use [`all_by_ids`](Self::all_by_ids), which splits
+    /// them across several requests.
+    ///
     /// # Errors
     ///
     /// Returns [`ResourceError::PathResolutionFailed`] if no valid path matches.
+    /// Returns an HTTP error for any 4xx or 5xx response, including the one
+    /// Shopify sends for an over-long URL.
     ///
     /// # Example
     ///
@@ -290,6 +522,7 @@ pub trait RestResource: Serialize + DeserializeOwned + Clone + Send + Sync + Siz
         client: &RestClient,
         params: Option<Self::AllParams>,
     ) -> Result<ResourceResponse<Vec<Self>>, ResourceError> {
+        record_deprecated_use::<Self>(client);
         let path = get_path(Self::PATHS, ResourceOperation::All, &[]).ok_or(
             ResourceError::PathResolutionFailed {
                 resource: Self::NAME,
@@ -301,32 +534,17 @@ pub trait RestResource: Serialize + DeserializeOwned + Clone + Send + Sync + Siz
         let full_path = Self::build_full_path(url);
 
         // Build query params from AllParams
-        let query = params
-            .map(|p| serialize_to_query(&p))
-            .transpose()?
-            .filter(|q| !q.is_empty());
+        let query = list_query(params, client.default_page_size())?;
 
-        // Make the request
-        let response = client.get(&full_path, query).await?;
-
-        // Check for error status codes
-        if !response.is_ok() {
-            return Err(ResourceError::from_http_response(
-                response.code,
-                &response.body,
-                Self::NAME,
-                None,
-                response.request_id(),
-            ));
-        }
-
-        // Parse the response
-        ResourceResponse::from_http_response(response, Self::PLURAL)
+        let result = client.get(&full_path, query).await;
+        list_from_result(result, Self::NAME, Self::PLURAL, None)
     }
 
     /// Lists resources with a specific parent resource ID.
     ///
     /// For nested resources that require a parent ID (e.g., variants under products).
+    /// A parent with no children gives an empty list; a parent that doesn't
+    /// exist gives [`ResourceError::NotFound`] naming the parent.
     ///
     /// # Arguments
     ///
@@ -338,12 +556,26 @@ pub trait RestResource: Serialize + DeserializeOwned + Clone + Send + Sync + Siz
     /// # Errors
     ///
     /// Returns [`ResourceError::PathResolutionFailed`] if no valid path matches.
+    /// Returns [`ResourceError::NotFound`] if the parent doesn't exist (HTTP 404).
+    /// Returns an HTTP error for any other 4xx or 5xx response.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// match Variant::all_with_parent(&client, "product_id", 123, None).await {
+    ///     Ok(variants) if variants.is_empty() => println!("No variants"),
+    ///     Ok(variants) => println!("{} variants", variants.len()),
+    ///     Err(ResourceError::NotFound { resource, id }) => println!("{resource} {id} is gone"),
+    ///     Err(e) => return Err(e.into()),
+    /// }
+    /// ```
     async fn all_with_parent<ParentId: Display + Send>(
         client: &RestClient,
         parent_id_name: &str,
         parent_id: ParentId,
         params: Option<Self::AllParams>,
     ) -> Result<ResourceResponse<Vec<Self>>, ResourceError> {
+        record_deprecated_use::<Self>(client);
         let mut ids: HashMap<&str, String> = HashMap::new();
         ids.insert(parent_id_name, parent_id.to_string());
 
@@ -358,24 +590,12 @@ pub trait RestResource: Serialize + DeserializeOwned + Clone + Send + Sync + Siz
         let url = build_path(path.template, &ids);
         let full_path = Self::build_full_path(&url);
 
-        let query = params
-            .map(|p| serialize_to_query(&p))
-            .transpose()?
-            .filter(|q| !q.is_empty());
-
-        let response = client.get(&full_path, query).await?;
-
-        if !response.is_ok() {
-            return Err(ResourceError::from_http_response(
-                response.code,
-                &response.body,
-                Self::NAME,
-                None,
-                response.request_id(),
-            ));
-        }
+        let query = list_query(params, client.default_page_size())?;
 
-        ResourceResponse::from_http_response(response, Self::PLURAL)
+        let result = client.get(&full_path, query).await;
+        let parent_id = parent_id.to_string();
+        let parent = (parent_id_name, parent_id.as_str());
+        list_from_result(result, Self::NAME, Self::PLURAL, Some(parent))
     }
 
     /// Saves the resource (create or update).
@@ -411,6 +631,7 @@ pub trait RestResource: Serialize + DeserializeOwned + Clone + Send + Sync + Siz
     /// let saved = product.save(&client).await?;
     /// ```
     async fn save(&self, client: &RestClient) -> Result<Self, ResourceError> {
+        record_deprecated_use::<Self>(client);
         let is_new = self.get_id().is_none();
         let key = Self::resource_key();
 
@@ -527,6 +748,7 @@ pub trait RestResource: Serialize + DeserializeOwned + Clone + Send + Sync + Siz
         client: &RestClient,
         changed_fields: Value,
     ) -> Result<Self, ResourceError> {
+        record_deprecated_use::<Self>(client);
         let id = self.get_id().ok_or(ResourceError::PathResolutionFailed {
             resource: Self::NAME,
             operation: "update",
@@ -569,6 +791,126 @@ pub trait RestResource: Serialize + DeserializeOwned + Clone + Send + Sync + Siz
         Ok(result.into_inner())
     }
 
+    /// Creates many resources, pacing requests against the store's call limit.
+    ///
+    /// Each item is sent with [`save`](Self::save), after waiting on the
+    /// client's [`RateLimiter`](crate::clients::RateLimiter) for room in the
+    /// call limit bucket. Batches run on clients from the same
+    /// [`ClientFactory`](crate::clients::ClientFactory) share the shop's
+    /// bucket. Items should not have an ID, or they are updated instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The REST client to use
+    /// * `items` - The resources to create
+    /// * `options` - Concurrency and error handling options
+    ///
+    /// # Returns
+    ///
+    /// One result per attempted item, in the order of `items`. When
+    /// `options.continue_on_error` is `false` and an item fails, items not yet
+    /// started are skipped and the results are shorter than `items`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use shopify_sdk::rest::BatchOptions;
+    ///
+    /// let options = BatchOptions {
+    ///     concurrency: 2,
+    ///     continue_on_error: false,
+    /// };
+    /// let results = Product::create_all(&client, products, options).await;
+    /// let created = results.into_iter().collect::<Result<Vec<_>, _>>()?;
+    /// ```
+    #[must_use]
+    async fn create_all(
+        client: &RestClient,
+        items: Vec<Self>,
+        options: BatchOptions,
+    ) -> Vec<Result<Self, ResourceError>> {
+        let rate_limiter = client.rate_limiter();
+        run_batch(items, options, |item| async move {
+            rate_limiter.acquire().await;
+            item.save(client).await
+        })
+        .await
+    }
+
+    /// Lists the resources with the given IDs, splitting long ID lists
+    /// across several requests.
+    ///
+    /// Shopify rejects list requests whose URL is too long, so a large `ids`
+    /// filter can't be sent at once. The IDs are sent in chunks of
+    /// `options.chunk_size`, each as the `ids` filter of an [`all`](Self::all)
+    /// request with a `limit` covering the whole chunk. Requests are paced
+    /// against the client's [`RateLimiter`](crate::clients::RateLimiter),
+    /// with up to `options.concurrency` in flight.
+    ///
+    /// Only use this with resources whose list endpoint accepts an `ids`
+    /// filter. Any `ids` or `limit` in `params` is replaced; other filters
+    /// apply to every chunk.
+    ///
+    /// # Returns
+    ///
+    /// The merged resources in the order of `ids`, with duplicate IDs
+    /// requested and returned once. IDs that match no resource are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResourceError::PathResolutionFailed`] if no list path exists.
+    /// Returns the first error of any chunk; no further chunks are started
+    /// after it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use shopify_sdk::rest::IdChunkOptions;
+    ///
+    /// let products = Product::all_by_ids(&client, &ids, None, IdChunkOptions::default()).await?;
+    /// ```
+    async fn all_by_ids(
+        client: &RestClient,
+        ids: &[Self::Id],
+        params: Option<Self::AllParams>,
+        options: IdChunkOptions,
+    ) -> Result<Vec<Self>, ResourceError>
+    where
+        Self::Id: Eq + Hash,
+    {
+        record_deprecated_use::<Self>(client);
+        let path = get_path(Self::PATHS, ResourceOperation::All, &[]).ok_or(
+            ResourceError::PathResolutionFailed {
+                resource: Self::NAME,
+                operation: "all",
+            },
+        )?;
+        let full_path = Self::build_full_path(path.template);
+        let query = list_query(params, None)?.unwrap_or_default();
+        let rate_limiter = client.rate_limiter();
+
+        run_id_chunks(
+            ids,
+            options,
+            |chunk| {
+                let mut query = query.clone();
+                let full_path = &full_path;
+                async move {
+                    let ids: Vec<String> = chunk.iter().map(ToString::to_string).collect();
+                    query.insert("ids".to_string(), ids.join(","));
+                    query.insert("limit".to_string(), chunk.len().to_string());
+
+                    rate_limiter.acquire().await;
+                    let result = client.get(full_path, Some(query)).await;
+                    list_from_result(result, Self::NAME, Self::PLURAL, None)
+                        .map(ResourceResponse::into_inner)
+                }
+            },
+            Self::get_id,
+        )
+        .await
+    }
+
     /// Deletes the resource.
     ///
     /// # Arguments
@@ -587,6 +929,7 @@ pub trait RestResource: Serialize + DeserializeOwned + Clone + Send + Sync + Siz
     /// product.delete(&client).await?;
     /// ```
     async fn delete(&self, client: &RestClient) -> Result<(), ResourceError> {
+        record_deprecated_use::<Self>(client);
         let id = self.get_id().ok_or(ResourceError::PathResolutionFailed {
             resource: Self::NAME,
             operation: "delete",
@@ -646,6 +989,7 @@ pub trait RestResource: Serialize + DeserializeOwned + Clone + Send + Sync + Siz
         client: &RestClient,
         params: Option<Self::CountParams>,
     ) -> Result<u64, ResourceError> {
+        record_deprecated_use::<Self>(client);
         let path = get_path(Self::PATHS, ResourceOperation::Count, &[]).ok_or(
             ResourceError::PathResolutionFailed {
                 resource: Self::NAME,
@@ -691,6 +1035,108 @@ pub trait RestResource: Serialize + DeserializeOwned + Clone + Send + Sync + Siz
         Ok(count)
     }
 
+    /// Exports all resources matching the given parameters as JSON lines.
+    ///
+    /// Pages through the list endpoint and writes each resource to `writer`
+    /// as a single line of JSON, flushing after every page. Only one page is
+    /// held in memory at a time.
+    ///
+    /// API errors do not abort the export: the pages written so far are kept
+    /// and the error is reported in [`ExportSummary::error`]. Since the next
+    /// page's cursor comes from the previous response, a failed page ends
+    /// the export.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The REST client to use for the requests
+    /// * `params` - Optional filters for the first page (subsequent pages only
+    ///   carry `limit` and the pagination cursor, as Shopify requires)
+    /// * `writer` - Where to write the JSON lines
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if writing to or flushing `writer` fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let file = tokio::fs::File::create("orders.jsonl").await?;
+    /// let summary = Order::export_jsonl(&client, None, file).await?;
+    /// println!("Exported {} orders", summary.records);
+    /// ```
+    async fn export_jsonl<W: AsyncWrite + Unpin + Send>(
+        client: &RestClient,
+        params: Option<Self::AllParams>,
+        mut writer: W,
+    ) -> std::io::Result<ExportSummary> {
+        record_deprecated_use::<Self>(client);
+        let mut summary = ExportSummary::default();
+
+        let Some(path) = get_path(Self::PATHS, ResourceOperation::All, &[]) else {
+            summary.error = Some(ResourceError::PathResolutionFailed {
+                resource: Self::NAME,
+                operation: "export_jsonl",
+            });
+            return Ok(summary);
+        };
+        let full_path = Self::build_full_path(path.template);
+
+        let mut query = match list_query(params, client.default_page_size()) {
+            Ok(query) => query.unwrap_or_default(),
+            Err(e) => {
+                summary.error = Some(e);
+                return Ok(summary);
+            }
+        };
+
+        loop {
+            let request_query = Some(query.clone()).filter(|q| !q.is_empty());
+            let response = match client.get(&full_path, request_query).await {
+                Ok(response) => response,
+                Err(e) => {
+                    summary.error = Some(e.into());
+                    break;
+                }
+            };
+
+            if !response.is_ok() {
+                summary.error = Some(ResourceError::from_http_response(
+                    response.code,
+                    &response.body,
+                    Self::NAME,
+                    None,
+                    response.request_id(),
+                ));
+                break;
+            }
+
+            let page: ResourceResponse<Vec<Self>> =
+                match ResourceResponse::from_http_response(response, Self::PLURAL) {
+                    Ok(page) => page,
+                    Err(e) => {
+                        summary.error = Some(e);
+                        break;
+                    }
+                };
+
+            write_jsonl_page(&mut writer, page.data(), &mut summary).await?;
+
+            let Some(page_info) = page.next_page_info() else {
+                break;
+            };
+
+            // Shopify rejects filters alongside page_info; only limit carries over
+            let limit = query.remove("limit");
+            query.clear();
+            if let Some(limit) = limit {
+                query.insert("limit".to_string(), limit);
+            }
+            query.insert("page_info".to_string(), page_info.to_string());
+        }
+
+        Ok(summary)
+    }
+
     /// Builds the full path including any prefix.
     #[must_use]
     fn build_full_path(path: &str) -> String {
@@ -698,6 +1144,128 @@ pub trait RestResource: Serialize + DeserializeOwned + Clone + Send + Sync + Siz
     }
 }
 
+/// Logs and records the use of a deprecated resource.
+fn record_deprecated_use<R: RestResource>(client: &RestClient) {
+    let Some(reason) = R::DEPRECATION else {
+        return;
+    };
+    tracing::warn!(resource = R::NAME, "{reason}");
+    if let Some(registry) = client.deprecation_registry() {
+        registry.record(DeprecationNotice {
+            source: DeprecationSource::Resource,
+            reason: reason.to_string(),
+            path: None,
+        });
+    }
+}
+
+/// Builds the query for an existence check, requesting only the `id` field.
+fn exists_query() -> HashMap<String, String> {
+    HashMap::from([("fields".to_string(), "id".to_string())])
+}
+
+/// Maps the outcome of an existence check request to whether the resource
+/// exists, treating a 404 as `false`.
+fn exists_from_result(
+    result: Result<HttpResponse, RestError>,
+    resource: &'static str,
+    id: &str,
+) -> Result<bool, ResourceError> {
+    match result {
+        Ok(response) if response.is_ok() => Ok(true),
+        Ok(response) if response.code == 404 => Ok(false),
+        Ok(response) => Err(ResourceError::from_http_response(
+            response.code,
+            &response.body,
+            resource,
+            Some(id),
+            response.request_id(),
+        )),
+        Err(RestError::Http(HttpError::Response(error))) if error.code == 404 => Ok(false),
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Maps the outcome of a list request to a list response.
+///
+/// A successful response is parsed from `key`, so an empty array gives an
+/// empty list. For a nested list, a 404 means the parent is missing and is
+/// reported as [`ResourceError::NotFound`] for the parent; any other error
+/// status is mapped by [`ResourceError::from_http_response`].
+fn list_from_result<T: DeserializeOwned>(
+    result: Result<HttpResponse, RestError>,
+    resource: &'static str,
+    key: &str,
+    parent: Option<(&str, &str)>,
+) -> Result<ResourceResponse<Vec<T>>, ResourceError> {
+    let missing_parent = |code: u16| {
+        parent
+            .filter(|_| code == 404)
+            .map(|(name, id)| ResourceError::NotFound {
+                resource: parent_resource_name(name),
+                id: id.to_string(),
+            })
+    };
+
+    match result {
+        Ok(response) if response.is_ok() => ResourceResponse::from_http_response(response, key),
+        Ok(response) => Err(missing_parent(response.code).unwrap_or_else(|| {
+            ResourceError::from_http_response(
+                response.code,
+                &response.body,
+                resource,
+                None,
+                response.request_id(),
+            )
+        })),
+        Err(RestError::Http(HttpError::Response(error))) => Err(missing_parent(error.code)
+            .unwrap_or_else(|| RestError::Http(HttpError::Response(error)).into())),
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Returns the resource name for a parent ID parameter such as `product_id`.
+fn parent_resource_name(parent_id_name: &str) -> &'static str {
+    match parent_id_name {
+        "article_id" => "Article",
+        "blog_id" => "Blog",
+        "collection_id" => "Collection",
+        "country_id" => "Country",
+        "customer_id" => "Customer",
+        "fulfillment_order_id" => "FulfillmentOrder",
+        "gift_card_id" => "GiftCard",
+        "inventory_item_id" => "InventoryItem",
+        "location_id" => "Location",
+        "order_id" => "Order",
+        "price_rule_id" => "PriceRule",
+        "product_id" => "Product",
+        "recurring_application_charge_id" => "RecurringApplicationCharge",
+        "theme_id" => "Theme",
+        "variant_id" => "Variant",
+        _ => "Parent",
+    }
+}
+
+/// Builds the query for a list request, adding `default_limit` as `limit`
+/// when the params don't set one.
+fn list_query<T: Serialize>(
+    params: Option<T>,
+    default_limit: Option<u32>,
+) -> Result<Option<HashMap<String, String>>, ResourceError> {
+    let mut query = params
+        .map(|p| serialize_to_query(&p))
+        .transpose()?
+        .unwrap_or_default();
+
+    if let Some(limit) = default_limit {
+        query
+            .entry("limit".to_string())
+            .or_insert_with(|| limit.to_string());
+    }
+
+    Ok(Some(query).filter(|q| !q.is_empty()))
+}
+
 /// Serializes a params struct to a query parameter map.
 fn serialize_to_query<T: Serialize>(params: &T) -> Result<HashMap<String, String>, ResourceError> {
     let value = serde_json::to_value(params).map_err(|e| {
@@ -932,6 +1500,40 @@ mod tests {
         assert_eq!(product.get_id(), Some(123));
     }
 
+    #[test]
+    fn test_required_params_returns_minimum_ids_for_operation() {
+        // Find has nested and standalone paths; the standalone one needs fewer IDs
+        assert_eq!(
+            MockVariant::required_params(ResourceOperation::Find),
+            Some(&["id"][..])
+        );
+        assert_eq!(
+            MockVariant::required_params(ResourceOperation::All),
+            Some(&["product_id"][..])
+        );
+        assert_eq!(
+            MockLocation::required_params(ResourceOperation::All),
+            Some(&[][..])
+        );
+        assert_eq!(MockVariant::required_params(ResourceOperation::Count), None);
+    }
+
+    #[test]
+    fn test_available_operations_are_derived_from_paths() {
+        assert_eq!(
+            MockVariant::available_operations(),
+            vec![ResourceOperation::Find, ResourceOperation::All]
+        );
+        assert_eq!(
+            MockLocation::available_operations(),
+            vec![
+                ResourceOperation::Find,
+                ResourceOperation::All,
+                ResourceOperation::Count
+            ]
+        );
+    }
+
     #[test]
     fn test_build_full_path_without_prefix() {
         let path = MockProduct::build_full_path("products/123");
@@ -979,6 +1581,353 @@ mod tests {
         assert!(!query.contains_key("page_info"));
     }
 
+    #[test]
+    fn test_serialize_to_query_formats_product_time_filters_as_rfc3339() {
+        use crate::rest::resources::v2026_04::ProductListParams;
+        use chrono::{TimeZone, Utc};
+
+        let params = ProductListParams {
+            created_at_min: Some(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()),
+            created_at_max: Some(Utc.with_ymd_and_hms(2024, 1, 31, 23, 59, 59).unwrap()),
+            updated_at_min: Some(Utc.with_ymd_and_hms(2024, 2, 1, 12, 30, 0).unwrap()),
+            published_at_max: Some(Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap()),
+            vendor: Some("Acme".to_string()),
+            product_type: Some("Shirts".to_string()),
+            title: Some("IPod Nano".to_string()),
+            ..Default::default()
+        };
+
+        let query = serialize_to_query(&params).unwrap();
+
+        assert_eq!(query["created_at_min"], "2024-01-01T00:00:00Z");
+        assert_eq!(query["created_at_max"], "2024-01-31T23:59:59Z");
+        assert_eq!(query["updated_at_min"], "2024-02-01T12:30:00Z");
+        assert_eq!(query["published_at_max"], "2024-03-01T00:00:00Z");
+        assert_eq!(query["vendor"], "Acme");
+        assert_eq!(query["product_type"], "Shirts");
+        assert_eq!(query["title"], "IPod Nano");
+        assert!(!query.contains_key("updated_at_max"));
+        assert!(!query.contains_key("published_at_min"));
+    }
+
+    #[test]
+    fn test_list_query_encodes_sort_order() {
+        use crate::clients::HttpRequest;
+        use crate::rest::resources::v2026_04::{OrderListParams, SortOrder};
+
+        let params = OrderListParams {
+            order: Some(SortOrder::desc("created_at")),
+            ..Default::default()
+        };
+
+        let query = list_query(Some(params), None).unwrap().unwrap();
+        assert_eq!(query["order"], "created_at desc");
+
+        let request = HttpRequest::builder(HttpMethod::Get, "orders.json")
+            .query(query)
+            .build()
+            .unwrap();
+        let url = request
+            .resolved_url("https://my-store.myshopify.com", "/admin/api/2026-04")
+            .unwrap();
+        assert_eq!(url.query(), Some("order=created_at+desc"));
+    }
+
+    #[test]
+    fn test_exists_requests_only_id_field() {
+        use crate::clients::HttpRequest;
+
+        let request = HttpRequest::builder(HttpMethod::Get, "products/123.json")
+            .query(exists_query())
+            .build()
+            .unwrap();
+        let url = request
+            .resolved_url("https://my-store.myshopify.com", "/admin/api/2026-04")
+            .unwrap();
+        assert_eq!(url.query(), Some("fields=id"));
+    }
+
+    #[tokio::test]
+    async fn test_exists_is_true_when_the_resource_is_found() {
+        use crate::config::ApiVersion;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/admin/api/2026-04/products/123.json"))
+            .and(query_param("fields", "id"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "product": { "id": 123 } })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = RestClient::for_mock_server(&server.uri(), ApiVersion::V2026_04);
+
+        assert!(MockProduct::exists(&client, 123).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_exists_is_false_when_the_resource_is_not_found() {
+        use crate::config::ApiVersion;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/admin/api/2026-04/products/123.json"))
+            .and(query_param("fields", "id"))
+            .respond_with(
+                ResponseTemplate::new(404)
+                    .set_body_json(serde_json::json!({ "errors": "Not Found" })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = RestClient::for_mock_server(&server.uri(), ApiVersion::V2026_04);
+
+        assert!(!MockProduct::exists(&client, 123).await.unwrap());
+    }
+
+    #[test]
+    fn test_exists_maps_not_found_to_false() {
+        use crate::clients::HttpResponseError;
+
+        let not_found = Err(RestError::Http(HttpError::Response(HttpResponseError {
+            code: 404,
+            message: r#"{"errors":"Not Found"}"#.to_string(),
+            error_reference: None,
+        })));
+        assert!(!exists_from_result(not_found, "Product", "123").unwrap());
+
+        let found = Ok(HttpResponse::new(
+            200,
+            HashMap::new(),
+            serde_json::json!({"product": {"id": 123}}),
+        ));
+        assert!(exists_from_result(found, "Product", "123").unwrap());
+
+        let forbidden = Err(RestError::Http(HttpError::Response(HttpResponseError {
+            code: 403,
+            message: "Forbidden".to_string(),
+            error_reference: None,
+        })));
+        assert!(exists_from_result(forbidden, "Product", "123").is_err());
+    }
+
+    #[test]
+    fn test_list_with_empty_array_is_ok_and_empty() {
+        let empty = Ok(HttpResponse::new(
+            200,
+            HashMap::new(),
+            serde_json::json!({"variants": []}),
+        ));
+
+        let response: ResourceResponse<Vec<MockVariant>> =
+            list_from_result(empty, "Variant", "variants", Some(("product_id", "123"))).unwrap();
+
+        assert!(response.is_empty());
+    }
+
+    #[test]
+    fn test_list_with_missing_parent_is_parent_not_found() {
+        use crate::clients::HttpResponseError;
+
+        let missing = Err(RestError::Http(HttpError::Response(HttpResponseError {
+            code: 404,
+            message: r#"{"errors":"Not Found"}"#.to_string(),
+            error_reference: None,
+        })));
+        let result: Result<ResourceResponse<Vec<MockVariant>>, _> =
+            list_from_result(missing, "Variant", "variants", Some(("product_id", "123")));
+        assert!(matches!(
+            result.unwrap_err(),
+            ResourceError::NotFound { resource: "Product", id } if id == "123"
+        ));
+
+        let missing = Ok(HttpResponse::new(
+            404,
+            HashMap::new(),
+            serde_json::json!({"errors": "Not Found"}),
+        ));
+        let result: Result<ResourceResponse<Vec<MockVariant>>, _> =
+            list_from_result(missing, "Variant", "variants", Some(("product_id", "123")));
+        assert!(matches!(
+            result.unwrap_err(),
+            ResourceError::NotFound {
+                resource: "Product",
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_all_with_parent_returns_empty_list_for_empty_array() {
+        use crate::config::ApiVersion;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/admin/api/2026-04/products/123/variants.json"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "variants": [] })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = RestClient::for_mock_server(&server.uri(), ApiVersion::V2026_04);
+
+        let variants = MockVariant::all_with_parent(&client, "product_id", 123, None)
+            .await
+            .unwrap();
+
+        assert!(variants.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_all_with_parent_reports_missing_parent_as_not_found() {
+        use crate::config::ApiVersion;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/admin/api/2026-04/products/123/variants.json"))
+            .respond_with(
+                ResponseTemplate::new(404)
+                    .set_body_json(serde_json::json!({ "errors": "Not Found" })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = RestClient::for_mock_server(&server.uri(), ApiVersion::V2026_04);
+
+        let result = MockVariant::all_with_parent(&client, "product_id", 123, None).await;
+
+        assert!(
+            matches!(
+                result,
+                Err(ResourceError::NotFound { resource: "Product", ref id }) if id == "123"
+            ),
+            "expected the parent to be NotFound, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn test_list_errors_are_not_parent_not_found() {
+        use crate::clients::HttpResponseError;
+
+        let server_error = Err(RestError::Http(HttpError::Response(HttpResponseError {
+            code: 500,
+            message: "Internal Server Error".to_string(),
+            error_reference: None,
+        })));
+        let result: Result<ResourceResponse<Vec<MockVariant>>, _> = list_from_result(
+            server_error,
+            "Variant",
+            "variants",
+            Some(("product_id", "123")),
+        );
+        assert!(matches!(
+            result.unwrap_err(),
+            ResourceError::Rest(RestError::Http(HttpError::Response(HttpResponseError {
+                code: 500,
+                ..
+            })))
+        ));
+
+        let not_found = Err(RestError::Http(HttpError::Response(HttpResponseError {
+            code: 404,
+            message: "Not Found".to_string(),
+            error_reference: None,
+        })));
+        let result: Result<ResourceResponse<Vec<MockProduct>>, _> =
+            list_from_result(not_found, "Product", "products", None);
+        assert!(!matches!(
+            result.unwrap_err(),
+            ResourceError::NotFound { .. }
+        ));
+    }
+
+    #[test]
+    fn test_list_query_uses_default_page_size_when_limit_unset() {
+        let params = MockProductParams {
+            limit: None,
+            page_info: None,
+        };
+
+        let query = list_query(Some(params), Some(250)).unwrap().unwrap();
+        assert_eq!(query.get("limit"), Some(&"250".to_string()));
+
+        let query = list_query(None::<MockProductParams>, Some(250))
+            .unwrap()
+            .unwrap();
+        assert_eq!(query.get("limit"), Some(&"250".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_all_sends_configured_default_page_size() {
+        use crate::config::{ApiKey, ApiSecretKey, ApiVersion, ShopDomain};
+        use crate::{AuthScopes, Session, ShopifyConfig};
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/admin/api/2026-04/products.json"))
+            .and(query_param("limit", "250"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "products": [{ "id": 1, "title": "Snowboard" }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let config = ShopifyConfig::builder()
+            .api_key(ApiKey::new("test-key").unwrap())
+            .api_secret_key(ApiSecretKey::new("test-secret").unwrap())
+            .api_version(ApiVersion::V2026_04)
+            .default_page_size(250)
+            .build()
+            .unwrap();
+        let session = Session::new(
+            "test-session".to_string(),
+            ShopDomain::new("test-shop").unwrap(),
+            "test-access-token".to_string(),
+            AuthScopes::new(),
+            false,
+            None,
+        );
+        let client = RestClient::new(&session, Some(&config))
+            .unwrap()
+            .with_mock_server(&server.uri());
+
+        let products = MockProduct::all(&client, None).await.unwrap();
+
+        assert_eq!(products.len(), 1);
+    }
+
+    #[test]
+    fn test_list_query_keeps_explicit_limit() {
+        let params = MockProductParams {
+            limit: Some(10),
+            page_info: None,
+        };
+
+        let query = list_query(Some(params), Some(250)).unwrap().unwrap();
+        assert_eq!(query.get("limit"), Some(&"10".to_string()));
+    }
+
+    #[test]
+    fn test_list_query_without_default_page_size() {
+        assert!(list_query(None::<MockProductParams>, None)
+            .unwrap()
+            .is_none());
+    }
+
     #[test]
     fn test_serialize_to_query_handles_arrays() {
         #[derive(Serialize)]
@@ -1074,4 +2023,88 @@ mod tests {
         assert_eq!(MockLocation::PLURAL, "locations");
         assert_eq!(MockLocation::resource_key(), "location");
     }
+
+    #[test]
+    fn test_admin_url_for_product_and_order() {
+        use crate::rest::resources::v2026_04::{Order, Product};
+
+        let shop = ShopDomain::new("my-store.myshopify.com").unwrap();
+        let product = Product {
+            id: Some(632_910_392),
+            ..Default::default()
+        };
+        let order = Order {
+            id: Some(450_789_469),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            product.admin_url(&shop).as_deref(),
+            Some("https://admin.shopify.com/store/my-store/products/632910392")
+        );
+        assert_eq!(
+            order.admin_url(&shop).as_deref(),
+            Some("https://admin.shopify.com/store/my-store/orders/450789469")
+        );
+        assert_eq!(Product::default().admin_url(&shop), None);
+    }
+
+    #[test]
+    fn test_admin_url_for_resources_with_other_or_no_pages() {
+        use crate::rest::resources::v2026_04::{
+            CustomCollection, PriceRule, RefundResource, SmartCollection, Transaction, Variant,
+        };
+
+        let shop = ShopDomain::new("my-store").unwrap();
+        let custom = CustomCollection {
+            id: Some(841_564_295),
+            ..Default::default()
+        };
+        let smart = SmartCollection {
+            id: Some(482_865_238),
+            ..Default::default()
+        };
+        let price_rule = PriceRule {
+            id: Some(507_328_175),
+            ..Default::default()
+        };
+        let variant = Variant {
+            id: Some(808_950_810),
+            product_id: Some(632_910_392),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            custom.admin_url(&shop).as_deref(),
+            Some("https://admin.shopify.com/store/my-store/collections/841564295")
+        );
+        assert_eq!(
+            smart.admin_url(&shop).as_deref(),
+            Some("https://admin.shopify.com/store/my-store/collections/482865238")
+        );
+        assert_eq!(
+            price_rule.admin_url(&shop).as_deref(),
+            Some("https://admin.shopify.com/store/my-store/discounts/507328175")
+        );
+        assert_eq!(
+            variant.admin_url(&shop).as_deref(),
+            Some("https://admin.shopify.com/store/my-store/products/632910392/variants/808950810")
+        );
+        let orphan = Variant {
+            id: Some(808_950_810),
+            ..Default::default()
+        };
+        assert_eq!(orphan.admin_url(&shop), None);
+
+        let refund = RefundResource {
+            id: Some(509_562_969),
+            ..Default::default()
+        };
+        let transaction = Transaction {
+            id: Some(389_404_469),
+            ..Default::default()
+        };
+        assert_eq!(refund.admin_url(&shop), None);
+        assert_eq!(transaction.admin_url(&shop), None);
+    }
 }
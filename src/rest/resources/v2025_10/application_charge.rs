@@ -45,6 +45,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::config::ShopDomain;
 use crate::rest::{ResourceOperation, ResourcePath, RestResource};
 use crate::HttpMethod;
 
@@ -219,6 +220,11 @@ impl RestResource for ApplicationCharge {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    /// Application charges have no page of their own in the Shopify admin.
+    fn admin_url(&self, _shop: &ShopDomain) -> Option<String> {
+        None
+    }
 }
 
 /// Parameters for finding a single application charge.
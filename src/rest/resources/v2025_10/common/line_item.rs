@@ -244,6 +244,37 @@ pub struct LineItem {
     pub admin_graphql_api_id: Option<String>,
 }
 
+impl LineItem {
+    /// Returns the value of the custom property with the given name.
+    ///
+    /// If the property appears more than once, the first value is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::rest::resources::v2025_10::common::{LineItem, LineItemProperty};
+    ///
+    /// let line_item = LineItem {
+    ///     properties: Some(vec![LineItemProperty {
+    ///         name: "engraving".to_string(),
+    ///         value: "J + M".to_string(),
+    ///     }]),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(line_item.property("engraving"), Some("J + M"));
+    /// assert_eq!(line_item.property("gift_wrap"), None);
+    /// ```
+    #[must_use]
+    pub fn property(&self, name: &str) -> Option<&str> {
+        self.properties
+            .as_deref()?
+            .iter()
+            .find(|p| p.name == name)
+            .map(|p| p.value.as_str())
+    }
+}
+
 /// A shipping line on an order.
 ///
 /// Represents a shipping method applied to the order, including
@@ -512,4 +543,25 @@ mod tests {
         assert_eq!(parsed["name"], "engraving");
         assert_eq!(parsed["value"], "J + M");
     }
+
+    #[test]
+    fn test_line_item_property_lookup() {
+        let line_item = LineItem {
+            properties: Some(vec![
+                LineItemProperty {
+                    name: "engraving".to_string(),
+                    value: "J + M".to_string(),
+                },
+                LineItemProperty {
+                    name: "gift_wrap".to_string(),
+                    value: "yes".to_string(),
+                },
+            ]),
+            ..Default::default()
+        };
+
+        assert_eq!(line_item.property("gift_wrap"), Some("yes"));
+        assert_eq!(line_item.property("monogram"), None);
+        assert_eq!(LineItem::default().property("engraving"), None);
+    }
 }
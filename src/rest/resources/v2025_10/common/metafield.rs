@@ -1,7 +1,8 @@
 //! Metafield owner types for polymorphic metafield paths.
 //!
 //! This module provides the `MetafieldOwner` enum used to specify
-//! which resource type owns a metafield.
+//! which resource type owns a metafield, and the `MetafieldInput` struct
+//! used to create metafields along with their owner.
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -89,6 +90,56 @@ impl fmt::Display for MetafieldOwner {
     }
 }
 
+/// A metafield to create along with the resource that owns it.
+///
+/// Products, orders and customers accept a `metafields` array in their
+/// create body. Unlike [`Metafield`](super::super::Metafield), this carries
+/// only the fields Shopify reads on create.
+///
+/// # Example
+///
+/// ```rust
+/// use shopify_sdk::rest::resources::v2025_10::common::MetafieldInput;
+///
+/// let input = MetafieldInput::new(
+///     "custom",
+///     "care_guide",
+///     "single_line_text_field",
+///     "Machine wash cold",
+/// );
+/// assert_eq!(input.metafield_type, "single_line_text_field");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetafieldInput {
+    /// The container for a group of metafields.
+    pub namespace: String,
+    /// The name of the metafield within its namespace.
+    pub key: String,
+    /// The metafield's type, e.g. `single_line_text_field`.
+    #[serde(rename = "type")]
+    pub metafield_type: String,
+    /// The metafield's value, formatted for its type.
+    pub value: String,
+}
+
+impl MetafieldInput {
+    /// Creates a metafield input.
+    #[must_use]
+    pub fn new(
+        namespace: impl Into<String>,
+        key: impl Into<String>,
+        metafield_type: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        Self {
+            namespace: namespace.into(),
+            key: key.into(),
+            metafield_type: metafield_type.into(),
+            value: value.into(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
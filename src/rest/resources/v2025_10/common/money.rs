@@ -51,6 +51,35 @@ pub struct MoneySet {
     pub presentment_money: Option<Money>,
 }
 
+/// A variant's price in one of the shop's presentment currencies.
+///
+/// Shopify returns these in a variant's `presentment_prices` array, one
+/// entry per enabled currency.
+///
+/// # Example
+///
+/// ```rust
+/// use shopify_sdk::rest::resources::v2025_10::common::PresentmentPrice;
+///
+/// let json = r#"{
+///     "price": { "amount": "25.99", "currency_code": "CAD" },
+///     "compare_at_price": null
+/// }"#;
+///
+/// let price: PresentmentPrice = serde_json::from_str(json).unwrap();
+/// assert_eq!(price.price.unwrap().currency_code.as_deref(), Some("CAD"));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct PresentmentPrice {
+    /// The price in the presentment currency.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<Money>,
+
+    /// The compare-at price in the presentment currency.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compare_at_price: Option<Money>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -101,4 +130,20 @@ mod tests {
             Some("EUR".to_string())
         );
     }
+
+    #[test]
+    fn test_presentment_price_deserialization() {
+        let json = r#"{
+            "price": { "amount": "25.99", "currency_code": "CAD" },
+            "compare_at_price": { "amount": "32.99", "currency_code": "CAD" }
+        }"#;
+
+        let price: PresentmentPrice = serde_json::from_str(json).unwrap();
+
+        assert_eq!(price.price.unwrap().amount.as_deref(), Some("25.99"));
+        assert_eq!(
+            price.compare_at_price.unwrap().amount.as_deref(),
+            Some("32.99")
+        );
+    }
 }
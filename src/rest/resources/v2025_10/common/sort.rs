@@ -0,0 +1,130 @@
+//! Sort order types for list endpoints.
+//!
+//! This module provides the `order` query parameter accepted by list
+//! endpoints such as products, orders and customers.
+
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The direction of a sort.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    /// Ascending order, oldest or smallest first.
+    #[default]
+    Asc,
+    /// Descending order, newest or largest first.
+    Desc,
+}
+
+impl SortDirection {
+    /// Returns the direction as used in the `order` parameter.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Asc => "asc",
+            Self::Desc => "desc",
+        }
+    }
+}
+
+/// The `order` parameter of a list request, e.g. `created_at desc`.
+///
+/// Serializes to a single `"{field} {direction}"` string. Shopify only
+/// honours the sort on the first page; later pages requested with
+/// `page_info` keep the order of the first.
+///
+/// # Example
+///
+/// ```rust
+/// use shopify_sdk::rest::resources::v2025_10::common::SortOrder;
+///
+/// let order = SortOrder::desc("created_at");
+/// assert_eq!(order.to_string(), "created_at desc");
+/// assert_eq!(serde_json::to_string(&order).unwrap(), "\"created_at desc\"");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SortOrder {
+    /// The field to sort by, e.g. `created_at` or `updated_at`.
+    pub field: String,
+    /// The sort direction.
+    pub direction: SortDirection,
+}
+
+impl SortOrder {
+    /// Creates a sort order on `field` in the given direction.
+    #[must_use]
+    pub fn new(field: impl Into<String>, direction: SortDirection) -> Self {
+        Self {
+            field: field.into(),
+            direction,
+        }
+    }
+
+    /// Creates an ascending sort order on `field`.
+    #[must_use]
+    pub fn asc(field: impl Into<String>) -> Self {
+        Self::new(field, SortDirection::Asc)
+    }
+
+    /// Creates a descending sort order on `field`.
+    #[must_use]
+    pub fn desc(field: impl Into<String>) -> Self {
+        Self::new(field, SortDirection::Desc)
+    }
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.field, self.direction.as_str())
+    }
+}
+
+impl Serialize for SortOrder {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SortOrder {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        let (field, direction) = value.split_once(' ').unwrap_or((&value, "asc"));
+        let direction = match direction.trim().to_ascii_lowercase().as_str() {
+            "asc" => SortDirection::Asc,
+            "desc" => SortDirection::Desc,
+            other => {
+                return Err(serde::de::Error::custom(format!(
+                    "unknown sort direction `{other}`"
+                )))
+            }
+        };
+        Ok(Self::new(field, direction))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sort_order_serialization() {
+        let json = serde_json::to_string(&SortOrder::desc("created_at")).unwrap();
+        assert_eq!(json, "\"created_at desc\"");
+
+        let json = serde_json::to_string(&SortOrder::asc("title")).unwrap();
+        assert_eq!(json, "\"title asc\"");
+    }
+
+    #[test]
+    fn test_sort_order_deserialization() {
+        let order: SortOrder = serde_json::from_str("\"updated_at DESC\"").unwrap();
+        assert_eq!(order, SortOrder::desc("updated_at"));
+
+        let order: SortOrder = serde_json::from_str("\"title\"").unwrap();
+        assert_eq!(order, SortOrder::asc("title"));
+
+        assert!(serde_json::from_str::<SortOrder>("\"title sideways\"").is_err());
+    }
+}
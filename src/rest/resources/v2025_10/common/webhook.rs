@@ -159,6 +159,139 @@ pub enum WebhookTopic {
     /// Triggered when an inventory item is deleted.
     #[serde(rename = "inventory_items/delete")]
     InventoryItemsDelete,
+
+    // Fulfillment order topics
+    /// Triggered when a merchant accepts a cancellation request.
+    #[serde(rename = "fulfillment_orders/cancellation_request_accepted")]
+    FulfillmentOrdersCancellationRequestAccepted,
+    /// Triggered when a merchant rejects a cancellation request.
+    #[serde(rename = "fulfillment_orders/cancellation_request_rejected")]
+    FulfillmentOrdersCancellationRequestRejected,
+    /// Triggered when a merchant requests cancellation of a fulfillment order.
+    #[serde(rename = "fulfillment_orders/cancellation_request_submitted")]
+    FulfillmentOrdersCancellationRequestSubmitted,
+    /// Triggered when a fulfillment order is cancelled.
+    #[serde(rename = "fulfillment_orders/cancelled")]
+    FulfillmentOrdersCancelled,
+    /// Triggered when a fulfillment service accepts a fulfillment request.
+    #[serde(rename = "fulfillment_orders/fulfillment_request_accepted")]
+    FulfillmentOrdersFulfillmentRequestAccepted,
+    /// Triggered when a fulfillment service rejects a fulfillment request.
+    #[serde(rename = "fulfillment_orders/fulfillment_request_rejected")]
+    FulfillmentOrdersFulfillmentRequestRejected,
+    /// Triggered when a merchant requests fulfillment of a fulfillment order.
+    #[serde(rename = "fulfillment_orders/fulfillment_request_submitted")]
+    FulfillmentOrdersFulfillmentRequestSubmitted,
+    /// Triggered when a fulfillment service fails to complete a fulfillment order.
+    #[serde(rename = "fulfillment_orders/fulfillment_service_failed_to_complete")]
+    FulfillmentOrdersFulfillmentServiceFailedToComplete,
+    /// Triggered when a hold on a fulfillment order is released.
+    #[serde(rename = "fulfillment_orders/hold_released")]
+    FulfillmentOrdersHoldReleased,
+    /// Triggered when line items are prepared for local delivery.
+    #[serde(rename = "fulfillment_orders/line_items_prepared_for_local_delivery")]
+    FulfillmentOrdersLineItemsPreparedForLocalDelivery,
+    /// Triggered when line items are ready for pickup.
+    #[serde(rename = "fulfillment_orders/line_items_prepared_for_pickup")]
+    FulfillmentOrdersLineItemsPreparedForPickup,
+    /// Triggered when fulfillment orders are merged.
+    #[serde(rename = "fulfillment_orders/merged")]
+    FulfillmentOrdersMerged,
+    /// Triggered when a fulfillment order moves to another location.
+    #[serde(rename = "fulfillment_orders/moved")]
+    FulfillmentOrdersMoved,
+    /// Triggered when order routing assigns fulfillment orders to locations.
+    #[serde(rename = "fulfillment_orders/order_routing_complete")]
+    FulfillmentOrdersOrderRoutingComplete,
+    /// Triggered when a fulfillment order is placed on hold.
+    #[serde(rename = "fulfillment_orders/placed_on_hold")]
+    FulfillmentOrdersPlacedOnHold,
+    /// Triggered when a scheduled fulfillment order's fulfill-at date changes.
+    #[serde(rename = "fulfillment_orders/rescheduled")]
+    FulfillmentOrdersRescheduled,
+    /// Triggered when a scheduled fulfillment order is ready to fulfill.
+    #[serde(rename = "fulfillment_orders/scheduled_fulfillment_order_ready")]
+    FulfillmentOrdersScheduledFulfillmentOrderReady,
+    /// Triggered when a fulfillment order is split.
+    #[serde(rename = "fulfillment_orders/split")]
+    FulfillmentOrdersSplit,
+
+    // Dispute topics
+    /// Triggered when a dispute is created.
+    #[serde(rename = "disputes/create")]
+    DisputesCreate,
+    /// Triggered when a dispute is updated.
+    #[serde(rename = "disputes/update")]
+    DisputesUpdate,
+
+    // Location topics
+    /// Triggered when a location is created.
+    #[serde(rename = "locations/create")]
+    LocationsCreate,
+    /// Triggered when a location is updated.
+    #[serde(rename = "locations/update")]
+    LocationsUpdate,
+    /// Triggered when a location is deleted.
+    #[serde(rename = "locations/delete")]
+    LocationsDelete,
+    /// Triggered when a location is activated.
+    #[serde(rename = "locations/activate")]
+    LocationsActivate,
+    /// Triggered when a location is deactivated.
+    #[serde(rename = "locations/deactivate")]
+    LocationsDeactivate,
+
+    // Subscription contract topics
+    /// Triggered when a subscription contract is created.
+    #[serde(rename = "subscription_contracts/create")]
+    SubscriptionContractsCreate,
+    /// Triggered when a subscription contract is updated.
+    #[serde(rename = "subscription_contracts/update")]
+    SubscriptionContractsUpdate,
+    /// Triggered when a subscription contract is activated.
+    #[serde(rename = "subscription_contracts/activate")]
+    SubscriptionContractsActivate,
+    /// Triggered when a subscription contract is cancelled.
+    #[serde(rename = "subscription_contracts/cancel")]
+    SubscriptionContractsCancel,
+    /// Triggered when a subscription contract expires.
+    #[serde(rename = "subscription_contracts/expire")]
+    SubscriptionContractsExpire,
+    /// Triggered when a subscription contract fails.
+    #[serde(rename = "subscription_contracts/fail")]
+    SubscriptionContractsFail,
+    /// Triggered when a subscription contract is paused.
+    #[serde(rename = "subscription_contracts/pause")]
+    SubscriptionContractsPause,
+}
+
+impl WebhookTopic {
+    /// Parses a topic from its GraphQL enum form (e.g. `"ORDERS_CREATE"`).
+    ///
+    /// This is the form used by the GraphQL Admin API, such as the `topic`
+    /// field of a `WebhookSubscription`. Returns `None` for topics this SDK
+    /// does not model.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::rest::resources::v2025_10::common::WebhookTopic;
+    ///
+    /// assert_eq!(
+    ///     WebhookTopic::from_graphql_enum("INVENTORY_LEVELS_UPDATE"),
+    ///     Some(WebhookTopic::InventoryLevelsUpdate)
+    /// );
+    /// assert_eq!(WebhookTopic::from_graphql_enum("NOT_A_TOPIC"), None);
+    /// ```
+    #[must_use]
+    pub fn from_graphql_enum(value: &str) -> Option<Self> {
+        // Topics are "resource/event", so one of the underscores is the slash
+        let lower = value.to_ascii_lowercase();
+        lower.match_indices('_').find_map(|(index, _)| {
+            let candidate = format!("{}/{}", &lower[..index], &lower[index + 1..]);
+            serde_json::from_value(serde_json::Value::String(candidate)).ok()
+        })
+    }
 }
 
 /// The format for webhook payloads.
@@ -213,6 +346,156 @@ mod tests {
         assert_eq!(topic, WebhookTopic::AppUninstalled);
     }
 
+    #[test]
+    fn test_webhook_topic_from_graphql_enum() {
+        assert_eq!(
+            WebhookTopic::from_graphql_enum("ORDERS_CREATE"),
+            Some(WebhookTopic::OrdersCreate)
+        );
+        assert_eq!(
+            WebhookTopic::from_graphql_enum("ORDERS_PARTIALLY_FULFILLED"),
+            Some(WebhookTopic::OrdersPartiallyFulfilled)
+        );
+        assert_eq!(
+            WebhookTopic::from_graphql_enum("APP_UNINSTALLED"),
+            Some(WebhookTopic::AppUninstalled)
+        );
+        assert_eq!(WebhookTopic::from_graphql_enum("ORDERS"), None);
+        assert_eq!(WebhookTopic::from_graphql_enum(""), None);
+    }
+
+    #[test]
+    fn test_fulfillment_order_dispute_location_and_subscription_topics_round_trip() {
+        let topics = [
+            (
+                WebhookTopic::FulfillmentOrdersCancellationRequestAccepted,
+                "fulfillment_orders/cancellation_request_accepted",
+            ),
+            (
+                WebhookTopic::FulfillmentOrdersCancellationRequestRejected,
+                "fulfillment_orders/cancellation_request_rejected",
+            ),
+            (
+                WebhookTopic::FulfillmentOrdersCancellationRequestSubmitted,
+                "fulfillment_orders/cancellation_request_submitted",
+            ),
+            (
+                WebhookTopic::FulfillmentOrdersCancelled,
+                "fulfillment_orders/cancelled",
+            ),
+            (
+                WebhookTopic::FulfillmentOrdersFulfillmentRequestAccepted,
+                "fulfillment_orders/fulfillment_request_accepted",
+            ),
+            (
+                WebhookTopic::FulfillmentOrdersFulfillmentRequestRejected,
+                "fulfillment_orders/fulfillment_request_rejected",
+            ),
+            (
+                WebhookTopic::FulfillmentOrdersFulfillmentRequestSubmitted,
+                "fulfillment_orders/fulfillment_request_submitted",
+            ),
+            (
+                WebhookTopic::FulfillmentOrdersFulfillmentServiceFailedToComplete,
+                "fulfillment_orders/fulfillment_service_failed_to_complete",
+            ),
+            (
+                WebhookTopic::FulfillmentOrdersHoldReleased,
+                "fulfillment_orders/hold_released",
+            ),
+            (
+                WebhookTopic::FulfillmentOrdersLineItemsPreparedForLocalDelivery,
+                "fulfillment_orders/line_items_prepared_for_local_delivery",
+            ),
+            (
+                WebhookTopic::FulfillmentOrdersLineItemsPreparedForPickup,
+                "fulfillment_orders/line_items_prepared_for_pickup",
+            ),
+            (
+                WebhookTopic::FulfillmentOrdersMerged,
+                "fulfillment_orders/merged",
+            ),
+            (
+                WebhookTopic::FulfillmentOrdersMoved,
+                "fulfillment_orders/moved",
+            ),
+            (
+                WebhookTopic::FulfillmentOrdersOrderRoutingComplete,
+                "fulfillment_orders/order_routing_complete",
+            ),
+            (
+                WebhookTopic::FulfillmentOrdersPlacedOnHold,
+                "fulfillment_orders/placed_on_hold",
+            ),
+            (
+                WebhookTopic::FulfillmentOrdersRescheduled,
+                "fulfillment_orders/rescheduled",
+            ),
+            (
+                WebhookTopic::FulfillmentOrdersScheduledFulfillmentOrderReady,
+                "fulfillment_orders/scheduled_fulfillment_order_ready",
+            ),
+            (
+                WebhookTopic::FulfillmentOrdersSplit,
+                "fulfillment_orders/split",
+            ),
+            (WebhookTopic::DisputesCreate, "disputes/create"),
+            (WebhookTopic::DisputesUpdate, "disputes/update"),
+            (WebhookTopic::LocationsCreate, "locations/create"),
+            (WebhookTopic::LocationsUpdate, "locations/update"),
+            (WebhookTopic::LocationsDelete, "locations/delete"),
+            (WebhookTopic::LocationsActivate, "locations/activate"),
+            (WebhookTopic::LocationsDeactivate, "locations/deactivate"),
+            (
+                WebhookTopic::SubscriptionContractsCreate,
+                "subscription_contracts/create",
+            ),
+            (
+                WebhookTopic::SubscriptionContractsUpdate,
+                "subscription_contracts/update",
+            ),
+            (
+                WebhookTopic::SubscriptionContractsActivate,
+                "subscription_contracts/activate",
+            ),
+            (
+                WebhookTopic::SubscriptionContractsCancel,
+                "subscription_contracts/cancel",
+            ),
+            (
+                WebhookTopic::SubscriptionContractsExpire,
+                "subscription_contracts/expire",
+            ),
+            (
+                WebhookTopic::SubscriptionContractsFail,
+                "subscription_contracts/fail",
+            ),
+            (
+                WebhookTopic::SubscriptionContractsPause,
+                "subscription_contracts/pause",
+            ),
+        ];
+
+        for (topic, value) in topics {
+            let json = serde_json::to_string(&topic).unwrap();
+            assert_eq!(json, format!("\"{value}\""));
+            let parsed: WebhookTopic = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed, topic);
+
+            let graphql = value.replace('/', "_").to_uppercase();
+            assert_eq!(WebhookTopic::from_graphql_enum(&graphql), Some(topic));
+        }
+
+        assert_eq!(
+            WebhookTopic::from_graphql_enum("FULFILLMENT_ORDERS_CANCELLATION_REQUEST_SUBMITTED"),
+            Some(WebhookTopic::FulfillmentOrdersCancellationRequestSubmitted)
+        );
+        assert_eq!(
+            WebhookTopic::from_graphql_enum("SUBSCRIPTION_CONTRACTS_CANCEL"),
+            Some(WebhookTopic::SubscriptionContractsCancel)
+        );
+    }
+
     #[test]
     fn test_webhook_format_serialization() {
         let format = WebhookFormat::Json;
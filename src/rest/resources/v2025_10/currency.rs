@@ -31,6 +31,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::config::ShopDomain;
 use crate::rest::{ReadOnlyResource, ResourceOperation, ResourcePath, RestResource};
 use crate::HttpMethod;
 
@@ -92,6 +93,11 @@ impl RestResource for Currency {
     fn get_id(&self) -> Option<Self::Id> {
         self.currency.clone()
     }
+
+    /// Currencies have no page of their own in the Shopify admin.
+    fn admin_url(&self, _shop: &ShopDomain) -> Option<String> {
+        None
+    }
 }
 
 impl ReadOnlyResource for Currency {}
@@ -9,6 +9,7 @@
 //! In addition to standard CRUD operations, the DraftOrder resource provides:
 //! - [`DraftOrder::complete`] - Convert a draft order to an actual order
 //! - [`DraftOrder::send_invoice`] - Send an invoice email to the customer
+//! - [`DraftOrder::from_abandoned_checkout`] - Build a draft order from an abandoned checkout
 //!
 //! # Example
 //!
@@ -44,9 +45,21 @@
 //! // Complete the draft order to create an actual order
 //! let params = DraftOrderCompleteParams {
 //!     payment_pending: Some(true),
+//!     source_name: Some("wholesale-portal".to_string()),
 //! };
 //! let completed = updated.complete(&client, Some(params)).await?;
 //! println!("Created order ID: {:?}", completed.order_id);
+//!
+//! // List a customer's unpaid invoices and discard the stale ones
+//! let params = DraftOrderListParams {
+//!     status: Some(DraftOrderStatus::InvoiceSent),
+//!     customer_id: Some(789012),
+//!     ..Default::default()
+//! };
+//! let invoiced = DraftOrder::all(&client, Some(params)).await?;
+//! for draft in invoiced.iter() {
+//!     draft.delete(&client).await?;
+//! }
 //! ```
 
 use chrono::{DateTime, Utc};
@@ -56,7 +69,8 @@ use crate::clients::RestClient;
 use crate::rest::{ResourceError, ResourceOperation, ResourcePath, RestResource};
 use crate::HttpMethod;
 
-use super::common::{Address, NoteAttribute, ShippingLine, TaxLine};
+use super::checkout::{Checkout, CheckoutLineItem};
+use super::common::{Address, NoteAttribute, ShippingLine, SortOrder, TaxLine};
 use super::customer::Customer;
 
 /// The status of a draft order.
@@ -222,6 +236,11 @@ pub struct DraftOrderCompleteParams {
     /// If false or omitted, the order is marked as paid.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub payment_pending: Option<bool>,
+
+    /// The source to record on the resulting order (e.g. `"wholesale-portal"`).
+    /// If omitted, Shopify uses its default source for draft orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_name: Option<String>,
 }
 
 /// A draft order in Shopify.
@@ -434,6 +453,10 @@ impl RestResource for DraftOrder {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
 }
 
 impl DraftOrder {
@@ -444,7 +467,9 @@ impl DraftOrder {
     /// # Arguments
     ///
     /// * `client` - The REST client to use for the request
-    /// * `params` - Optional parameters including `payment_pending`
+    /// * `params` - Optional parameters including `payment_pending` and
+    ///   `source_name`. Without `payment_pending`, the order is marked as paid
+    ///   immediately.
     ///
     /// # Returns
     ///
@@ -460,6 +485,7 @@ impl DraftOrder {
     /// ```rust,ignore
     /// let params = DraftOrderCompleteParams {
     ///     payment_pending: Some(true),
+    ///     ..Default::default()
     /// };
     /// let completed = draft_order.complete(&client, Some(params)).await?;
     /// println!("Created order ID: {:?}", completed.order_id);
@@ -474,14 +500,7 @@ impl DraftOrder {
             operation: "complete",
         })?;
 
-        // Build query string for payment_pending parameter
-        let query_string = params
-            .as_ref()
-            .and_then(|p| p.payment_pending)
-            .map(|pp| format!("?payment_pending={}", pp))
-            .unwrap_or_default();
-
-        let path = format!("draft_orders/{id}/complete{query_string}");
+        let path = complete_path(id, params.as_ref());
         let body = serde_json::json!({});
 
         let response = client.put(&path, body, None).await?;
@@ -609,6 +628,113 @@ impl DraftOrder {
 
         Ok(draft_order)
     }
+
+    /// Builds a draft order from an abandoned checkout.
+    ///
+    /// Used in cart recovery flows to send the customer a custom invoice for
+    /// the items they left behind. The returned draft order is unsaved and
+    /// can be adjusted before calling `save`.
+    ///
+    /// The following are carried over:
+    /// - Line items: variant items keep their `variant_id`; custom items
+    ///   (without a variant) keep their title and price. Quantities and
+    ///   properties are kept for both.
+    /// - The customer (by ID) and email
+    /// - Shipping and billing addresses, and the first shipping line
+    /// - The first discount code, as a fixed-amount applied discount, since
+    ///   draft orders only support a single order-level discount
+    /// - The note and note attributes
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut draft = DraftOrder::from_abandoned_checkout(&checkout);
+    /// draft.note = Some("Recovered cart".to_string());
+    /// let saved = draft.save(&client).await?;
+    /// ```
+    #[must_use]
+    pub fn from_abandoned_checkout(checkout: &Checkout) -> Self {
+        let shipping_line = checkout
+            .shipping_lines
+            .as_ref()
+            .and_then(|lines| lines.first())
+            .map(|line| ShippingLine {
+                title: line.title.clone(),
+                price: line.price.clone(),
+                ..Default::default()
+            });
+
+        let applied_discount = checkout
+            .discount_codes
+            .as_ref()
+            .and_then(|codes| codes.first())
+            .map(|code| AppliedDiscount {
+                title: code.code.clone(),
+                description: None,
+                value: code.amount.clone(),
+                value_type: Some("fixed_amount".to_string()),
+                amount: code.amount.clone(),
+            });
+
+        Self {
+            email: checkout.email.clone(),
+            currency: checkout.currency.clone(),
+            note: checkout.note.clone(),
+            note_attributes: checkout.note_attributes.clone(),
+            customer_id: checkout.customer.as_ref().and_then(|c| c.id),
+            line_items: checkout
+                .line_items
+                .as_ref()
+                .map(|items| items.iter().map(draft_line_item_from_checkout).collect()),
+            shipping_address: checkout.shipping_address.clone(),
+            billing_address: checkout.billing_address.clone(),
+            shipping_line,
+            applied_discount,
+            ..Default::default()
+        }
+    }
+}
+
+/// Converts an abandoned checkout line item into a draft order line item.
+///
+/// Variant items are referenced by `variant_id` so Shopify fills in the
+/// product details. Custom items carry their own title, price and shipping
+/// details, since there is no variant to take them from.
+fn draft_line_item_from_checkout(item: &CheckoutLineItem) -> DraftOrderLineItem {
+    let properties = item
+        .properties
+        .as_ref()
+        .and_then(|properties| match properties {
+            serde_json::Value::Array(list) => Some(list.clone()),
+            serde_json::Value::Object(map) => Some(
+                map.iter()
+                    .map(|(name, value)| serde_json::json!({ "name": name, "value": value }))
+                    .collect(),
+            ),
+            _ => None,
+        });
+
+    if item.variant_id.is_some() {
+        return DraftOrderLineItem {
+            variant_id: item.variant_id,
+            quantity: item.quantity,
+            properties,
+            ..Default::default()
+        };
+    }
+
+    DraftOrderLineItem {
+        title: item.title.clone(),
+        price: item.price.clone(),
+        quantity: item.quantity,
+        taxable: item.taxable,
+        requires_shipping: item.requires_shipping,
+        grams: item.grams,
+        sku: item.sku.clone(),
+        properties,
+        custom: Some(true),
+        ..Default::default()
+    }
 }
 
 /// Parameters for finding a single draft order.
@@ -656,6 +782,14 @@ pub struct DraftOrderListParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<DraftOrderStatus>,
 
+    /// Return only draft orders for the specified customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_id: Option<u64>,
+
+    /// Sort order of the results, e.g. `created_at desc`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<SortOrder>,
+
     /// Comma-separated list of fields to include in the response.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fields: Option<String>,
@@ -693,6 +827,25 @@ pub struct DraftOrderCountParams {
     pub updated_at_max: Option<DateTime<Utc>>,
 }
 
+/// Builds the path for [`DraftOrder::complete`], including any query parameters.
+fn complete_path(id: u64, params: Option<&DraftOrderCompleteParams>) -> String {
+    let mut query = Vec::new();
+    if let Some(params) = params {
+        if let Some(payment_pending) = params.payment_pending {
+            query.push(format!("payment_pending={payment_pending}"));
+        }
+        if let Some(source_name) = &params.source_name {
+            query.push(format!("source_name={}", urlencoding::encode(source_name)));
+        }
+    }
+
+    if query.is_empty() {
+        format!("draft_orders/{id}/complete")
+    } else {
+        format!("draft_orders/{id}/complete?{}", query.join("&"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -900,6 +1053,29 @@ mod tests {
         assert_eq!(DraftOrder::PLURAL, "draft_orders");
     }
 
+    #[test]
+    fn test_complete_path_with_payment_pending_and_source_name() {
+        let params = DraftOrderCompleteParams {
+            payment_pending: Some(true),
+            source_name: Some("wholesale portal".to_string()),
+        };
+
+        assert_eq!(
+            complete_path(123, Some(&params)),
+            "draft_orders/123/complete?payment_pending=true&source_name=wholesale%20portal"
+        );
+    }
+
+    #[test]
+    fn test_complete_path_defaults_to_immediate_payment() {
+        // No payment_pending means Shopify marks the order as paid
+        assert_eq!(complete_path(123, None), "draft_orders/123/complete");
+        assert_eq!(
+            complete_path(123, Some(&DraftOrderCompleteParams::default())),
+            "draft_orders/123/complete"
+        );
+    }
+
     #[test]
     fn test_complete_method_signature() {
         // Verify the complete method signature compiles correctly
@@ -914,6 +1090,7 @@ mod tests {
         // Verify DraftOrderCompleteParams
         let params = DraftOrderCompleteParams {
             payment_pending: Some(true),
+            ..Default::default()
         };
         assert_eq!(params.payment_pending, Some(true));
 
@@ -1003,6 +1180,83 @@ mod tests {
         assert_eq!(empty_json, serde_json::json!({}));
     }
 
+    #[test]
+    fn test_from_abandoned_checkout_carries_over_line_items_and_customer() {
+        let checkout: Checkout = serde_json::from_value(serde_json::json!({
+            "id": 450789469,
+            "email": "bob.norman@example.com",
+            "currency": "USD",
+            "customer": { "id": 207119551, "email": "bob.norman@example.com" },
+            "line_items": [
+                {
+                    "variant_id": 39072856,
+                    "product_id": 632910392,
+                    "title": "IPod Nano - 8GB",
+                    "quantity": 2,
+                    "price": "199.00",
+                    "properties": { "engraving": "Bob" }
+                },
+                {
+                    "title": "Custom engraving service",
+                    "quantity": 1,
+                    "price": "15.50",
+                    "taxable": false,
+                    "requires_shipping": false
+                }
+            ],
+            "shipping_address": { "first_name": "Bob", "city": "Louisville" },
+            "shipping_lines": [{ "title": "Standard", "price": "10.00", "code": "standard" }],
+            "discount_codes": [{ "code": "SPRING", "amount": "5.00", "type": "percentage" }]
+        }))
+        .unwrap();
+
+        let draft = DraftOrder::from_abandoned_checkout(&checkout);
+
+        assert_eq!(draft.id, None);
+        assert_eq!(draft.customer_id, Some(207119551));
+        assert_eq!(draft.email.as_deref(), Some("bob.norman@example.com"));
+
+        let line_items = draft.line_items.as_ref().unwrap();
+        assert_eq!(line_items.len(), 2);
+        assert_eq!(line_items[0].variant_id, Some(39072856));
+        assert_eq!(line_items[0].quantity, Some(2));
+        assert_eq!(line_items[0].price, None);
+        assert_eq!(
+            line_items[0].properties,
+            Some(vec![
+                serde_json::json!({ "name": "engraving", "value": "Bob" })
+            ])
+        );
+
+        // Custom-price items keep their own title and price
+        assert_eq!(line_items[1].variant_id, None);
+        assert_eq!(line_items[1].custom, Some(true));
+        assert_eq!(
+            line_items[1].title.as_deref(),
+            Some("Custom engraving service")
+        );
+        assert_eq!(line_items[1].price.as_deref(), Some("15.50"));
+        assert_eq!(line_items[1].taxable, Some(false));
+
+        assert_eq!(
+            draft.shipping_address.as_ref().unwrap().city.as_deref(),
+            Some("Louisville")
+        );
+        assert_eq!(
+            draft.shipping_line.as_ref().unwrap().title.as_deref(),
+            Some("Standard")
+        );
+        let discount = draft.applied_discount.as_ref().unwrap();
+        assert_eq!(discount.title.as_deref(), Some("SPRING"));
+        assert_eq!(discount.value.as_deref(), Some("5.00"));
+        assert_eq!(discount.value_type.as_deref(), Some("fixed_amount"));
+
+        // The result is ready to save
+        let json = serde_json::to_value(&draft).unwrap();
+        assert_eq!(json["customer_id"], 207119551);
+        assert_eq!(json["line_items"][1]["price"], "15.50");
+    }
+
     #[test]
     fn test_draft_order_get_id_returns_correct_value() {
         let draft_with_id = DraftOrder {
@@ -1019,4 +1273,55 @@ mod tests {
         };
         assert_eq!(draft_without_id.get_id(), None);
     }
+
+    #[test]
+    fn test_draft_order_list_params_serialization() {
+        let updated_at_min = DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let params = DraftOrderListParams {
+            status: Some(DraftOrderStatus::InvoiceSent),
+            customer_id: Some(207119551),
+            since_id: Some(100),
+            updated_at_min: Some(updated_at_min),
+            ids: Some(vec![994118539, 994118540]),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_value(&params).unwrap();
+
+        assert_eq!(json["status"], "invoice_sent");
+        assert_eq!(json["customer_id"], 207119551);
+        assert_eq!(json["since_id"], 100);
+        assert_eq!(json["ids"], serde_json::json!([994118539, 994118540]));
+        assert!(json["updated_at_min"].as_str().is_some());
+        assert!(json.get("updated_at_max").is_none());
+
+        let empty_json = serde_json::to_value(DraftOrderListParams::default()).unwrap();
+        assert_eq!(empty_json, serde_json::json!({}));
+    }
+
+    #[tokio::test]
+    async fn test_draft_order_delete_sends_delete_to_draft_order_path() {
+        use crate::config::ApiVersion;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path("/admin/api/2025-10/draft_orders/994118539.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = RestClient::for_mock_server(&server.uri(), ApiVersion::V2025_10);
+
+        let draft = DraftOrder {
+            id: Some(994118539),
+            ..Default::default()
+        };
+
+        draft.delete(&client).await.unwrap();
+    }
 }
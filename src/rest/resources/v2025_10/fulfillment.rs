@@ -42,10 +42,12 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::clients::RestClient;
+use crate::config::ShopDomain;
 use crate::rest::{ResourceError, ResourceOperation, ResourcePath, RestResource};
 use crate::HttpMethod;
 
 use super::common::Address;
+use super::fulfillment_order::FulfillmentOrder;
 
 /// The status of a fulfillment.
 ///
@@ -272,6 +274,11 @@ pub struct Fulfillment {
     #[serde(skip_serializing)]
     pub admin_graphql_api_id: Option<String>,
 
+    /// The receipt returned by the fulfillment service, such as a
+    /// `testcase` flag and an `authorization` code.
+    #[serde(skip_serializing)]
+    pub receipt: Option<serde_json::Value>,
+
     // --- Core fields ---
     /// The status of the fulfillment.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -376,6 +383,15 @@ impl RestResource for Fulfillment {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
+
+    /// Fulfillments have no page of their own in the Shopify admin.
+    fn admin_url(&self, _shop: &ShopDomain) -> Option<String> {
+        None
+    }
 }
 
 impl Fulfillment {
@@ -542,6 +558,72 @@ impl Fulfillment {
 
         Ok(fulfillment)
     }
+
+    /// Fetches the fulfillment orders this fulfillment was created from.
+    ///
+    /// Fulfillment responses don't carry a `fulfillment_order_id`, so the
+    /// order's fulfillment orders are listed from
+    /// `/admin/api/{version}/orders/{order_id}/fulfillment_orders.json` and
+    /// those with a line item fulfilled here are returned. Each fulfillment
+    /// order line item's `line_item_id` is matched against the IDs of this
+    /// fulfillment's line items.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The REST client to use for the request
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResourceError::PathResolutionFailed`] if the fulfillment has no `order_id`.
+    /// Returns other [`ResourceError`] variants if listing the fulfillment orders fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let fulfillment = Fulfillment::find(&client, 255858046, None).await?;
+    /// for fulfillment_order in fulfillment.fulfillment_orders(&client).await? {
+    ///     println!("{:?} {:?}", fulfillment_order.id, fulfillment_order.status);
+    /// }
+    /// ```
+    pub async fn fulfillment_orders(
+        &self,
+        client: &RestClient,
+    ) -> Result<Vec<FulfillmentOrder>, ResourceError> {
+        let order_id = self.order_id.ok_or(ResourceError::PathResolutionFailed {
+            resource: Self::NAME,
+            operation: "fulfillment_orders",
+        })?;
+
+        let candidates =
+            FulfillmentOrder::all_with_parent(client, "order_id", order_id, None).await?;
+
+        Ok(self.matching_fulfillment_orders(candidates.into_inner()))
+    }
+
+    /// Keeps the fulfillment orders sharing an order line item with this fulfillment.
+    fn matching_fulfillment_orders(
+        &self,
+        candidates: Vec<FulfillmentOrder>,
+    ) -> Vec<FulfillmentOrder> {
+        let line_item_ids: Vec<u64> = self
+            .line_items
+            .iter()
+            .flatten()
+            .filter_map(|line_item| line_item.id)
+            .collect();
+
+        candidates
+            .into_iter()
+            .filter(|fulfillment_order| {
+                fulfillment_order
+                    .line_items
+                    .iter()
+                    .flatten()
+                    .filter_map(|line_item| line_item.line_item_id)
+                    .any(|id| line_item_ids.contains(&id))
+            })
+            .collect()
+    }
 }
 
 /// Parameters for finding a single fulfillment.
@@ -1026,4 +1108,93 @@ mod tests {
         assert_eq!(parsed["origin_address"]["city"], "Los Angeles");
         assert_eq!(parsed["origin_address"]["province_code"], "CA");
     }
+
+    #[test]
+    fn test_matching_fulfillment_orders_links_by_line_item() {
+        use super::super::fulfillment_order::FulfillmentOrderLineItem;
+
+        let fulfillment_order = |id: u64, line_item_ids: &[u64]| FulfillmentOrder {
+            id: Some(id),
+            order_id: Some(450789469),
+            line_items: Some(
+                line_item_ids
+                    .iter()
+                    .map(|&line_item_id| FulfillmentOrderLineItem {
+                        fulfillment_order_id: Some(id),
+                        line_item_id: Some(line_item_id),
+                        ..Default::default()
+                    })
+                    .collect(),
+            ),
+            ..Default::default()
+        };
+
+        let fulfillment: Fulfillment = serde_json::from_value(serde_json::json!({
+            "id": 255858046,
+            "order_id": 450789469,
+            "receipt": {"testcase": true, "authorization": "123456"},
+            "line_items": [{"id": 466157049}, {"id": 518995019}]
+        }))
+        .unwrap();
+        assert_eq!(
+            fulfillment.receipt.as_ref().unwrap()["authorization"],
+            "123456"
+        );
+
+        let matched = fulfillment.matching_fulfillment_orders(vec![
+            fulfillment_order(1046000778, &[466157049]),
+            fulfillment_order(1046000779, &[703073504]),
+            fulfillment_order(1046000780, &[703073504, 518995019]),
+            FulfillmentOrder {
+                id: Some(1046000781),
+                ..Default::default()
+            },
+        ]);
+
+        let ids: Vec<u64> = matched.iter().filter_map(|order| order.id).collect();
+        assert_eq!(ids, vec![1046000778, 1046000780]);
+    }
+
+    #[tokio::test]
+    async fn test_fulfillment_orders_lists_the_order_and_keeps_matches() {
+        use crate::config::ApiVersion;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(
+                "/admin/api/2025-10/orders/450789469/fulfillment_orders.json",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "fulfillment_orders": [
+                    {
+                        "id": 1046000778,
+                        "order_id": 450789469,
+                        "line_items": [{ "id": 1, "line_item_id": 466157049 }]
+                    },
+                    {
+                        "id": 1046000779,
+                        "order_id": 450789469,
+                        "line_items": [{ "id": 2, "line_item_id": 703073504 }]
+                    }
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = RestClient::for_mock_server(&server.uri(), ApiVersion::V2025_10);
+
+        let fulfillment: Fulfillment = serde_json::from_value(serde_json::json!({
+            "id": 255858046,
+            "order_id": 450789469,
+            "line_items": [{"id": 466157049}]
+        }))
+        .unwrap();
+
+        let matched = fulfillment.fulfillment_orders(&client).await.unwrap();
+
+        let ids: Vec<u64> = matched.iter().filter_map(|order| order.id).collect();
+        assert_eq!(ids, vec![1046000778]);
+    }
 }
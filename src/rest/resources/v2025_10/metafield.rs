@@ -52,6 +52,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::clients::RestClient;
+use crate::config::ShopDomain;
 use crate::rest::{
     build_path, ResourceError, ResourceOperation, ResourcePath, ResourceResponse, RestResource,
 };
@@ -523,6 +524,15 @@ impl RestResource for Metafield {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
+
+    /// Metafields have no page of their own in the Shopify admin.
+    fn admin_url(&self, _shop: &ShopDomain) -> Option<String> {
+        None
+    }
 }
 
 impl Metafield {
@@ -32,9 +32,14 @@
 //! - [`CustomerListParams`] - Parameters for listing customers
 //! - [`CustomerFindParams`] - Parameters for finding a single customer
 //! - [`CustomerCountParams`] - Parameters for counting customers
+//! - [`CustomerInvite`] - Invite email details for customer account invites
 //! - [`EmailMarketingConsent`] - Email marketing consent information
 //! - [`SmsMarketingConsent`] - SMS marketing consent information
 //!
+//! The Customer resource provides resource-specific operations:
+//! - `Customer::account_activation_url()` - Generate a one-time account activation URL
+//! - `Customer::send_invite()` - Send an account invite email
+//!
 //! ## Order Resource
 //!
 //! - [`Order`] - An order in a Shopify store
@@ -47,6 +52,7 @@
 //! - [`DiscountCode`] - A discount code applied to an order
 //! - [`Refund`] - A refund associated with an order (embedded)
 //! - [`OrderFulfillment`] - Fulfillment data embedded in order responses
+//! - [`ClientDetails`] - Browser details of the session that placed an order
 //!
 //! The Order resource also provides resource-specific operations:
 //! - `Order::cancel()` - Cancel an order
@@ -68,6 +74,17 @@
 //! The DraftOrder resource provides resource-specific operations:
 //! - `DraftOrder::complete()` - Convert draft to actual order (PUT method)
 //! - `DraftOrder::send_invoice()` - Send invoice email to customer
+//! - `DraftOrder::from_abandoned_checkout()` - Build a draft order from an abandoned checkout
+//!
+//! ## Checkout Resource (Abandoned Checkouts, Read-Only)
+//!
+//! - [`Checkout`] - An abandoned checkout
+//! - [`CheckoutLineItem`] - A line item in an abandoned checkout
+//! - [`CheckoutListParams`] - Parameters for listing abandoned checkouts
+//! - [`CheckoutCountParams`] - Parameters for counting abandoned checkouts
+//! - [`CheckoutFindParams`] - Placeholder find parameters (no find endpoint)
+//!
+//! Abandoned checkouts only support `all()` and `count()`.
 //!
 //! ## FulfillmentOrder Resource (Modern Fulfillment Workflows)
 //!
@@ -103,6 +120,8 @@
 //! The GiftCard resource provides resource-specific operations:
 //! - `GiftCard::disable()` - Disable a gift card (cannot be re-enabled)
 //! - `GiftCard::search()` - Search for gift cards by query
+//! - `GiftCard::balance()` - Fetch the current balance
+//! - `GiftCard::transactions()` - List balance adjustments as [`GiftCardAdjustment`]s
 //!
 //! Key constraints:
 //! - `code` is write-only (only `last_characters` readable after creation)
@@ -125,6 +144,7 @@
 //!
 //! Use `Transaction::all_with_parent()` to list transactions under an order.
 //! Use `Transaction::count_with_parent()` to count transactions under an order.
+//! Use `Transaction::create()` to capture, void, or refund a payment.
 //!
 //! ## `RefundResource` (Nested under Order)
 //!
@@ -173,6 +193,8 @@
 //! `InventoryItem` uses standalone paths only (`/inventory_items/{id}`).
 //! The list operation requires the `ids` parameter (comma-separated).
 //! `InventoryItem` is linked to `Variant` via `Variant.inventory_item_id`.
+//! [`Variant::set_available_quantity`] sets a variant's stock at a location
+//! through its inventory item.
 //!
 //! ## `InventoryLevel` Resource
 //!
@@ -191,6 +213,7 @@
 //! - [`Shop`] - The current shop's information
 //!
 //! The Shop resource is a read-only singleton. Use `Shop::current()` to retrieve it.
+//! [`resolve_shop_id`] returns the shop's numeric ID, cached on the client.
 //! Shop does not support standard CRUD operations (no Create, Update, Delete).
 //!
 //! ## Location Resource (Read-Only)
@@ -322,6 +345,7 @@
 //!
 //! - [`Asset`] - A theme asset nested under a theme
 //! - [`AssetListParams`] - Parameters for listing assets
+//! - [`UploadProgress`] - Progress reported by `Asset::upload_stream()`
 //!
 //! Assets are files that make up a theme (templates, CSS, JS, images).
 //! Assets use a string `key` as their identifier (not numeric ID).
@@ -336,6 +360,7 @@
 //! - `Asset::all_for_theme()` - List all assets in a theme
 //! - `Asset::find_by_key()` - Find a specific asset by key
 //! - `Asset::save_to_theme()` - Create or update an asset
+//! - `Asset::upload_stream()` - Upload a large binary asset with progress
 //! - `Asset::delete_from_theme()` - Delete an asset
 //!
 //! ## Billing Resources
@@ -397,6 +422,7 @@
 //! - [`common::ProductImage`] - Product image with dimensions
 //! - [`common::ProductOption`] - Product option (e.g., Size, Color)
 //! - [`common::MetafieldOwner`] - Owner types for metafields
+//! - [`common::MetafieldInput`] - Metafields created along with their owner
 //! - [`common::WebhookTopic`] - Webhook event topics
 //! - [`common::WebhookFormat`] - Webhook payload formats
 //! - [`common::CollectionImage`] - Collection image data
@@ -405,6 +431,7 @@
 //! - [`common::BlogCommentable`] - Blog comment settings
 //! - [`common::ChargeStatus`] - Billing charge status
 //! - [`common::ChargeCurrency`] - Billing charge currency
+//! - [`common::SortOrder`] - The `order` parameter of list requests
 //!
 //! # Example
 //!
@@ -793,6 +820,7 @@ mod application_charge;
 mod article;
 mod asset;
 mod blog;
+mod checkout;
 mod collect;
 mod collection_trait;
 mod comment;
@@ -836,9 +864,9 @@ mod webhook;
 // Re-export common types for convenience
 pub use common::{
     Address, BlogCommentable, ChargeCurrency, ChargeStatus, CollectionImage, CustomerAddress,
-    DiscountAllocation, DiscountApplication, LineItem, LineItemProperty, MetafieldOwner, Money,
-    MoneySet, NoteAttribute, ProductImage, ProductOption, ShippingLine, SmartCollectionRule,
-    TaxLine, ThemeRole, WebhookFormat, WebhookTopic,
+    DiscountAllocation, DiscountApplication, LineItem, LineItemProperty, MetafieldInput,
+    MetafieldOwner, Money, MoneySet, NoteAttribute, ProductImage, ProductOption, ShippingLine,
+    SmartCollectionRule, SortDirection, SortOrder, TaxLine, ThemeRole, WebhookFormat, WebhookTopic,
 };
 
 // Re-export Product resource types
@@ -852,14 +880,19 @@ pub use variant::{Variant, VariantCountParams, VariantFindParams, VariantListPar
 
 // Re-export Customer resource types
 pub use customer::{
-    Customer, CustomerCountParams, CustomerFindParams, CustomerListParams, CustomerState,
-    EmailMarketingConsent, SmsMarketingConsent,
+    Customer, CustomerCountParams, CustomerFindParams, CustomerInvite, CustomerListParams,
+    CustomerState, EmailMarketingConsent, SmsMarketingConsent,
 };
 
 // Re-export Order resource types
 pub use order::{
-    CancelReason, DiscountCode, FinancialStatus, FulfillmentStatus, Order, OrderCountParams,
-    OrderFindParams, OrderFulfillment, OrderListParams, Refund,
+    CancelReason, ClientDetails, DiscountCode, FinancialStatus, FulfillmentStatus, Order,
+    OrderCountParams, OrderFindParams, OrderFulfillment, OrderListParams, Refund,
+};
+
+// Re-export Checkout resource types
+pub use checkout::{
+    Checkout, CheckoutCountParams, CheckoutFindParams, CheckoutLineItem, CheckoutListParams,
 };
 
 // Re-export DraftOrder resource types
@@ -879,7 +912,9 @@ pub use fulfillment_order::{
 };
 
 // Re-export GiftCard resource types
-pub use gift_card::{GiftCard, GiftCardCountParams, GiftCardFindParams, GiftCardListParams};
+pub use gift_card::{
+    GiftCard, GiftCardAdjustment, GiftCardCountParams, GiftCardFindParams, GiftCardListParams,
+};
 
 // Re-export Transaction resource types
 pub use transaction::{
@@ -918,7 +953,7 @@ pub use location::{
 pub use redirect::{Redirect, RedirectCountParams, RedirectFindParams, RedirectListParams};
 
 // Re-export Shop resource types
-pub use shop::Shop;
+pub use shop::{resolve_shop_id, Shop};
 
 // Re-export Metafield resource types
 pub use metafield::{Metafield, MetafieldCountParams, MetafieldFindParams, MetafieldListParams};
@@ -952,7 +987,7 @@ pub use article::{
 pub use theme::{Theme, ThemeFindParams, ThemeListParams};
 
 // Re-export Asset resource types
-pub use asset::{Asset, AssetListParams};
+pub use asset::{Asset, AssetListParams, UploadProgress};
 
 // Re-export Billing resource types
 pub use application_charge::{
@@ -53,6 +53,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::config::ShopDomain;
 use crate::rest::{ResourceOperation, ResourcePath, RestResource};
 use crate::HttpMethod;
 
@@ -339,9 +340,27 @@ impl RestResource for PriceRule {
         ),
     ];
 
+    /// Price rules are deprecated in favor of the GraphQL discount APIs.
+    const DEPRECATION: Option<&'static str> =
+        Some("PriceRule is deprecated; use the GraphQL discount APIs instead");
+
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
+
+    /// Price rules open on the admin's discounts page.
+    fn admin_url(&self, shop: &ShopDomain) -> Option<String> {
+        self.id.map(|id| {
+            format!(
+                "https://admin.shopify.com/store/{}/discounts/{id}",
+                shop.shop_name()
+            )
+        })
+    }
 }
 
 /// Parameters for finding a single price rule.
@@ -45,6 +45,15 @@
 //! };
 //! let saved = image.save(&client).await?;
 //!
+//! // Upload an image and show it for two variants
+//! let image = ProductImageResource::create_with_variant_ids(
+//!     &client,
+//!     632910392,
+//!     "https://example.com/red.png",
+//!     vec![808950810, 49148385],
+//! )
+//! .await?;
+//!
 //! // Upload via base64
 //! let image_data = std::fs::read("image.png").unwrap();
 //! let base64_image = base64::encode(&image_data);
@@ -61,6 +70,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::clients::RestClient;
+use crate::config::ShopDomain;
 use crate::rest::{
     build_path, get_path, ResourceError, ResourceOperation, ResourcePath, ResourceResponse,
     RestResource,
@@ -160,6 +170,59 @@ pub struct ProductImageResource {
 }
 
 impl ProductImageResource {
+    /// Uploads an image from a URL and associates it with variants.
+    ///
+    /// Sends a single POST to `products/{product_id}/images` with the
+    /// image's `src` and `variant_ids`, so each listed variant's `image_id`
+    /// points at the new image once it is created.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The REST client
+    /// * `product_id` - The parent product ID
+    /// * `src` - The URL Shopify downloads the image from
+    /// * `variant_ids` - The variants to show the image for
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResourceError::ValidationFailed`] if Shopify rejects the
+    /// image, for example because a variant belongs to another product.
+    /// Returns [`ResourceError::NotFound`] if the product doesn't exist.
+    pub async fn create_with_variant_ids(
+        client: &RestClient,
+        product_id: u64,
+        src: &str,
+        variant_ids: Vec<u64>,
+    ) -> Result<Self, ResourceError> {
+        let mut ids: HashMap<&str, String> = HashMap::new();
+        ids.insert("product_id", product_id.to_string());
+
+        let available_ids: Vec<&str> = ids.keys().copied().collect();
+        let path = get_path(Self::PATHS, ResourceOperation::Create, &available_ids).ok_or(
+            ResourceError::PathResolutionFailed {
+                resource: Self::NAME,
+                operation: "create",
+            },
+        )?;
+
+        let url = build_path(path.template, &ids);
+        let body = variant_image_body(product_id, src, variant_ids);
+        let response = client.post(&url, body, None).await?;
+
+        if !response.is_ok() {
+            return Err(ResourceError::from_http_response(
+                response.code,
+                &response.body,
+                Self::NAME,
+                None,
+                response.request_id(),
+            ));
+        }
+
+        let key = Self::resource_key();
+        ResourceResponse::from_http_response(response, &key).map(ResourceResponse::into_inner)
+    }
+
     /// Counts images under a specific product.
     ///
     /// # Arguments
@@ -346,6 +409,32 @@ impl RestResource for ProductImageResource {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
+
+    /// Shopify wraps a single product image in an `image` key.
+    fn resource_key() -> String {
+        "image".to_string()
+    }
+
+    /// Product images have no page of their own in the Shopify admin.
+    fn admin_url(&self, _shop: &ShopDomain) -> Option<String> {
+        None
+    }
+}
+
+/// Builds the create body for an image shown for specific variants.
+fn variant_image_body(product_id: u64, src: &str, variant_ids: Vec<u64>) -> serde_json::Value {
+    serde_json::json!({
+        "image": ProductImageResource {
+            product_id: Some(product_id),
+            src: Some(src.to_string()),
+            variant_ids: Some(variant_ids),
+            ..Default::default()
+        }
+    })
 }
 
 /// Parameters for finding a single image.
@@ -544,6 +633,70 @@ mod tests {
     fn test_product_image_resource_constants() {
         assert_eq!(ProductImageResource::NAME, "ProductImageResource");
         assert_eq!(ProductImageResource::PLURAL, "images");
+        assert_eq!(ProductImageResource::resource_key(), "image");
+    }
+
+    #[test]
+    fn test_variant_image_body_includes_variant_ids() {
+        let body = variant_image_body(
+            632910392,
+            "https://example.com/red.png",
+            vec![808950810, 49148385],
+        );
+
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "image": {
+                    "product_id": 632910392,
+                    "src": "https://example.com/red.png",
+                    "variant_ids": [808950810, 49148385]
+                }
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_with_variant_ids_round_trips_through_image_key() {
+        use crate::config::ApiVersion;
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/admin/api/2025-10/products/632910392/images.json"))
+            .and(body_json(serde_json::json!({
+                "image": {
+                    "product_id": 632910392,
+                    "src": "https://example.com/red.png",
+                    "variant_ids": [808950810]
+                }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "image": {
+                    "id": 1001473906,
+                    "product_id": 632910392,
+                    "position": 4,
+                    "src": "https://cdn.shopify.com/s/files/red.png",
+                    "variant_ids": [808950810]
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = RestClient::for_mock_server(&server.uri(), ApiVersion::V2025_10);
+
+        let image = ProductImageResource::create_with_variant_ids(
+            &client,
+            632910392,
+            "https://example.com/red.png",
+            vec![808950810],
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(image.id, Some(1001473906));
+        assert_eq!(image.variant_ids, Some(vec![808950810]));
     }
 
     #[test]
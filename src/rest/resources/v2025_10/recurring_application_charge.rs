@@ -59,6 +59,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::clients::RestClient;
+use crate::config::ShopDomain;
 use crate::rest::{ResourceError, ResourceOperation, ResourcePath, RestResource};
 use crate::HttpMethod;
 
@@ -438,6 +439,11 @@ impl RestResource for RecurringApplicationCharge {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    /// Recurring application charges have no page of their own in the Shopify admin.
+    fn admin_url(&self, _shop: &ShopDomain) -> Option<String> {
+        None
+    }
 }
 
 /// Parameters for finding a single recurring application charge.
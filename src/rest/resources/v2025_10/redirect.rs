@@ -35,6 +35,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::config::ShopDomain;
 use crate::rest::{ResourceOperation, ResourcePath, RestResource};
 use crate::HttpMethod;
 
@@ -116,6 +117,11 @@ impl RestResource for Redirect {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    /// URL redirects have no page of their own in the Shopify admin.
+    fn admin_url(&self, _shop: &ShopDomain) -> Option<String> {
+        None
+    }
 }
 
 /// Parameters for finding a single redirect.
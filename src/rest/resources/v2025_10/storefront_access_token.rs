@@ -38,6 +38,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::config::ShopDomain;
 use crate::rest::{ResourceOperation, ResourcePath, RestResource};
 use crate::HttpMethod;
 
@@ -134,6 +135,11 @@ impl RestResource for StorefrontAccessToken {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    /// Storefront access tokens have no page of their own in the Shopify admin.
+    fn admin_url(&self, _shop: &ShopDomain) -> Option<String> {
+        None
+    }
 }
 
 #[cfg(test)]
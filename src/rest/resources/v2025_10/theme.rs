@@ -140,6 +140,10 @@ impl RestResource for Theme {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
 }
 
 /// Parameters for finding a single theme.
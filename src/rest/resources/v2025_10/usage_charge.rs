@@ -51,6 +51,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::clients::RestClient;
+use crate::config::ShopDomain;
 use crate::rest::{
     build_path, get_path, ResourceError, ResourceOperation, ResourcePath, ResourceResponse,
     RestResource,
@@ -292,6 +293,11 @@ impl RestResource for UsageCharge {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    /// Usage charges have no page of their own in the Shopify admin.
+    fn admin_url(&self, _shop: &ShopDomain) -> Option<String> {
+        None
+    }
 }
 
 /// Parameters for finding a single usage charge.
@@ -38,6 +38,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::clients::RestClient;
+use crate::config::ShopDomain;
 use crate::rest::{
     ReadOnlyResource, ResourceError, ResourceOperation, ResourcePath, ResourceResponse,
     RestResource,
@@ -193,6 +194,11 @@ impl RestResource for User {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    /// Staff accounts have no page of their own in the Shopify admin.
+    fn admin_url(&self, _shop: &ShopDomain) -> Option<String> {
+        None
+    }
 }
 
 impl ReadOnlyResource for User {}
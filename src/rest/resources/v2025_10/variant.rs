@@ -35,14 +35,30 @@
 //!     ..Default::default()
 //! };
 //! let saved = variant.save(&client).await?;
+//!
+//! // Set the variant's available stock at a location
+//! let level = saved.set_available_quantity(&client, 655441491, 25).await?;
 //! ```
+//!
+//! # Inventory
+//!
+//! `inventory_quantity` is read-only. Stock is tracked per location on the
+//! variant's inventory item, so use [`Variant::set_available_quantity`] (or
+//! [`InventoryLevel`] directly) to change it.
+
+use std::collections::HashMap;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::rest::{ResourceOperation, ResourcePath, RestResource};
+use crate::clients::RestClient;
+use crate::config::ShopDomain;
+use crate::rest::{ResourceError, ResourceOperation, ResourcePath, RestResource};
 use crate::HttpMethod;
 
+use super::common::PresentmentPrice;
+use super::InventoryLevel;
+
 /// The unit of measurement for variant weight.
 ///
 /// Used to specify whether the weight is in kilograms, grams, pounds, or ounces.
@@ -60,6 +76,33 @@ pub enum WeightUnit {
     Oz,
 }
 
+impl WeightUnit {
+    /// Returns the number of grams in one of this unit.
+    const fn grams(self) -> f64 {
+        match self {
+            Self::Kg => 1000.0,
+            Self::G => 1.0,
+            Self::Lb => 453.592_37,
+            Self::Oz => 28.349_523_125,
+        }
+    }
+
+    /// Converts `value` from this unit to `to`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::rest::resources::v2025_10::WeightUnit;
+    ///
+    /// let pounds = WeightUnit::Kg.convert(1.0, WeightUnit::Lb);
+    /// assert!((pounds - 2.204_622_6).abs() < 1e-6);
+    /// ```
+    #[must_use]
+    pub fn convert(self, value: f64, to: Self) -> f64 {
+        value * self.grams() / to.grams()
+    }
+}
+
 /// A product variant in a Shopify store.
 ///
 /// Variants represent different versions of a product, typically distinguished by
@@ -103,6 +146,7 @@ pub enum WeightUnit {
 /// - `created_at` - When the variant was created
 /// - `updated_at` - When the variant was last updated
 /// - `admin_graphql_api_id` - The GraphQL API ID
+/// - `presentment_prices` - Prices in the shop's presentment currencies
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 pub struct Variant {
     /// The unique identifier of the variant.
@@ -219,6 +263,11 @@ pub struct Variant {
     /// Read-only field.
     #[serde(skip_serializing)]
     pub admin_graphql_api_id: Option<String>,
+
+    /// The variant's prices in each of the shop's presentment currencies.
+    /// Read-only field.
+    #[serde(skip_serializing)]
+    pub presentment_prices: Option<Vec<PresentmentPrice>>,
 }
 
 impl RestResource for Variant {
@@ -293,6 +342,126 @@ impl RestResource for Variant {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
+
+    /// Variants open under their product, so `product_id` must be set too.
+    fn admin_url(&self, shop: &ShopDomain) -> Option<String> {
+        let (product_id, id) = self.product_id.zip(self.id)?;
+        Some(format!(
+            "https://admin.shopify.com/store/{}/products/{product_id}/variants/{id}",
+            shop.shop_name()
+        ))
+    }
+}
+
+impl Variant {
+    /// Returns the variant's weight converted to `unit`.
+    ///
+    /// Uses `weight` and `weight_unit` when both are set, falling back to
+    /// the deprecated `grams` field. Returns `None` if neither is available.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::rest::resources::v2025_10::{Variant, WeightUnit};
+    ///
+    /// let variant = Variant {
+    ///     weight: Some(2.0),
+    ///     weight_unit: Some(WeightUnit::Kg),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(variant.weight_in(WeightUnit::G), Some(2000.0));
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn weight_in(&self, unit: WeightUnit) -> Option<f64> {
+        match (self.weight, self.weight_unit) {
+            (Some(weight), Some(weight_unit)) => Some(weight_unit.convert(weight, unit)),
+            _ => self
+                .grams
+                .map(|grams| WeightUnit::G.convert(grams as f64, unit)),
+        }
+    }
+
+    /// Returns the variant's option values in order, skipping unset options.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::rest::resources::v2025_10::Variant;
+    ///
+    /// let variant = Variant {
+    ///     option1: Some("Large".to_string()),
+    ///     option2: Some("Blue".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(variant.option_values(), vec!["Large", "Blue"]);
+    /// ```
+    #[must_use]
+    pub fn option_values(&self) -> Vec<&str> {
+        [&self.option1, &self.option2, &self.option3]
+            .into_iter()
+            .filter_map(Option::as_deref)
+            .collect()
+    }
+
+    /// Sets the variant's available quantity at a location.
+    ///
+    /// Routes through [`InventoryLevel::set`] using the variant's
+    /// `inventory_item_id`, since `inventory_quantity` can no longer be
+    /// written directly.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The REST client to use for the request
+    /// * `location_id` - The ID of the location to set stock at
+    /// * `quantity` - The absolute available quantity
+    ///
+    /// # Returns
+    ///
+    /// The updated inventory level.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResourceError::ValidationFailed`] if the variant has no
+    /// `inventory_item_id` (for example, a variant built locally rather than
+    /// fetched from the API). No request is sent in that case.
+    /// Returns any error from the inventory level request.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let variant = Variant::find(&client, 808950810, None).await?;
+    /// let level = variant.set_available_quantity(&client, 655441491, 25).await?;
+    /// assert_eq!(level.available, Some(25));
+    /// ```
+    pub async fn set_available_quantity(
+        &self,
+        client: &RestClient,
+        location_id: u64,
+        quantity: i64,
+    ) -> Result<InventoryLevel, ResourceError> {
+        let inventory_item_id = self.require_inventory_item_id()?;
+        InventoryLevel::set(client, inventory_item_id, location_id, quantity, None).await
+    }
+
+    /// Returns the variant's inventory item ID, or a validation error.
+    fn require_inventory_item_id(&self) -> Result<u64, ResourceError> {
+        self.inventory_item_id
+            .ok_or_else(|| ResourceError::ValidationFailed {
+                errors: HashMap::from([(
+                    "inventory_item_id".to_string(),
+                    vec![
+                        "is missing; fetch the variant from the API before setting its inventory"
+                            .to_string(),
+                    ],
+                )]),
+                request_id: None,
+            })
+    }
 }
 
 /// Parameters for finding a single variant.
@@ -371,6 +540,7 @@ mod tests {
                     .with_timezone(&Utc),
             ), // Read-only
             admin_graphql_api_id: Some("gid://shopify/ProductVariant/12345".to_string()), // Read-only
+            presentment_prices: None, // Read-only
         };
 
         let json = serde_json::to_string(&variant).unwrap();
@@ -622,4 +792,100 @@ mod tests {
         };
         assert_eq!(variant_without_id.get_id(), None);
     }
+
+    #[tokio::test]
+    async fn test_set_available_quantity_posts_to_inventory_levels_set() {
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/admin/api/2025-10/inventory_levels/set.json"))
+            .and(body_json(serde_json::json!({
+                "inventory_item_id": 341629834,
+                "location_id": 655441491,
+                "available": 25
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "inventory_level": {
+                    "inventory_item_id": 341629834,
+                    "location_id": 655441491,
+                    "available": 25
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client =
+            RestClient::for_mock_server(&server.uri(), crate::config::ApiVersion::V2025_10);
+
+        let variant: Variant = serde_json::from_value(serde_json::json!({
+            "id": 808950810,
+            "product_id": 632910392,
+            "inventory_item_id": 341629834,
+            "inventory_quantity": 10
+        }))
+        .unwrap();
+        let level = variant
+            .set_available_quantity(&client, 655441491, 25)
+            .await
+            .unwrap();
+
+        assert_eq!(level.available, Some(25));
+    }
+
+    #[test]
+    fn test_weight_in_converts_between_kg_and_lb() {
+        let variant = Variant {
+            weight: Some(1.0),
+            weight_unit: Some(WeightUnit::Kg),
+            ..Default::default()
+        };
+        let pounds = variant.weight_in(WeightUnit::Lb).unwrap();
+        assert!((pounds - 2.204_622_62).abs() < 1e-6);
+        assert_eq!(variant.weight_in(WeightUnit::Kg), Some(1.0));
+
+        let variant = Variant {
+            weight: Some(2.204_622_62),
+            weight_unit: Some(WeightUnit::Lb),
+            ..Default::default()
+        };
+        let kilograms = variant.weight_in(WeightUnit::Kg).unwrap();
+        assert!((kilograms - 1.0).abs() < 1e-6);
+
+        let variant = Variant {
+            grams: Some(500),
+            ..Default::default()
+        };
+        assert_eq!(variant.weight_in(WeightUnit::Kg), Some(0.5));
+        assert_eq!(Variant::default().weight_in(WeightUnit::Kg), None);
+    }
+
+    #[test]
+    fn test_option_values_skips_unset_options() {
+        let variant = Variant {
+            option1: Some("Large".to_string()),
+            option2: None,
+            option3: Some("Cotton".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(variant.option_values(), vec!["Large", "Cotton"]);
+        assert!(Variant::default().option_values().is_empty());
+    }
+
+    #[test]
+    fn test_set_available_quantity_requires_inventory_item_id() {
+        let variant = Variant {
+            id: Some(808950810),
+            ..Default::default()
+        };
+
+        let result = variant.require_inventory_item_id();
+
+        assert!(matches!(
+            result,
+            Err(ResourceError::ValidationFailed { ref errors, .. })
+                if errors.contains_key("inventory_item_id")
+        ));
+    }
 }
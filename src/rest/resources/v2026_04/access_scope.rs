@@ -29,6 +29,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::clients::RestClient;
+use crate::config::ShopDomain;
 use crate::rest::{
     ReadOnlyResource, ResourceError, ResourceOperation, ResourcePath, ResourceResponse,
     RestResource,
@@ -120,6 +121,11 @@ impl RestResource for AccessScope {
     fn get_id(&self) -> Option<Self::Id> {
         self.handle.clone()
     }
+
+    /// Access scopes have no page of their own in the Shopify admin.
+    fn admin_url(&self, _shop: &ShopDomain) -> Option<String> {
+        None
+    }
 }
 
 impl ReadOnlyResource for AccessScope {}
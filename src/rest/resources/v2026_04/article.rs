@@ -41,6 +41,9 @@
 //! // Count articles in a blog
 //! let count = Article::count_with_parent(&client, "blog_id", 123, None).await?;
 //! println!("Total articles: {}", count);
+//!
+//! // Fetch an article's metafields
+//! let metafields = saved.metafields(&client, None).await?;
 //! ```
 
 use std::collections::HashMap;
@@ -50,10 +53,14 @@ use serde::{Deserialize, Serialize};
 
 use crate::clients::RestClient;
 use crate::rest::{
-    build_path, get_path, ResourceError, ResourceOperation, ResourcePath, RestResource,
+    build_path, get_path, ResourceError, ResourceOperation, ResourcePath, ResourceResponse,
+    RestResource,
 };
 use crate::HttpMethod;
 
+use super::common::MetafieldOwner;
+use super::{Metafield, MetafieldListParams};
+
 /// An image associated with an article.
 ///
 /// Similar to `CollectionImage`, this represents the featured image
@@ -114,6 +121,7 @@ pub struct ArticleImage {
 /// - `tags` - Comma-separated tags for the article
 /// - `image` - The featured image for the article
 /// - `published_at` - When the article was published (can be set to future for scheduling)
+/// - `metafields` - Metafields to create along with the article
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
 pub struct Article {
     /// The unique identifier of the article.
@@ -167,6 +175,13 @@ pub struct Article {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub published_at: Option<DateTime<Utc>>,
 
+    /// Metafields to create along with the article.
+    ///
+    /// Shopify only returns these when they are requested explicitly; use
+    /// [`Article::metafields`] to fetch an existing article's metafields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metafields: Option<Vec<Metafield>>,
+
     /// The ID of the user who authored the article.
     /// Read-only field.
     #[serde(skip_serializing)]
@@ -189,6 +204,39 @@ pub struct Article {
 }
 
 impl Article {
+    /// Fetches the metafields attached to this article.
+    ///
+    /// Sends a GET request to `/admin/api/{version}/articles/{id}/metafields.json`
+    /// through [`Metafield::all_for_owner`].
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The REST client to use for the request
+    /// * `params` - Optional parameters for filtering (e.g. by namespace)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResourceError::PathResolutionFailed`] if the article has no ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let article = Article::find(&client, 134645308, None).await?;
+    /// let metafields = article.metafields(&client, None).await?;
+    /// ```
+    pub async fn metafields(
+        &self,
+        client: &RestClient,
+        params: Option<MetafieldListParams>,
+    ) -> Result<ResourceResponse<Vec<Metafield>>, ResourceError> {
+        let id = self.id.ok_or(ResourceError::PathResolutionFailed {
+            resource: Self::NAME,
+            operation: "metafields",
+        })?;
+
+        Metafield::all_for_owner(client, MetafieldOwner::Article, id, params).await
+    }
+
     /// Counts articles under a specific blog.
     ///
     /// # Arguments
@@ -354,6 +402,10 @@ impl RestResource for Article {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
 }
 
 /// Parameters for finding a single article.
@@ -495,6 +547,7 @@ mod tests {
                     .unwrap()
                     .with_timezone(&Utc),
             ),
+            metafields: None,
             user_id: Some(111222),
             created_at: Some(
                 DateTime::parse_from_rfc3339("2024-01-10T08:00:00Z")
@@ -782,4 +835,49 @@ mod tests {
         assert_eq!(Article::NAME, "Article");
         assert_eq!(Article::PLURAL, "articles");
     }
+
+    #[test]
+    fn test_article_deserializes_summary_html_and_inline_metafields() {
+        let article: Article = serde_json::from_value(serde_json::json!({
+            "id": 134645308,
+            "blog_id": 241253187,
+            "title": "My new blog post",
+            "handle": "my-new-blog-post",
+            "summary_html": "<p>A short teaser.</p>",
+            "template_suffix": "feature",
+            "image": { "src": "https://cdn.shopify.com/article.jpg", "alt": "Teaser image" },
+            "metafields": [{
+                "id": 721389482,
+                "namespace": "seo",
+                "key": "hidden",
+                "value": "1",
+                "type": "number_integer"
+            }]
+        }))
+        .unwrap();
+
+        assert_eq!(
+            article.summary_html.as_deref(),
+            Some("<p>A short teaser.</p>")
+        );
+        assert_eq!(article.template_suffix.as_deref(), Some("feature"));
+        assert_eq!(
+            article.image.and_then(|image| image.alt).as_deref(),
+            Some("Teaser image")
+        );
+        let metafields = article.metafields.unwrap();
+        assert_eq!(metafields[0].namespace.as_deref(), Some("seo"));
+        assert_eq!(metafields[0].key.as_deref(), Some("hidden"));
+    }
+
+    #[test]
+    fn test_article_metafields_use_nested_article_path() {
+        let path = get_path(Metafield::PATHS, ResourceOperation::All, &["article_id"]).unwrap();
+        let ids = HashMap::from([("article_id", "134645308")]);
+
+        assert_eq!(
+            build_path(path.template, &ids),
+            "articles/134645308/metafields"
+        );
+    }
 }
@@ -24,6 +24,8 @@
 //!
 //! Use `Asset::upload_from_bytes()` for creating binary assets and
 //! `Asset::download_content()` for retrieving content regardless of type.
+//! For large files, `Asset::upload_stream()` reads from an async reader and
+//! reports progress as it goes.
 //!
 //! # Example
 //!
@@ -62,11 +64,36 @@ use base64::prelude::*;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt};
 
 use crate::clients::RestClient;
 use crate::rest::{build_path, get_path, ResourceError, ResourceOperation, ResourcePath};
 use crate::HttpMethod;
 
+/// Size of each chunk read by [`Asset::upload_stream`].
+const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Minimum timeout for [`Asset::upload_stream`] requests.
+const UPLOAD_BASE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Conservative upload throughput used to scale the timeout, in bytes per second.
+const UPLOAD_MIN_BYTES_PER_SECOND: u64 = 256 * 1024;
+
+/// Largest buffer reserved up front from an upload's size hint.
+///
+/// Larger uploads still work; the buffer grows as content is read.
+const UPLOAD_MAX_PREALLOCATION: usize = 8 * 1024 * 1024;
+
+/// Progress of an [`Asset::upload_stream`] upload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UploadProgress {
+    /// Number of bytes read from the source so far.
+    pub bytes_read: u64,
+    /// Total size of the source, if known from the size hint.
+    pub total_bytes: Option<u64>,
+}
+
 /// A theme asset in a Shopify store.
 ///
 /// Assets are files that make up a theme, including Liquid templates,
@@ -525,18 +552,7 @@ impl Asset {
         theme_id: u64,
         asset: &Self,
     ) -> Result<Self, ResourceError> {
-        let mut ids: HashMap<&str, String> = HashMap::new();
-        ids.insert("theme_id", theme_id.to_string());
-
-        let available_ids: Vec<&str> = ids.keys().copied().collect();
-        let path = get_path(Self::PATHS, ResourceOperation::Update, &available_ids).ok_or(
-            ResourceError::PathResolutionFailed {
-                resource: "Asset",
-                operation: "save",
-            },
-        )?;
-
-        let url = build_path(path.template, &ids);
+        let url = Self::save_path(theme_id)?;
 
         // Wrap asset in the asset key
         let mut body_map = serde_json::Map::new();
@@ -555,13 +571,110 @@ impl Asset {
         let body = serde_json::Value::Object(body_map);
 
         let response = client.put(&url, body, None).await?;
+        Self::saved_asset_from_response(&response, &asset.key)
+    }
 
+    /// Uploads a binary asset from an async reader, reporting progress.
+    ///
+    /// The reader is consumed in 64 KiB chunks and `on_progress` is invoked
+    /// after each chunk. Shopify requires the content to be sent as a single
+    /// base64-encoded JSON body, so the whole file is buffered before the
+    /// PUT request is sent.
+    ///
+    /// The request timeout scales with `size_hint` (or the number of bytes
+    /// read, if no hint is given) so large fonts and images are not cut off
+    /// by the default timeout.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The REST client to use
+    /// * `theme_id` - The ID of the theme
+    /// * `key` - The path for the asset (e.g., `assets/font.woff2`)
+    /// * `reader` - The source of the asset content
+    /// * `size_hint` - The expected size in bytes, if known
+    /// * `on_progress` - Called after each chunk is read
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResourceError::Read`] if reading from `reader` fails, or an
+    /// error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let file = tokio::fs::File::open("font.woff2").await?;
+    /// let size = file.metadata().await?.len();
+    ///
+    /// let saved = Asset::upload_stream(
+    ///     &client,
+    ///     123,
+    ///     "assets/font.woff2",
+    ///     file,
+    ///     Some(size),
+    ///     |progress| println!("Read {} of {:?} bytes", progress.bytes_read, progress.total_bytes),
+    /// ).await?;
+    /// ```
+    pub async fn upload_stream<R, F>(
+        client: &RestClient,
+        theme_id: u64,
+        key: &str,
+        reader: R,
+        size_hint: Option<u64>,
+        mut on_progress: F,
+    ) -> Result<Self, ResourceError>
+    where
+        R: AsyncRead + Unpin,
+        F: FnMut(UploadProgress),
+    {
+        let url = Self::save_path(theme_id)?;
+
+        let bytes = read_with_progress(reader, size_hint, UPLOAD_CHUNK_SIZE, &mut on_progress)
+            .await
+            .map_err(|source| ResourceError::Read {
+                resource: "Asset",
+                source,
+            })?;
+
+        let asset = Self::upload_from_bytes(key, &bytes);
+        let body = serde_json::json!({
+            "asset": {
+                "key": asset.key,
+                "attachment": asset.attachment,
+            }
+        });
+
+        let timeout = upload_timeout(size_hint.unwrap_or(bytes.len() as u64));
+        let response = client.put_with_timeout(&url, body, None, timeout).await?;
+        Self::saved_asset_from_response(&response, key)
+    }
+
+    /// Resolves the PUT path used to create or update assets in a theme.
+    fn save_path(theme_id: u64) -> Result<String, ResourceError> {
+        let mut ids: HashMap<&str, String> = HashMap::new();
+        ids.insert("theme_id", theme_id.to_string());
+
+        let available_ids: Vec<&str> = ids.keys().copied().collect();
+        let path = get_path(Self::PATHS, ResourceOperation::Update, &available_ids).ok_or(
+            ResourceError::PathResolutionFailed {
+                resource: "Asset",
+                operation: "save",
+            },
+        )?;
+
+        Ok(build_path(path.template, &ids))
+    }
+
+    /// Parses the saved asset from a create or update response.
+    fn saved_asset_from_response(
+        response: &crate::clients::HttpResponse,
+        key: &str,
+    ) -> Result<Self, ResourceError> {
         if !response.is_ok() {
             return Err(ResourceError::from_http_response(
                 response.code,
                 &response.body,
                 "Asset",
-                Some(&asset.key),
+                Some(key),
                 response.request_id(),
             ));
         }
@@ -647,6 +760,48 @@ pub struct AssetListParams {
     pub fields: Option<String>,
 }
 
+/// Reads `reader` to the end in chunks of `chunk_size`, invoking
+/// `on_progress` after each chunk.
+async fn read_with_progress<R, F>(
+    mut reader: R,
+    size_hint: Option<u64>,
+    chunk_size: usize,
+    on_progress: &mut F,
+) -> std::io::Result<Vec<u8>>
+where
+    R: AsyncRead + Unpin,
+    F: FnMut(UploadProgress),
+{
+    // The hint is only trusted up to a cap, so a wrong hint can't reserve
+    // an unbounded buffer
+    let capacity = size_hint
+        .and_then(|size| usize::try_from(size).ok())
+        .unwrap_or(chunk_size)
+        .min(UPLOAD_MAX_PREALLOCATION);
+    let mut bytes = Vec::with_capacity(capacity);
+    let mut chunk = vec![0; chunk_size];
+
+    loop {
+        let read = reader.read(&mut chunk).await?;
+        if read == 0 {
+            return Ok(bytes);
+        }
+        bytes.extend_from_slice(&chunk[..read]);
+        on_progress(UploadProgress {
+            bytes_read: bytes.len() as u64,
+            total_bytes: size_hint,
+        });
+    }
+}
+
+/// Returns the request timeout for uploading `size` bytes.
+///
+/// Accounts for base64 encoding growing the body by a third.
+fn upload_timeout(size: u64) -> Duration {
+    let encoded_size = size.saturating_mul(4) / 3;
+    UPLOAD_BASE_TIMEOUT + Duration::from_secs(encoded_size / UPLOAD_MIN_BYTES_PER_SECOND)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -833,6 +988,85 @@ mod tests {
         assert_eq!(downloaded, original_data);
     }
 
+    #[tokio::test]
+    async fn test_read_with_progress_invokes_callback_per_chunk() {
+        let content: &[u8] = b"0123456789";
+        let mut progress = Vec::new();
+
+        let bytes = read_with_progress(content, Some(10), 4, &mut |p| progress.push(p))
+            .await
+            .unwrap();
+
+        assert_eq!(bytes, content);
+        assert_eq!(
+            progress.iter().map(|p| p.bytes_read).collect::<Vec<_>>(),
+            [4, 8, 10]
+        );
+        assert!(progress.iter().all(|p| p.total_bytes == Some(10)));
+    }
+
+    #[tokio::test]
+    async fn test_read_with_progress_caps_preallocation_from_size_hint() {
+        let content: &[u8] = b"0123456789";
+
+        let bytes = read_with_progress(content, Some(u64::MAX), 4, &mut |_| {})
+            .await
+            .unwrap();
+
+        assert_eq!(bytes, content);
+        assert!(bytes.capacity() <= UPLOAD_MAX_PREALLOCATION);
+    }
+
+    #[tokio::test]
+    async fn test_upload_stream_reports_read_failures() {
+        let session = crate::Session::new(
+            "test-session".to_string(),
+            crate::ShopDomain::new("test-shop").unwrap(),
+            "test-access-token".to_string(),
+            crate::AuthScopes::new(),
+            false,
+            None,
+        );
+        let client = RestClient::new(&session, None).unwrap();
+        let reader = tokio_test::io::Builder::new()
+            .read(b"partial")
+            .read_error(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "disk unavailable",
+            ))
+            .build();
+
+        // The read fails before any request is sent
+        let result =
+            Asset::upload_stream(&client, 123, "assets/font.woff2", reader, None, |_| {}).await;
+
+        assert!(matches!(
+            result,
+            Err(ResourceError::Read { resource: "Asset", ref source })
+                if source.to_string() == "disk unavailable"
+        ));
+    }
+
+    #[test]
+    fn test_upload_stream_uses_theme_assets_put_path() {
+        assert_eq!(Asset::save_path(123).unwrap(), "themes/123/assets");
+
+        let path = get_path(Asset::PATHS, ResourceOperation::Update, &["theme_id"]).unwrap();
+        assert_eq!(path.http_method, HttpMethod::Put);
+    }
+
+    #[test]
+    fn test_upload_timeout_scales_with_size() {
+        assert_eq!(upload_timeout(0), UPLOAD_BASE_TIMEOUT);
+
+        // 3 MiB encodes to 4 MiB, which takes 16s at 256 KiB/s
+        assert_eq!(
+            upload_timeout(3 * 1024 * 1024),
+            UPLOAD_BASE_TIMEOUT + Duration::from_secs(16)
+        );
+        assert!(upload_timeout(50 * 1024 * 1024) > upload_timeout(5 * 1024 * 1024));
+    }
+
     #[test]
     fn test_asset_paths_are_nested_under_theme() {
         // All path should require theme_id
@@ -0,0 +1,439 @@
+//! Checkout resource implementation.
+//!
+//! This module provides the [`Checkout`] resource for reading abandoned
+//! checkouts in a Shopify store. An abandoned checkout is a checkout where the
+//! customer entered contact information but did not complete the purchase.
+//!
+//! # Read-Only Resource
+//!
+//! Checkout is a read-only resource that implements the [`ReadOnlyResource`]
+//! marker trait. Shopify only exposes list and count operations for abandoned
+//! checkouts; there is no find, create, update, or delete.
+//!
+//! Use [`DraftOrder::from_abandoned_checkout`](super::DraftOrder::from_abandoned_checkout)
+//! to turn an abandoned checkout into a draft order for cart recovery.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use shopify_sdk::rest::{RestResource, ResourceResponse};
+//! use shopify_sdk::rest::resources::v2026_04::{Checkout, CheckoutListParams, DraftOrder};
+//!
+//! // List open abandoned checkouts
+//! let params = CheckoutListParams {
+//!     status: Some("open".to_string()),
+//!     ..Default::default()
+//! };
+//! let checkouts = Checkout::all(&client, Some(params)).await?;
+//!
+//! // Recover the first one as a draft order
+//! if let Some(checkout) = checkouts.iter().next() {
+//!     let mut draft = DraftOrder::from_abandoned_checkout(checkout);
+//!     let saved = draft.save(&client).await?;
+//! }
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::rest::{ReadOnlyResource, ResourceOperation, ResourcePath, RestResource};
+use crate::HttpMethod;
+
+use super::common::{Address, NoteAttribute, ShippingLine, TaxLine};
+use super::customer::Customer;
+use super::order::DiscountCode;
+
+/// A line item in an abandoned checkout.
+///
+/// Line items without a `variant_id` are custom items whose title and price
+/// were set directly rather than taken from a product variant.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct CheckoutLineItem {
+    /// A unique key for the line item within the checkout.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+
+    /// The ID of the product variant, or `None` for custom items.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant_id: Option<u64>,
+
+    /// The ID of the product.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_id: Option<u64>,
+
+    /// The title of the product or custom item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// The title of the variant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant_title: Option<String>,
+
+    /// The SKU of the variant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sku: Option<String>,
+
+    /// The vendor of the product.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor: Option<String>,
+
+    /// The quantity of items.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<i64>,
+
+    /// The price per item charged in the checkout.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+
+    /// The variant's regular price, if the item is a variant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant_price: Option<String>,
+
+    /// The price of the line (`price` multiplied by `quantity`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_price: Option<String>,
+
+    /// Whether the item requires shipping.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requires_shipping: Option<bool>,
+
+    /// Whether the item is taxable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxable: Option<bool>,
+
+    /// Whether the item is a gift card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gift_card: Option<bool>,
+
+    /// The weight in grams.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grams: Option<i64>,
+
+    /// The fulfillment service for the item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fulfillment_service: Option<String>,
+
+    /// Custom properties on the line item.
+    ///
+    /// Shopify returns these either as a list of `{ "name", "value" }`
+    /// objects or as a single object keyed by property name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<serde_json::Value>,
+
+    /// Tax lines applied to this line item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_lines: Option<Vec<TaxLine>>,
+}
+
+/// An abandoned checkout in Shopify.
+///
+/// # Read-Only Resource
+///
+/// This resource implements the [`ReadOnlyResource`] marker trait. All fields
+/// are populated from API responses; abandoned checkouts cannot be created or
+/// modified through the REST API.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use shopify_sdk::rest::resources::v2026_04::Checkout;
+///
+/// for checkout in checkouts.iter() {
+///     println!(
+///         "{} abandoned {} item(s): {}",
+///         checkout.email.as_deref().unwrap_or("unknown"),
+///         checkout.line_items.as_ref().map_or(0, Vec::len),
+///         checkout.abandoned_checkout_url.as_deref().unwrap_or("")
+///     );
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct Checkout {
+    /// The unique identifier of the checkout.
+    #[serde(skip_serializing)]
+    pub id: Option<u64>,
+
+    /// The unique token of the checkout.
+    #[serde(skip_serializing)]
+    pub token: Option<String>,
+
+    /// The token of the cart the checkout was created from.
+    #[serde(skip_serializing)]
+    pub cart_token: Option<String>,
+
+    /// The customer's email address.
+    #[serde(skip_serializing)]
+    pub email: Option<String>,
+
+    /// The customer's phone number.
+    #[serde(skip_serializing)]
+    pub phone: Option<String>,
+
+    /// The URL the customer can use to resume the checkout.
+    #[serde(skip_serializing)]
+    pub abandoned_checkout_url: Option<String>,
+
+    /// The currency of the shop (e.g., "USD").
+    #[serde(skip_serializing)]
+    pub currency: Option<String>,
+
+    /// The currency the customer saw at checkout.
+    #[serde(skip_serializing)]
+    pub presentment_currency: Option<String>,
+
+    /// Whether the customer agreed to receive marketing email.
+    #[serde(skip_serializing)]
+    pub buyer_accepts_marketing: Option<bool>,
+
+    /// The customer's locale.
+    #[serde(skip_serializing)]
+    pub customer_locale: Option<String>,
+
+    /// The note left by the customer.
+    #[serde(skip_serializing)]
+    pub note: Option<String>,
+
+    /// Additional note attributes (key-value pairs).
+    #[serde(skip_serializing)]
+    pub note_attributes: Option<Vec<NoteAttribute>>,
+
+    /// Whether taxes are included in the prices.
+    #[serde(skip_serializing)]
+    pub taxes_included: Option<bool>,
+
+    /// The subtotal price before taxes, shipping and order-level discounts.
+    #[serde(skip_serializing)]
+    pub subtotal_price: Option<String>,
+
+    /// The total tax amount.
+    #[serde(skip_serializing)]
+    pub total_tax: Option<String>,
+
+    /// The total discount amount.
+    #[serde(skip_serializing)]
+    pub total_discounts: Option<String>,
+
+    /// The total price including taxes, shipping and discounts.
+    #[serde(skip_serializing)]
+    pub total_price: Option<String>,
+
+    /// The source of the checkout (e.g., "web").
+    #[serde(skip_serializing)]
+    pub source_name: Option<String>,
+
+    /// The customer who started the checkout.
+    #[serde(skip_serializing)]
+    pub customer: Option<Customer>,
+
+    /// Line items in the checkout.
+    #[serde(skip_serializing)]
+    pub line_items: Option<Vec<CheckoutLineItem>>,
+
+    /// The shipping address.
+    #[serde(skip_serializing)]
+    pub shipping_address: Option<Address>,
+
+    /// The billing address.
+    #[serde(skip_serializing)]
+    pub billing_address: Option<Address>,
+
+    /// The shipping methods selected in the checkout.
+    #[serde(skip_serializing)]
+    pub shipping_lines: Option<Vec<ShippingLine>>,
+
+    /// Discount codes applied to the checkout.
+    #[serde(skip_serializing)]
+    pub discount_codes: Option<Vec<DiscountCode>>,
+
+    /// Tax lines applied to the checkout.
+    #[serde(skip_serializing)]
+    pub tax_lines: Option<Vec<TaxLine>>,
+
+    /// When the checkout was completed, if it was.
+    #[serde(skip_serializing)]
+    pub completed_at: Option<DateTime<Utc>>,
+
+    /// When the checkout was closed, if it was.
+    #[serde(skip_serializing)]
+    pub closed_at: Option<DateTime<Utc>>,
+
+    /// When the checkout was created.
+    #[serde(skip_serializing)]
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// When the checkout was last updated.
+    #[serde(skip_serializing)]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl RestResource for Checkout {
+    type Id = u64;
+    type FindParams = CheckoutFindParams;
+    type AllParams = CheckoutListParams;
+    type CountParams = CheckoutCountParams;
+
+    const NAME: &'static str = "Checkout";
+    const PLURAL: &'static str = "checkouts";
+
+    /// Paths for the Checkout resource.
+    ///
+    /// Abandoned checkouts only support listing and counting:
+    /// - All: GET `/checkouts`
+    /// - Count: GET `/checkouts/count`
+    const PATHS: &'static [ResourcePath] = &[
+        ResourcePath::new(HttpMethod::Get, ResourceOperation::All, &[], "checkouts"),
+        ResourcePath::new(
+            HttpMethod::Get,
+            ResourceOperation::Count,
+            &[],
+            "checkouts/count",
+        ),
+        // No Find, Create, Update, or Delete paths
+    ];
+
+    fn get_id(&self) -> Option<Self::Id> {
+        self.id
+    }
+}
+
+/// Marker trait implementation indicating Checkout is read-only.
+impl ReadOnlyResource for Checkout {}
+
+/// Parameters for finding a single checkout.
+///
+/// Abandoned checkouts have no find endpoint; this type exists to satisfy
+/// [`RestResource`].
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct CheckoutFindParams {
+    // No find endpoint for checkouts
+}
+
+/// Parameters for listing abandoned checkouts.
+///
+/// All fields are optional. Unset fields will not be included in the request.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct CheckoutListParams {
+    /// Maximum number of results to return (default: 50, max: 250).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Return only checkouts after the specified ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since_id: Option<u64>,
+
+    /// Show checkouts created at or after this date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at_min: Option<DateTime<Utc>>,
+
+    /// Show checkouts created at or before this date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at_max: Option<DateTime<Utc>>,
+
+    /// Show checkouts last updated at or after this date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at_min: Option<DateTime<Utc>>,
+
+    /// Show checkouts last updated at or before this date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at_max: Option<DateTime<Utc>>,
+
+    /// Filter by status: "open" (default) or "closed".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// Page info for cursor-based pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<String>,
+}
+
+/// Parameters for counting abandoned checkouts.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct CheckoutCountParams {
+    /// Return only checkouts after the specified ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since_id: Option<u64>,
+
+    /// Show checkouts created at or after this date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at_min: Option<DateTime<Utc>>,
+
+    /// Show checkouts created at or before this date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at_max: Option<DateTime<Utc>>,
+
+    /// Show checkouts last updated at or after this date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at_min: Option<DateTime<Utc>>,
+
+    /// Show checkouts last updated at or before this date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at_max: Option<DateTime<Utc>>,
+
+    /// Filter by status: "open" (default) or "closed".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::get_path;
+
+    #[test]
+    fn test_checkout_has_only_list_and_count_paths() {
+        fn assert_read_only<T: ReadOnlyResource>() {}
+        assert_read_only::<Checkout>();
+
+        let all_path = get_path(Checkout::PATHS, ResourceOperation::All, &[]);
+        assert_eq!(all_path.unwrap().template, "checkouts");
+        let count_path = get_path(Checkout::PATHS, ResourceOperation::Count, &[]);
+        assert_eq!(count_path.unwrap().template, "checkouts/count");
+
+        assert!(get_path(Checkout::PATHS, ResourceOperation::Find, &["id"]).is_none());
+        assert!(get_path(Checkout::PATHS, ResourceOperation::Create, &[]).is_none());
+        assert!(get_path(Checkout::PATHS, ResourceOperation::Delete, &["id"]).is_none());
+    }
+
+    #[test]
+    fn test_checkout_deserialization_from_api_response() {
+        let json_str = r#"{
+            "id": 450789469,
+            "token": "2a1ace52255252df566eb5fe0cbac9a6",
+            "cart_token": "68778783ad298f1c80c3bafcddeea02f",
+            "email": "bob.norman@example.com",
+            "abandoned_checkout_url": "https://checkout.local/548380009/checkouts/2a1ace52255252df566eb5fe0cbac9a6/recover?key=abc",
+            "currency": "USD",
+            "total_price": "398.00",
+            "line_items": [
+                {
+                    "key": "variant-item",
+                    "variant_id": 39072856,
+                    "product_id": 632910392,
+                    "title": "IPod Nano - 8GB",
+                    "quantity": 1,
+                    "price": "199.00",
+                    "variant_price": "199.00",
+                    "properties": null
+                }
+            ],
+            "discount_codes": [{ "code": "SPRING", "amount": "10.00", "type": "fixed_amount" }],
+            "customer": { "id": 207119551, "email": "bob.norman@example.com" },
+            "created_at": "2024-01-15T10:30:00Z"
+        }"#;
+
+        let checkout: Checkout = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(checkout.id, Some(450789469));
+        assert_eq!(checkout.email.as_deref(), Some("bob.norman@example.com"));
+        assert_eq!(checkout.total_price.as_deref(), Some("398.00"));
+        let line_items = checkout.line_items.unwrap();
+        assert_eq!(line_items[0].variant_id, Some(39072856));
+        assert_eq!(line_items[0].properties, None);
+        assert_eq!(
+            checkout.discount_codes.unwrap()[0].code.as_deref(),
+            Some("SPRING")
+        );
+        assert_eq!(checkout.customer.unwrap().id, Some(207119551));
+        assert!(checkout.created_at.is_some());
+    }
+}
@@ -46,6 +46,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::config::ShopDomain;
 use crate::rest::{ResourceOperation, ResourcePath, RestResource};
 use crate::HttpMethod;
 
@@ -147,6 +148,11 @@ impl RestResource for Collect {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    /// Collects have no page of their own in the Shopify admin.
+    fn admin_url(&self, _shop: &ShopDomain) -> Option<String> {
+        None
+    }
 }
 
 /// Parameters for finding a single collect.
@@ -4,6 +4,98 @@
 //! in orders and customer addresses.
 
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Country names accepted by [`Address::country_code_normalized`], with
+/// their ISO 3166-1 alpha-2 codes.
+///
+/// Names are matched ignoring ASCII case and surrounding whitespace.
+const COUNTRY_NAMES: &[(&str, &str)] = &[
+    ("argentina", "AR"),
+    ("australia", "AU"),
+    ("austria", "AT"),
+    ("belgium", "BE"),
+    ("brazil", "BR"),
+    ("canada", "CA"),
+    ("chile", "CL"),
+    ("china", "CN"),
+    ("colombia", "CO"),
+    ("czech republic", "CZ"),
+    ("czechia", "CZ"),
+    ("denmark", "DK"),
+    ("finland", "FI"),
+    ("france", "FR"),
+    ("germany", "DE"),
+    ("greece", "GR"),
+    ("hong kong", "HK"),
+    ("hungary", "HU"),
+    ("india", "IN"),
+    ("indonesia", "ID"),
+    ("ireland", "IE"),
+    ("israel", "IL"),
+    ("italy", "IT"),
+    ("japan", "JP"),
+    ("malaysia", "MY"),
+    ("mexico", "MX"),
+    ("netherlands", "NL"),
+    ("new zealand", "NZ"),
+    ("norway", "NO"),
+    ("philippines", "PH"),
+    ("poland", "PL"),
+    ("portugal", "PT"),
+    ("romania", "RO"),
+    ("saudi arabia", "SA"),
+    ("singapore", "SG"),
+    ("south africa", "ZA"),
+    ("south korea", "KR"),
+    ("spain", "ES"),
+    ("sweden", "SE"),
+    ("switzerland", "CH"),
+    ("taiwan", "TW"),
+    ("thailand", "TH"),
+    ("turkey", "TR"),
+    ("united arab emirates", "AE"),
+    ("united kingdom", "GB"),
+    ("great britain", "GB"),
+    ("uk", "GB"),
+    ("united states", "US"),
+    ("united states of america", "US"),
+    ("usa", "US"),
+    ("vietnam", "VN"),
+];
+
+/// Countries that don't use postal codes, as ISO 3166-1 alpha-2 codes.
+///
+/// Every other country requires a `zip` in [`Address::validate`].
+const COUNTRIES_WITHOUT_POSTAL_CODES: &[&str] = &[
+    "AE", "AG", "AO", "AW", "BF", "BI", "BJ", "BO", "BS", "BW", "BZ", "CD", "CF", "CG", "CI", "CK",
+    "CM", "DJ", "DM", "ER", "FJ", "GA", "GD", "GH", "GM", "GQ", "GY", "HK", "JM", "KI", "KM", "KN",
+    "KP", "LC", "ML", "MO", "MR", "MS", "MW", "NR", "NU", "QA", "RW", "SB", "SC", "SL", "SO", "SR",
+    "ST", "SY", "TD", "TF", "TG", "TK", "TL", "TO", "TT", "TV", "UG", "VU", "YE", "ZW",
+];
+
+/// Why an [`Address`] failed [`Address::validate`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum AddressError {
+    /// Neither `country` nor `country_code` is set.
+    #[error("Address has no country or country code")]
+    MissingCountry,
+
+    /// `country` is set but is not a country name this SDK recognizes, and
+    /// no `country_code` is set.
+    #[error("Unrecognized country '{country}'; set country_code instead")]
+    UnknownCountry {
+        /// The unrecognized country name.
+        country: String,
+    },
+
+    /// The address has no `zip` but its country requires a postal code.
+    #[error("Address in {country_code} requires a postal code")]
+    MissingZip {
+        /// The ISO 3166-1 alpha-2 code of the address's country.
+        country_code: String,
+    },
+}
 
 /// A physical address used for billing or shipping.
 ///
@@ -91,6 +183,88 @@ pub struct Address {
     pub longitude: Option<f64>,
 }
 
+impl Address {
+    /// Returns the address's ISO 3166-1 alpha-2 country code.
+    ///
+    /// Uses `country_code` when set, uppercased. Otherwise maps `country`
+    /// from a list of common country names, ignoring case. Returns `None` if
+    /// neither gives a code.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::rest::resources::v2026_04::common::Address;
+    ///
+    /// let address = Address {
+    ///     country: Some("United States".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(address.country_code_normalized().as_deref(), Some("US"));
+    /// ```
+    #[must_use]
+    pub fn country_code_normalized(&self) -> Option<String> {
+        if let Some(code) = non_blank(self.country_code.as_deref()) {
+            return Some(code.to_ascii_uppercase());
+        }
+        let country = non_blank(self.country.as_deref())?;
+        COUNTRY_NAMES
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(country))
+            .map(|(_, code)| (*code).to_string())
+    }
+
+    /// Checks that the address has a country, and a postal code when the
+    /// country uses them.
+    ///
+    /// This is a basic sanity check before sending an address to Shopify; it
+    /// doesn't check that the postal code or province is valid for the
+    /// country.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AddressError::MissingCountry`] if neither `country` nor
+    /// `country_code` is set, [`AddressError::UnknownCountry`] if only an
+    /// unrecognized `country` name is set, and [`AddressError::MissingZip`]
+    /// if the country requires a postal code and `zip` is blank.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::rest::resources::v2026_04::common::{Address, AddressError};
+    ///
+    /// let address = Address {
+    ///     country_code: Some("US".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     address.validate(),
+    ///     Err(AddressError::MissingZip { country_code: "US".to_string() })
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), AddressError> {
+        let country_code = self.country_code_normalized().ok_or_else(|| {
+            non_blank(self.country.as_deref()).map_or(AddressError::MissingCountry, |country| {
+                AddressError::UnknownCountry {
+                    country: country.to_string(),
+                }
+            })
+        })?;
+
+        if non_blank(self.zip.as_deref()).is_none()
+            && !COUNTRIES_WITHOUT_POSTAL_CODES.contains(&country_code.as_str())
+        {
+            return Err(AddressError::MissingZip { country_code });
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the trimmed value, or `None` if it is missing or blank.
+fn non_blank(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|value| !value.is_empty())
+}
+
 /// An address associated with a customer.
 ///
 /// Extends the base `Address` with customer-specific fields like `id`,
@@ -353,4 +527,58 @@ mod tests {
         assert_eq!(address.first_name, Some("Carol".to_string()));
         assert_eq!(address.city, Some("San Francisco".to_string()));
     }
+
+    #[test]
+    fn test_validate_accepts_complete_us_address() {
+        let address = Address {
+            address1: Some("123 Main St".to_string()),
+            city: Some("New York".to_string()),
+            province_code: Some("NY".to_string()),
+            country: Some("united states".to_string()),
+            zip: Some("10001".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(address.country_code_normalized().as_deref(), Some("US"));
+        assert_eq!(address.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_rejects_missing_zip_and_country() {
+        let address = Address {
+            address1: Some("123 Main St".to_string()),
+            country_code: Some("us".to_string()),
+            zip: Some("  ".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            address.validate(),
+            Err(AddressError::MissingZip {
+                country_code: "US".to_string()
+            })
+        );
+
+        // Countries without postal codes don't need a zip
+        let address = Address {
+            country: Some("Hong Kong".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(address.validate(), Ok(()));
+
+        assert_eq!(
+            Address::default().validate(),
+            Err(AddressError::MissingCountry)
+        );
+        let address = Address {
+            country: Some("Atlantis".to_string()),
+            zip: Some("12345".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            address.validate(),
+            Err(AddressError::UnknownCountry {
+                country: "Atlantis".to_string()
+            })
+        );
+    }
 }
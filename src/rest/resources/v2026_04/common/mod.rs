@@ -33,10 +33,11 @@ mod line_item;
 mod metafield;
 mod money;
 mod product;
+mod sort;
 mod theme;
 mod webhook;
 
-pub use address::{Address, CustomerAddress};
+pub use address::{Address, AddressError, CustomerAddress};
 pub use billing::{ChargeCurrency, ChargeStatus};
 pub use blog::BlogCommentable;
 pub use collection::{CollectionImage, SmartCollectionRule};
@@ -44,8 +45,9 @@ pub use line_item::{
     DiscountAllocation, DiscountApplication, LineItem, LineItemProperty, NoteAttribute,
     ShippingLine, TaxLine,
 };
-pub use metafield::MetafieldOwner;
-pub use money::{Money, MoneySet};
+pub use metafield::{MetafieldInput, MetafieldOwner};
+pub use money::{Money, MoneySet, PresentmentPrice};
 pub use product::{ProductImage, ProductOption};
+pub use sort::{SortDirection, SortOrder};
 pub use theme::ThemeRole;
 pub use webhook::{WebhookFormat, WebhookTopic};
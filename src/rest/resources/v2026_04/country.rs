@@ -36,6 +36,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::config::ShopDomain;
 use crate::rest::{ResourceOperation, ResourcePath, RestResource};
 use crate::HttpMethod;
 
@@ -131,6 +132,11 @@ impl RestResource for Country {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    /// Countries have no page of their own in the Shopify admin.
+    fn admin_url(&self, _shop: &ShopDomain) -> Option<String> {
+        None
+    }
 }
 
 /// Parameters for finding a single country.
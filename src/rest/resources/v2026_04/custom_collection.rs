@@ -36,6 +36,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::config::ShopDomain;
 use crate::rest::{ResourceOperation, ResourcePath, RestResource};
 use crate::HttpMethod;
 
@@ -199,6 +200,20 @@ impl RestResource for CustomCollection {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
+
+    /// Custom collections open on the admin's collections page.
+    fn admin_url(&self, shop: &ShopDomain) -> Option<String> {
+        self.id.map(|id| {
+            format!(
+                "https://admin.shopify.com/store/{}/collections/{id}",
+                shop.shop_name()
+            )
+        })
+    }
 }
 
 /// Parameters for finding a single custom collection.
@@ -1,12 +1,16 @@
 //! Customer resource implementation.
 
+#[cfg(feature = "capture-unknown-fields")]
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::rest::{ResourceOperation, ResourcePath, RestResource};
+use crate::clients::RestClient;
+use crate::rest::{tags, ResourceError, ResourceOperation, ResourcePath, RestResource};
 use crate::HttpMethod;
 
-use super::common::CustomerAddress;
+use super::common::{CustomerAddress, MetafieldInput, SortOrder};
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "lowercase")]
@@ -88,6 +92,19 @@ pub struct Customer {
     pub email_marketing_consent: Option<EmailMarketingConsent>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sms_marketing_consent: Option<SmsMarketingConsent>,
+    /// Metafields to create along with the customer (only sent on create).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metafields: Option<Vec<MetafieldInput>>,
+
+    /// Fields returned by the API that this struct doesn't model.
+    ///
+    /// Only present with the `capture-unknown-fields` feature. Captured
+    /// fields are sent back when the customer is saved, so values from newer
+    /// API versions survive a round trip. Read one with
+    /// [`extra_field`](Self::extra_field).
+    #[cfg(feature = "capture-unknown-fields")]
+    #[serde(flatten, default, skip_serializing_if = "HashMap::is_empty")]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 impl RestResource for Customer {
@@ -136,6 +153,495 @@ impl RestResource for Customer {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
+}
+
+impl Customer {
+    /// Returns an unmodeled field captured from the API response.
+    ///
+    /// Only available with the `capture-unknown-fields` feature.
+    #[cfg(feature = "capture-unknown-fields")]
+    #[must_use]
+    pub fn extra_field(&self, name: &str) -> Option<&serde_json::Value> {
+        self.extra.get(name)
+    }
+
+    /// Returns the customer's tags, trimmed and without duplicates.
+    #[must_use]
+    pub fn tag_list(&self) -> Vec<String> {
+        tags::tag_list(self.tags.as_deref())
+    }
+
+    /// Returns `true` if the customer has `tag`, ignoring case.
+    #[must_use]
+    pub fn has_tag(&self, tag: &str) -> bool {
+        tags::has_tag(self.tags.as_deref(), tag)
+    }
+
+    /// Adds `tag` unless the customer already has it.
+    ///
+    /// Call [`save`](RestResource::save) to apply the change.
+    pub fn add_tag(&mut self, tag: &str) {
+        tags::add_tag(&mut self.tags, tag);
+    }
+
+    /// Removes `tag` if the customer has it.
+    ///
+    /// Removing the last tag sets `tags` to an empty string, so saving
+    /// clears the tags on Shopify too.
+    pub fn remove_tag(&mut self, tag: &str) {
+        tags::remove_tag(&mut self.tags, tag);
+    }
+
+    /// Generates a one-time account activation URL for the customer.
+    ///
+    /// Sends a POST request to
+    /// `/admin/api/{version}/customers/{id}/account_activation_url.json`.
+    /// The URL can be shared with a customer whose account is not yet enabled;
+    /// generating a new URL invalidates any previous one.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The REST client to use for the request
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResourceError::NotFound`] if the customer doesn't exist.
+    /// Returns [`ResourceError::PathResolutionFailed`] if the customer has no ID.
+    /// Returns [`ResourceError::ValidationFailed`] if the account is already enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let customer = Customer::find(&client, 207119551, None).await?;
+    /// let url = customer.account_activation_url(&client).await?;
+    /// println!("Activation URL: {url}");
+    /// ```
+    pub async fn account_activation_url(
+        &self,
+        client: &RestClient,
+    ) -> Result<String, ResourceError> {
+        let id = self.get_id().ok_or(ResourceError::PathResolutionFailed {
+            resource: Self::NAME,
+            operation: "account_activation_url",
+        })?;
+
+        let path = account_activation_url_path(id);
+        let response = client.post(&path, serde_json::json!({}), None).await?;
+
+        if !response.is_ok() {
+            return Err(ResourceError::from_http_response(
+                response.code,
+                &response.body,
+                Self::NAME,
+                Some(&id.to_string()),
+                response.request_id(),
+            ));
+        }
+
+        response
+            .body
+            .get("account_activation_url")
+            .and_then(serde_json::Value::as_str)
+            .map(ToString::to_string)
+            .ok_or_else(|| {
+                ResourceError::Http(crate::clients::HttpError::Response(
+                    crate::clients::HttpResponseError {
+                        code: response.code,
+                        message: "Missing 'account_activation_url' in response".to_string(),
+                        error_reference: response.request_id().map(ToString::to_string),
+                    },
+                ))
+            })
+    }
+
+    /// Sends an account invite email to the customer.
+    ///
+    /// Sends a POST request to `/admin/api/{version}/customers/{id}/send_invite.json`.
+    /// When `invite` is `None`, Shopify sends its default invite email.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The REST client to use for the request
+    /// * `invite` - Optional overrides for the recipient, sender, subject, and message
+    ///
+    /// # Returns
+    ///
+    /// The invite as sent by Shopify.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResourceError::NotFound`] if the customer doesn't exist.
+    /// Returns [`ResourceError::PathResolutionFailed`] if the customer has no ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let invite = CustomerInvite {
+    ///     subject: Some("Welcome to our store".to_string()),
+    ///     custom_message: Some("Activate your account to track orders.".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let sent = customer.send_invite(&client, Some(invite)).await?;
+    /// ```
+    pub async fn send_invite(
+        &self,
+        client: &RestClient,
+        invite: Option<CustomerInvite>,
+    ) -> Result<CustomerInvite, ResourceError> {
+        let id = self.get_id().ok_or(ResourceError::PathResolutionFailed {
+            resource: Self::NAME,
+            operation: "send_invite",
+        })?;
+
+        let path = format!("customers/{id}/send_invite");
+        let body = send_invite_body(invite);
+
+        let response = client.post(&path, body, None).await?;
+
+        if !response.is_ok() {
+            return Err(ResourceError::from_http_response(
+                response.code,
+                &response.body,
+                Self::NAME,
+                Some(&id.to_string()),
+                response.request_id(),
+            ));
+        }
+
+        // Parse the response - Shopify returns the invite wrapped in "customer_invite" key
+        let invite: CustomerInvite = response
+            .body
+            .get("customer_invite")
+            .ok_or_else(|| {
+                ResourceError::Http(crate::clients::HttpError::Response(
+                    crate::clients::HttpResponseError {
+                        code: response.code,
+                        message: "Missing 'customer_invite' in response".to_string(),
+                        error_reference: response.request_id().map(ToString::to_string),
+                    },
+                ))
+            })
+            .and_then(|v| {
+                serde_json::from_value(v.clone()).map_err(|e| {
+                    ResourceError::Http(crate::clients::HttpError::Response(
+                        crate::clients::HttpResponseError {
+                            code: response.code,
+                            message: format!("Failed to deserialize customer_invite: {e}"),
+                            error_reference: response.request_id().map(ToString::to_string),
+                        },
+                    ))
+                })
+            })?;
+
+        Ok(invite)
+    }
+
+    /// Adds an address to the customer.
+    ///
+    /// Sends a POST request to `/admin/api/{version}/customers/{id}/addresses.json`.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The REST client to use for the request
+    /// * `address` - The address to add. Leave `id` unset; it is sent if set
+    ///
+    /// # Returns
+    ///
+    /// The created address, including its ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResourceError::NotFound`] if the customer doesn't exist.
+    /// Returns [`ResourceError::PathResolutionFailed`] if the customer has no ID.
+    /// Returns [`ResourceError::ValidationFailed`] if Shopify rejects the address.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let address = CustomerAddress {
+    ///     address1: Some("1 Rue des Carrieres".to_string()),
+    ///     city: Some("Montreal".to_string()),
+    ///     province_code: Some("QC".to_string()),
+    ///     country_code: Some("CA".to_string()),
+    ///     zip: Some("G1R 4P5".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let created = customer.create_address(&client, &address).await?;
+    /// ```
+    pub async fn create_address(
+        &self,
+        client: &RestClient,
+        address: &CustomerAddress,
+    ) -> Result<CustomerAddress, ResourceError> {
+        let id = self.id_for("create_address")?;
+
+        let response = client
+            .post(&address_path(id, None), address_body(address), None)
+            .await?;
+
+        if !response.is_ok() {
+            return Err(ResourceError::from_http_response(
+                response.code,
+                &response.body,
+                Self::NAME,
+                Some(&id.to_string()),
+                response.request_id(),
+            ));
+        }
+
+        parse_customer_address(&response)
+    }
+
+    /// Updates one of the customer's addresses.
+    ///
+    /// Sends a PUT request to
+    /// `/admin/api/{version}/customers/{id}/addresses/{address_id}.json`.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The REST client to use for the request
+    /// * `address_id` - The ID of the address to update
+    /// * `address` - The fields to change; unset fields are left as they are
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResourceError::NotFound`] if the customer or address doesn't exist.
+    /// Returns [`ResourceError::PathResolutionFailed`] if the customer has no ID.
+    /// Returns [`ResourceError::ValidationFailed`] if Shopify rejects the address.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let changes = CustomerAddress {
+    ///     zip: Some("90210".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let updated = customer.update_address(&client, 207119551, &changes).await?;
+    /// ```
+    pub async fn update_address(
+        &self,
+        client: &RestClient,
+        address_id: u64,
+        address: &CustomerAddress,
+    ) -> Result<CustomerAddress, ResourceError> {
+        let id = self.id_for("update_address")?;
+
+        let response = client
+            .put(
+                &address_path(id, Some(address_id)),
+                address_body(address),
+                None,
+            )
+            .await?;
+
+        if !response.is_ok() {
+            return Err(ResourceError::from_http_response(
+                response.code,
+                &response.body,
+                "CustomerAddress",
+                Some(&address_id.to_string()),
+                response.request_id(),
+            ));
+        }
+
+        parse_customer_address(&response)
+    }
+
+    /// Removes one of the customer's addresses.
+    ///
+    /// Sends a DELETE request to
+    /// `/admin/api/{version}/customers/{id}/addresses/{address_id}.json`.
+    /// Shopify refuses to delete the customer's default address.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The REST client to use for the request
+    /// * `address_id` - The ID of the address to delete
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResourceError::NotFound`] if the customer or address doesn't exist.
+    /// Returns [`ResourceError::PathResolutionFailed`] if the customer has no ID.
+    /// Returns [`ResourceError::ValidationFailed`] if the address is the default.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// customer.delete_address(&client, 1053317291).await?;
+    /// ```
+    pub async fn delete_address(
+        &self,
+        client: &RestClient,
+        address_id: u64,
+    ) -> Result<(), ResourceError> {
+        let id = self.id_for("delete_address")?;
+
+        let response = client
+            .delete(&address_path(id, Some(address_id)), None)
+            .await?;
+
+        if !response.is_ok() {
+            return Err(ResourceError::from_http_response(
+                response.code,
+                &response.body,
+                "CustomerAddress",
+                Some(&address_id.to_string()),
+                response.request_id(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Makes one of the customer's addresses their default address.
+    ///
+    /// Sends a PUT request to
+    /// `/admin/api/{version}/customers/{id}/addresses/{address_id}/default.json`.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The REST client to use for the request
+    /// * `address_id` - The ID of the address to make the default
+    ///
+    /// # Returns
+    ///
+    /// The address, with `default` set to `true`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResourceError::NotFound`] if the customer or address doesn't exist.
+    /// Returns [`ResourceError::PathResolutionFailed`] if the customer has no ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let address = customer.set_default_address(&client, 1053317291).await?;
+    /// assert_eq!(address.default, Some(true));
+    /// ```
+    pub async fn set_default_address(
+        &self,
+        client: &RestClient,
+        address_id: u64,
+    ) -> Result<CustomerAddress, ResourceError> {
+        let id = self.id_for("set_default_address")?;
+
+        let response = client
+            .put(
+                &default_address_path(id, address_id),
+                serde_json::json!({}),
+                None,
+            )
+            .await?;
+
+        if !response.is_ok() {
+            return Err(ResourceError::from_http_response(
+                response.code,
+                &response.body,
+                "CustomerAddress",
+                Some(&address_id.to_string()),
+                response.request_id(),
+            ));
+        }
+
+        parse_customer_address(&response)
+    }
+
+    /// Returns the customer's ID, or an error naming `operation` if unset.
+    fn id_for(&self, operation: &'static str) -> Result<u64, ResourceError> {
+        self.get_id().ok_or(ResourceError::PathResolutionFailed {
+            resource: Self::NAME,
+            operation,
+        })
+    }
+}
+
+/// Builds the path for a customer's addresses, or one of them.
+fn address_path(customer_id: u64, address_id: Option<u64>) -> String {
+    address_id.map_or_else(
+        || format!("customers/{customer_id}/addresses"),
+        |address_id| format!("customers/{customer_id}/addresses/{address_id}"),
+    )
+}
+
+/// Builds the path that makes an address the customer's default.
+fn default_address_path(customer_id: u64, address_id: u64) -> String {
+    format!("customers/{customer_id}/addresses/{address_id}/default")
+}
+
+/// Builds the request body for creating or updating an address.
+fn address_body(address: &CustomerAddress) -> serde_json::Value {
+    serde_json::json!({ "address": address })
+}
+
+/// Parses the `customer_address` returned by the address endpoints.
+fn parse_customer_address(
+    response: &crate::clients::HttpResponse,
+) -> Result<CustomerAddress, ResourceError> {
+    response
+        .body
+        .get("customer_address")
+        .ok_or_else(|| {
+            ResourceError::Http(crate::clients::HttpError::Response(
+                crate::clients::HttpResponseError {
+                    code: response.code,
+                    message: "Missing 'customer_address' in response".to_string(),
+                    error_reference: response.request_id().map(ToString::to_string),
+                },
+            ))
+        })
+        .and_then(|v| {
+            serde_json::from_value(v.clone()).map_err(|e| {
+                ResourceError::Http(crate::clients::HttpError::Response(
+                    crate::clients::HttpResponseError {
+                        code: response.code,
+                        message: format!("Failed to deserialize customer_address: {e}"),
+                        error_reference: response.request_id().map(ToString::to_string),
+                    },
+                ))
+            })
+        })
+}
+
+/// Builds the path for the account activation URL endpoint.
+fn account_activation_url_path(id: u64) -> String {
+    format!("customers/{id}/account_activation_url")
+}
+
+/// Builds the request body for the send invite endpoint.
+fn send_invite_body(invite: Option<CustomerInvite>) -> serde_json::Value {
+    serde_json::json!({
+        "customer_invite": invite.unwrap_or_default()
+    })
+}
+
+/// Invite email details for [`Customer::send_invite`].
+///
+/// All fields are optional; unset fields fall back to the shop's defaults.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct CustomerInvite {
+    /// The email address to send the invite to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+
+    /// The email address the invite is sent from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+
+    /// Email addresses to BCC on the invite.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bcc: Option<Vec<String>>,
+
+    /// The subject line of the email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+
+    /// A custom message included in the invite email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_message: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
@@ -161,6 +667,8 @@ pub struct CustomerListParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_at_max: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<SortOrder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub fields: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub page_info: Option<String>,
@@ -208,6 +716,9 @@ mod tests {
             default_address: None,
             email_marketing_consent: None,
             sms_marketing_consent: None,
+            metafields: None,
+            #[cfg(feature = "capture-unknown-fields")]
+            extra: HashMap::new(),
         };
 
         let json = serde_json::to_string(&customer).unwrap();
@@ -286,6 +797,7 @@ mod tests {
             updated_at_min: None,
             updated_at_max: None,
             fields: Some("id,email".into()),
+            order: None,
             page_info: None,
         };
 
@@ -373,4 +885,228 @@ mod tests {
         assert_eq!(Customer::NAME, "Customer");
         assert_eq!(Customer::PLURAL, "customers");
     }
+
+    #[test]
+    fn test_account_activation_url_request_path() {
+        assert_eq!(
+            account_activation_url_path(207119551),
+            "customers/207119551/account_activation_url"
+        );
+
+        fn _assert_account_activation_url_signature<F, Fut>(f: F)
+        where
+            F: Fn(&Customer, &RestClient) -> Fut,
+            Fut: std::future::Future<Output = Result<String, ResourceError>>,
+        {
+            let _ = f;
+        }
+    }
+
+    #[test]
+    fn test_send_invite_body_shape() {
+        let invite = CustomerInvite {
+            to: Some("new.customer@example.com".into()),
+            from: Some("store@example.com".into()),
+            bcc: Some(vec!["admin@example.com".into()]),
+            subject: Some("Welcome".into()),
+            custom_message: Some("Activate your account".into()),
+        };
+
+        let body = send_invite_body(Some(invite));
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "customer_invite": {
+                    "to": "new.customer@example.com",
+                    "from": "store@example.com",
+                    "bcc": ["admin@example.com"],
+                    "subject": "Welcome",
+                    "custom_message": "Activate your account"
+                }
+            })
+        );
+
+        // Without overrides Shopify uses the default invite
+        let default_body = send_invite_body(None);
+        assert_eq!(default_body, serde_json::json!({ "customer_invite": {} }));
+    }
+
+    #[test]
+    fn test_address_paths() {
+        assert_eq!(
+            address_path(207119551, None),
+            "customers/207119551/addresses"
+        );
+        assert_eq!(
+            address_path(207119551, Some(1053317291)),
+            "customers/207119551/addresses/1053317291"
+        );
+    }
+
+    #[test]
+    fn test_set_default_address_path() {
+        assert_eq!(
+            default_address_path(207119551, 1053317291),
+            "customers/207119551/addresses/1053317291/default"
+        );
+    }
+
+    fn address_mock_client(server: &wiremock::MockServer) -> RestClient {
+        RestClient::for_mock_server(&server.uri(), crate::config::ApiVersion::V2026_04)
+    }
+
+    fn saved_customer() -> Customer {
+        Customer {
+            id: Some(207119551),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_address_posts_address() {
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path(
+                "/admin/api/2026-04/customers/207119551/addresses.json",
+            ))
+            .and(body_json(serde_json::json!({
+                "address": { "address1": "1 Rue des Carrieres", "city": "Montreal" }
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "customer_address": {
+                    "id": 1053317291,
+                    "customer_id": 207119551,
+                    "address1": "1 Rue des Carrieres",
+                    "city": "Montreal"
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let address = CustomerAddress {
+            address1: Some("1 Rue des Carrieres".into()),
+            city: Some("Montreal".into()),
+            ..Default::default()
+        };
+        let created = saved_customer()
+            .create_address(&address_mock_client(&server), &address)
+            .await
+            .unwrap();
+
+        assert_eq!(created.id, Some(1053317291));
+    }
+
+    #[tokio::test]
+    async fn test_update_address_puts_changes() {
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path(
+                "/admin/api/2026-04/customers/207119551/addresses/1053317291.json",
+            ))
+            .and(body_json(
+                serde_json::json!({ "address": { "zip": "90210" } }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "customer_address": { "id": 1053317291, "zip": "90210" }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let changes = CustomerAddress {
+            zip: Some("90210".into()),
+            ..Default::default()
+        };
+        let updated = saved_customer()
+            .update_address(&address_mock_client(&server), 1053317291, &changes)
+            .await
+            .unwrap();
+
+        assert_eq!(updated.zip.as_deref(), Some("90210"));
+    }
+
+    #[tokio::test]
+    async fn test_delete_address_sends_delete() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path(
+                "/admin/api/2026-04/customers/207119551/addresses/1053317291.json",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        saved_customer()
+            .delete_address(&address_mock_client(&server), 1053317291)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_set_default_address_puts_to_default_path() {
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path(
+                "/admin/api/2026-04/customers/207119551/addresses/1053317291/default.json",
+            ))
+            .and(body_json(serde_json::json!({})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "customer_address": { "id": 1053317291, "default": true }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let address = saved_customer()
+            .set_default_address(&address_mock_client(&server), 1053317291)
+            .await
+            .unwrap();
+
+        assert_eq!(address.default, Some(true));
+    }
+
+    #[test]
+    fn test_address_body_wraps_address() {
+        let address = CustomerAddress {
+            id: Some(1053317291),
+            address1: Some("1 Rue des Carrieres".into()),
+            city: Some("Montreal".into()),
+            country_code: Some("CA".into()),
+            ..Default::default()
+        };
+
+        let body = address_body(&address);
+
+        assert_eq!(body["address"]["address1"], "1 Rue des Carrieres");
+        assert_eq!(body["address"]["city"], "Montreal");
+        assert_eq!(body["address"]["country_code"], "CA");
+        assert!(body["address"].get("zip").is_none());
+    }
+
+    #[test]
+    fn test_customer_address_operations_require_customer_id() {
+        let customer = Customer::default();
+
+        assert!(matches!(
+            customer.id_for("set_default_address"),
+            Err(ResourceError::PathResolutionFailed {
+                resource: "Customer",
+                operation: "set_default_address"
+            })
+        ));
+    }
 }
@@ -52,12 +52,16 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::clients::RestClient;
+use crate::config::ShopDomain;
 use crate::rest::{
     build_path, get_path, ResourceError, ResourceOperation, ResourcePath, ResourceResponse,
     RestResource,
 };
 use crate::HttpMethod;
 
+/// Redirects followed by [`DiscountCode::lookup`].
+const LOOKUP_MAX_REDIRECTS: u32 = 3;
+
 /// A discount code associated with a price rule.
 ///
 /// Discount codes are the customer-facing codes that shoppers enter at checkout.
@@ -331,6 +335,9 @@ impl DiscountCode {
     /// This uses a standalone path that doesn't require knowing the price rule ID.
     /// Useful when you only know the discount code string.
     ///
+    /// Shopify answers the lookup with a `303 See Other` pointing at the
+    /// discount code under its price rule; the redirect is followed.
+    ///
     /// # Arguments
     ///
     /// * `client` - The REST client to use for the request
@@ -358,7 +365,9 @@ impl DiscountCode {
         let mut query = HashMap::new();
         query.insert("code".to_string(), code.to_string());
 
-        let response = client.get(url, Some(query)).await?;
+        let response = client
+            .get_following_redirects(url, Some(query), LOOKUP_MAX_REDIRECTS)
+            .await?;
 
         if !response.is_ok() {
             return Err(ResourceError::from_http_response(
@@ -632,6 +641,20 @@ impl RestResource for DiscountCode {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
+
+    /// Shopify wraps a single discount code in a `discount_code` key.
+    fn resource_key() -> String {
+        "discount_code".to_string()
+    }
+
+    /// Discount codes have no page of their own in the Shopify admin.
+    fn admin_url(&self, _shop: &ShopDomain) -> Option<String> {
+        None
+    }
 }
 
 /// Parameters for finding a single discount code.
@@ -904,4 +927,32 @@ mod tests {
         let code_without_id = DiscountCode::default();
         assert_eq!(code_without_id.get_id(), None);
     }
+
+    #[tokio::test]
+    async fn test_find_with_parent_reads_discount_code_key() {
+        use crate::config::ApiVersion;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(
+                "/admin/api/2026-04/price_rules/507328175/discount_codes/12345.json",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "discount_code": { "id": 12345, "code": "SUMMER20" }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = RestClient::for_mock_server(&server.uri(), ApiVersion::V2026_04);
+
+        let code = DiscountCode::find_with_parent(&client, 507328175, 12345, None)
+            .await
+            .unwrap();
+
+        assert_eq!(DiscountCode::resource_key(), "discount_code");
+        assert_eq!(code.id, Some(12345));
+        assert_eq!(code.code.as_deref(), Some("SUMMER20"));
+    }
 }
@@ -55,6 +55,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::clients::RestClient;
+use crate::config::ShopDomain;
 use crate::rest::{
     build_path, get_path, ReadOnlyResource, ResourceError, ResourceOperation, ResourcePath,
     ResourceResponse, RestResource,
@@ -369,6 +370,11 @@ impl RestResource for Event {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    /// Events have no page of their own in the Shopify admin.
+    fn admin_url(&self, _shop: &ShopDomain) -> Option<String> {
+        None
+    }
 }
 
 impl ReadOnlyResource for Event {}
@@ -62,6 +62,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::clients::RestClient;
+use crate::config::ShopDomain;
 use crate::rest::{ResourceError, ResourceOperation, ResourcePath, RestResource};
 use crate::HttpMethod;
 
@@ -380,9 +381,28 @@ impl RestResource for FulfillmentOrder {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    /// Fulfillment orders have no page of their own in the Shopify admin.
+    fn admin_url(&self, _shop: &ShopDomain) -> Option<String> {
+        None
+    }
 }
 
 impl FulfillmentOrder {
+    /// Returns `true` if the fulfillment order is on hold.
+    ///
+    /// A fulfillment order is on hold when its status is
+    /// [`FulfillmentOrderStatus::OnHold`] or it has active
+    /// [`fulfillment_holds`](Self::fulfillment_holds).
+    #[must_use]
+    pub fn is_on_hold(&self) -> bool {
+        self.status == Some(FulfillmentOrderStatus::OnHold)
+            || self
+                .fulfillment_holds
+                .as_ref()
+                .is_some_and(|holds| !holds.is_empty())
+    }
+
     /// Cancels the fulfillment order.
     ///
     /// Sends a POST request to `/admin/api/{version}/fulfillment_orders/{id}/cancel.json`.
@@ -1364,6 +1384,29 @@ mod tests {
         assert_eq!(FulfillmentOrder::PLURAL, "fulfillment_orders");
     }
 
+    #[test]
+    fn test_fulfillment_order_path_introspection() {
+        assert_eq!(
+            FulfillmentOrder::required_params(ResourceOperation::All),
+            Some(&["order_id"][..])
+        );
+        assert_eq!(
+            FulfillmentOrder::required_params(ResourceOperation::Find),
+            Some(&["id"][..])
+        );
+        assert_eq!(
+            FulfillmentOrder::required_params(ResourceOperation::Create),
+            None
+        );
+
+        let operations = FulfillmentOrder::available_operations();
+        assert_eq!(
+            operations,
+            vec![ResourceOperation::Find, ResourceOperation::All]
+        );
+        assert!(!operations.contains(&ResourceOperation::Create));
+    }
+
     #[test]
     fn test_special_operation_method_signatures() {
         // Verify cancel signature
@@ -1515,4 +1558,26 @@ mod tests {
         };
         assert_eq!(fo_without_id.get_id(), None);
     }
+
+    #[test]
+    fn test_is_on_hold() {
+        let on_hold = FulfillmentOrder {
+            status: Some(FulfillmentOrderStatus::OnHold),
+            ..Default::default()
+        };
+        assert!(on_hold.is_on_hold());
+
+        let with_holds: FulfillmentOrder = serde_json::from_str(
+            r#"{"status": "open", "fulfillment_holds": [{"reason": "awaiting_payment"}]}"#,
+        )
+        .unwrap();
+        assert!(with_holds.is_on_hold());
+
+        let open = FulfillmentOrder {
+            status: Some(FulfillmentOrderStatus::Open),
+            fulfillment_holds: Some(vec![]),
+            ..Default::default()
+        };
+        assert!(!open.is_on_hold());
+    }
 }
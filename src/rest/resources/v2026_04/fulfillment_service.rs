@@ -40,6 +40,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::config::ShopDomain;
 use crate::rest::{ResourceOperation, ResourcePath, RestResource};
 use crate::HttpMethod;
 
@@ -165,6 +166,11 @@ impl RestResource for FulfillmentService {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    /// Fulfillment services have no page of their own in the Shopify admin.
+    fn admin_url(&self, _shop: &ShopDomain) -> Option<String> {
+        None
+    }
 }
 
 /// Parameters for finding a single fulfillment service.
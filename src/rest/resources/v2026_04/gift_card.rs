@@ -14,6 +14,8 @@
 //! In addition to standard CRUD operations (no Delete), the GiftCard resource provides:
 //! - [`GiftCard::disable`] - Disable a gift card (cannot be re-enabled)
 //! - [`GiftCard::search`] - Search for gift cards by query
+//! - [`GiftCard::balance`] - Fetch the current balance
+//! - [`GiftCard::transactions`] - List the balance adjustments
 //!
 //! # Field Constraints
 //!
@@ -52,9 +54,11 @@ use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::clients::RestClient;
-use crate::rest::{ResourceError, ResourceOperation, ResourcePath, RestResource};
+use crate::rest::{ResourceError, ResourceOperation, ResourcePath, ResourceResponse, RestResource};
 use crate::HttpMethod;
 
+use super::common::Money;
+
 /// A gift card in Shopify.
 ///
 /// Gift cards are a Shopify Plus feature that allow merchants to sell
@@ -258,6 +262,15 @@ impl RestResource for GiftCard {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
+
+    /// Shopify wraps a single gift card in a `gift_card` key.
+    fn resource_key() -> String {
+        "gift_card".to_string()
+    }
 }
 
 impl GiftCard {
@@ -403,6 +416,143 @@ impl GiftCard {
 
         Ok(gift_cards)
     }
+
+    /// Fetches the gift card's current balance.
+    ///
+    /// Sends a GET request to `/admin/api/{version}/gift_cards/{id}.json`, so
+    /// the balance reflects adjustments made since this gift card was loaded.
+    /// The balance is returned in the gift card's currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResourceError::NotFound`] if the gift card doesn't exist.
+    /// Returns [`ResourceError::PathResolutionFailed`] if the gift card has no ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let balance = gift_card.balance(&client).await?;
+    /// println!("Remaining: {:?} {:?}", balance.amount, balance.currency_code);
+    /// ```
+    pub async fn balance(&self, client: &RestClient) -> Result<Money, ResourceError> {
+        let id = self.get_id().ok_or(ResourceError::PathResolutionFailed {
+            resource: Self::NAME,
+            operation: "balance",
+        })?;
+
+        let current = Self::find(client, id, None).await?.into_inner();
+        Ok(Money {
+            amount: current.balance,
+            currency_code: current.currency,
+        })
+    }
+
+    /// Lists the adjustments made to the gift card's balance.
+    ///
+    /// Sends a GET request to `/admin/api/{version}/gift_cards/{id}/adjustments.json`.
+    /// Both supported API versions expose gift card adjustments.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResourceError::NotFound`] if the gift card doesn't exist.
+    /// Returns [`ResourceError::PathResolutionFailed`] if the gift card has no ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// for adjustment in gift_card.transactions(&client).await? {
+    ///     println!("{:?} {:?}", adjustment.processed_at, adjustment.amount);
+    /// }
+    /// ```
+    pub async fn transactions(
+        &self,
+        client: &RestClient,
+    ) -> Result<Vec<GiftCardAdjustment>, ResourceError> {
+        let id = self.get_id().ok_or(ResourceError::PathResolutionFailed {
+            resource: Self::NAME,
+            operation: "transactions",
+        })?;
+
+        let response = client.get(&adjustments_path(id), None).await?;
+
+        if !response.is_ok() {
+            return Err(ResourceError::from_http_response(
+                response.code,
+                &response.body,
+                Self::NAME,
+                Some(&id.to_string()),
+                response.request_id(),
+            ));
+        }
+
+        let adjustments: ResourceResponse<Vec<GiftCardAdjustment>> =
+            ResourceResponse::from_http_response(response, "adjustments")?;
+        Ok(adjustments.into_inner())
+    }
+}
+
+/// Builds the path listing a gift card's adjustments.
+fn adjustments_path(gift_card_id: u64) -> String {
+    format!("gift_cards/{gift_card_id}/adjustments")
+}
+
+/// A change to a gift card's balance.
+///
+/// Adjustments are made by the app or merchant, or by an order paid with the
+/// gift card. Listed with [`GiftCard::transactions`].
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct GiftCardAdjustment {
+    /// The unique identifier of the adjustment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    /// The ID of the gift card the adjustment applies to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gift_card_id: Option<u64>,
+
+    /// The ID of the app that made the adjustment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_client_id: Option<u64>,
+
+    /// The ID of the staff member who made the adjustment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<u64>,
+
+    /// The ID of the order transaction that caused the adjustment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_transaction_id: Option<u64>,
+
+    /// The sequence number of the adjustment on its gift card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<u64>,
+
+    /// The amount added to (positive) or removed from (negative) the balance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+
+    /// A note about the adjustment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+
+    /// A reference to the adjustment in an external system.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_transaction_ref: Option<String>,
+
+    /// A URL for the adjustment in an external system.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_transaction_url: Option<String>,
+
+    /// When the adjustment was processed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processed_at: Option<DateTime<Utc>>,
+
+    /// When the adjustment was created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// When the adjustment was last updated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
 }
 
 /// Parameters for finding a single gift card.
@@ -596,6 +746,99 @@ mod tests {
         assert_eq!(GiftCard::PLURAL, "gift_cards");
     }
 
+    #[tokio::test]
+    async fn test_balance_fetches_amount_in_gift_card_currency() {
+        use crate::config::ApiVersion;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/admin/api/2026-04/gift_cards/1035197676.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "gift_card": { "id": 1035197676, "balance": "74.50", "currency": "CAD" }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = RestClient::for_mock_server(&server.uri(), ApiVersion::V2026_04);
+
+        let gift_card = GiftCard {
+            id: Some(1035197676),
+            balance: Some("100.00".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            gift_card.balance(&client).await.unwrap(),
+            Money {
+                amount: Some("74.50".to_string()),
+                currency_code: Some("CAD".to_string()),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_transactions_lists_adjustments() {
+        use crate::config::ApiVersion;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(
+                "/admin/api/2026-04/gift_cards/1035197676/adjustments.json",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "adjustments": [
+                    {
+                        "id": 1,
+                        "gift_card_id": 1035197676,
+                        "api_client_id": 755357713,
+                        "user_id": null,
+                        "order_transaction_id": null,
+                        "number": 1,
+                        "amount": "10.00",
+                        "processed_at": "2024-01-15T10:30:00Z",
+                        "created_at": "2024-01-15T10:30:00Z",
+                        "updated_at": "2024-01-15T10:30:00Z",
+                        "note": "Loyalty top-up",
+                        "remote_transaction_ref": "ref-1",
+                        "remote_transaction_url": null
+                    },
+                    {
+                        "id": 2,
+                        "gift_card_id": 1035197676,
+                        "order_transaction_id": 389404469,
+                        "number": 2,
+                        "amount": "-25.50",
+                        "processed_at": "2024-02-01T08:00:00Z"
+                    }
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = RestClient::for_mock_server(&server.uri(), ApiVersion::V2026_04);
+
+        let gift_card = GiftCard {
+            id: Some(1035197676),
+            ..Default::default()
+        };
+        let adjustments = gift_card.transactions(&client).await.unwrap();
+
+        assert_eq!(adjustments.len(), 2);
+        assert_eq!(adjustments[0].amount.as_deref(), Some("10.00"));
+        assert_eq!(adjustments[0].note.as_deref(), Some("Loyalty top-up"));
+        assert_eq!(
+            adjustments[0].remote_transaction_ref.as_deref(),
+            Some("ref-1")
+        );
+        assert_eq!(adjustments[1].amount.as_deref(), Some("-25.50"));
+        assert_eq!(adjustments[1].order_transaction_id, Some(389404469));
+        assert!(adjustments[1].processed_at.is_some());
+    }
+
     #[test]
     fn test_disable_method_signature() {
         // Verify the disable method signature compiles correctly
@@ -50,9 +50,13 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::clients::RestClient;
+use crate::config::ShopDomain;
 use crate::rest::{ResourceError, ResourceOperation, ResourcePath, RestResource};
 use crate::HttpMethod;
 
+/// Path used by [`InventoryLevel::set`].
+const SET_PATH: &str = "inventory_levels/set";
+
 /// An inventory level in a Shopify store.
 ///
 /// Inventory levels represent the quantity of an inventory item available at
@@ -287,18 +291,13 @@ impl InventoryLevel {
         available: i64,
         disconnect_if_necessary: Option<bool>,
     ) -> Result<Self, ResourceError> {
-        let path = "inventory_levels/set";
-        let mut body = serde_json::json!({
-            "inventory_item_id": inventory_item_id,
-            "location_id": location_id,
-            "available": available
-        });
-
-        if let Some(disconnect) = disconnect_if_necessary {
-            body["disconnect_if_necessary"] = serde_json::json!(disconnect);
-        }
-
-        let response = client.post(path, body, None).await?;
+        let body = set_body(
+            inventory_item_id,
+            location_id,
+            available,
+            disconnect_if_necessary,
+        );
+        let response = client.post(SET_PATH, body, None).await?;
 
         if !response.is_ok() {
             return Err(ResourceError::from_http_response(
@@ -424,6 +423,11 @@ impl RestResource for InventoryLevel {
         // Use the special operations (adjust, connect, set, delete_at_location) instead
         None
     }
+
+    /// Inventory levels have no page of their own in the Shopify admin.
+    fn admin_url(&self, _shop: &ShopDomain) -> Option<String> {
+        None
+    }
 }
 
 /// Parameters for listing inventory levels.
@@ -448,6 +452,26 @@ pub struct InventoryLevelListParams {
     pub updated_at_min: Option<DateTime<Utc>>,
 }
 
+/// Builds the request body for [`InventoryLevel::set`].
+fn set_body(
+    inventory_item_id: u64,
+    location_id: u64,
+    available: i64,
+    disconnect_if_necessary: Option<bool>,
+) -> serde_json::Value {
+    let mut body = serde_json::json!({
+        "inventory_item_id": inventory_item_id,
+        "location_id": location_id,
+        "available": available
+    });
+
+    if let Some(disconnect) = disconnect_if_necessary {
+        body["disconnect_if_necessary"] = serde_json::json!(disconnect);
+    }
+
+    body
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
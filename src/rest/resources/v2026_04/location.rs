@@ -40,6 +40,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::clients::RestClient;
+use crate::config::ShopDomain;
 use crate::rest::{ReadOnlyResource, ResourceError, ResourceOperation, ResourcePath, RestResource};
 use crate::HttpMethod;
 
@@ -127,10 +128,17 @@ pub struct Location {
     #[serde(skip_serializing)]
     pub active: Option<bool>,
 
-    /// Whether this is a legacy location.
+    /// Whether this is a fulfillment service location.
+    ///
+    /// `true` for locations managed by a fulfillment service, `false` for
+    /// locations created by the merchant.
     #[serde(skip_serializing)]
     pub legacy: Option<bool>,
 
+    /// The handle of the fulfillment service that manages this location, if any.
+    #[serde(skip_serializing)]
+    pub fulfillment_service: Option<String>,
+
     /// When the location was created.
     #[serde(skip_serializing)]
     pub created_at: Option<DateTime<Utc>>,
@@ -145,6 +153,27 @@ pub struct Location {
 }
 
 impl Location {
+    /// Returns `true` if this location is managed by a fulfillment service.
+    ///
+    /// Fulfillments for items stocked here are handled by the service rather
+    /// than the merchant, so they need to be routed through the service's
+    /// fulfillment orders. Checks [`legacy`](Self::legacy) and
+    /// [`fulfillment_service`](Self::fulfillment_service).
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let locations = Location::all(&client, None).await?;
+    /// let merchant_managed: Vec<_> = locations
+    ///     .iter()
+    ///     .filter(|l| !l.is_fulfillment_service())
+    ///     .collect();
+    /// ```
+    #[must_use]
+    pub const fn is_fulfillment_service(&self) -> bool {
+        matches!(self.legacy, Some(true)) || self.fulfillment_service.is_some()
+    }
+
     /// Retrieves inventory levels at this location.
     ///
     /// Sends a GET request to `/admin/api/{version}/locations/{id}/inventory_levels.json`.
@@ -296,6 +325,16 @@ impl RestResource for Location {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    /// Locations open under the admin's settings.
+    fn admin_url(&self, shop: &ShopDomain) -> Option<String> {
+        self.id.map(|id| {
+            format!(
+                "https://admin.shopify.com/store/{}/settings/locations/{id}",
+                shop.shop_name()
+            )
+        })
+    }
 }
 
 /// Marker trait implementation indicating Location is read-only.
@@ -410,6 +449,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_fulfillment_service_location_deserialization() {
+        let json = r#"{
+            "id": 905684977,
+            "name": "Mars Fulfillment",
+            "address1": null,
+            "city": null,
+            "country": "US",
+            "country_code": "US",
+            "localized_country_name": "United States",
+            "localized_province_name": null,
+            "active": true,
+            "legacy": true,
+            "fulfillment_service": "mars-fulfillment",
+            "admin_graphql_api_id": "gid://shopify/Location/905684977"
+        }"#;
+
+        let location: Location = serde_json::from_str(json).unwrap();
+
+        assert_eq!(location.legacy, Some(true));
+        assert_eq!(
+            location.fulfillment_service.as_deref(),
+            Some("mars-fulfillment")
+        );
+        assert_eq!(
+            location.localized_country_name.as_deref(),
+            Some("United States")
+        );
+        assert_eq!(location.localized_province_name, None);
+        assert!(location.is_fulfillment_service());
+    }
+
+    #[test]
+    fn test_is_fulfillment_service_for_merchant_location() {
+        let merchant = Location {
+            legacy: Some(false),
+            ..Default::default()
+        };
+        assert!(!merchant.is_fulfillment_service());
+        assert!(!Location::default().is_fulfillment_service());
+
+        let linked = Location {
+            fulfillment_service: Some("mars-fulfillment".to_string()),
+            ..Default::default()
+        };
+        assert!(linked.is_fulfillment_service());
+    }
+
     #[test]
     fn test_location_serialization_is_empty() {
         // Since all fields are read-only (skip_serializing), serialization should produce empty object
@@ -38,15 +38,24 @@
 //! let reopened_order = closed_order.open(&client).await?;
 //! ```
 
+#[cfg(feature = "capture-unknown-fields")]
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::clients::RestClient;
-use crate::rest::{ResourceError, ResourceOperation, ResourcePath, RestResource};
+use crate::rest::{tags, ResourceError, ResourceOperation, ResourcePath, RestResource};
 use crate::HttpMethod;
 
-use super::common::{Address, DiscountApplication, LineItem, NoteAttribute, ShippingLine, TaxLine};
+use super::common::{
+    Address, DiscountApplication, LineItem, MetafieldInput, MoneySet, NoteAttribute, ShippingLine,
+    SortOrder, TaxLine,
+};
 use super::customer::Customer;
+use super::fulfillment::ShipmentStatus;
+use super::refund::{OrderAdjustment, RefundLineItem};
+use super::transaction::{PaymentDetails, Transaction};
 
 /// The financial status of an order.
 ///
@@ -163,23 +172,55 @@ pub struct Refund {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub refund_duties: Option<serde_json::Value>,
 
-    /// Line items included in the refund (complex structure).
+    /// Line items included in the refund.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub refund_line_items: Option<serde_json::Value>,
+    pub refund_line_items: Option<Vec<RefundLineItem>>,
 
-    /// Transactions for the refund (complex structure).
+    /// Transactions for the refund.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transactions: Option<serde_json::Value>,
+    pub transactions: Option<Vec<Transaction>>,
 
-    /// Order adjustments from the refund (complex structure).
+    /// Order adjustments from the refund.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order_adjustments: Option<serde_json::Value>,
+    pub order_adjustments: Option<Vec<OrderAdjustment>>,
 
     /// The admin GraphQL API ID.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub admin_graphql_api_id: Option<String>,
 }
 
+/// Details of the browser that placed an order.
+///
+/// Parsed from [`Order::client_details`] with
+/// [`Order::parse_client_details`]. Fields Shopify omits, such as the
+/// browser size for orders not placed in a browser, are `None`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct ClientDetails {
+    /// The languages and locales the browser understands.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accept_language: Option<String>,
+
+    /// The browser screen height in pixels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub browser_height: Option<i64>,
+
+    /// The browser screen width in pixels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub browser_width: Option<i64>,
+
+    /// The IP address the order was placed from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub browser_ip: Option<String>,
+
+    /// A hash of the session that placed the order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_hash: Option<String>,
+
+    /// The browser's user agent string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+}
+
 /// An embedded fulfillment within an order response.
 ///
 /// This is a simplified view of fulfillment data when embedded in order responses.
@@ -215,7 +256,7 @@ pub struct OrderFulfillment {
 
     /// The shipment status.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipment_status: Option<String>,
+    pub shipment_status: Option<ShipmentStatus>,
 
     /// The ID of the location that fulfilled the order.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -333,6 +374,10 @@ pub struct Order {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<String>,
 
+    /// The currency the customer was shown and paid in (e.g., "CAD").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presentment_currency: Option<String>,
+
     /// The total price of the order including taxes and discounts.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub total_price: Option<String>,
@@ -401,9 +446,9 @@ pub struct Order {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub total_price_usd: Option<String>,
 
-    /// Total shipping price.
+    /// Total shipping price in shop and presentment currencies.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub total_shipping_price_set: Option<serde_json::Value>,
+    pub total_shipping_price_set: Option<MoneySet>,
 
     /// Total line items price.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -417,6 +462,10 @@ pub struct Order {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub current_total_price: Option<String>,
 
+    /// Current total price in shop and presentment currencies.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_total_price_set: Option<MoneySet>,
+
     /// Current subtotal price.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub current_subtotal_price: Option<String>,
@@ -539,12 +588,32 @@ pub struct Order {
     pub customer: Option<Customer>,
 
     /// Client details (complex structure).
+    ///
+    /// Read as [`ClientDetails`] with [`parse_client_details`](Self::parse_client_details).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_details: Option<serde_json::Value>,
 
     /// Payment details (complex structure).
+    ///
+    /// Read as [`PaymentDetails`] with [`parse_payment_details`](Self::parse_payment_details).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub payment_details: Option<serde_json::Value>,
+
+    /// Metafields to create along with the order.
+    ///
+    /// Only sent on create; Shopify doesn't return them in order responses.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metafields: Option<Vec<MetafieldInput>>,
+
+    /// Fields returned by the API that this struct doesn't model.
+    ///
+    /// Only present with the `capture-unknown-fields` feature. Captured
+    /// fields are sent back when the order is saved, so values from newer
+    /// API versions survive a round trip. Read one with
+    /// [`extra_field`](Self::extra_field).
+    #[cfg(feature = "capture-unknown-fields")]
+    #[serde(flatten, default, skip_serializing_if = "HashMap::is_empty")]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 impl RestResource for Order {
@@ -588,9 +657,115 @@ impl RestResource for Order {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
 }
 
 impl Order {
+    /// Returns an unmodeled field captured from the API response.
+    ///
+    /// Only available with the `capture-unknown-fields` feature.
+    #[cfg(feature = "capture-unknown-fields")]
+    #[must_use]
+    pub fn extra_field(&self, name: &str) -> Option<&serde_json::Value> {
+        self.extra.get(name)
+    }
+
+    /// Returns the order's tags, trimmed and without duplicates.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::rest::resources::v2026_04::Order;
+    ///
+    /// let mut order = Order {
+    ///     tags: Some("vip, wholesale".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// order.add_tag("priority");
+    /// order.remove_tag("wholesale");
+    /// assert_eq!(order.tag_list(), vec!["vip", "priority"]);
+    /// assert!(order.has_tag("VIP"));
+    /// ```
+    #[must_use]
+    pub fn tag_list(&self) -> Vec<String> {
+        tags::tag_list(self.tags.as_deref())
+    }
+
+    /// Returns `true` if the order has `tag`, ignoring case.
+    #[must_use]
+    pub fn has_tag(&self, tag: &str) -> bool {
+        tags::has_tag(self.tags.as_deref(), tag)
+    }
+
+    /// Adds `tag` unless the order already has it.
+    ///
+    /// Call [`save`](RestResource::save) to apply the change.
+    pub fn add_tag(&mut self, tag: &str) {
+        tags::add_tag(&mut self.tags, tag);
+    }
+
+    /// Removes `tag` if the order has it.
+    ///
+    /// Removing the last tag sets `tags` to an empty string, so saving
+    /// clears the tags on Shopify too.
+    pub fn remove_tag(&mut self, tag: &str) {
+        tags::remove_tag(&mut self.tags, tag);
+    }
+
+    /// Returns the value of the note attribute with the given name.
+    ///
+    /// Note attributes are the cart attributes captured at checkout. If the
+    /// name appears more than once, the first value is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::rest::resources::v2026_04::Order;
+    /// use shopify_sdk::rest::resources::v2026_04::common::NoteAttribute;
+    ///
+    /// let order = Order {
+    ///     note_attributes: Some(vec![NoteAttribute {
+    ///         name: "delivery_date".to_string(),
+    ///         value: "2024-12-24".to_string(),
+    ///     }]),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(order.note_attribute("delivery_date"), Some("2024-12-24"));
+    /// assert_eq!(order.note_attribute("gift_message"), None);
+    /// ```
+    #[must_use]
+    pub fn note_attribute(&self, name: &str) -> Option<&str> {
+        self.note_attributes
+            .as_deref()?
+            .iter()
+            .find(|a| a.name == name)
+            .map(|a| a.value.as_str())
+    }
+
+    /// Parses [`client_details`](Self::client_details) into [`ClientDetails`].
+    ///
+    /// Returns `None` if the order has no client details or they don't have
+    /// the documented shape. Missing fields are left as `None`.
+    #[must_use]
+    pub fn parse_client_details(&self) -> Option<ClientDetails> {
+        ClientDetails::deserialize(self.client_details.as_ref()?).ok()
+    }
+
+    /// Parses [`payment_details`](Self::payment_details) into [`PaymentDetails`],
+    /// the same shape [`Transaction`](super::Transaction) uses.
+    ///
+    /// Returns `None` if the order has no payment details or they don't have
+    /// the documented shape. Missing fields are left as `None`.
+    #[must_use]
+    pub fn parse_payment_details(&self) -> Option<PaymentDetails> {
+        PaymentDetails::deserialize(self.payment_details.as_ref()?).ok()
+    }
+
     /// Cancels the order.
     ///
     /// Sends a POST request to `/admin/api/{version}/orders/{id}/cancel.json`.
@@ -864,6 +1039,10 @@ pub struct OrderListParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attribution_app_id: Option<u64>,
 
+    /// Sort order of the results, e.g. `created_at desc`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<SortOrder>,
+
     /// Comma-separated list of fields to include in the response.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fields: Option<String>,
@@ -908,6 +1087,7 @@ pub struct OrderCountParams {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::rest::resources::v2026_04::TransactionKind;
     use crate::rest::{get_path, ResourceOperation};
 
     #[test]
@@ -1026,6 +1206,83 @@ mod tests {
         assert_eq!(customer.first_name.as_deref(), Some("John"));
     }
 
+    #[test]
+    fn test_order_deserialization_with_presentment_money() {
+        let json = r#"{
+            "id": 450789469,
+            "currency": "USD",
+            "presentment_currency": "CAD",
+            "current_total_price": "110.00",
+            "current_total_price_set": {
+                "shop_money": { "amount": "110.00", "currency_code": "USD" },
+                "presentment_money": { "amount": "149.60", "currency_code": "CAD" }
+            },
+            "total_shipping_price_set": {
+                "shop_money": { "amount": "10.00", "currency_code": "USD" },
+                "presentment_money": { "amount": "13.60", "currency_code": "CAD" }
+            }
+        }"#;
+
+        let order: Order = serde_json::from_str(json).unwrap();
+
+        assert_eq!(order.currency.as_deref(), Some("USD"));
+        assert_eq!(order.presentment_currency.as_deref(), Some("CAD"));
+
+        let total = order.current_total_price_set.unwrap();
+        let shop_total = total.shop_money.unwrap();
+        let presentment_total = total.presentment_money.unwrap();
+        assert_eq!(shop_total.amount.as_deref(), Some("110.00"));
+        assert_eq!(shop_total.currency_code.as_deref(), Some("USD"));
+        assert_eq!(presentment_total.amount.as_deref(), Some("149.60"));
+        assert_eq!(presentment_total.currency_code.as_deref(), Some("CAD"));
+
+        let shipping = order.total_shipping_price_set.unwrap();
+        assert_eq!(
+            shipping.shop_money.unwrap().amount.as_deref(),
+            Some("10.00")
+        );
+        assert_eq!(
+            shipping.presentment_money.unwrap().amount.as_deref(),
+            Some("13.60")
+        );
+    }
+
+    #[test]
+    fn test_order_note_attribute_lookup() {
+        let order: Order = serde_json::from_value(serde_json::json!({
+            "id": 450789469,
+            "note_attributes": [
+                { "name": "delivery_date", "value": "2024-12-24" },
+                { "name": "gift_message", "value": "Happy holidays" }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(order.note_attribute("gift_message"), Some("Happy holidays"));
+        assert_eq!(order.note_attribute("colour"), None);
+        assert_eq!(Order::default().note_attribute("gift_message"), None);
+    }
+
+    #[test]
+    fn test_order_tag_helpers_ignore_duplicates_and_missing_tags() {
+        let mut order = Order {
+            tags: Some("important, vip".to_string()),
+            ..Default::default()
+        };
+
+        order.add_tag("VIP");
+        assert_eq!(order.tags.as_deref(), Some("important, vip"));
+
+        order.remove_tag("wholesale");
+        assert_eq!(order.tags.as_deref(), Some("important, vip"));
+
+        order.add_tag("rush");
+        order.remove_tag("important");
+        assert_eq!(order.tag_list(), vec!["vip", "rush"]);
+        assert!(order.has_tag("rush"));
+        assert!(!order.has_tag("important"));
+    }
+
     #[test]
     fn test_financial_status_enum_serialization() {
         // Test serialization
@@ -1151,6 +1408,7 @@ mod tests {
             processed_at_max: None,
             attribution_app_id: Some(12345),
             fields: Some("id,name,total_price".to_string()),
+            order: None,
             page_info: None,
         };
 
@@ -1280,6 +1538,73 @@ mod tests {
         assert_eq!(parsed_discount.discount_type.as_deref(), Some("percentage"));
     }
 
+    #[test]
+    fn test_order_parses_client_and_payment_details() {
+        let order: Order = serde_json::from_str(
+            r#"{
+                "id": 450789469,
+                "client_details": {
+                    "accept_language": "en-US,en;q=0.9",
+                    "browser_height": 1320,
+                    "browser_ip": "216.191.105.146",
+                    "browser_width": 1280,
+                    "session_hash": "9ad4d1f4e6a8977b9dd98eed1e477643",
+                    "user_agent": "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7)"
+                },
+                "payment_details": {
+                    "credit_card_bin": "424242",
+                    "avs_result_code": "Y",
+                    "cvv_result_code": "M",
+                    "credit_card_number": "•••• •••• •••• 4242",
+                    "credit_card_company": "Visa",
+                    "credit_card_name": "Bob Norman",
+                    "buyer_action_info": null
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let client = order.parse_client_details().unwrap();
+        assert_eq!(client.browser_ip.as_deref(), Some("216.191.105.146"));
+        assert_eq!(client.browser_height, Some(1320));
+        assert_eq!(client.browser_width, Some(1280));
+        assert_eq!(client.accept_language.as_deref(), Some("en-US,en;q=0.9"));
+
+        let payment = order.parse_payment_details().unwrap();
+        assert_eq!(payment.credit_card_bin.as_deref(), Some("424242"));
+        assert_eq!(payment.avs_result_code.as_deref(), Some("Y"));
+        assert_eq!(payment.cvv_result_code.as_deref(), Some("M"));
+        assert_eq!(payment.credit_card_company.as_deref(), Some("Visa"));
+    }
+
+    #[test]
+    fn test_order_details_tolerate_missing_fields() {
+        let order: Order = serde_json::from_str(
+            r#"{
+                "id": 450789469,
+                "client_details": {
+                    "browser_ip": "216.191.105.146",
+                    "browser_height": null,
+                    "user_agent": null
+                },
+                "payment_details": {}
+            }"#,
+        )
+        .unwrap();
+
+        let client = order.parse_client_details().unwrap();
+        assert_eq!(client.browser_ip.as_deref(), Some("216.191.105.146"));
+        assert_eq!(client.browser_height, None);
+        assert_eq!(client.user_agent, None);
+        assert_eq!(
+            order.parse_payment_details(),
+            Some(PaymentDetails::default())
+        );
+
+        assert_eq!(Order::default().parse_client_details(), None);
+        assert_eq!(Order::default().parse_payment_details(), None);
+    }
+
     #[test]
     fn test_refund_struct_serialization() {
         let refund = Refund {
@@ -1299,6 +1624,69 @@ mod tests {
         assert_eq!(parsed["restock"], true);
     }
 
+    #[test]
+    fn test_order_refund_deserializes_typed_nested_structures() {
+        let json = r#"{
+            "id": 509562969,
+            "order_id": 450789469,
+            "note": "Damaged in transit",
+            "refund_line_items": [
+                {
+                    "id": 104689539,
+                    "quantity": 1,
+                    "line_item_id": 703073504,
+                    "location_id": 487838322,
+                    "restock_type": "return",
+                    "subtotal": "195.66",
+                    "total_tax": "3.98",
+                    "line_item": { "id": 703073504, "title": "IPod Nano - 8gb" }
+                }
+            ],
+            "transactions": [
+                {
+                    "id": 179259969,
+                    "order_id": 450789469,
+                    "kind": "refund",
+                    "gateway": "bogus",
+                    "status": "success",
+                    "amount": "209.00",
+                    "currency": "USD",
+                    "parent_id": 801038806
+                }
+            ],
+            "order_adjustments": [
+                {
+                    "id": 1030976842,
+                    "order_id": 450789469,
+                    "refund_id": 509562969,
+                    "kind": "shipping_refund",
+                    "reason": "Shipping refund",
+                    "amount": "-5.00",
+                    "tax_amount": "0.00"
+                }
+            ]
+        }"#;
+
+        let refund: Refund = serde_json::from_str(json).unwrap();
+
+        let line_items = refund.refund_line_items.as_deref().unwrap();
+        assert_eq!(line_items.len(), 1);
+        assert_eq!(line_items[0].line_item_id, Some(703073504));
+        assert_eq!(line_items[0].quantity, Some(1));
+        assert_eq!(line_items[0].restock_type.as_deref(), Some("return"));
+        assert_eq!(line_items[0].subtotal.as_deref(), Some("195.66"));
+
+        let transactions = refund.transactions.as_deref().unwrap();
+        assert_eq!(transactions[0].kind, Some(TransactionKind::Refund));
+        assert_eq!(transactions[0].amount.as_deref(), Some("209.00"));
+        assert_eq!(transactions[0].parent_id, Some(801038806));
+
+        let adjustments = refund.order_adjustments.as_deref().unwrap();
+        assert_eq!(adjustments[0].kind.as_deref(), Some("shipping_refund"));
+        assert_eq!(adjustments[0].amount.as_deref(), Some("-5.00"));
+        assert_eq!(adjustments[0].refund_id, Some(509562969));
+    }
+
     #[test]
     fn test_order_with_all_nested_structures() {
         // Use r##"..."## to allow # characters in the JSON string
@@ -1370,4 +1758,56 @@ mod tests {
             Some("1234")
         );
     }
+
+    #[test]
+    fn test_order_fulfillment_shipment_status_is_typed() {
+        let order: Order = serde_json::from_str(
+            r#"{
+                "id": 450789469,
+                "fulfillments": [
+                    {"id": 1, "shipment_status": "in_transit"},
+                    {"id": 2, "shipment_status": "delivered"},
+                    {"id": 3, "shipment_status": null}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let statuses: Vec<Option<ShipmentStatus>> = order
+            .fulfillments
+            .unwrap()
+            .into_iter()
+            .map(|fulfillment| fulfillment.shipment_status)
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                Some(ShipmentStatus::InTransit),
+                Some(ShipmentStatus::Delivered),
+                None
+            ]
+        );
+    }
+
+    #[cfg(feature = "capture-unknown-fields")]
+    #[test]
+    fn test_order_round_trips_unmodeled_fields() {
+        let order: Order = serde_json::from_value(serde_json::json!({
+            "id": 450789469,
+            "email": "bob.norman@mail.example.com",
+            "merchant_business_entity_id": "MBE123"
+        }))
+        .unwrap();
+
+        assert_eq!(order.email.as_deref(), Some("bob.norman@mail.example.com"));
+        assert_eq!(
+            order.extra_field("merchant_business_entity_id"),
+            Some(&serde_json::json!("MBE123"))
+        );
+        assert!(order.extra_field("email").is_none());
+
+        let json = serde_json::to_value(&order).unwrap();
+        assert_eq!(json["merchant_business_entity_id"], "MBE123");
+        assert_eq!(json["email"], "bob.norman@mail.example.com");
+    }
 }
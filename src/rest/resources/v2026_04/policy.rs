@@ -32,6 +32,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::config::ShopDomain;
 use crate::rest::{ReadOnlyResource, ResourceOperation, ResourcePath, RestResource};
 use crate::HttpMethod;
 
@@ -115,6 +116,11 @@ impl RestResource for Policy {
         // Return the handle as a fallback identifier
         self.handle.clone()
     }
+
+    /// Policies have no page of their own in the Shopify admin.
+    fn admin_url(&self, _shop: &ShopDomain) -> Option<String> {
+        None
+    }
 }
 
 impl ReadOnlyResource for Policy {}
@@ -7,12 +7,17 @@
 //!
 //! ```rust,ignore
 //! use shopify_sdk::rest::{RestResource, ResourceResponse};
-//! use shopify_sdk::rest::resources::v2026_04::{Product, ProductListParams, ProductStatus};
+//! use shopify_sdk::rest::resources::v2026_04::{
+//!     MetafieldInput, Product, ProductListParams, ProductStatus,
+//! };
 //!
 //! // Find a single product
 //! let product = Product::find(&client, 123, None).await?;
 //! println!("Product: {}", product.title.as_deref().unwrap_or(""));
 //!
+//! // Find a product by the handle in its storefront URL
+//! let product = Product::find_by_handle(&client, "example-t-shirt").await?;
+//!
 //! // List products with filters
 //! let params = ProductListParams {
 //!     status: Some(ProductStatus::Active),
@@ -26,18 +31,30 @@
 //!     title: Some("My New Product".to_string()),
 //!     vendor: Some("My Store".to_string()),
 //!     product_type: Some("T-Shirts".to_string()),
+//!     metafields: Some(vec![MetafieldInput::new(
+//!         "custom",
+//!         "care_guide",
+//!         "single_line_text_field",
+//!         "Machine wash cold",
+//!     )]),
 //!     ..Default::default()
 //! };
 //! let saved = product.save(&client).await?;
 //! ```
 
+#[cfg(feature = "capture-unknown-fields")]
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::rest::{ResourceOperation, ResourcePath, RestResource};
+use crate::clients::RestClient;
+use crate::rest::{
+    tags, ResourceError, ResourceOperation, ResourcePath, ResourceResponse, RestResource,
+};
 use crate::HttpMethod;
 
-use super::common::{ProductImage, ProductOption};
+use super::common::{MetafieldInput, PresentmentPrice, ProductImage, ProductOption, SortOrder};
 
 /// The status of a product.
 ///
@@ -120,6 +137,11 @@ pub struct ProductVariant {
     /// Read-only field.
     #[serde(skip_serializing)]
     pub updated_at: Option<DateTime<Utc>>,
+
+    /// The variant's prices in each of the shop's presentment currencies.
+    /// Read-only field.
+    #[serde(skip_serializing)]
+    pub presentment_prices: Option<Vec<PresentmentPrice>>,
 }
 
 /// A product in a Shopify store.
@@ -139,6 +161,7 @@ pub struct ProductVariant {
 /// - `status` - Whether the product is active, archived, or draft
 /// - `tags` - A comma-separated list of tags
 /// - `template_suffix` - The suffix of the template used for this product
+/// - `metafields` - Metafields to create along with the product
 ///
 /// ## Read-Only Fields
 /// - `id` - The unique identifier
@@ -231,6 +254,24 @@ pub struct Product {
     /// The main/featured image of the product.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub image: Option<ProductImage>,
+
+    /// Metafields to create along with the product.
+    ///
+    /// Sent in the create body so the product and its metafields are
+    /// created in one request. Shopify doesn't return them in product
+    /// responses; fetch them with [`Metafield::all`](crate::rest::RestResource::all).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metafields: Option<Vec<MetafieldInput>>,
+
+    /// Fields returned by the API that this struct doesn't model.
+    ///
+    /// Only present with the `capture-unknown-fields` feature. Captured
+    /// fields are sent back when the product is saved, so values from newer
+    /// API versions survive a round trip. Read one with
+    /// [`extra_field`](Self::extra_field).
+    #[cfg(feature = "capture-unknown-fields")]
+    #[serde(flatten, default, skip_serializing_if = "HashMap::is_empty")]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 impl RestResource for Product {
@@ -274,6 +315,101 @@ impl RestResource for Product {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
+}
+
+impl Product {
+    /// Returns an unmodeled field captured from the API response.
+    ///
+    /// Only available with the `capture-unknown-fields` feature.
+    #[cfg(feature = "capture-unknown-fields")]
+    #[must_use]
+    pub fn extra_field(&self, name: &str) -> Option<&serde_json::Value> {
+        self.extra.get(name)
+    }
+
+    /// Returns the product's tags, trimmed and without duplicates.
+    #[must_use]
+    pub fn tag_list(&self) -> Vec<String> {
+        tags::tag_list(self.tags.as_deref())
+    }
+
+    /// Returns `true` if the product has `tag`, ignoring case.
+    #[must_use]
+    pub fn has_tag(&self, tag: &str) -> bool {
+        tags::has_tag(self.tags.as_deref(), tag)
+    }
+
+    /// Adds `tag` unless the product already has it.
+    ///
+    /// Call [`save`](RestResource::save) to apply the change.
+    pub fn add_tag(&mut self, tag: &str) {
+        tags::add_tag(&mut self.tags, tag);
+    }
+
+    /// Removes `tag` if the product has it.
+    ///
+    /// Removing the last tag sets `tags` to an empty string, so saving
+    /// clears the tags on Shopify too.
+    pub fn remove_tag(&mut self, tag: &str) {
+        tags::remove_tag(&mut self.tags, tag);
+    }
+
+    /// Finds a product by its handle.
+    ///
+    /// Sends a GET request to `/admin/api/{version}/products.json?handle={handle}`
+    /// and returns the single matching product. Handles are unique within a
+    /// shop, so at most one product matches.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The REST client to use for the request
+    /// * `handle` - The product handle (e.g. `"example-t-shirt"`)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResourceError::NotFound`] if no product has the handle.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let product = Product::find_by_handle(&client, "example-t-shirt").await?;
+    /// println!("Product ID: {:?}", product.id);
+    /// ```
+    pub async fn find_by_handle(
+        client: &RestClient,
+        handle: &str,
+    ) -> Result<ResourceResponse<Self>, ResourceError> {
+        let response = Self::all(client, Some(handle_params(handle))).await?;
+        single_match(response, handle)
+    }
+}
+
+/// Builds the list parameters that filter products by handle.
+fn handle_params(handle: &str) -> ProductListParams {
+    ProductListParams {
+        handle: Some(handle.to_string()),
+        limit: Some(1),
+        ..Default::default()
+    }
+}
+
+/// Unwraps the only product of a handle-filtered list response.
+fn single_match(
+    response: ResourceResponse<Vec<Product>>,
+    handle: &str,
+) -> Result<ResourceResponse<Product>, ResourceError> {
+    let product = response
+        .first()
+        .cloned()
+        .ok_or_else(|| ResourceError::NotFound {
+            resource: Product::NAME,
+            id: handle.to_string(),
+        })?;
+    Ok(response.map(|_| product))
 }
 
 /// Parameters for finding a single product.
@@ -285,6 +421,9 @@ pub struct ProductFindParams {
 }
 
 /// Parameters for listing products.
+///
+/// Time filters are sent as RFC 3339 timestamps. For incremental syncs, set
+/// `updated_at_min` to the time of the previous sync.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
 pub struct ProductListParams {
     /// Return only products with the given IDs.
@@ -352,6 +491,10 @@ pub struct ProductListParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<ProductStatus>,
 
+    /// Sort order of the results, e.g. `created_at desc`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<SortOrder>,
+
     /// Comma-separated list of fields to include in the response.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fields: Option<String>,
@@ -453,6 +596,9 @@ mod tests {
             }]),
             images: Some(vec![]),
             image: None,
+            metafields: None,
+            #[cfg(feature = "capture-unknown-fields")]
+            extra: HashMap::new(),
         };
 
         let json = serde_json::to_string(&product).unwrap();
@@ -476,6 +622,50 @@ mod tests {
         assert!(parsed.get("admin_graphql_api_id").is_none());
     }
 
+    #[tokio::test]
+    async fn test_product_create_body_embeds_metafields() {
+        use crate::config::ApiVersion;
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/admin/api/2026-04/products.json"))
+            .and(body_json(serde_json::json!({
+                "product": {
+                    "title": "Burton Custom Freestyle 151",
+                    "metafields": [{
+                        "namespace": "custom",
+                        "key": "care_guide",
+                        "type": "single_line_text_field",
+                        "value": "Machine wash cold"
+                    }]
+                }
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "product": { "id": 1071559582, "title": "Burton Custom Freestyle 151" }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = RestClient::for_mock_server(&server.uri(), ApiVersion::V2026_04);
+
+        let product = Product {
+            title: Some("Burton Custom Freestyle 151".to_string()),
+            metafields: Some(vec![MetafieldInput::new(
+                "custom",
+                "care_guide",
+                "single_line_text_field",
+                "Machine wash cold",
+            )]),
+            ..Default::default()
+        };
+
+        let saved = product.save(&client).await.unwrap();
+
+        assert_eq!(saved.id, Some(1071559582));
+    }
+
     #[test]
     fn test_product_deserialization_from_api_response() {
         let json = r#"{
@@ -705,6 +895,7 @@ mod tests {
                     .unwrap()
                     .with_timezone(&Utc),
             ),
+            presentment_prices: Some(vec![PresentmentPrice::default()]),
         };
 
         // Test serialization - read-only fields should be skipped
@@ -727,6 +918,34 @@ mod tests {
         assert!(parsed.get("inventory_quantity").is_none());
         assert!(parsed.get("created_at").is_none());
         assert!(parsed.get("updated_at").is_none());
+        assert!(parsed.get("presentment_prices").is_none());
+    }
+
+    #[test]
+    fn test_product_variant_presentment_prices_deserialization() {
+        let json = r#"{
+            "id": 111222333,
+            "price": "19.99",
+            "presentment_prices": [
+                {
+                    "price": { "amount": "19.99", "currency_code": "USD" },
+                    "compare_at_price": null
+                },
+                {
+                    "price": { "amount": "25.99", "currency_code": "CAD" },
+                    "compare_at_price": { "amount": "32.99", "currency_code": "CAD" }
+                }
+            ]
+        }"#;
+
+        let variant: ProductVariant = serde_json::from_str(json).unwrap();
+        let prices = variant.presentment_prices.unwrap();
+
+        assert_eq!(prices.len(), 2);
+        assert!(prices[0].compare_at_price.is_none());
+        let cad = prices[1].price.as_ref().unwrap();
+        assert_eq!(cad.amount.as_deref(), Some("25.99"));
+        assert_eq!(cad.currency_code.as_deref(), Some("CAD"));
     }
 
     #[test]
@@ -746,4 +965,37 @@ mod tests {
         assert_eq!(json["collection_id"], 123456);
         assert_eq!(json["published_status"], "published");
     }
+
+    #[test]
+    fn test_find_by_handle_queries_with_handle_filter() {
+        let params = serde_json::to_value(handle_params("example-t-shirt")).unwrap();
+
+        assert_eq!(params["handle"], "example-t-shirt");
+        assert_eq!(params["limit"], 1);
+    }
+
+    #[test]
+    fn test_find_by_handle_unwraps_single_result() {
+        let product = Product {
+            id: Some(632910392),
+            handle: Some("example-t-shirt".to_string()),
+            ..Default::default()
+        };
+        let response = ResourceResponse::new(vec![product], None, None, Some("req-1".to_string()));
+
+        let found = single_match(response, "example-t-shirt").unwrap();
+
+        assert_eq!(found.id, Some(632910392));
+        assert_eq!(found.request_id(), Some("req-1"));
+    }
+
+    #[test]
+    fn test_find_by_handle_without_match_is_not_found() {
+        let response = ResourceResponse::new(Vec::<Product>::new(), None, None, None);
+
+        assert!(matches!(
+            single_match(response, "missing-handle"),
+            Err(ResourceError::NotFound { resource: "Product", id }) if id == "missing-handle"
+        ));
+    }
 }
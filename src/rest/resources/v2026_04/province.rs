@@ -41,6 +41,7 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 
 use crate::clients::RestClient;
+use crate::config::ShopDomain;
 use crate::rest::{
     build_path, get_path, ResourceError, ResourceOperation, ResourcePath, ResourceResponse,
     RestResource,
@@ -262,6 +263,11 @@ impl RestResource for Province {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    /// Provinces have no page of their own in the Shopify admin.
+    fn admin_url(&self, _shop: &ShopDomain) -> Option<String> {
+        None
+    }
 }
 
 /// Parameters for finding a single province.
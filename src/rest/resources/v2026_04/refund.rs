@@ -52,9 +52,12 @@
 //!     order_id: Some(450789469),
 //!     note: Some("Customer requested refund".to_string()),
 //!     notify: Some(true),
-//!     refund_line_items: Some(serde_json::json!([
-//!         {"line_item_id": 669751112, "quantity": 1, "restock_type": "return"}
-//!     ])),
+//!     refund_line_items: Some(vec![RefundLineItem {
+//!         line_item_id: Some(669751112),
+//!         quantity: Some(1),
+//!         restock_type: Some("return".to_string()),
+//!         ..Default::default()
+//!     }]),
 //!     ..Default::default()
 //! };
 //! let saved = refund.save(&client).await?;
@@ -64,13 +67,16 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::clients::RestClient;
+use crate::config::ShopDomain;
 use crate::rest::{ResourceError, ResourceOperation, ResourcePath, RestResource};
 use crate::HttpMethod;
 
+use super::Transaction;
+
 /// A refund line item in a refund.
 ///
 /// Represents a line item that is being refunded.
-#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
 pub struct RefundLineItem {
     /// The unique identifier of the refund line item.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -116,7 +122,7 @@ pub struct RefundLineItem {
 /// An order adjustment from a refund.
 ///
 /// Represents adjustments made to the order totals due to a refund.
-#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
 pub struct OrderAdjustment {
     /// The unique identifier of the adjustment.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -156,7 +162,7 @@ pub struct OrderAdjustment {
 }
 
 /// Shipping refund information.
-#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
 pub struct RefundShipping {
     /// Whether to fully refund shipping.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -182,7 +188,7 @@ pub struct RefundLineItemInput {
 }
 
 /// A refund shipping line.
-#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
 pub struct RefundShippingLine {
     /// The ID of the shipping line being refunded.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -279,7 +285,7 @@ pub struct RefundResource {
 
     /// Line items included in the refund.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub refund_line_items: Option<serde_json::Value>,
+    pub refund_line_items: Option<Vec<RefundLineItem>>,
 
     /// Refund shipping lines.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -287,7 +293,7 @@ pub struct RefundResource {
 
     /// Transactions for the refund.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transactions: Option<serde_json::Value>,
+    pub transactions: Option<Vec<Transaction>>,
 
     /// Order adjustments from the refund.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -466,6 +472,11 @@ impl RestResource for RefundResource {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    /// Refunds have no page of their own in the Shopify admin.
+    fn admin_url(&self, _shop: &ShopDomain) -> Option<String> {
+        None
+    }
 }
 
 /// Parameters for finding a single refund.
@@ -507,6 +518,7 @@ pub struct RefundCountParams {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::rest::resources::v2026_04::TransactionKind;
     use crate::rest::{get_path, ResourceOperation};
 
     #[test]
@@ -685,8 +697,12 @@ mod tests {
         assert!(refund.created_at.is_some());
 
         // Check nested structures
-        assert!(refund.refund_line_items.is_some());
-        assert!(refund.transactions.is_some());
+        let line_items = refund.refund_line_items.unwrap();
+        assert_eq!(line_items[0].line_item_id, Some(669751112));
+        assert_eq!(line_items[0].restock_type.as_deref(), Some("return"));
+        let transactions = refund.transactions.unwrap();
+        assert_eq!(transactions[0].kind, Some(TransactionKind::Refund));
+        assert_eq!(transactions[0].amount.as_deref(), Some("214.99"));
 
         // Check order adjustments
         assert!(refund.order_adjustments.is_some());
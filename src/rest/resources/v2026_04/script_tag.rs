@@ -41,6 +41,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::config::ShopDomain;
 use crate::rest::{ResourceOperation, ResourcePath, RestResource};
 use crate::HttpMethod;
 
@@ -175,9 +176,22 @@ impl RestResource for ScriptTag {
         ),
     ];
 
+    /// Script tags are deprecated in favor of theme app extensions.
+    const DEPRECATION: Option<&'static str> =
+        Some("ScriptTag is deprecated; use theme app extensions and app blocks instead");
+
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
+
+    /// Script tags have no page of their own in the Shopify admin.
+    fn admin_url(&self, _shop: &ShopDomain) -> Option<String> {
+        None
+    }
 }
 
 /// Parameters for finding a single script tag.
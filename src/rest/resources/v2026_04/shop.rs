@@ -66,6 +66,10 @@ pub struct Shop {
     #[serde(skip_serializing)]
     pub email: Option<String>,
 
+    /// The email address the shop uses to communicate with customers.
+    #[serde(skip_serializing)]
+    pub customer_email: Option<String>,
+
     /// The shop's custom domain (e.g., "www.example.com").
     #[serde(skip_serializing)]
     pub domain: Option<String>,
@@ -175,6 +179,14 @@ pub struct Shop {
     #[serde(skip_serializing)]
     pub tax_shipping: Option<bool>,
 
+    /// Whether the shop applies county taxes.
+    #[serde(skip_serializing)]
+    pub county_taxes: Option<bool>,
+
+    /// Whether tax inclusivity is adjusted automatically per market.
+    #[serde(skip_serializing)]
+    pub auto_configure_tax_inclusivity: Option<bool>,
+
     /// Whether transactional SMS is disabled.
     #[serde(skip_serializing)]
     pub transactional_sms_disabled: Option<bool>,
@@ -195,6 +207,10 @@ pub struct Shop {
     #[serde(skip_serializing)]
     pub eligible_for_payments: Option<bool>,
 
+    /// Whether the shop can receive payouts through Shopify Payments.
+    #[serde(skip_serializing)]
+    pub finances: Option<bool>,
+
     /// Whether the shop requires extra payments agreement.
     #[serde(skip_serializing)]
     pub requires_extra_payments_agreement: Option<bool>,
@@ -211,6 +227,18 @@ pub struct Shop {
     #[serde(skip_serializing)]
     pub cookie_consent_level: Option<String>,
 
+    /// Whether customers can opt in to SMS marketing at checkout.
+    #[serde(skip_serializing)]
+    pub marketing_sms_consent_enabled_at_checkout: Option<bool>,
+
+    /// The Google Workspace domain linked to the shop, if any.
+    #[serde(skip_serializing)]
+    pub google_apps_domain: Option<String>,
+
+    /// Whether staff can log in with Google Workspace.
+    #[serde(skip_serializing)]
+    pub google_apps_login_enabled: Option<bool>,
+
     // --- Shop details ---
     /// The shop owner's name.
     #[serde(skip_serializing)]
@@ -341,6 +369,84 @@ impl Shop {
 
         Ok(shop)
     }
+
+    /// Returns the shop's numeric ID, if it was returned.
+    ///
+    /// [`get_id`](RestResource::get_id) always returns `None` for the shop,
+    /// as it is fetched without an ID.
+    #[must_use]
+    pub const fn id(&self) -> Option<u64> {
+        self.id
+    }
+
+    /// Returns `true` if the shop is on Shopify Plus.
+    ///
+    /// Checks [`plan_name`](Self::plan_name) for `shopify_plus` and
+    /// `plus_partner_sandbox` (Plus sandbox stores created by partners).
+    /// Returns `false` if the plan name was not returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let shop = Shop::current(&client).await?;
+    /// if shop.is_plus() {
+    ///     // Checkout extensibility and B2B features are available
+    /// }
+    /// ```
+    #[must_use]
+    pub fn is_plus(&self) -> bool {
+        matches!(
+            self.plan_name.as_deref(),
+            Some("shopify_plus" | "plus_partner_sandbox")
+        )
+    }
+
+    /// Returns `true` if the shop can stock inventory at more than one location.
+    ///
+    /// Returns `false` if the flag was not returned.
+    #[must_use]
+    pub const fn multi_location_enabled(&self) -> bool {
+        matches!(self.multi_location_enabled, Some(true))
+    }
+}
+
+/// Returns the numeric ID of the client's store.
+///
+/// Fetches [`Shop::current`] the first time and caches the ID on the
+/// client, so later calls with the same client don't send a request. The
+/// client also picks up the ID from the `X-Sorting-Hat-ShopId` header of any
+/// earlier response, in which case no request is sent at all.
+///
+/// # Errors
+///
+/// Returns [`ResourceError::Http`] if the request fails or the response
+/// has no shop ID.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use shopify_sdk::rest::resources::v2026_04::resolve_shop_id;
+///
+/// let shop_id = resolve_shop_id(&client).await?;
+/// assert_eq!(client.shop_id(), Some(shop_id));
+/// ```
+pub async fn resolve_shop_id(client: &RestClient) -> Result<u64, ResourceError> {
+    if let Some(id) = client.shop_id() {
+        return Ok(id);
+    }
+
+    let shop = Shop::current(client).await?;
+    let id = shop.id().ok_or_else(|| {
+        ResourceError::Http(crate::clients::HttpError::Response(
+            crate::clients::HttpResponseError {
+                code: 200,
+                message: "Missing 'id' in shop".to_string(),
+                error_reference: None,
+            },
+        ))
+    })?;
+    client.set_shop_id(id);
+    Ok(id)
 }
 
 #[cfg(test)]
@@ -492,6 +598,7 @@ mod tests {
             "id": 548380009,
             "name": "Complete Test Store",
             "email": "complete@example.com",
+            "customer_email": "support@example.com",
             "domain": "complete.example.com",
             "myshopify_domain": "complete-test.myshopify.com",
             "plan_name": "unlimited",
@@ -518,15 +625,21 @@ mod tests {
             "multi_location_enabled": true,
             "taxes_included": false,
             "tax_shipping": true,
+            "county_taxes": true,
+            "auto_configure_tax_inclusivity": null,
             "transactional_sms_disabled": false,
             "has_storefront_api": true,
             "has_discounts": true,
             "has_gift_cards": true,
             "eligible_for_payments": true,
+            "finances": true,
             "requires_extra_payments_agreement": false,
             "setup_required": false,
             "pre_launch_enabled": false,
             "cookie_consent_level": "implicit",
+            "marketing_sms_consent_enabled_at_checkout": false,
+            "google_apps_domain": null,
+            "google_apps_login_enabled": null,
             "shop_owner": "John Smith",
             "source": null,
             "weight_unit": "lb",
@@ -544,6 +657,7 @@ mod tests {
         assert_eq!(shop.id, Some(548380009));
         assert_eq!(shop.name.as_deref(), Some("Complete Test Store"));
         assert_eq!(shop.email.as_deref(), Some("complete@example.com"));
+        assert_eq!(shop.customer_email.as_deref(), Some("support@example.com"));
         assert_eq!(shop.domain.as_deref(), Some("complete.example.com"));
         assert_eq!(
             shop.myshopify_domain.as_deref(),
@@ -595,6 +709,12 @@ mod tests {
         assert_eq!(shop.eligible_for_payments, Some(true));
         assert_eq!(shop.setup_required, Some(false));
         assert_eq!(shop.pre_launch_enabled, Some(false));
+        assert_eq!(shop.county_taxes, Some(true));
+        assert_eq!(shop.auto_configure_tax_inclusivity, None);
+        assert_eq!(shop.finances, Some(true));
+        assert_eq!(shop.marketing_sms_consent_enabled_at_checkout, Some(false));
+        assert_eq!(shop.google_apps_domain, None);
+        assert_eq!(shop.google_apps_login_enabled, None);
 
         // Details
         assert_eq!(shop.shop_owner.as_deref(), Some("John Smith"));
@@ -620,6 +740,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_shop_is_plus_checks_plan_name() {
+        let plan = |name: &str| Shop {
+            plan_name: Some(name.to_string()),
+            ..Default::default()
+        };
+
+        assert!(plan("shopify_plus").is_plus());
+        assert!(plan("plus_partner_sandbox").is_plus());
+        assert!(!plan("unlimited").is_plus());
+        assert!(!plan("basic").is_plus());
+        assert!(!Shop::default().is_plus());
+    }
+
+    #[test]
+    fn test_shop_multi_location_enabled_defaults_to_false() {
+        let shop: Shop = serde_json::from_str(r#"{"multi_location_enabled": true}"#).unwrap();
+        assert!(shop.multi_location_enabled());
+
+        let shop: Shop = serde_json::from_str(r#"{"multi_location_enabled": false}"#).unwrap();
+        assert!(!shop.multi_location_enabled());
+
+        assert!(!Shop::default().multi_location_enabled());
+    }
+
     #[test]
     fn test_shop_get_id_returns_none_for_singleton() {
         let shop = Shop {
@@ -639,4 +784,18 @@ mod tests {
         assert_eq!(Shop::PLURAL, "shop");
         assert_eq!(Shop::PATHS.len(), 1);
     }
+
+    #[test]
+    fn test_shop_id_is_exposed_from_deserialized_shop() {
+        let shop: Shop = serde_json::from_value(serde_json::json!({
+            "id": 548380009,
+            "name": "John Smith Test Store",
+            "myshopify_domain": "john-smith-test-store.myshopify.com"
+        }))
+        .unwrap();
+
+        assert_eq!(shop.id(), Some(548380009));
+        assert_eq!(shop.get_id(), None);
+        assert_eq!(Shop::default().id(), None);
+    }
 }
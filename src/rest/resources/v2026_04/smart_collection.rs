@@ -45,6 +45,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::clients::RestClient;
+use crate::config::ShopDomain;
 use crate::rest::{ResourceError, ResourceOperation, ResourcePath, RestResource};
 use crate::HttpMethod;
 
@@ -247,12 +248,27 @@ impl RestResource for SmartCollection {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
+
+    /// Smart collections open on the admin's collections page.
+    fn admin_url(&self, shop: &ShopDomain) -> Option<String> {
+        self.id.map(|id| {
+            format!(
+                "https://admin.shopify.com/store/{}/collections/{id}",
+                shop.shop_name()
+            )
+        })
+    }
 }
 
 impl SmartCollection {
     /// Manually reorders products in the smart collection.
     ///
-    /// This method is only applicable when `sort_order` is set to "manual".
+    /// This method is only applicable when `sort_order` is set to "manual";
+    /// Shopify silently ignores reorders of collections sorted any other way.
     /// The products are reordered according to their position in the `product_ids` array.
     ///
     /// # Arguments
@@ -262,6 +278,8 @@ impl SmartCollection {
     ///
     /// # Errors
     ///
+    /// Returns [`ResourceError::InvalidInput`] if `sort_order` is set to
+    /// anything other than "manual". No request is sent in that case.
     /// Returns [`ResourceError::PathResolutionFailed`] if the collection has no ID.
     /// Returns [`ResourceError::Http`] if the API request fails.
     ///
@@ -288,6 +306,7 @@ impl SmartCollection {
             resource: Self::NAME,
             operation: "order",
         })?;
+        self.require_manual_sort_order()?;
 
         // Build the query parameters with products[] array
         let mut query: HashMap<String, String> = HashMap::new();
@@ -315,6 +334,24 @@ impl SmartCollection {
 
         Ok(())
     }
+
+    /// Returns an invalid input error if the collection is known not to be
+    /// sorted manually.
+    ///
+    /// A collection without `sort_order` (for example, one built locally
+    /// from just an ID) is let through, leaving the check to Shopify.
+    fn require_manual_sort_order(&self) -> Result<(), ResourceError> {
+        match self.sort_order.as_deref() {
+            None | Some("manual") => Ok(()),
+            Some(sort_order) => Err(ResourceError::InvalidInput {
+                resource: Self::NAME,
+                operation: "order",
+                message: format!(
+                    "sort_order must be \"manual\" to reorder products, but is \"{sort_order}\""
+                ),
+            }),
+        }
+    }
 }
 
 /// Parameters for finding a single smart collection.
@@ -645,6 +682,33 @@ mod tests {
         // The actual HTTP call would require a mock client.
     }
 
+    #[test]
+    fn test_require_manual_sort_order() {
+        let collection = SmartCollection {
+            id: Some(123),
+            sort_order: Some("best-selling".to_string()),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            collection.require_manual_sort_order(),
+            Err(ResourceError::InvalidInput {
+                resource: "SmartCollection",
+                operation: "order",
+                ref message,
+            }) if message.contains("best-selling")
+        ));
+
+        let manual = SmartCollection {
+            sort_order: Some("manual".to_string()),
+            ..collection
+        };
+        assert!(manual.require_manual_sort_order().is_ok());
+        assert!(SmartCollection::default()
+            .require_manual_sort_order()
+            .is_ok());
+    }
+
     #[test]
     fn test_disjunctive_field_logic() {
         // Test disjunctive = true (OR logic)
@@ -737,4 +801,37 @@ mod tests {
         assert_eq!(image["width"], 1200);
         assert_eq!(image["height"], 400);
     }
+
+    #[tokio::test]
+    async fn test_order_sends_nothing_unless_sorted_manually() {
+        use crate::config::ApiVersion;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/admin/api/2026-04/smart_collections/123/order.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .mount(&server)
+            .await;
+        let client = RestClient::for_mock_server(&server.uri(), ApiVersion::V2026_04);
+
+        let best_selling = SmartCollection {
+            id: Some(123),
+            sort_order: Some("best-selling".to_string()),
+            ..Default::default()
+        };
+        let result = best_selling.order(&client, vec![111, 222]).await;
+
+        assert!(matches!(result, Err(ResourceError::InvalidInput { .. })));
+        assert!(server.received_requests().await.unwrap().is_empty());
+
+        let manual = SmartCollection {
+            sort_order: Some("manual".to_string()),
+            ..best_selling
+        };
+        manual.order(&client, vec![111, 222]).await.unwrap();
+
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+    }
 }
@@ -12,6 +12,7 @@
 //! - Count: `/orders/{order_id}/transactions/count`
 //!
 //! Use `Transaction::all_with_parent()` to list transactions under a specific order.
+//! Use `Transaction::create()` to capture, void, or refund a payment.
 //!
 //! # Note
 //!
@@ -30,14 +31,16 @@
 //!     println!("Transaction: {} - {:?}", txn.amount.as_deref().unwrap_or("0"), txn.kind);
 //! }
 //!
-//! // Create a capture transaction
-//! let mut transaction = Transaction {
-//!     order_id: Some(450789469),
-//!     kind: Some(TransactionKind::Capture),
-//!     amount: Some("199.99".to_string()),
-//!     ..Default::default()
-//! };
-//! let saved = transaction.save(&client).await?;
+//! // Capture an authorization
+//! let capture = Transaction::create(
+//!     &client,
+//!     450789469,
+//!     TransactionKind::Capture,
+//!     Some("199.99"),
+//!     Some(389404469),
+//!     None,
+//! )
+//! .await?;
 //!
 //! // Count transactions for an order
 //! let count = Transaction::count_with_parent(&client, "order_id", 450789469, None).await?;
@@ -50,8 +53,10 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::clients::RestClient;
+use crate::config::ShopDomain;
 use crate::rest::{
-    build_path, get_path, ResourceError, ResourceOperation, ResourcePath, RestResource,
+    build_path, get_path, ResourceError, ResourceOperation, ResourcePath, ResourceResponse,
+    RestResource,
 };
 use crate::HttpMethod;
 
@@ -138,7 +143,7 @@ pub struct PaymentDetails {
 }
 
 /// Currency exchange adjustment for multi-currency transactions.
-#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
 pub struct CurrencyExchangeAdjustment {
     /// The ID of the adjustment.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -189,7 +194,7 @@ pub struct CurrencyExchangeAdjustment {
 /// - `currency` - The currency code
 /// - `gateway` - The payment gateway used
 /// - `parent_id` - The ID of the parent transaction (for captures/refunds)
-#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
 pub struct Transaction {
     /// The unique identifier of the transaction.
     /// Read-only field.
@@ -309,6 +314,69 @@ pub struct Transaction {
 }
 
 impl Transaction {
+    /// Creates a capture, void, or refund transaction on an order.
+    ///
+    /// Sends a POST request to `/admin/api/{version}/orders/{order_id}/transactions.json`.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The REST client to use for the request
+    /// * `order_id` - The order the transaction belongs to
+    /// * `kind` - [`TransactionKind::Capture`], [`TransactionKind::Void`], or
+    ///   [`TransactionKind::Refund`]
+    /// * `amount` - The amount to capture or refund. Omit to capture the full
+    ///   authorized amount; voids have no amount.
+    /// * `parent_id` - The transaction being captured, voided, or refunded.
+    ///   Required for captures and voids.
+    /// * `gateway` - The payment gateway, if it differs from the parent's
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResourceError::ValidationFailed`] without sending a request if
+    /// `kind` is not a capture, void, or refund, or if a capture or void has no
+    /// `parent_id`.
+    /// Returns [`ResourceError::ValidationFailed`] if Shopify rejects the
+    /// transaction (for example, an expired authorization).
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let void = Transaction::create(
+    ///     &client,
+    ///     450789469,
+    ///     TransactionKind::Void,
+    ///     None,
+    ///     Some(389404469),
+    ///     None,
+    /// )
+    /// .await?;
+    /// ```
+    pub async fn create(
+        client: &RestClient,
+        order_id: u64,
+        kind: TransactionKind,
+        amount: Option<&str>,
+        parent_id: Option<u64>,
+        gateway: Option<&str>,
+    ) -> Result<Self, ResourceError> {
+        let body = create_body(kind, amount, parent_id, gateway)?;
+        let url = format!("orders/{order_id}/transactions");
+        let response = client.post(&url, body, None).await?;
+
+        if !response.is_ok() {
+            return Err(ResourceError::from_http_response(
+                response.code,
+                &response.body,
+                Self::NAME,
+                None,
+                response.request_id(),
+            ));
+        }
+
+        let key = Self::resource_key();
+        ResourceResponse::from_http_response(response, &key).map(ResourceResponse::into_inner)
+    }
+
     /// Counts transactions under a specific order.
     ///
     /// # Arguments
@@ -462,6 +530,11 @@ impl RestResource for Transaction {
     fn get_id(&self) -> Option<Self::Id> {
         self.id
     }
+
+    /// Transactions have no page of their own in the Shopify admin.
+    fn admin_url(&self, _shop: &ShopDomain) -> Option<String> {
+        None
+    }
 }
 
 /// Parameters for finding a single transaction.
@@ -502,6 +575,42 @@ pub struct TransactionCountParams {
     // No specific count params for transactions
 }
 
+/// Builds the request body for [`Transaction::create`], validating the kind
+/// and parent transaction.
+fn create_body(
+    kind: TransactionKind,
+    amount: Option<&str>,
+    parent_id: Option<u64>,
+    gateway: Option<&str>,
+) -> Result<serde_json::Value, ResourceError> {
+    let invalid = |field: &str, message: &str| ResourceError::ValidationFailed {
+        errors: HashMap::from([(field.to_string(), vec![message.to_string()])]),
+        request_id: None,
+    };
+
+    match kind {
+        TransactionKind::Capture | TransactionKind::Void if parent_id.is_none() => {
+            return Err(invalid(
+                "parent_id",
+                "is required for capture and void transactions",
+            ));
+        }
+        TransactionKind::Capture | TransactionKind::Void | TransactionKind::Refund => {}
+        TransactionKind::Authorization | TransactionKind::Sale => {
+            return Err(invalid("kind", "must be capture, void, or refund"));
+        }
+    }
+
+    let transaction = Transaction {
+        kind: Some(kind),
+        amount: amount.map(String::from),
+        parent_id,
+        gateway: gateway.map(String::from),
+        ..Default::default()
+    };
+    Ok(serde_json::json!({ "transaction": transaction }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -807,4 +916,61 @@ mod tests {
         assert_eq!(Transaction::NAME, "Transaction");
         assert_eq!(Transaction::PLURAL, "transactions");
     }
+
+    #[test]
+    fn test_create_body_for_capture() {
+        let body = create_body(
+            TransactionKind::Capture,
+            Some("199.99"),
+            Some(389404469),
+            Some("bogus"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "transaction": {
+                    "kind": "capture",
+                    "amount": "199.99",
+                    "parent_id": 389404469,
+                    "gateway": "bogus"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_create_body_for_void_without_amount() {
+        let body = create_body(TransactionKind::Void, None, Some(389404469), None).unwrap();
+
+        assert_eq!(
+            body,
+            serde_json::json!({ "transaction": { "kind": "void", "parent_id": 389404469 } })
+        );
+    }
+
+    #[test]
+    fn test_create_body_requires_parent_id_for_capture_and_void() {
+        for kind in [TransactionKind::Capture, TransactionKind::Void] {
+            let result = create_body(kind, Some("10.00"), None, None);
+            assert!(matches!(
+                result,
+                Err(ResourceError::ValidationFailed { ref errors, .. }) if errors.contains_key("parent_id")
+            ));
+        }
+
+        // Refunds may omit the parent and let Shopify pick it
+        assert!(create_body(TransactionKind::Refund, Some("10.00"), None, None).is_ok());
+    }
+
+    #[test]
+    fn test_create_body_rejects_other_kinds() {
+        let result = create_body(TransactionKind::Sale, Some("10.00"), None, None);
+
+        assert!(matches!(
+            result,
+            Err(ResourceError::ValidationFailed { ref errors, .. }) if errors.contains_key("kind")
+        ));
+    }
 }
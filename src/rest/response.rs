@@ -56,7 +56,7 @@ use std::ops::{Deref, DerefMut};
 
 use serde::de::DeserializeOwned;
 
-use crate::clients::{ApiCallLimit, HttpResponse, PaginationInfo};
+use crate::clients::{ApiCallLimit, HttpResponse, Page, PaginationInfo};
 use crate::rest::ResourceError;
 
 /// A response from a REST resource operation.
@@ -285,6 +285,43 @@ impl<T> ResourceResponse<T> {
     }
 }
 
+impl<T> ResourceResponse<Vec<T>> {
+    /// Converts a list response into a transport-agnostic [`Page`].
+    ///
+    /// The next cursor is the `page_info` token for the next page.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::rest::ResourceResponse;
+    /// use shopify_sdk::clients::PaginationInfo;
+    ///
+    /// let response = ResourceResponse::new(
+    ///     vec![1, 2],
+    ///     Some(PaginationInfo {
+    ///         prev_page_info: None,
+    ///         next_page_info: Some("token".to_string()),
+    ///     }),
+    ///     None,
+    ///     None,
+    /// );
+    ///
+    /// let page = response.into_page();
+    /// assert_eq!(page.items, vec![1, 2]);
+    /// assert_eq!(page.next_cursor.as_deref(), Some("token"));
+    /// assert!(page.has_next);
+    /// ```
+    #[must_use]
+    pub fn into_page(self) -> Page<T> {
+        let next_cursor = self.pagination.and_then(|p| p.next_page_info);
+        Page {
+            has_next: next_cursor.is_some(),
+            items: self.data,
+            next_cursor,
+        }
+    }
+}
+
 impl<T: DeserializeOwned> ResourceResponse<T> {
     /// Creates a `ResourceResponse` from an HTTP response.
     ///
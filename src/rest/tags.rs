@@ -0,0 +1,108 @@
+//! Helpers for the comma-separated `tags` field.
+//!
+//! Orders, products and customers store their tags as a single string such
+//! as `"sale, summer"`. These helpers back the `tag_list`, `has_tag`,
+//! `add_tag` and `remove_tag` methods on those resources.
+//!
+//! Tags are trimmed, empty entries are dropped, and tags are compared
+//! ignoring ASCII case, as Shopify treats `"Sale"` and `"sale"` as the same
+//! tag.
+
+/// The separator written between tags.
+const SEPARATOR: &str = ", ";
+
+/// Splits a tags string into trimmed, non-empty, unique tags, keeping the
+/// first spelling of each.
+pub fn tag_list(tags: Option<&str>) -> Vec<String> {
+    let mut list: Vec<String> = Vec::new();
+    for tag in tags.unwrap_or_default().split(',').map(str::trim) {
+        if !tag.is_empty() && !contains(&list, tag) {
+            list.push(tag.to_string());
+        }
+    }
+    list
+}
+
+/// Returns `true` if the tags string contains `tag`.
+pub fn has_tag(tags: Option<&str>, tag: &str) -> bool {
+    contains(&tag_list(tags), tag.trim())
+}
+
+/// Appends `tag` unless it is blank or already present.
+///
+/// The tags are rewritten in normalized form only when a tag is added.
+pub fn add_tag(tags: &mut Option<String>, tag: &str) {
+    let tag = tag.trim();
+    let mut list = tag_list(tags.as_deref());
+    if tag.is_empty() || contains(&list, tag) {
+        return;
+    }
+    list.push(tag.to_string());
+    *tags = Some(list.join(SEPARATOR));
+}
+
+/// Removes `tag` if present.
+///
+/// Removing the last tag leaves an empty string rather than `None`, so the
+/// cleared field is still sent when the resource is saved.
+pub fn remove_tag(tags: &mut Option<String>, tag: &str) {
+    let tag = tag.trim();
+    let mut list = tag_list(tags.as_deref());
+    let before = list.len();
+    list.retain(|existing| !existing.eq_ignore_ascii_case(tag));
+    if list.len() != before {
+        *tags = Some(list.join(SEPARATOR));
+    }
+}
+
+/// Returns `true` if `list` contains `tag`, ignoring ASCII case.
+fn contains(list: &[String], tag: &str) -> bool {
+    list.iter()
+        .any(|existing| existing.eq_ignore_ascii_case(tag))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tag_list_normalizes_whitespace_and_duplicates() {
+        assert_eq!(
+            tag_list(Some(" sale,summer , ,Sale,  new arrival ")),
+            vec!["sale", "summer", "new arrival"]
+        );
+        assert!(tag_list(None).is_empty());
+        assert!(tag_list(Some("")).is_empty());
+    }
+
+    #[test]
+    fn test_add_tag_appends_new_tags_only() {
+        let mut tags = Some("sale,summer".to_string());
+
+        add_tag(&mut tags, "SALE");
+        add_tag(&mut tags, "  ");
+        assert_eq!(tags.as_deref(), Some("sale,summer"));
+
+        add_tag(&mut tags, " clearance ");
+        assert_eq!(tags.as_deref(), Some("sale, summer, clearance"));
+
+        let mut tags = None;
+        add_tag(&mut tags, "vip");
+        assert_eq!(tags.as_deref(), Some("vip"));
+    }
+
+    #[test]
+    fn test_remove_tag_removes_present_tags_only() {
+        let mut tags = Some("sale,summer".to_string());
+
+        remove_tag(&mut tags, "winter");
+        assert_eq!(tags.as_deref(), Some("sale,summer"));
+
+        remove_tag(&mut tags, "Summer");
+        assert_eq!(tags.as_deref(), Some("sale"));
+
+        remove_tag(&mut tags, "sale");
+        assert_eq!(tags.as_deref(), Some(""));
+        assert!(!has_tag(tags.as_deref(), "sale"));
+    }
+}
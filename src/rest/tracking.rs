@@ -52,12 +52,88 @@
 //! tracked.mark_clean();
 //! assert!(!tracked.is_dirty());
 //! ```
+//!
+//! # Clearing Fields
+//!
+//! Resource fields use `skip_serializing_if = "Option::is_none"`, so setting a
+//! field to `None` removes it from the serialized resource rather than
+//! sending `null`, and the partial update leaves the server value untouched.
+//! To clear a value on the server, either call
+//! [`TrackedResource::clear_field`], or switch the tracker to
+//! [`SerializationMode::ExplicitNull`] so any field set to `None` since
+//! loading is sent as `null`.
+//!
+//! ```rust
+//! use shopify_sdk::rest::{SerializationMode, TrackedResource};
+//! use serde::{Serialize, Deserialize};
+//! use serde_json::json;
+//!
+//! #[derive(Debug, Clone, Serialize, Deserialize)]
+//! struct Product {
+//!     id: u64,
+//!     #[serde(skip_serializing_if = "Option::is_none")]
+//!     vendor: Option<String>,
+//! }
+//!
+//! let product = Product { id: 123, vendor: Some("Acme".to_string()) };
+//!
+//! let mut tracked = TrackedResource::from_existing(product.clone());
+//! tracked.clear_field("vendor");
+//! assert_eq!(tracked.changed_fields(), json!({ "vendor": null }));
+//!
+//! let mut tracked = TrackedResource::from_existing(product)
+//!     .with_serialization_mode(SerializationMode::ExplicitNull);
+//! tracked.vendor = None;
+//! assert_eq!(tracked.changed_fields(), json!({ "vendor": null }));
+//! ```
+//!
+//! # Conditional Updates
+//!
+//! For REST resources, [`TrackedResource::save_if_unchanged`] re-fetches the
+//! resource before saving and refuses to send the update if the server's
+//! `updated_at` differs from the tracked copy, so concurrent edits are not
+//! silently overwritten.
+//!
+//! ```rust,ignore
+//! let product = Product::find(&client, 123, None).await?.into_inner();
+//! let mut tracked = TrackedResource::from_existing(product);
+//! tracked.title = Some("New Title".to_string());
+//!
+//! match tracked.save_if_unchanged(&client).await {
+//!     Ok(()) => println!("Saved"),
+//!     Err(ResourceError::Conflict { .. }) => println!("Someone else edited it first"),
+//!     Err(e) => return Err(e.into()),
+//! }
+//! ```
 
+use std::collections::{BTreeSet, HashMap};
 use std::ops::{Deref, DerefMut};
 
+use chrono::{DateTime, Utc};
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::Value;
 
+use crate::clients::RestClient;
+use crate::rest::{ResourceError, RestResource};
+
+/// How [`TrackedResource::changed_fields`] reports fields that were removed.
+///
+/// A field that serializes as absent (typically an `Option` set to `None`
+/// with `skip_serializing_if`) can either be left out of the partial body,
+/// keeping the server's value, or sent as `null`, clearing it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SerializationMode {
+    /// Removed fields are left out of the partial body (the default).
+    ///
+    /// Only fields cleared with [`TrackedResource::clear_field`] are sent
+    /// as `null`.
+    #[default]
+    OmitNull,
+    /// Fields present when the resource was loaded but no longer serialized
+    /// are sent as `null`.
+    ExplicitNull,
+}
+
 /// A wrapper that tracks changes to a resource.
 ///
 /// `TrackedResource<T>` stores both the current resource data and its
@@ -96,6 +172,10 @@ pub struct TrackedResource<T> {
     /// The original state captured when loaded or after save.
     /// `None` for new resources that haven't been saved yet.
     original_state: Option<Value>,
+    /// How removed fields are reported in `changed_fields()`.
+    mode: SerializationMode,
+    /// Fields explicitly cleared with `clear_field()` since the last save.
+    cleared_fields: BTreeSet<String>,
 }
 
 impl<T: Serialize + DeserializeOwned + Clone> TrackedResource<T> {
@@ -121,6 +201,8 @@ impl<T: Serialize + DeserializeOwned + Clone> TrackedResource<T> {
         Self {
             resource,
             original_state: None,
+            mode: SerializationMode::OmitNull,
+            cleared_fields: BTreeSet::new(),
         }
     }
 
@@ -147,9 +229,75 @@ impl<T: Serialize + DeserializeOwned + Clone> TrackedResource<T> {
         Self {
             resource,
             original_state,
+            mode: SerializationMode::OmitNull,
+            cleared_fields: BTreeSet::new(),
         }
     }
 
+    /// Sets how removed fields are reported in [`changed_fields`](Self::changed_fields).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::rest::{SerializationMode, TrackedResource};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(Debug, Clone, Serialize, Deserialize)]
+    /// struct Product {
+    ///     #[serde(skip_serializing_if = "Option::is_none")]
+    ///     vendor: Option<String>,
+    /// }
+    ///
+    /// let mut tracked = TrackedResource::from_existing(Product { vendor: Some("Acme".to_string()) })
+    ///     .with_serialization_mode(SerializationMode::ExplicitNull);
+    ///
+    /// tracked.vendor = None;
+    /// assert!(tracked.changed_fields()["vendor"].is_null());
+    /// ```
+    #[must_use]
+    pub const fn with_serialization_mode(mut self, mode: SerializationMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Returns how removed fields are reported in [`changed_fields`](Self::changed_fields).
+    #[must_use]
+    pub const fn serialization_mode(&self) -> SerializationMode {
+        self.mode
+    }
+
+    /// Clears a field, sending it as `null` in the next partial update.
+    ///
+    /// The field is sent as `null` regardless of the [`SerializationMode`]
+    /// until [`mark_clean`](Self::mark_clean) is called. The wrapped resource
+    /// itself is not modified; set the field to `None` as well if the local
+    /// copy should reflect the change before it is saved.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The serialized field name (e.g. `"body_html"`)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::rest::TrackedResource;
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(Debug, Clone, Serialize, Deserialize)]
+    /// struct Product {
+    ///     #[serde(skip_serializing_if = "Option::is_none")]
+    ///     vendor: Option<String>,
+    /// }
+    ///
+    /// let mut tracked = TrackedResource::from_existing(Product { vendor: Some("Acme".to_string()) });
+    /// tracked.clear_field("vendor");
+    ///
+    /// assert!(tracked.changed_fields()["vendor"].is_null());
+    /// ```
+    pub fn clear_field(&mut self, name: &str) {
+        self.cleared_fields.insert(name.to_string());
+    }
+
     /// Returns `true` if the resource has been modified since loading or last save.
     ///
     /// For new resources (no original state), always returns `true`.
@@ -172,6 +320,10 @@ impl<T: Serialize + DeserializeOwned + Clone> TrackedResource<T> {
     #[must_use]
     #[allow(clippy::option_if_let_else)]
     pub fn is_dirty(&self) -> bool {
+        if !self.cleared_fields.is_empty() {
+            return true;
+        }
+
         match &self.original_state {
             None => true, // New resource, always dirty
             Some(original) => {
@@ -189,6 +341,11 @@ impl<T: Serialize + DeserializeOwned + Clone> TrackedResource<T> {
     /// For existing resources, returns only the fields whose values differ
     /// from the original state. Nested objects are handled recursively.
     ///
+    /// Fields cleared with [`clear_field`](Self::clear_field) are included as
+    /// `null`. With [`SerializationMode::ExplicitNull`], so are top-level
+    /// fields that were present in the original state but are no longer
+    /// serialized.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -213,10 +370,24 @@ impl<T: Serialize + DeserializeOwned + Clone> TrackedResource<T> {
     pub fn changed_fields(&self) -> Value {
         let current = serde_json::to_value(&self.resource).unwrap_or(Value::Null);
 
-        match &self.original_state {
+        let mut changes = match &self.original_state {
             None => current, // New resource, return all fields
-            Some(original) => diff_json_objects(original, &current),
+            Some(original) => {
+                let mut diff = diff_json_objects(original, &current);
+                if self.mode == SerializationMode::ExplicitNull {
+                    add_removed_fields_as_null(&mut diff, original, &current);
+                }
+                diff
+            }
+        };
+
+        if let Value::Object(map) = &mut changes {
+            for name in &self.cleared_fields {
+                map.insert(name.clone(), Value::Null);
+            }
         }
+
+        changes
     }
 
     /// Marks the resource as clean by capturing the current state as original.
@@ -241,6 +412,7 @@ impl<T: Serialize + DeserializeOwned + Clone> TrackedResource<T> {
     /// ```
     pub fn mark_clean(&mut self) {
         self.original_state = serde_json::to_value(&self.resource).ok();
+        self.cleared_fields.clear();
     }
 
     /// Returns a reference to the inner resource.
@@ -272,6 +444,79 @@ impl<T: Serialize + DeserializeOwned + Clone> TrackedResource<T> {
     }
 }
 
+impl<T: RestResource> TrackedResource<T> {
+    /// Saves the changed fields only if the resource is unchanged on the server.
+    ///
+    /// Re-fetches the resource and compares its `updated_at` with the tracked
+    /// copy. If they match, the changed fields are sent as a partial update,
+    /// the resource is replaced with the server's response, and the tracked
+    /// state is marked clean. If they differ, nothing is sent.
+    ///
+    /// Resources that don't report `updated_at` (see
+    /// [`RestResource::updated_at`]) can't be checked and are rejected.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResourceError::Conflict`] if the resource was modified on the
+    /// server since it was fetched.
+    /// Returns [`ResourceError::ValidationFailed`] if the tracked resource has
+    /// no `updated_at` to compare against.
+    /// Returns [`ResourceError::PathResolutionFailed`] if the resource has no ID.
+    /// Returns any error from the re-fetch or the update request.
+    pub async fn save_if_unchanged(&mut self, client: &RestClient) -> Result<(), ResourceError> {
+        let id = self
+            .resource
+            .get_id()
+            .ok_or(ResourceError::PathResolutionFailed {
+                resource: T::NAME,
+                operation: "update",
+            })?;
+
+        let server = T::find(client, id.clone(), None).await?.into_inner();
+        check_unchanged::<T>(
+            &id.to_string(),
+            self.resource.updated_at(),
+            server.updated_at(),
+        )?;
+
+        let saved = self
+            .resource
+            .save_partial(client, self.changed_fields())
+            .await?;
+        self.resource = saved;
+        self.mark_clean();
+        Ok(())
+    }
+}
+
+/// Verifies that the server's `updated_at` matches the tracked copy's.
+fn check_unchanged<T: RestResource>(
+    id: &str,
+    tracked: Option<DateTime<Utc>>,
+    server: Option<DateTime<Utc>>,
+) -> Result<(), ResourceError> {
+    let Some(tracked) = tracked else {
+        return Err(ResourceError::ValidationFailed {
+            errors: HashMap::from([(
+                "updated_at".to_string(),
+                vec!["is required for a conditional update".to_string()],
+            )]),
+            request_id: None,
+        });
+    };
+
+    if server == Some(tracked) {
+        return Ok(());
+    }
+
+    Err(ResourceError::Conflict {
+        resource: T::NAME,
+        id: id.to_string(),
+        expected: tracked.to_rfc3339(),
+        actual: server.map_or_else(|| "none".to_string(), |s| s.to_rfc3339()),
+    })
+}
+
 /// Computes the difference between two JSON objects.
 ///
 /// Returns a JSON object containing only the fields from `current` that
@@ -323,6 +568,20 @@ fn diff_json_objects(original: &Value, current: &Value) -> Value {
     }
 }
 
+/// Adds a `null` entry to `diff` for each top-level field of `original` that
+/// is missing from `current`.
+fn add_removed_fields_as_null(diff: &mut Value, original: &Value, current: &Value) {
+    if let (Value::Object(diff_map), Value::Object(orig_map), Value::Object(curr_map)) =
+        (diff, original, current)
+    {
+        for key in orig_map.keys() {
+            if !curr_map.contains_key(key) {
+                diff_map.insert(key.clone(), Value::Null);
+            }
+        }
+    }
+}
+
 /// Provides transparent read access to the inner resource.
 impl<T> Deref for TrackedResource<T> {
     type Target = T;
@@ -350,6 +609,7 @@ const _: fn() = || {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::rest::resources::v2026_04::Product;
     use serde::{Deserialize, Serialize};
     use serde_json::json;
 
@@ -521,6 +781,47 @@ mod tests {
         assert!(changes.get("tags").is_some());
     }
 
+    #[test]
+    fn test_clear_field_serializes_as_explicit_null() {
+        let product = Product {
+            id: Some(123),
+            title: Some("Shirt".to_string()),
+            body_html: Some("<p>Old description</p>".to_string()),
+            ..Default::default()
+        };
+        let mut tracked = TrackedResource::from_existing(product);
+
+        tracked.clear_field("body_html");
+
+        assert!(tracked.is_dirty());
+        assert_eq!(tracked.id, Some(123));
+        assert_eq!(tracked.changed_fields(), json!({ "body_html": null }));
+
+        tracked.mark_clean();
+        assert!(!tracked.is_dirty());
+        assert_eq!(tracked.changed_fields(), json!({}));
+    }
+
+    #[test]
+    fn test_serialization_mode_controls_removed_fields() {
+        let product = Product {
+            id: Some(123),
+            vendor: Some("Acme".to_string()),
+            ..Default::default()
+        };
+
+        // Default: setting None omits the field, leaving the server value alone
+        let mut omit = TrackedResource::from_existing(product.clone());
+        assert_eq!(omit.serialization_mode(), SerializationMode::OmitNull);
+        omit.vendor = None;
+        assert_eq!(omit.changed_fields(), json!({}));
+
+        let mut explicit = TrackedResource::from_existing(product)
+            .with_serialization_mode(SerializationMode::ExplicitNull);
+        explicit.vendor = None;
+        assert_eq!(explicit.changed_fields(), json!({ "vendor": null }));
+    }
+
     #[test]
     fn test_deref_allows_field_access() {
         let product = TestProduct {
@@ -648,4 +949,149 @@ mod tests {
         assert!(changes.get("title").is_none()); // Was cleaned
         assert_eq!(changes.get("vendor"), Some(&json!("New Vendor")));
     }
+
+    #[test]
+    fn test_check_unchanged_accepts_matching_updated_at() {
+        let fetched_at = "2024-01-01T10:00:00Z".parse().ok();
+
+        assert!(check_unchanged::<Product>("1", fetched_at, fetched_at).is_ok());
+    }
+
+    #[test]
+    fn test_concurrent_modification_triggers_conflict() {
+        let product: Product = serde_json::from_value(json!({
+            "id": 632910392,
+            "title": "IPod Nano",
+            "updated_at": "2024-01-01T10:00:00Z"
+        }))
+        .unwrap();
+        let mut tracked = TrackedResource::from_existing(product.clone());
+        tracked.title = Some("IPod Nano - 8GB".to_string());
+
+        // Someone else saved the product after we fetched it
+        let mut server = product;
+        server.updated_at = "2024-01-01T10:05:00Z".parse().ok();
+
+        let result =
+            check_unchanged::<Product>("632910392", tracked.updated_at(), server.updated_at());
+
+        let Err(ResourceError::Conflict {
+            resource,
+            id,
+            expected,
+            actual,
+        }) = result
+        else {
+            panic!("expected Conflict, got {result:?}");
+        };
+        assert_eq!(resource, "Product");
+        assert_eq!(id, "632910392");
+        assert_eq!(expected, "2024-01-01T10:00:00+00:00");
+        assert_eq!(actual, "2024-01-01T10:05:00+00:00");
+    }
+
+    #[test]
+    fn test_check_unchanged_requires_tracked_updated_at() {
+        let result = check_unchanged::<Product>("1", None, "2024-01-01T10:00:00Z".parse().ok());
+
+        assert!(matches!(
+            result,
+            Err(ResourceError::ValidationFailed { ref errors, .. }) if errors.contains_key("updated_at")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_save_if_unchanged_rejects_server_side_change_without_saving() {
+        use crate::config::ApiVersion;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/admin/api/2026-04/products/632910392.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "product": {
+                    "id": 632910392,
+                    "title": "IPod Nano",
+                    "updated_at": "2024-01-01T10:05:00Z"
+                }
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+        let client = RestClient::for_mock_server(&server.uri(), ApiVersion::V2026_04);
+
+        let product: Product = serde_json::from_value(json!({
+            "id": 632910392,
+            "title": "IPod Nano",
+            "updated_at": "2024-01-01T10:00:00Z"
+        }))
+        .unwrap();
+        let mut tracked = TrackedResource::from_existing(product);
+        tracked.title = Some("IPod Nano - 8GB".to_string());
+
+        let result = tracked.save_if_unchanged(&client).await;
+
+        assert!(
+            matches!(result, Err(ResourceError::Conflict { ref id, .. }) if id == "632910392"),
+            "expected Conflict, got {result:?}"
+        );
+        assert!(tracked.is_dirty());
+    }
+
+    #[tokio::test]
+    async fn test_save_if_unchanged_sends_changed_fields_when_unchanged() {
+        use crate::config::ApiVersion;
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/admin/api/2026-04/products/632910392.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "product": {
+                    "id": 632910392,
+                    "title": "IPod Nano",
+                    "updated_at": "2024-01-01T10:00:00Z"
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/admin/api/2026-04/products/632910392.json"))
+            .and(body_json(json!({
+                "product": { "title": "IPod Nano - 8GB" }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "product": {
+                    "id": 632910392,
+                    "title": "IPod Nano - 8GB",
+                    "updated_at": "2024-01-01T10:10:00Z"
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = RestClient::for_mock_server(&server.uri(), ApiVersion::V2026_04);
+
+        let product: Product = serde_json::from_value(json!({
+            "id": 632910392,
+            "title": "IPod Nano",
+            "updated_at": "2024-01-01T10:00:00Z"
+        }))
+        .unwrap();
+        let mut tracked = TrackedResource::from_existing(product);
+        tracked.title = Some("IPod Nano - 8GB".to_string());
+
+        tracked.save_if_unchanged(&client).await.unwrap();
+
+        assert!(!tracked.is_dirty());
+        assert_eq!(tracked.title.as_deref(), Some("IPod Nano - 8GB"));
+        assert_eq!(tracked.updated_at(), "2024-01-01T10:10:00Z".parse().ok());
+    }
 }
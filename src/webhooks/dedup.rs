@@ -0,0 +1,227 @@
+//! Duplicate delivery detection for incoming webhooks.
+//!
+//! Shopify delivers webhooks at least once, so the same event can arrive more
+//! than once (for example, when a response times out and Shopify retries).
+//! Every delivery of an event carries the same `X-Shopify-Webhook-Id`, exposed
+//! as [`WebhookContext::webhook_id`](super::WebhookContext::webhook_id).
+//!
+//! A [`DedupStore`] registered with
+//! [`WebhookRegistry::set_dedup_store`](super::WebhookRegistry::set_dedup_store)
+//! records the IDs of handled webhooks. Later deliveries with a recorded ID
+//! are acknowledged without invoking the handler.
+//!
+//! [`InMemoryDedupStore`] keeps the most recently seen IDs in memory. It works
+//! for a single process; apps running several instances should implement
+//! [`DedupStore`] over a shared store such as Redis.
+//!
+//! # Example
+//!
+//! ```rust
+//! use shopify_sdk::webhooks::{InMemoryDedupStore, WebhookRegistry};
+//!
+//! let mut registry = WebhookRegistry::new();
+//! registry.set_dedup_store(InMemoryDedupStore::new(10_000));
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+
+use super::types::BoxFuture;
+
+/// Records which webhook deliveries have already been handled.
+///
+/// The registry calls [`contains`](Self::contains) before dispatching a
+/// webhook and [`insert`](Self::insert) after its handler succeeds, so a
+/// delivery whose handler failed is handled again when Shopify retries it.
+///
+/// Methods return boxed futures so implementations can be backed by
+/// asynchronous stores.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashSet;
+/// use std::sync::Mutex;
+/// use shopify_sdk::webhooks::{BoxFuture, DedupStore};
+///
+/// #[derive(Default)]
+/// struct UnboundedStore {
+///     seen: Mutex<HashSet<String>>,
+/// }
+///
+/// impl DedupStore for UnboundedStore {
+///     fn contains<'a>(&'a self, webhook_id: &'a str) -> BoxFuture<'a, bool> {
+///         Box::pin(async move { self.seen.lock().unwrap().contains(webhook_id) })
+///     }
+///
+///     fn insert<'a>(&'a self, webhook_id: &'a str) -> BoxFuture<'a, ()> {
+///         Box::pin(async move {
+///             self.seen.lock().unwrap().insert(webhook_id.to_string());
+///         })
+///     }
+/// }
+/// ```
+pub trait DedupStore: Send + Sync {
+    /// Returns `true` if a webhook with this ID has already been handled.
+    fn contains<'a>(&'a self, webhook_id: &'a str) -> BoxFuture<'a, bool>;
+
+    /// Records a webhook ID as handled.
+    fn insert<'a>(&'a self, webhook_id: &'a str) -> BoxFuture<'a, ()>;
+}
+
+/// An in-memory [`DedupStore`] that remembers a bounded number of webhook IDs.
+///
+/// When full, the least recently seen ID is evicted. Size the capacity to
+/// cover the deliveries received during Shopify's retry window.
+#[derive(Debug)]
+pub struct InMemoryDedupStore {
+    capacity: usize,
+    state: Mutex<LruState>,
+}
+
+/// Recency-ordered set of webhook IDs.
+#[derive(Debug, Default)]
+struct LruState {
+    /// Monotonic counter used to order entries by recency.
+    tick: u64,
+    /// Webhook ID to the tick it was last seen at.
+    ticks: HashMap<String, u64>,
+    /// Tick to webhook ID, oldest first.
+    order: BTreeMap<u64, String>,
+}
+
+impl LruState {
+    /// Marks `webhook_id` as most recently seen, returning whether it was present.
+    fn touch(&mut self, webhook_id: &str) -> bool {
+        let Some(old) = self.ticks.get(webhook_id).copied() else {
+            return false;
+        };
+        self.tick += 1;
+        self.order.remove(&old);
+        self.order.insert(self.tick, webhook_id.to_string());
+        self.ticks.insert(webhook_id.to_string(), self.tick);
+        true
+    }
+
+    /// Inserts `webhook_id`, evicting the oldest entries beyond `capacity`.
+    fn insert(&mut self, webhook_id: &str, capacity: usize) {
+        if self.touch(webhook_id) {
+            return;
+        }
+        self.tick += 1;
+        self.order.insert(self.tick, webhook_id.to_string());
+        self.ticks.insert(webhook_id.to_string(), self.tick);
+
+        while self.ticks.len() > capacity {
+            let Some((_, oldest)) = self.order.pop_first() else {
+                break;
+            };
+            self.ticks.remove(&oldest);
+        }
+    }
+}
+
+impl InMemoryDedupStore {
+    /// Creates a store that remembers up to `capacity` webhook IDs.
+    ///
+    /// A capacity of zero is treated as one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::webhooks::InMemoryDedupStore;
+    ///
+    /// let store = InMemoryDedupStore::new(10_000);
+    /// assert!(store.is_empty());
+    /// ```
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            state: Mutex::new(LruState::default()),
+        }
+    }
+
+    /// Returns the maximum number of IDs the store remembers.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of IDs currently remembered.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().ticks.len()
+    }
+
+    /// Returns `true` if no IDs are remembered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Locks the state, recovering it if a previous holder panicked.
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruState> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl DedupStore for InMemoryDedupStore {
+    fn contains<'a>(&'a self, webhook_id: &'a str) -> BoxFuture<'a, bool> {
+        let seen = self.lock().touch(webhook_id);
+        Box::pin(async move { seen })
+    }
+
+    fn insert<'a>(&'a self, webhook_id: &'a str) -> BoxFuture<'a, ()> {
+        self.lock().insert(webhook_id, self.capacity);
+        Box::pin(async {})
+    }
+}
+
+// Verify InMemoryDedupStore is Send + Sync at compile time
+const _: fn() = || {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<InMemoryDedupStore>();
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_in_memory_store_remembers_inserted_ids() {
+        let store = InMemoryDedupStore::new(10);
+
+        assert!(!store.contains("b9f0c7a1").await);
+        store.insert("b9f0c7a1").await;
+        assert!(store.contains("b9f0c7a1").await);
+        assert!(!store.contains("5d2e8f44").await);
+
+        // Inserting again does not grow the store
+        store.insert("b9f0c7a1").await;
+        assert_eq!(store.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_store_evicts_least_recently_seen() {
+        let store = InMemoryDedupStore::new(2);
+
+        store.insert("first").await;
+        store.insert("second").await;
+        // Seeing "first" again makes "second" the oldest entry
+        assert!(store.contains("first").await);
+        store.insert("third").await;
+
+        assert_eq!(store.len(), 2);
+        assert!(store.contains("first").await);
+        assert!(!store.contains("second").await);
+        assert!(store.contains("third").await);
+    }
+
+    #[test]
+    fn test_in_memory_store_zero_capacity_is_one() {
+        assert_eq!(InMemoryDedupStore::new(0).capacity(), 1);
+    }
+}
@@ -13,6 +13,7 @@
 //! - [`WebhookError::InvalidHmac`]: When webhook signature verification fails
 //! - [`WebhookError::NoHandlerForTopic`]: When no handler is registered for a topic
 //! - [`WebhookError::PayloadParseError`]: When webhook payload JSON parsing fails
+//! - [`WebhookError::ShopMismatch`]: When a webhook was sent by a different shop than expected
 //!
 //! # Example
 //!
@@ -82,6 +83,8 @@ pub enum WebhookError {
     ShopifyError {
         /// The error message from Shopify.
         message: String,
+        /// The `X-Request-Id` of the response, when the error came from one.
+        request_id: Option<String>,
     },
 
     /// Webhook subscription not found in Shopify.
@@ -99,6 +102,11 @@ pub enum WebhookError {
     /// This error occurs when the HMAC signature in the webhook request
     /// does not match the expected signature computed from the request body.
     /// The error message is intentionally generic to avoid leaking security details.
+    ///
+    /// The most common cause is verifying a body that was parsed and
+    /// re-serialized by a web framework instead of the raw request bytes;
+    /// [`verify_webhook`](crate::webhooks::verify_webhook) logs a warning
+    /// when the body looks re-serialized.
     #[error("Webhook signature verification failed")]
     InvalidHmac,
 
@@ -121,6 +129,38 @@ pub enum WebhookError {
         /// The error message from the JSON parser.
         message: String,
     },
+
+    /// Webhook shop domain does not match the expected shop.
+    ///
+    /// This error occurs when a webhook passes signature verification but its
+    /// `X-Shopify-Shop-Domain` header names a different shop than the one the
+    /// handler expects (for example, a delivery for shop A replayed against
+    /// shop B's endpoint). A missing header is also treated as a mismatch.
+    #[error(
+        "Webhook shop domain mismatch: expected {expected}, received {}",
+        .actual.as_deref().unwrap_or("<none>")
+    )]
+    ShopMismatch {
+        /// The shop domain the webhook was expected to come from.
+        expected: String,
+        /// The shop domain from the webhook headers, if present.
+        actual: Option<String>,
+    },
+}
+
+impl WebhookError {
+    /// Returns the `X-Request-Id` of the response that caused the error, if any.
+    ///
+    /// Set for [`WebhookError::ShopifyError`] raised while reading a
+    /// registration response, and for wrapped HTTP errors.
+    #[must_use]
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Self::ShopifyError { request_id, .. } => request_id.as_deref(),
+            Self::GraphqlError(e) => e.request_id(),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -146,10 +186,31 @@ mod tests {
         assert!(message.contains("OrdersCreate"));
     }
 
+    #[test]
+    fn test_request_id_accessor() {
+        let error = WebhookError::ShopifyError {
+            message: "Address is invalid".to_string(),
+            request_id: Some("req-1".to_string()),
+        };
+        assert_eq!(error.request_id(), Some("req-1"));
+
+        let error = WebhookError::GraphqlError(GraphqlError::Http(HttpError::Response(
+            HttpResponseError {
+                code: 401,
+                message: "Unauthorized".to_string(),
+                error_reference: Some("req-2".to_string()),
+            },
+        )));
+        assert_eq!(error.request_id(), Some("req-2"));
+
+        assert_eq!(WebhookError::InvalidHmac.request_id(), None);
+    }
+
     #[test]
     fn test_shopify_error_message() {
         let error = WebhookError::ShopifyError {
             message: "Invalid callback URL".to_string(),
+            request_id: None,
         };
         let message = error.to_string();
         assert!(message.contains("Shopify API error"));
@@ -187,6 +248,7 @@ mod tests {
         // ShopifyError
         let error: &dyn std::error::Error = &WebhookError::ShopifyError {
             message: "test".to_string(),
+            request_id: None,
         };
         let _ = error;
 
@@ -215,6 +277,13 @@ mod tests {
             message: "invalid json".to_string(),
         };
         let _ = error;
+
+        // ShopMismatch
+        let error: &dyn std::error::Error = &WebhookError::ShopMismatch {
+            expected: "shop-b.myshopify.com".to_string(),
+            actual: Some("shop-a.myshopify.com".to_string()),
+        };
+        let _ = error;
     }
 
     #[test]
@@ -260,4 +329,22 @@ mod tests {
         assert!(message.contains("Failed to parse webhook payload"));
         assert!(message.contains("expected value at line 1 column 1"));
     }
+
+    #[test]
+    fn test_shop_mismatch_error_message_formatting() {
+        let error = WebhookError::ShopMismatch {
+            expected: "shop-b.myshopify.com".to_string(),
+            actual: Some("shop-a.myshopify.com".to_string()),
+        };
+        let message = error.to_string();
+        assert!(message.contains("shop domain mismatch"));
+        assert!(message.contains("shop-b.myshopify.com"));
+        assert!(message.contains("shop-a.myshopify.com"));
+
+        let error = WebhookError::ShopMismatch {
+            expected: "shop-b.myshopify.com".to_string(),
+            actual: None,
+        };
+        assert!(error.to_string().contains("<none>"));
+    }
 }
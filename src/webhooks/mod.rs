@@ -15,12 +15,16 @@
 //! - [`WebhookRegistration`]: Configuration for a single webhook subscription
 //! - [`WebhookRegistrationBuilder`]: Builder for creating registrations
 //! - [`WebhookRegistrationResult`]: Result of registration operations
+//! - [`WebhookPlan`]: What registration would do, from [`WebhookRegistry::plan`]
+//! - [`RemoteWebhook`]: A subscription as it exists in Shopify
 //! - [`WebhookDeliveryMethod`]: Delivery method for webhooks (HTTP, EventBridge, Pub/Sub)
 //!
 //! ## Handler
 //!
 //! - [`WebhookHandler`]: Trait for implementing webhook handlers
 //! - [`BoxFuture`]: Type alias for boxed futures used in handler returns
+//! - [`DedupStore`]: Trait for skipping duplicate deliveries
+//! - [`InMemoryDedupStore`]: Bounded in-memory [`DedupStore`]
 //!
 //! ## Verification
 //!
@@ -50,6 +54,14 @@
 //! - Compares configuration to detect changes
 //! - Only creates/updates when necessary
 //!
+//! [`WebhookRegistry::plan`] runs the same comparison without creating or
+//! updating anything, to preview a deploy.
+//!
+//! To audit what Shopify actually has, including subscriptions left over from
+//! a previous deploy, use [`WebhookRegistry::list_remote`]. Subscriptions that
+//! are no longer in the local registry can be removed with
+//! [`WebhookRegistry::prune_remote`].
+//!
 //! # Delivery Methods
 //!
 //! Webhooks can be delivered via three different methods:
@@ -217,7 +229,7 @@
 //!         WebhookError::GraphqlError(e) => {
 //!             println!("API error: {}", e);
 //!         }
-//!         WebhookError::ShopifyError { message } => {
+//!         WebhookError::ShopifyError { message, .. } => {
 //!             println!("Shopify error: {}", message);
 //!         }
 //!         WebhookError::SubscriptionNotFound { topic } => {
@@ -232,6 +244,9 @@
 //!         WebhookError::PayloadParseError { message } => {
 //!             println!("Failed to parse webhook payload: {}", message);
 //!         }
+//!         WebhookError::ShopMismatch { expected, actual } => {
+//!             println!("Webhook for {} came from {:?}", expected, actual);
+//!         }
 //!     }
 //! }
 //! ```
@@ -241,16 +256,18 @@
 //! All types in this module are `Send + Sync`, making them safe to share
 //! across async tasks.
 
+mod dedup;
 mod errors;
 mod registry;
 mod types;
 mod verification;
 
+pub use dedup::{DedupStore, InMemoryDedupStore};
 pub use errors::WebhookError;
 pub use registry::WebhookRegistry;
 pub use types::{
-    BoxFuture, WebhookDeliveryMethod, WebhookHandler, WebhookRegistration,
-    WebhookRegistrationBuilder, WebhookRegistrationResult,
+    BoxFuture, RemoteWebhook, WebhookDeliveryMethod, WebhookHandler, WebhookPlan,
+    WebhookRegistration, WebhookRegistrationBuilder, WebhookRegistrationResult,
 };
 
 // Verification exports
@@ -259,5 +276,5 @@ pub use verification::{
     HEADER_SHOP_DOMAIN, HEADER_TOPIC, HEADER_WEBHOOK_ID,
 };
 
-// Re-export WebhookTopic for convenience
-pub use crate::rest::resources::v2026_04::common::WebhookTopic;
+// Re-export WebhookTopic and WebhookFormat for convenience
+pub use crate::rest::resources::v2026_04::common::{WebhookFormat, WebhookTopic};
@@ -39,17 +39,21 @@
 //! ```
 
 use std::collections::HashMap;
+use std::future::Future;
+
+use serde_json::{json, Value};
 
 use crate::auth::Session;
 use crate::clients::GraphqlClient;
-use crate::config::ShopifyConfig;
+use crate::config::{ShopDomain, ShopifyConfig};
 
+use super::dedup::DedupStore;
 use super::errors::WebhookError;
 use super::types::{
-    WebhookDeliveryMethod, WebhookHandler, WebhookRegistration, WebhookRegistrationResult,
-    WebhookTopic,
+    RemoteWebhook, WebhookDeliveryMethod, WebhookFormat, WebhookHandler, WebhookPlan,
+    WebhookRegistration, WebhookRegistrationResult, WebhookTopic,
 };
-use super::verification::{verify_webhook, WebhookRequest};
+use super::verification::{verify_webhook, WebhookContext, WebhookRequest};
 
 /// Registry for managing webhook subscriptions.
 ///
@@ -115,6 +119,8 @@ pub struct WebhookRegistry {
     registrations: HashMap<WebhookTopic, WebhookRegistration>,
     /// Internal storage for webhook handlers, keyed by topic.
     handlers: HashMap<WebhookTopic, Box<dyn WebhookHandler>>,
+    /// Store consulted to skip duplicate deliveries, if configured.
+    dedup_store: Option<Box<dyn DedupStore>>,
 }
 
 // Implement Debug manually since trait objects don't implement Debug
@@ -123,6 +129,10 @@ impl std::fmt::Debug for WebhookRegistry {
         f.debug_struct("WebhookRegistry")
             .field("registrations", &self.registrations)
             .field("handlers", &format!("<{} handlers>", self.handlers.len()))
+            .field(
+                "dedup_store",
+                &self.dedup_store.as_ref().map(|_| "<dedup store>"),
+            )
             .finish()
     }
 }
@@ -149,9 +159,32 @@ impl WebhookRegistry {
         Self {
             registrations: HashMap::new(),
             handlers: HashMap::new(),
+            dedup_store: None,
         }
     }
 
+    /// Sets the store used to skip duplicate webhook deliveries.
+    ///
+    /// Once set, [`process`](Self::process) and
+    /// [`process_for_shop`](Self::process_for_shop) return `Ok(())` without
+    /// invoking the handler for a webhook whose `X-Shopify-Webhook-Id` the
+    /// store has already recorded. IDs are recorded after the handler
+    /// succeeds, so failed deliveries are handled again on retry. Webhooks
+    /// without the header are always dispatched.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::webhooks::{InMemoryDedupStore, WebhookRegistry};
+    ///
+    /// let mut registry = WebhookRegistry::new();
+    /// registry.set_dedup_store(InMemoryDedupStore::new(10_000));
+    /// ```
+    pub fn set_dedup_store(&mut self, store: impl DedupStore + 'static) -> &mut Self {
+        self.dedup_store = Some(Box::new(store));
+        self
+    }
+
     /// Adds a webhook registration to the registry.
     ///
     /// If a registration for the same topic already exists, it will be replaced.
@@ -290,9 +323,11 @@ impl WebhookRegistry {
     /// # Flow
     ///
     /// 1. Verify the webhook signature using [`verify_webhook`]
-    /// 2. Look up the handler by topic
-    /// 3. Parse the request body as JSON
-    /// 4. Invoke the handler with the context and payload
+    /// 2. Skip the delivery if the configured [`DedupStore`] has already
+    ///    recorded its webhook ID
+    /// 3. Look up the handler by topic
+    /// 4. Parse the request body as JSON
+    /// 5. Invoke the handler with the context and payload
     ///
     /// # Arguments
     ///
@@ -325,7 +360,81 @@ impl WebhookRegistry {
         // Step 1: Verify webhook signature and get context
         let context = verify_webhook(config, request)?;
 
-        // Step 2: Look up handler by topic
+        self.dispatch(context, request).await
+    }
+
+    /// Processes an incoming webhook request that must belong to a specific shop.
+    ///
+    /// Behaves like [`process`](Self::process), but after signature verification
+    /// also confirms that the `X-Shopify-Shop-Domain` header matches
+    /// `expected_shop`. Because every shop's webhooks are signed with the same
+    /// app secret, this guards against a delivery for one shop being replayed
+    /// against another shop's endpoint.
+    ///
+    /// The header value is normalized the same way as [`ShopDomain::new`], so
+    /// casing and surrounding whitespace do not cause false mismatches.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The Shopify configuration containing the API secret key
+    /// * `request` - The incoming webhook request
+    /// * `expected_shop` - The shop the webhook is expected to come from
+    ///
+    /// # Errors
+    ///
+    /// Returns `WebhookError::InvalidHmac` if signature verification fails.
+    /// Returns `WebhookError::ShopMismatch` if the shop domain header is missing
+    /// or names a different shop.
+    /// Returns the same errors as [`process`](Self::process) otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use shopify_sdk::webhooks::{WebhookRegistry, WebhookRequest};
+    /// use shopify_sdk::ShopDomain;
+    ///
+    /// let shop = ShopDomain::new("my-store").unwrap();
+    /// registry.process_for_shop(&config, &request, &shop).await?;
+    /// ```
+    pub async fn process_for_shop(
+        &self,
+        config: &ShopifyConfig,
+        request: &WebhookRequest,
+        expected_shop: &ShopDomain,
+    ) -> Result<(), WebhookError> {
+        let context = verify_webhook(config, request)?;
+
+        let matches = context
+            .shop_domain()
+            .and_then(|domain| ShopDomain::new(domain).ok())
+            .is_some_and(|domain| &domain == expected_shop);
+
+        if !matches {
+            return Err(WebhookError::ShopMismatch {
+                expected: expected_shop.as_ref().to_string(),
+                actual: context.shop_domain().map(String::from),
+            });
+        }
+
+        self.dispatch(context, request).await
+    }
+
+    /// Looks up the handler for a verified webhook, parses the payload, and invokes it.
+    async fn dispatch(
+        &self,
+        context: WebhookContext,
+        request: &WebhookRequest,
+    ) -> Result<(), WebhookError> {
+        // Step 2: Skip deliveries that were already handled
+        let webhook_id = context.webhook_id().map(String::from);
+        if let (Some(store), Some(id)) = (&self.dedup_store, &webhook_id) {
+            if store.contains(id).await {
+                tracing::debug!(webhook_id = %id, "Skipping duplicate webhook delivery");
+                return Ok(());
+            }
+        }
+
+        // Step 3: Look up handler by topic
         let handler = match context.topic() {
             Some(topic) => self.handlers.get(&topic),
             None => None,
@@ -335,15 +444,20 @@ impl WebhookRegistry {
             topic: context.topic_raw().to_string(),
         })?;
 
-        // Step 3: Parse request body as JSON
+        // Step 4: Parse request body as JSON
         let payload: serde_json::Value = serde_json::from_slice(request.body()).map_err(|e| {
             WebhookError::PayloadParseError {
                 message: e.to_string(),
             }
         })?;
 
-        // Step 4: Invoke handler
-        handler.handle(context, payload).await
+        // Step 5: Invoke handler
+        handler.handle(context, payload).await?;
+
+        if let (Some(store), Some(id)) = (&self.dedup_store, &webhook_id) {
+            store.insert(id).await;
+        }
+        Ok(())
     }
 
     /// Registers a single webhook with Shopify.
@@ -479,6 +593,83 @@ impl WebhookRegistry {
         results
     }
 
+    /// Shows what [`register_all`](Self::register_all) would do, without
+    /// changing any subscriptions.
+    ///
+    /// Queries the existing subscription for each registration and compares
+    /// it the same way [`register`](Self::register) does, but issues no
+    /// mutations. Use it to review changes before a deploy.
+    ///
+    /// # Arguments
+    ///
+    /// * `session` - The authenticated session for API calls
+    /// * `config` - The SDK configuration
+    ///
+    /// # Returns
+    ///
+    /// One entry per registration. Failed lookups are captured in
+    /// [`WebhookPlan::Error`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use shopify_sdk::webhooks::WebhookPlan;
+    ///
+    /// for entry in registry.plan(&session, &config).await {
+    ///     match entry {
+    ///         WebhookPlan::Create { topic } => println!("create {topic:?}"),
+    ///         WebhookPlan::Update { topic, id } => println!("update {topic:?} ({id})"),
+    ///         WebhookPlan::NoChange { .. } => {}
+    ///         WebhookPlan::Error { topic, error } => eprintln!("{topic:?}: {error}"),
+    ///     }
+    /// }
+    /// ```
+    pub async fn plan(&self, session: &Session, config: &ShopifyConfig) -> Vec<WebhookPlan> {
+        let client = GraphqlClient::new(session, Some(config));
+
+        self.plan_with(|graphql_topic| {
+            let client = &client;
+            async move {
+                let query = existing_subscription_query(&graphql_topic);
+                let response = client.query(&query, None, None, None).await?;
+                Ok(response.body)
+            }
+        })
+        .await
+    }
+
+    /// Plans every registration, fetching the subscriptions for each topic
+    /// through `fetch`.
+    ///
+    /// `fetch` receives the topic in GraphQL form and returns the response
+    /// body of the subscriptions query.
+    async fn plan_with<F, Fut>(&self, mut fetch: F) -> Vec<WebhookPlan>
+    where
+        F: FnMut(String) -> Fut,
+        Fut: Future<Output = Result<Value, WebhookError>>,
+    {
+        let mut plan = Vec::new();
+
+        for registration in self.registrations.values() {
+            let topic = registration.topic;
+            let existing = match fetch(topic_to_graphql_format(&topic)).await {
+                Ok(body) => find_existing_subscription(&body, &registration.delivery_method, None),
+                Err(error) => Err(error),
+            };
+
+            plan.push(match existing {
+                Ok(Some((id, existing))) if self.config_matches(&existing, registration) => {
+                    WebhookPlan::NoChange { topic, id }
+                }
+                Ok(Some((id, _))) => WebhookPlan::Update { topic, id },
+                Ok(None) => WebhookPlan::Create { topic },
+                Err(error) => WebhookPlan::Error { topic, error },
+            });
+        }
+
+        plan
+    }
+
     /// Unregisters a webhook from Shopify.
     ///
     /// Queries for the existing webhook subscription and deletes it.
@@ -584,147 +775,207 @@ impl WebhookRegistry {
         }
     }
 
-    /// Queries Shopify for an existing webhook subscription by topic and delivery method.
-    async fn query_existing_subscription(
+    /// Lists every webhook subscription the app has in Shopify.
+    ///
+    /// Unlike [`list_registrations`](Self::list_registrations), this queries
+    /// Shopify, so it also returns subscriptions that are not in the local
+    /// registry (for example, ones created by a previous deploy). All pages of
+    /// `webhookSubscriptions` are fetched.
+    ///
+    /// # Arguments
+    ///
+    /// * `session` - The authenticated session for API calls
+    /// * `config` - The SDK configuration
+    ///
+    /// # Errors
+    ///
+    /// Returns `WebhookError::GraphqlError` for underlying API errors.
+    /// Returns `WebhookError::ShopifyError` if the response is malformed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use shopify_sdk::webhooks::WebhookRegistry;
+    ///
+    /// let registry = WebhookRegistry::new();
+    /// for webhook in registry.list_remote(&session, &config).await? {
+    ///     println!("{} {} {:?}", webhook.id, webhook.graphql_topic, webhook.endpoint);
+    /// }
+    /// ```
+    pub async fn list_remote(
         &self,
-        client: &GraphqlClient,
-        graphql_topic: &str,
-        delivery_method: &WebhookDeliveryMethod,
-    ) -> Result<Option<(String, ExistingWebhookConfig)>, WebhookError> {
-        let query = format!(
-            r#"
-            query {{
-                webhookSubscriptions(first: 25, topics: [{topic}]) {{
-                    edges {{
-                        node {{
-                            id
-                            endpoint {{
-                                ... on WebhookHttpEndpoint {{
-                                    callbackUrl
-                                }}
-                                ... on WebhookEventBridgeEndpoint {{
-                                    arn
-                                }}
-                                ... on WebhookPubSubEndpoint {{
-                                    pubSubProject
-                                    pubSubTopic
-                                }}
-                            }}
-                            includeFields
-                            metafieldNamespaces
-                            filter
-                        }}
-                    }}
-                }}
-            }}
-            "#,
-            topic = graphql_topic
-        );
+        session: &Session,
+        config: &ShopifyConfig,
+    ) -> Result<Vec<RemoteWebhook>, WebhookError> {
+        let client = GraphqlClient::new(session, Some(config));
 
-        let response = client.query(&query, None, None, None).await?;
+        collect_remote_webhooks(|cursor| {
+            let client = &client;
+            async move {
+                let variables = json!({ "after": cursor });
+                let response = client
+                    .query(LIST_SUBSCRIPTIONS_QUERY, Some(variables), None, None)
+                    .await?;
+                Ok(response.body)
+            }
+        })
+        .await
+    }
 
-        // Parse the response
-        let edges = response.body["data"]["webhookSubscriptions"]["edges"]
-            .as_array()
-            .ok_or_else(|| WebhookError::ShopifyError {
-                message: "Invalid response structure".to_string(),
-            })?;
+    /// Deletes webhook subscriptions in Shopify that are not in the local registry.
+    ///
+    /// A remote subscription is kept only if the registry has a registration
+    /// for the same topic with the same delivery method. Everything else,
+    /// including subscriptions for topics this SDK does not model, is deleted.
+    ///
+    /// # Arguments
+    ///
+    /// * `session` - The authenticated session for API calls
+    /// * `config` - The SDK configuration
+    ///
+    /// # Returns
+    ///
+    /// The IDs of the deleted subscriptions.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WebhookError::GraphqlError` for underlying API errors.
+    /// Returns `WebhookError::ShopifyError` if the listing is malformed or a
+    /// deletion is rejected. Subscriptions deleted before the error are not
+    /// restored.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let deleted = registry.prune_remote(&session, &config).await?;
+    /// println!("Removed {} stale webhooks", deleted.len());
+    /// ```
+    pub async fn prune_remote(
+        &self,
+        session: &Session,
+        config: &ShopifyConfig,
+    ) -> Result<Vec<String>, WebhookError> {
+        let remote = self.list_remote(session, config).await?;
+        let client = GraphqlClient::new(session, Some(config));
 
-        if edges.is_empty() {
-            return Ok(None);
+        let mut deleted = Vec::new();
+        for webhook in self.stale_remote_webhooks(&remote) {
+            self.delete_subscription(&client, &webhook.id).await?;
+            deleted.push(webhook.id.clone());
         }
 
-        // Find a matching subscription by delivery method
-        for edge in edges {
-            let node = &edge["node"];
-            let endpoint = &node["endpoint"];
+        Ok(deleted)
+    }
 
-            // Parse endpoint and check if it matches the desired delivery method
-            let parsed_delivery_method = if let Some(uri) = endpoint["callbackUrl"].as_str() {
-                Some(WebhookDeliveryMethod::Http {
-                    uri: uri.to_string(),
-                })
-            } else if let Some(arn) = endpoint["arn"].as_str() {
-                Some(WebhookDeliveryMethod::EventBridge {
-                    arn: arn.to_string(),
-                })
-            } else if let (Some(project), Some(topic)) = (
-                endpoint["pubSubProject"].as_str(),
-                endpoint["pubSubTopic"].as_str(),
-            ) {
-                Some(WebhookDeliveryMethod::PubSub {
-                    project_id: project.to_string(),
-                    topic_id: topic.to_string(),
-                })
-            } else {
-                None
-            };
+    /// Deletes the webhook subscriptions in Shopify whose topic matches `predicate`.
+    ///
+    /// Works over every remote subscription, as listed by
+    /// [`list_remote`](Self::list_remote), whether or not it is in the local
+    /// registry. Subscriptions for topics this SDK does not model never match.
+    /// Continues past failed deletions.
+    ///
+    /// # Arguments
+    ///
+    /// * `session` - The authenticated session for API calls
+    /// * `config` - The SDK configuration
+    /// * `predicate` - Returns `true` for topics whose subscriptions to delete
+    ///
+    /// # Returns
+    ///
+    /// Each matching subscription with the result of deleting it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WebhookError::GraphqlError` or `WebhookError::ShopifyError` if
+    /// the remote subscriptions cannot be listed. Failed deletions are
+    /// reported in the returned results instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use shopify_sdk::webhooks::WebhookTopic;
+    ///
+    /// let results = registry
+    ///     .unregister_matching(&session, &config, |topic| {
+    ///         matches!(
+    ///             topic,
+    ///             WebhookTopic::InventoryLevelsUpdate
+    ///                 | WebhookTopic::InventoryLevelsConnect
+    ///                 | WebhookTopic::InventoryLevelsDisconnect
+    ///         )
+    ///     })
+    ///     .await?;
+    ///
+    /// for (webhook, result) in results {
+    ///     if let Err(error) = result {
+    ///         eprintln!("Failed to delete {}: {error}", webhook.graphql_topic);
+    ///     }
+    /// }
+    /// ```
+    pub async fn unregister_matching(
+        &self,
+        session: &Session,
+        config: &ShopifyConfig,
+        predicate: impl Fn(&WebhookTopic) -> bool,
+    ) -> Result<Vec<(RemoteWebhook, Result<(), WebhookError>)>, WebhookError> {
+        let remote = self.list_remote(session, config).await?;
+        let client = GraphqlClient::new(session, Some(config));
 
-            // Check if the delivery method type matches (we compare full method for exact match later)
-            if let Some(ref parsed_method) = parsed_delivery_method {
-                let type_matches = match (parsed_method, delivery_method) {
-                    (WebhookDeliveryMethod::Http { .. }, WebhookDeliveryMethod::Http { .. }) => {
-                        true
-                    }
-                    (
-                        WebhookDeliveryMethod::EventBridge { .. },
-                        WebhookDeliveryMethod::EventBridge { .. },
-                    ) => true,
-                    (
-                        WebhookDeliveryMethod::PubSub { .. },
-                        WebhookDeliveryMethod::PubSub { .. },
-                    ) => true,
-                    _ => false,
-                };
+        Ok(delete_matching(remote, predicate, |id| {
+            let client = &client;
+            async move { self.delete_subscription(client, &id).await }
+        })
+        .await)
+    }
 
-                if type_matches {
-                    let id = node["id"]
-                        .as_str()
-                        .ok_or_else(|| WebhookError::ShopifyError {
-                            message: "Missing webhook ID".to_string(),
-                        })?
-                        .to_string();
-
-                    let include_fields = node["includeFields"].as_array().map(|arr| {
-                        arr.iter()
-                            .filter_map(|v| v.as_str().map(String::from))
-                            .collect()
-                    });
-
-                    let metafield_namespaces = node["metafieldNamespaces"].as_array().map(|arr| {
-                        arr.iter()
-                            .filter_map(|v| v.as_str().map(String::from))
-                            .collect()
-                    });
-
-                    let filter = node["filter"].as_str().map(String::from);
-
-                    return Ok(Some((
-                        id,
-                        ExistingWebhookConfig {
-                            delivery_method: parsed_method.clone(),
-                            include_fields,
-                            metafield_namespaces,
-                            filter,
-                        },
-                    )));
-                }
-            }
-        }
+    /// Returns the remote subscriptions that have no matching local registration.
+    fn stale_remote_webhooks<'a>(&self, remote: &'a [RemoteWebhook]) -> Vec<&'a RemoteWebhook> {
+        remote
+            .iter()
+            .filter(|webhook| {
+                let registration = webhook
+                    .topic
+                    .as_ref()
+                    .and_then(|topic| self.get_registration(topic));
+                !matches!(
+                    (registration, &webhook.endpoint),
+                    (Some(registration), Some(endpoint)) if registration.delivery_method == *endpoint
+                )
+            })
+            .collect()
+    }
+
+    /// Queries Shopify for an existing webhook subscription by topic and delivery method.
+    async fn query_existing_subscription(
+        &self,
+        client: &GraphqlClient,
+        graphql_topic: &str,
+        delivery_method: &WebhookDeliveryMethod,
+    ) -> Result<Option<(String, ExistingWebhookConfig)>, WebhookError> {
+        let query = existing_subscription_query(graphql_topic);
+        let response = client.query(&query, None, None, None).await?;
 
-        Ok(None)
+        find_existing_subscription(&response.body, delivery_method, response.request_id())
     }
 
     /// Compares existing webhook configuration with desired configuration.
+    ///
+    /// Lists and filters are normalized first (see [`normalize_list`] and
+    /// [`normalize_filter`]), so differences Shopify introduces when storing a
+    /// subscription don't trigger a no-op update.
     fn config_matches(
         &self,
         existing: &ExistingWebhookConfig,
         registration: &WebhookRegistration,
     ) -> bool {
         existing.delivery_method == registration.delivery_method
-            && existing.include_fields == registration.include_fields
-            && existing.metafield_namespaces == registration.metafield_namespaces
-            && existing.filter == registration.filter
+            && existing.format == registration.format
+            && normalize_list(existing.include_fields.as_deref())
+                == normalize_list(registration.include_fields.as_deref())
+            && normalize_list(existing.metafield_namespaces.as_deref())
+                == normalize_list(registration.metafield_namespaces.as_deref())
+            && normalize_filter(existing.filter.as_deref())
+                == normalize_filter(registration.filter.as_deref())
     }
 
     /// Creates a new webhook subscription in Shopify.
@@ -734,57 +985,7 @@ impl WebhookRegistry {
         graphql_topic: &str,
         registration: &WebhookRegistration,
     ) -> Result<WebhookRegistrationResult, WebhookError> {
-        let delivery_input = build_delivery_input(&registration.delivery_method);
-
-        let include_fields_input = registration
-            .include_fields
-            .as_ref()
-            .map(|fields| {
-                let quoted: Vec<String> = fields.iter().map(|f| format!("\"{}\"", f)).collect();
-                format!(", includeFields: [{}]", quoted.join(", "))
-            })
-            .unwrap_or_default();
-
-        let metafield_namespaces_input = registration
-            .metafield_namespaces
-            .as_ref()
-            .map(|ns| {
-                let quoted: Vec<String> = ns.iter().map(|n| format!("\"{}\"", n)).collect();
-                format!(", metafieldNamespaces: [{}]", quoted.join(", "))
-            })
-            .unwrap_or_default();
-
-        let filter_input = registration
-            .filter
-            .as_ref()
-            .map(|f| format!(", filter: \"{}\"", f))
-            .unwrap_or_default();
-
-        let mutation = format!(
-            r#"
-            mutation {{
-                webhookSubscriptionCreate(
-                    topic: {topic},
-                    webhookSubscription: {{
-                        {delivery}{include_fields}{metafield_namespaces}{filter}
-                    }}
-                ) {{
-                    webhookSubscription {{
-                        id
-                    }}
-                    userErrors {{
-                        field
-                        message
-                    }}
-                }}
-            }}
-            "#,
-            topic = graphql_topic,
-            delivery = delivery_input,
-            include_fields = include_fields_input,
-            metafield_namespaces = metafield_namespaces_input,
-            filter = filter_input
-        );
+        let mutation = create_mutation(graphql_topic, registration);
 
         let response = client.query(&mutation, None, None, None).await?;
 
@@ -798,6 +999,7 @@ impl WebhookRegistry {
                     .collect();
                 return Err(WebhookError::ShopifyError {
                     message: messages.join("; "),
+                    request_id: response.request_id().map(String::from),
                 });
             }
         }
@@ -807,6 +1009,7 @@ impl WebhookRegistry {
             .as_str()
             .ok_or_else(|| WebhookError::ShopifyError {
                 message: "Missing webhook subscription ID in response".to_string(),
+                request_id: response.request_id().map(String::from),
             })?
             .to_string();
 
@@ -820,57 +1023,7 @@ impl WebhookRegistry {
         id: &str,
         registration: &WebhookRegistration,
     ) -> Result<WebhookRegistrationResult, WebhookError> {
-        let delivery_input = build_delivery_input(&registration.delivery_method);
-
-        let include_fields_input = registration
-            .include_fields
-            .as_ref()
-            .map(|fields| {
-                let quoted: Vec<String> = fields.iter().map(|f| format!("\"{}\"", f)).collect();
-                format!(", includeFields: [{}]", quoted.join(", "))
-            })
-            .unwrap_or_default();
-
-        let metafield_namespaces_input = registration
-            .metafield_namespaces
-            .as_ref()
-            .map(|ns| {
-                let quoted: Vec<String> = ns.iter().map(|n| format!("\"{}\"", n)).collect();
-                format!(", metafieldNamespaces: [{}]", quoted.join(", "))
-            })
-            .unwrap_or_default();
-
-        let filter_input = registration
-            .filter
-            .as_ref()
-            .map(|f| format!(", filter: \"{}\"", f))
-            .unwrap_or_default();
-
-        let mutation = format!(
-            r#"
-            mutation {{
-                webhookSubscriptionUpdate(
-                    id: "{id}",
-                    webhookSubscription: {{
-                        {delivery}{include_fields}{metafield_namespaces}{filter}
-                    }}
-                ) {{
-                    webhookSubscription {{
-                        id
-                    }}
-                    userErrors {{
-                        field
-                        message
-                    }}
-                }}
-            }}
-            "#,
-            id = id,
-            delivery = delivery_input,
-            include_fields = include_fields_input,
-            metafield_namespaces = metafield_namespaces_input,
-            filter = filter_input
-        );
+        let mutation = update_mutation(id, registration);
 
         let response = client.query(&mutation, None, None, None).await?;
 
@@ -884,6 +1037,7 @@ impl WebhookRegistry {
                     .collect();
                 return Err(WebhookError::ShopifyError {
                     message: messages.join("; "),
+                    request_id: response.request_id().map(String::from),
                 });
             }
         }
@@ -924,6 +1078,7 @@ impl WebhookRegistry {
                     .collect();
                 return Err(WebhookError::ShopifyError {
                     message: messages.join("; "),
+                    request_id: response.request_id().map(String::from),
                 });
             }
         }
@@ -932,15 +1087,387 @@ impl WebhookRegistry {
     }
 }
 
+/// Query used by [`WebhookRegistry::list_remote`].
+const LIST_SUBSCRIPTIONS_QUERY: &str = r"
+query webhookSubscriptions($after: String) {
+    webhookSubscriptions(first: 250, after: $after) {
+        edges {
+            node {
+                id
+                topic
+                endpoint {
+                    ... on WebhookHttpEndpoint {
+                        callbackUrl
+                    }
+                    ... on WebhookEventBridgeEndpoint {
+                        arn
+                    }
+                    ... on WebhookPubSubEndpoint {
+                        pubSubProject
+                        pubSubTopic
+                    }
+                }
+                filter
+            }
+        }
+        pageInfo {
+            hasNextPage
+            endCursor
+        }
+    }
+}
+";
+
+/// Fetches every page of `webhookSubscriptions` through `fetch`.
+///
+/// `fetch` receives the cursor to continue after (`None` for the first page)
+/// and returns the response body.
+async fn collect_remote_webhooks<F, Fut>(mut fetch: F) -> Result<Vec<RemoteWebhook>, WebhookError>
+where
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = Result<Value, WebhookError>>,
+{
+    let mut webhooks = Vec::new();
+    let mut cursor = None;
+
+    loop {
+        let body = fetch(cursor).await?;
+        let connection = &body["data"]["webhookSubscriptions"];
+        let edges = connection["edges"]
+            .as_array()
+            .ok_or_else(|| WebhookError::ShopifyError {
+                message: "Invalid response structure".to_string(),
+                request_id: None,
+            })?;
+
+        for edge in edges {
+            webhooks.push(parse_remote_webhook(&edge["node"])?);
+        }
+
+        let page_info = &connection["pageInfo"];
+        match page_info["endCursor"].as_str() {
+            Some(end_cursor) if page_info["hasNextPage"].as_bool() == Some(true) => {
+                cursor = Some(end_cursor.to_string());
+            }
+            _ => return Ok(webhooks),
+        }
+    }
+}
+
+/// Deletes, through `delete`, the subscriptions whose topic matches `predicate`.
+///
+/// `delete` receives the subscription ID. Subscriptions for topics this SDK
+/// does not model are skipped.
+async fn delete_matching<P, F, Fut>(
+    remote: Vec<RemoteWebhook>,
+    predicate: P,
+    mut delete: F,
+) -> Vec<(RemoteWebhook, Result<(), WebhookError>)>
+where
+    P: Fn(&WebhookTopic) -> bool,
+    F: FnMut(String) -> Fut,
+    Fut: Future<Output = Result<(), WebhookError>>,
+{
+    let mut results = Vec::new();
+    for webhook in remote {
+        if webhook.topic.as_ref().is_some_and(&predicate) {
+            let result = delete(webhook.id.clone()).await;
+            results.push((webhook, result));
+        }
+    }
+    results
+}
+
+/// Parses a `WebhookSubscription` node.
+fn parse_remote_webhook(node: &Value) -> Result<RemoteWebhook, WebhookError> {
+    let id = node["id"]
+        .as_str()
+        .ok_or_else(|| WebhookError::ShopifyError {
+            message: "Missing webhook ID".to_string(),
+            request_id: None,
+        })?
+        .to_string();
+    let graphql_topic = node["topic"].as_str().unwrap_or_default().to_string();
+
+    Ok(RemoteWebhook {
+        id,
+        topic: WebhookTopic::from_graphql_enum(&graphql_topic),
+        graphql_topic,
+        endpoint: parse_endpoint(&node["endpoint"]),
+        filter: node["filter"].as_str().map(String::from),
+    })
+}
+
+/// Builds the query for the subscriptions to `graphql_topic`.
+fn existing_subscription_query(graphql_topic: &str) -> String {
+    format!(
+        r#"
+        query {{
+            webhookSubscriptions(first: 25, topics: [{topic}]) {{
+                edges {{
+                    node {{
+                        id
+                        endpoint {{
+                            ... on WebhookHttpEndpoint {{
+                                callbackUrl
+                            }}
+                            ... on WebhookEventBridgeEndpoint {{
+                                arn
+                            }}
+                            ... on WebhookPubSubEndpoint {{
+                                pubSubProject
+                                pubSubTopic
+                            }}
+                        }}
+                        format
+                        includeFields
+                        metafieldNamespaces
+                        filter
+                    }}
+                }}
+            }}
+        }}
+        "#,
+        topic = graphql_topic
+    )
+}
+
+/// Finds the subscription in a `webhookSubscriptions` response body whose
+/// endpoint has the same kind as `delivery_method`.
+fn find_existing_subscription(
+    body: &Value,
+    delivery_method: &WebhookDeliveryMethod,
+    request_id: Option<&str>,
+) -> Result<Option<(String, ExistingWebhookConfig)>, WebhookError> {
+    // Parse the response
+    let edges = body["data"]["webhookSubscriptions"]["edges"]
+        .as_array()
+        .ok_or_else(|| WebhookError::ShopifyError {
+            message: "Invalid response structure".to_string(),
+            request_id: request_id.map(String::from),
+        })?;
+
+    if edges.is_empty() {
+        return Ok(None);
+    }
+
+    // Find a matching subscription by delivery method
+    for edge in edges {
+        let node = &edge["node"];
+        let endpoint = &node["endpoint"];
+
+        // Parse endpoint and check if it matches the desired delivery method
+        let parsed_delivery_method = parse_endpoint(endpoint);
+
+        // Check if the delivery method type matches (we compare full method for exact match later)
+        if let Some(ref parsed_method) = parsed_delivery_method {
+            let type_matches = match (parsed_method, delivery_method) {
+                (WebhookDeliveryMethod::Http { .. }, WebhookDeliveryMethod::Http { .. }) => true,
+                (
+                    WebhookDeliveryMethod::EventBridge { .. },
+                    WebhookDeliveryMethod::EventBridge { .. },
+                ) => true,
+                (WebhookDeliveryMethod::PubSub { .. }, WebhookDeliveryMethod::PubSub { .. }) => {
+                    true
+                }
+                _ => false,
+            };
+
+            if type_matches {
+                let id = node["id"]
+                    .as_str()
+                    .ok_or_else(|| WebhookError::ShopifyError {
+                        message: "Missing webhook ID".to_string(),
+                        request_id: request_id.map(String::from),
+                    })?
+                    .to_string();
+
+                let include_fields = node["includeFields"].as_array().map(|arr| {
+                    arr.iter()
+                        .filter_map(|v| v.as_str().map(String::from))
+                        .collect()
+                });
+
+                let metafield_namespaces = node["metafieldNamespaces"].as_array().map(|arr| {
+                    arr.iter()
+                        .filter_map(|v| v.as_str().map(String::from))
+                        .collect()
+                });
+
+                let filter = node["filter"].as_str().map(String::from);
+
+                return Ok(Some((
+                    id,
+                    ExistingWebhookConfig {
+                        delivery_method: parsed_method.clone(),
+                        format: format_from_graphql(node["format"].as_str()),
+                        include_fields,
+                        metafield_namespaces,
+                        filter,
+                    },
+                )));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// Parses a `WebhookSubscriptionEndpoint` into a delivery method.
+fn parse_endpoint(endpoint: &Value) -> Option<WebhookDeliveryMethod> {
+    if let Some(uri) = endpoint["callbackUrl"].as_str() {
+        Some(WebhookDeliveryMethod::Http {
+            uri: uri.to_string(),
+        })
+    } else if let Some(arn) = endpoint["arn"].as_str() {
+        Some(WebhookDeliveryMethod::EventBridge {
+            arn: arn.to_string(),
+        })
+    } else if let (Some(project), Some(topic)) = (
+        endpoint["pubSubProject"].as_str(),
+        endpoint["pubSubTopic"].as_str(),
+    ) {
+        Some(WebhookDeliveryMethod::PubSub {
+            project_id: project.to_string(),
+            topic_id: topic.to_string(),
+        })
+    } else {
+        None
+    }
+}
+
 /// Internal struct for holding existing webhook configuration from Shopify.
 #[derive(Debug, Clone)]
 struct ExistingWebhookConfig {
     delivery_method: WebhookDeliveryMethod,
+    format: WebhookFormat,
     include_fields: Option<Vec<String>>,
     metafield_namespaces: Option<Vec<String>>,
     filter: Option<String>,
 }
 
+/// Normalizes `includeFields` or `metafieldNamespaces` for comparison.
+///
+/// Entries are trimmed, sorted and de-duplicated, since their order has no
+/// meaning. An empty list is treated the same as no list, because Shopify
+/// returns `[]` for subscriptions created without one.
+fn normalize_list(values: Option<&[String]>) -> Option<Vec<&str>> {
+    let mut values: Vec<&str> = values?.iter().map(|v| v.trim()).collect();
+    values.sort_unstable();
+    values.dedup();
+    (!values.is_empty()).then_some(values)
+}
+
+/// Normalizes a subscription filter for comparison.
+///
+/// Surrounding whitespace is trimmed, and a blank filter is treated the same
+/// as no filter.
+fn normalize_filter(filter: Option<&str>) -> Option<&str> {
+    filter.map(str::trim).filter(|f| !f.is_empty())
+}
+
+/// Builds the `webhookSubscriptionCreate` mutation for a registration.
+fn create_mutation(graphql_topic: &str, registration: &WebhookRegistration) -> String {
+    format!(
+        r#"
+            mutation {{
+                webhookSubscriptionCreate(
+                    topic: {topic},
+                    webhookSubscription: {{
+                        {input}
+                    }}
+                ) {{
+                    webhookSubscription {{
+                        id
+                    }}
+                    userErrors {{
+                        field
+                        message
+                    }}
+                }}
+            }}
+            "#,
+        topic = graphql_topic,
+        input = build_subscription_input(registration)
+    )
+}
+
+/// Builds the `webhookSubscriptionUpdate` mutation for a registration.
+fn update_mutation(id: &str, registration: &WebhookRegistration) -> String {
+    format!(
+        r#"
+            mutation {{
+                webhookSubscriptionUpdate(
+                    id: "{id}",
+                    webhookSubscription: {{
+                        {input}
+                    }}
+                ) {{
+                    webhookSubscription {{
+                        id
+                    }}
+                    userErrors {{
+                        field
+                        message
+                    }}
+                }}
+            }}
+            "#,
+        id = id,
+        input = build_subscription_input(registration)
+    )
+}
+
+/// Builds the fields of the `webhookSubscription` input for a registration.
+fn build_subscription_input(registration: &WebhookRegistration) -> String {
+    let delivery_input = build_delivery_input(&registration.delivery_method);
+
+    let include_fields_input = registration
+        .include_fields
+        .as_ref()
+        .map(|fields| {
+            let quoted: Vec<String> = fields.iter().map(|f| format!("\"{}\"", f)).collect();
+            format!(", includeFields: [{}]", quoted.join(", "))
+        })
+        .unwrap_or_default();
+
+    let metafield_namespaces_input = registration
+        .metafield_namespaces
+        .as_ref()
+        .map(|ns| {
+            let quoted: Vec<String> = ns.iter().map(|n| format!("\"{}\"", n)).collect();
+            format!(", metafieldNamespaces: [{}]", quoted.join(", "))
+        })
+        .unwrap_or_default();
+
+    let filter_input = registration
+        .filter
+        .as_ref()
+        .map(|f| format!(", filter: \"{}\"", f))
+        .unwrap_or_default();
+
+    format!(
+        "{delivery_input}, format: {format}{include_fields_input}{metafield_namespaces_input}{filter_input}",
+        format = format_to_graphql(registration.format)
+    )
+}
+
+/// Converts a `WebhookFormat` to its GraphQL enum value.
+const fn format_to_graphql(format: WebhookFormat) -> &'static str {
+    match format {
+        WebhookFormat::Json => "JSON",
+        WebhookFormat::Xml => "XML",
+    }
+}
+
+/// Parses a GraphQL `WebhookSubscriptionFormat` value, defaulting to JSON.
+fn format_from_graphql(value: Option<&str>) -> WebhookFormat {
+    match value {
+        Some("XML") => WebhookFormat::Xml,
+        _ => WebhookFormat::Json,
+    }
+}
+
 /// Builds the GraphQL input for the delivery method.
 ///
 /// Uses the unified `uri` field which accepts:
@@ -991,8 +1518,9 @@ mod tests {
     use crate::auth::oauth::hmac::compute_signature_base64;
     use crate::config::{ApiKey, ApiSecretKey};
     use crate::webhooks::types::BoxFuture;
+    use crate::webhooks::InMemoryDedupStore;
     use crate::WebhookRegistrationBuilder;
-    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
     use std::sync::Arc;
 
     // Test handler implementation
@@ -1014,6 +1542,25 @@ mod tests {
         }
     }
 
+    // Handler that counts its invocations
+    struct CountingHandler {
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl WebhookHandler for CountingHandler {
+        fn handle<'a>(
+            &'a self,
+            _context: super::super::verification::WebhookContext,
+            _payload: serde_json::Value,
+        ) -> BoxFuture<'a, Result<(), WebhookError>> {
+            let calls = self.calls.clone();
+            Box::pin(async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            })
+        }
+    }
+
     // Error handler implementation for testing error propagation
     struct ErrorHandler {
         error_message: String,
@@ -1026,7 +1573,12 @@ mod tests {
             _payload: serde_json::Value,
         ) -> BoxFuture<'a, Result<(), WebhookError>> {
             let message = self.error_message.clone();
-            Box::pin(async move { Err(WebhookError::ShopifyError { message }) })
+            Box::pin(async move {
+                Err(WebhookError::ShopifyError {
+                    message,
+                    request_id: None,
+                })
+            })
         }
     }
 
@@ -1043,6 +1595,7 @@ mod tests {
             include_fields: Some(vec!["id".to_string()]),
             metafield_namespaces: None,
             filter: None,
+            format: WebhookFormat::Json,
         };
 
         assert!(matches!(
@@ -1060,6 +1613,7 @@ mod tests {
             include_fields: None,
             metafield_namespaces: None,
             filter: Some("status:active".to_string()),
+            format: WebhookFormat::Json,
         };
 
         assert!(matches!(
@@ -1079,6 +1633,7 @@ mod tests {
             include_fields: None,
             metafield_namespaces: Some(vec!["custom".to_string()]),
             filter: None,
+            format: WebhookFormat::Json,
         };
 
         match config.delivery_method {
@@ -1146,6 +1701,7 @@ mod tests {
             include_fields: None,
             metafield_namespaces: None,
             filter: None,
+            format: WebhookFormat::Json,
         };
 
         let registration = WebhookRegistrationBuilder::new(
@@ -1170,6 +1726,7 @@ mod tests {
             include_fields: None,
             metafield_namespaces: None,
             filter: None,
+            format: WebhookFormat::Json,
         };
 
         let registration = WebhookRegistrationBuilder::new(
@@ -1194,6 +1751,7 @@ mod tests {
             include_fields: None,
             metafield_namespaces: None,
             filter: None,
+            format: WebhookFormat::Json,
         };
 
         let registration = WebhookRegistrationBuilder::new(
@@ -1219,6 +1777,7 @@ mod tests {
             include_fields: None,
             metafield_namespaces: None,
             filter: None,
+            format: WebhookFormat::Json,
         };
 
         let registration = WebhookRegistrationBuilder::new(
@@ -1244,6 +1803,7 @@ mod tests {
             include_fields: None,
             metafield_namespaces: None,
             filter: None,
+            format: WebhookFormat::Json,
         };
 
         let registration = WebhookRegistrationBuilder::new(
@@ -1268,34 +1828,149 @@ mod tests {
             include_fields: Some(vec!["id".to_string()]),
             metafield_namespaces: Some(vec!["custom".to_string()]),
             filter: Some("status:active".to_string()),
+            format: WebhookFormat::Json,
+        };
+
+        let registration = WebhookRegistrationBuilder::new(
+            WebhookTopic::OrdersCreate,
+            WebhookDeliveryMethod::Http {
+                uri: "https://example.com/webhooks".to_string(),
+            },
+        )
+        .include_fields(vec!["id".to_string()])
+        .metafield_namespaces(vec!["custom".to_string()])
+        .filter("status:active".to_string())
+        .build();
+
+        assert!(registry.config_matches(&existing, &registration));
+
+        // Different filter should not match
+        let registration_different = WebhookRegistrationBuilder::new(
+            WebhookTopic::OrdersCreate,
+            WebhookDeliveryMethod::Http {
+                uri: "https://example.com/webhooks".to_string(),
+            },
+        )
+        .include_fields(vec!["id".to_string()])
+        .metafield_namespaces(vec!["custom".to_string()])
+        .filter("status:inactive".to_string())
+        .build();
+
+        assert!(!registry.config_matches(&existing, &registration_different));
+    }
+
+    #[test]
+    fn test_config_matches_ignores_order_and_whitespace_differences() {
+        let registry = WebhookRegistry::new();
+
+        // As Shopify returns it: reordered lists and a trimmed filter
+        let existing = ExistingWebhookConfig {
+            delivery_method: WebhookDeliveryMethod::Http {
+                uri: "https://example.com/webhooks".to_string(),
+            },
+            include_fields: Some(vec!["email".to_string(), "id".to_string()]),
+            metafield_namespaces: Some(vec!["app".to_string(), "custom".to_string()]),
+            filter: Some("status:active".to_string()),
+            format: WebhookFormat::Json,
+        };
+
+        let registration = WebhookRegistrationBuilder::new(
+            WebhookTopic::OrdersCreate,
+            WebhookDeliveryMethod::Http {
+                uri: "https://example.com/webhooks".to_string(),
+            },
+        )
+        .include_fields(vec!["id".to_string(), "email".to_string()])
+        .metafield_namespaces(vec!["custom".to_string(), "app".to_string()])
+        .filter("  status:active ".to_string())
+        .build();
+
+        assert!(registry.config_matches(&existing, &registration));
+    }
+
+    #[test]
+    fn test_config_matches_treats_empty_lists_as_unset() {
+        let registry = WebhookRegistry::new();
+
+        let existing = ExistingWebhookConfig {
+            delivery_method: WebhookDeliveryMethod::Http {
+                uri: "https://example.com/webhooks".to_string(),
+            },
+            include_fields: Some(vec![]),
+            metafield_namespaces: Some(vec![]),
+            filter: Some(String::new()),
+            format: WebhookFormat::Json,
+        };
+
+        let registration = WebhookRegistrationBuilder::new(
+            WebhookTopic::OrdersCreate,
+            WebhookDeliveryMethod::Http {
+                uri: "https://example.com/webhooks".to_string(),
+            },
+        )
+        .build();
+
+        assert!(registry.config_matches(&existing, &registration));
+    }
+
+    #[test]
+    fn test_config_matches_compares_format() {
+        let registry = WebhookRegistry::new();
+
+        let existing = ExistingWebhookConfig {
+            delivery_method: WebhookDeliveryMethod::Http {
+                uri: "https://example.com/webhooks".to_string(),
+            },
+            include_fields: None,
+            metafield_namespaces: None,
+            filter: None,
+            format: WebhookFormat::Json,
+        };
+
+        let registration = |format| {
+            WebhookRegistrationBuilder::new(
+                WebhookTopic::OrdersCreate,
+                WebhookDeliveryMethod::Http {
+                    uri: "https://example.com/webhooks".to_string(),
+                },
+            )
+            .format(format)
+            .build()
         };
 
-        let registration = WebhookRegistrationBuilder::new(
+        assert!(registry.config_matches(&existing, &registration(WebhookFormat::Json)));
+        assert!(!registry.config_matches(&existing, &registration(WebhookFormat::Xml)));
+        assert_eq!(format_from_graphql(Some("XML")), WebhookFormat::Xml);
+        assert_eq!(format_from_graphql(None), WebhookFormat::Json);
+    }
+
+    #[test]
+    fn test_mutations_include_format() {
+        let json = WebhookRegistrationBuilder::new(
             WebhookTopic::OrdersCreate,
             WebhookDeliveryMethod::Http {
                 uri: "https://example.com/webhooks".to_string(),
             },
         )
-        .include_fields(vec!["id".to_string()])
-        .metafield_namespaces(vec!["custom".to_string()])
         .filter("status:active".to_string())
         .build();
+        assert_eq!(
+            build_subscription_input(&json),
+            r#"uri: "https://example.com/webhooks", format: JSON, filter: "status:active""#
+        );
+        assert!(create_mutation("ORDERS_CREATE", &json).contains("format: JSON"));
 
-        assert!(registry.config_matches(&existing, &registration));
-
-        // Different filter should not match
-        let registration_different = WebhookRegistrationBuilder::new(
+        let xml = WebhookRegistrationBuilder::new(
             WebhookTopic::OrdersCreate,
             WebhookDeliveryMethod::Http {
                 uri: "https://example.com/webhooks".to_string(),
             },
         )
-        .include_fields(vec!["id".to_string()])
-        .metafield_namespaces(vec!["custom".to_string()])
-        .filter("status:inactive".to_string())
+        .format(WebhookFormat::Xml)
         .build();
-
-        assert!(!registry.config_matches(&existing, &registration_different));
+        let mutation = update_mutation("gid://shopify/WebhookSubscription/1", &xml);
+        assert!(mutation.contains("webhookSubscriptionUpdate("));
+        assert!(mutation.contains(r#"uri: "https://example.com/webhooks", format: XML"#));
     }
 
     // ========================================================================
@@ -1580,6 +2255,13 @@ mod tests {
         assert_eq!(graphql_format, "INVENTORY_LEVELS_UPDATE");
     }
 
+    #[test]
+    fn test_topic_to_graphql_format_fulfillment_orders_hold_released() {
+        let topic = WebhookTopic::FulfillmentOrdersHoldReleased;
+        let graphql_format = topic_to_graphql_format(&topic);
+        assert_eq!(graphql_format, "FULFILLMENT_ORDERS_HOLD_RELEASED");
+    }
+
     #[test]
     fn test_add_registration_returns_mut_self_for_chaining() {
         let mut registry = WebhookRegistry::new();
@@ -1826,6 +2508,62 @@ mod tests {
         assert!(invoked.load(Ordering::SeqCst));
     }
 
+    #[tokio::test]
+    async fn test_process_skips_duplicate_webhook_id() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut registry = WebhookRegistry::new();
+        registry
+            .add_registration(
+                WebhookRegistrationBuilder::new(
+                    WebhookTopic::OrdersCreate,
+                    WebhookDeliveryMethod::Http {
+                        uri: "https://example.com/webhooks/orders".to_string(),
+                    },
+                )
+                .handler(CountingHandler {
+                    calls: calls.clone(),
+                })
+                .build(),
+            )
+            .set_dedup_store(InMemoryDedupStore::new(100));
+
+        let config = ShopifyConfig::builder()
+            .api_key(ApiKey::new("key").unwrap())
+            .api_secret_key(ApiSecretKey::new("secret").unwrap())
+            .build()
+            .unwrap();
+
+        let body = br#"{"order_id": 123}"#;
+        let delivery = |webhook_id: &str| {
+            WebhookRequest::new(
+                body.to_vec(),
+                compute_signature_base64(body, "secret"),
+                Some("orders/create".to_string()),
+                Some("shop.myshopify.com".to_string()),
+                None,
+                Some(webhook_id.to_string()),
+            )
+        };
+
+        // Shopify retries the same delivery with the same webhook ID
+        registry
+            .process(&config, &delivery("b54557e4-bdd9-4b37-8a5f-bf7d70bcd043"))
+            .await
+            .unwrap();
+        registry
+            .process(&config, &delivery("b54557e4-bdd9-4b37-8a5f-bf7d70bcd043"))
+            .await
+            .unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // A different webhook is still handled
+        registry
+            .process(&config, &delivery("0e5f1c2a-7d38-4f0b-9a36-1c1f2d3e4b5a"))
+            .await
+            .unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
     #[tokio::test]
     async fn test_handler_error_propagation_through_process() {
         let handler = ErrorHandler {
@@ -1866,7 +2604,7 @@ mod tests {
         assert!(result.is_err());
 
         match result.unwrap_err() {
-            WebhookError::ShopifyError { message } => {
+            WebhookError::ShopifyError { message, .. } => {
                 assert_eq!(message, "Handler failed intentionally");
             }
             other => panic!("Expected ShopifyError, got: {:?}", other),
@@ -2108,4 +2846,466 @@ mod tests {
         // Handler should not be invoked
         assert!(!invoked.load(Ordering::SeqCst));
     }
+
+    #[tokio::test]
+    async fn test_process_for_shop_invokes_handler_when_shop_matches() {
+        let invoked = Arc::new(AtomicBool::new(false));
+        let handler = TestHandler {
+            invoked: invoked.clone(),
+        };
+
+        let mut registry = WebhookRegistry::new();
+
+        registry.add_registration(
+            WebhookRegistrationBuilder::new(
+                WebhookTopic::OrdersCreate,
+                WebhookDeliveryMethod::Http {
+                    uri: "https://example.com/webhooks/orders".to_string(),
+                },
+            )
+            .handler(handler)
+            .build(),
+        );
+
+        let config = ShopifyConfig::builder()
+            .api_key(ApiKey::new("key").unwrap())
+            .api_secret_key(ApiSecretKey::new("secret").unwrap())
+            .build()
+            .unwrap();
+
+        let body = br#"{"order_id": 123}"#;
+        let hmac = compute_signature_base64(body, "secret");
+        let request = WebhookRequest::new(
+            body.to_vec(),
+            hmac,
+            Some("orders/create".to_string()),
+            Some("Shop-A.myshopify.com".to_string()),
+            None,
+            None,
+        );
+
+        let expected = ShopDomain::new("shop-a").unwrap();
+        let result = registry
+            .process_for_shop(&config, &request, &expected)
+            .await;
+        assert!(result.is_ok());
+        assert!(invoked.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn test_process_for_shop_rejects_mismatched_shop_domain() {
+        let invoked = Arc::new(AtomicBool::new(false));
+        let handler = TestHandler {
+            invoked: invoked.clone(),
+        };
+
+        let mut registry = WebhookRegistry::new();
+
+        registry.add_registration(
+            WebhookRegistrationBuilder::new(
+                WebhookTopic::OrdersCreate,
+                WebhookDeliveryMethod::Http {
+                    uri: "https://example.com/webhooks/orders".to_string(),
+                },
+            )
+            .handler(handler)
+            .build(),
+        );
+
+        let config = ShopifyConfig::builder()
+            .api_key(ApiKey::new("key").unwrap())
+            .api_secret_key(ApiSecretKey::new("secret").unwrap())
+            .build()
+            .unwrap();
+
+        // A valid delivery for shop A replayed against shop B's handler
+        let body = br#"{"order_id": 123}"#;
+        let hmac = compute_signature_base64(body, "secret");
+        let request = WebhookRequest::new(
+            body.to_vec(),
+            hmac,
+            Some("orders/create".to_string()),
+            Some("shop-a.myshopify.com".to_string()),
+            None,
+            None,
+        );
+
+        let expected = ShopDomain::new("shop-b").unwrap();
+        let result = registry
+            .process_for_shop(&config, &request, &expected)
+            .await;
+
+        match result.unwrap_err() {
+            WebhookError::ShopMismatch { expected, actual } => {
+                assert_eq!(expected, "shop-b.myshopify.com");
+                assert_eq!(actual.as_deref(), Some("shop-a.myshopify.com"));
+            }
+            other => panic!("Expected ShopMismatch, got: {:?}", other),
+        }
+
+        // Handler should not be invoked
+        assert!(!invoked.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn test_process_for_shop_rejects_missing_shop_domain() {
+        let registry = WebhookRegistry::new();
+
+        let config = ShopifyConfig::builder()
+            .api_key(ApiKey::new("key").unwrap())
+            .api_secret_key(ApiSecretKey::new("secret").unwrap())
+            .build()
+            .unwrap();
+
+        let body = br#"{"order_id": 123}"#;
+        let hmac = compute_signature_base64(body, "secret");
+        let request = WebhookRequest::new(
+            body.to_vec(),
+            hmac,
+            Some("orders/create".to_string()),
+            None,
+            None,
+            None,
+        );
+
+        let expected = ShopDomain::new("shop-b").unwrap();
+        let result = registry
+            .process_for_shop(&config, &request, &expected)
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            WebhookError::ShopMismatch { actual: None, .. }
+        ));
+    }
+
+    // ========================================================================
+    // Remote Subscription Listing Tests
+    // ========================================================================
+
+    fn subscriptions_page(nodes: Value, end_cursor: &str, has_next_page: bool) -> Value {
+        json!({
+            "data": {
+                "webhookSubscriptions": {
+                    "edges": nodes
+                        .as_array()
+                        .unwrap()
+                        .iter()
+                        .map(|node| json!({ "node": node }))
+                        .collect::<Vec<_>>(),
+                    "pageInfo": { "hasNextPage": has_next_page, "endCursor": end_cursor }
+                }
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_collect_remote_webhooks_follows_pages() {
+        let mut cursors = Vec::new();
+        let mut pages = vec![
+            subscriptions_page(
+                json!([{
+                    "id": "gid://shopify/WebhookSubscription/1",
+                    "topic": "ORDERS_CREATE",
+                    "endpoint": { "callbackUrl": "https://example.com/webhooks/orders" },
+                    "filter": "financial_status:paid"
+                }]),
+                "cursor-1",
+                true,
+            ),
+            subscriptions_page(
+                json!([
+                    {
+                        "id": "gid://shopify/WebhookSubscription/2",
+                        "topic": "PRODUCTS_UPDATE",
+                        "endpoint": { "pubSubProject": "my-project", "pubSubTopic": "products" },
+                        "filter": null
+                    },
+                    {
+                        "id": "gid://shopify/WebhookSubscription/3",
+                        "topic": "SOME_NEW_TOPIC",
+                        "endpoint": { "arn": "arn:aws:events:us-east-1::event-source/x" },
+                        "filter": null
+                    }
+                ]),
+                "cursor-2",
+                false,
+            ),
+        ]
+        .into_iter();
+
+        let webhooks = collect_remote_webhooks(|cursor| {
+            cursors.push(cursor);
+            let page = pages.next().unwrap();
+            async move { Ok(page) }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(cursors, vec![None, Some("cursor-1".to_string())]);
+        assert_eq!(webhooks.len(), 3);
+        assert_eq!(webhooks[0].topic, Some(WebhookTopic::OrdersCreate));
+        assert_eq!(
+            webhooks[0].endpoint,
+            Some(WebhookDeliveryMethod::Http {
+                uri: "https://example.com/webhooks/orders".to_string()
+            })
+        );
+        assert_eq!(webhooks[0].filter.as_deref(), Some("financial_status:paid"));
+        assert_eq!(webhooks[1].topic, Some(WebhookTopic::ProductsUpdate));
+        assert!(matches!(
+            webhooks[1].endpoint,
+            Some(WebhookDeliveryMethod::PubSub { .. })
+        ));
+        // Topics the SDK does not model are still listed
+        assert_eq!(webhooks[2].topic, None);
+        assert_eq!(webhooks[2].graphql_topic, "SOME_NEW_TOPIC");
+    }
+
+    #[tokio::test]
+    async fn test_collect_remote_webhooks_rejects_invalid_response() {
+        let result = collect_remote_webhooks(|_| async { Ok(json!({ "data": null })) }).await;
+
+        assert!(matches!(result, Err(WebhookError::ShopifyError { .. })));
+    }
+
+    #[test]
+    fn test_stale_remote_webhooks_keeps_only_matching_registrations() {
+        let mut registry = WebhookRegistry::new();
+        registry.add_registration(
+            WebhookRegistrationBuilder::new(
+                WebhookTopic::OrdersCreate,
+                WebhookDeliveryMethod::Http {
+                    uri: "https://example.com/webhooks/orders".to_string(),
+                },
+            )
+            .build(),
+        );
+
+        let remote = |id: &str, topic: &str, uri: &str| RemoteWebhook {
+            id: id.to_string(),
+            graphql_topic: topic.to_string(),
+            topic: WebhookTopic::from_graphql_enum(topic),
+            endpoint: Some(WebhookDeliveryMethod::Http {
+                uri: uri.to_string(),
+            }),
+            filter: None,
+        };
+        let remote = vec![
+            remote("1", "ORDERS_CREATE", "https://example.com/webhooks/orders"),
+            remote(
+                "2",
+                "ORDERS_CREATE",
+                "https://old-deploy.example.com/orders",
+            ),
+            remote(
+                "3",
+                "PRODUCTS_UPDATE",
+                "https://example.com/webhooks/products",
+            ),
+            remote("4", "SOME_NEW_TOPIC", "https://example.com/webhooks/new"),
+        ];
+
+        let stale: Vec<&str> = registry
+            .stale_remote_webhooks(&remote)
+            .iter()
+            .map(|webhook| webhook.id.as_str())
+            .collect();
+
+        assert_eq!(stale, vec!["2", "3", "4"]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_matching_deletes_only_matching_remote_webhooks() {
+        let remote = collect_remote_webhooks(|_| async {
+            Ok(subscriptions_page(
+                json!([
+                    {
+                        "id": "gid://shopify/WebhookSubscription/1",
+                        "topic": "INVENTORY_LEVELS_UPDATE",
+                        "endpoint": { "callbackUrl": "https://example.com/webhooks/inventory" },
+                        "filter": null
+                    },
+                    {
+                        "id": "gid://shopify/WebhookSubscription/2",
+                        "topic": "ORDERS_CREATE",
+                        "endpoint": { "callbackUrl": "https://example.com/webhooks/orders" },
+                        "filter": null
+                    },
+                    {
+                        "id": "gid://shopify/WebhookSubscription/3",
+                        "topic": "INVENTORY_ITEMS_DELETE",
+                        "endpoint": { "callbackUrl": "https://example.com/webhooks/inventory" },
+                        "filter": null
+                    },
+                    {
+                        "id": "gid://shopify/WebhookSubscription/4",
+                        "topic": "INVENTORY_SOMETHING_NEW",
+                        "endpoint": { "callbackUrl": "https://example.com/webhooks/inventory" },
+                        "filter": null
+                    }
+                ]),
+                "cursor-1",
+                false,
+            ))
+        })
+        .await
+        .unwrap();
+
+        let mut deleted = Vec::new();
+        let results = delete_matching(
+            remote,
+            |topic| {
+                matches!(
+                    topic,
+                    WebhookTopic::InventoryLevelsUpdate | WebhookTopic::InventoryItemsDelete
+                )
+            },
+            |id| {
+                deleted.push(id.clone());
+                async move {
+                    if id.ends_with("/3") {
+                        Err(WebhookError::ShopifyError {
+                            message: "Webhook subscription does not exist".to_string(),
+                            request_id: None,
+                        })
+                    } else {
+                        Ok(())
+                    }
+                }
+            },
+        )
+        .await;
+
+        assert_eq!(
+            deleted,
+            vec![
+                "gid://shopify/WebhookSubscription/1",
+                "gid://shopify/WebhookSubscription/3"
+            ]
+        );
+        assert_eq!(results.len(), 2);
+        assert_eq!(
+            results[0].0.topic,
+            Some(WebhookTopic::InventoryLevelsUpdate)
+        );
+        assert!(results[0].1.is_ok());
+        assert_eq!(results[1].0.topic, Some(WebhookTopic::InventoryItemsDelete));
+        assert!(matches!(
+            results[1].1,
+            Err(WebhookError::ShopifyError { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_plan_classifies_each_registration_without_mutating() {
+        let mut registry = WebhookRegistry::new();
+        for (topic, uri) in [
+            (
+                WebhookTopic::OrdersCreate,
+                "https://example.com/webhooks/orders",
+            ),
+            (
+                WebhookTopic::ProductsUpdate,
+                "https://example.com/webhooks/products",
+            ),
+            (
+                WebhookTopic::CustomersCreate,
+                "https://example.com/webhooks/customers",
+            ),
+            (
+                WebhookTopic::AppUninstalled,
+                "https://example.com/webhooks/app",
+            ),
+        ] {
+            registry.add_registration(
+                WebhookRegistrationBuilder::new(
+                    topic,
+                    WebhookDeliveryMethod::Http {
+                        uri: uri.to_string(),
+                    },
+                )
+                .build(),
+            );
+        }
+
+        let subscription = |id: &str, uri: &str| {
+            json!({
+                "data": {
+                    "webhookSubscriptions": {
+                        "edges": [{
+                            "node": {
+                                "id": id,
+                                "endpoint": { "callbackUrl": uri },
+                                "format": "JSON",
+                                "includeFields": [],
+                                "metafieldNamespaces": [],
+                                "filter": null
+                            }
+                        }]
+                    }
+                }
+            })
+        };
+        let mut queried = Vec::new();
+
+        let plan = registry
+            .plan_with(|graphql_topic| {
+                queried.push(graphql_topic.clone());
+                let body = match graphql_topic.as_str() {
+                    "ORDERS_CREATE" => Ok(subscription(
+                        "gid://shopify/WebhookSubscription/1",
+                        "https://example.com/webhooks/orders",
+                    )),
+                    "PRODUCTS_UPDATE" => Ok(subscription(
+                        "gid://shopify/WebhookSubscription/2",
+                        "https://old-deploy.example.com/products",
+                    )),
+                    "CUSTOMERS_CREATE" => Ok(json!({
+                        "data": { "webhookSubscriptions": { "edges": [] } }
+                    })),
+                    _ => Err(WebhookError::ShopifyError {
+                        message: "Throttled".to_string(),
+                        request_id: None,
+                    }),
+                };
+                async move { body }
+            })
+            .await;
+
+        // Only the four lookups are made
+        queried.sort();
+        assert_eq!(
+            queried,
+            vec![
+                "APP_UNINSTALLED",
+                "CUSTOMERS_CREATE",
+                "ORDERS_CREATE",
+                "PRODUCTS_UPDATE"
+            ]
+        );
+
+        let entry =
+            |topic: WebhookTopic| plan.iter().find(|entry| *entry.topic() == topic).unwrap();
+        assert!(matches!(
+            entry(WebhookTopic::OrdersCreate),
+            WebhookPlan::NoChange { id, .. } if id == "gid://shopify/WebhookSubscription/1"
+        ));
+        assert!(matches!(
+            entry(WebhookTopic::ProductsUpdate),
+            WebhookPlan::Update { id, .. } if id == "gid://shopify/WebhookSubscription/2"
+        ));
+        assert!(matches!(
+            entry(WebhookTopic::CustomersCreate),
+            WebhookPlan::Create { .. }
+        ));
+        assert!(matches!(
+            entry(WebhookTopic::AppUninstalled),
+            WebhookPlan::Error {
+                error: WebhookError::ShopifyError { .. },
+                ..
+            }
+        ));
+        assert_eq!(plan.iter().filter(|entry| entry.is_change()).count(), 2);
+    }
 }
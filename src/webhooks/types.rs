@@ -29,7 +29,7 @@ use std::pin::Pin;
 
 use super::verification::WebhookContext;
 use super::WebhookError;
-pub use crate::rest::resources::v2026_04::common::WebhookTopic;
+pub use crate::rest::resources::v2026_04::common::{WebhookFormat, WebhookTopic};
 
 /// A boxed future that is Send.
 ///
@@ -214,6 +214,9 @@ pub struct WebhookRegistration {
     /// Example: "status:active"
     pub filter: Option<String>,
 
+    /// The payload format Shopify delivers. Defaults to JSON.
+    pub format: WebhookFormat,
+
     /// Optional handler for processing incoming webhooks.
     ///
     /// When set, the handler will be invoked by [`WebhookRegistry::process()`](crate::webhooks::WebhookRegistry::process)
@@ -230,6 +233,7 @@ impl fmt::Debug for WebhookRegistration {
             .field("include_fields", &self.include_fields)
             .field("metafield_namespaces", &self.metafield_namespaces)
             .field("filter", &self.filter)
+            .field("format", &self.format)
             .field(
                 "handler",
                 &if self.handler.is_some() {
@@ -274,6 +278,7 @@ pub struct WebhookRegistrationBuilder {
     include_fields: Option<Vec<String>>,
     metafield_namespaces: Option<Vec<String>>,
     filter: Option<String>,
+    format: WebhookFormat,
     handler: Option<Box<dyn WebhookHandler>>,
 }
 
@@ -286,6 +291,7 @@ impl fmt::Debug for WebhookRegistrationBuilder {
             .field("include_fields", &self.include_fields)
             .field("metafield_namespaces", &self.metafield_namespaces)
             .field("filter", &self.filter)
+            .field("format", &self.format)
             .field(
                 "handler",
                 &if self.handler.is_some() {
@@ -327,6 +333,7 @@ impl WebhookRegistrationBuilder {
             include_fields: None,
             metafield_namespaces: None,
             filter: None,
+            format: WebhookFormat::Json,
             handler: None,
         }
     }
@@ -411,6 +418,33 @@ impl WebhookRegistrationBuilder {
         self
     }
 
+    /// Sets the payload format Shopify delivers.
+    ///
+    /// Defaults to [`WebhookFormat::Json`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shopify_sdk::webhooks::{WebhookDeliveryMethod, WebhookFormat, WebhookRegistrationBuilder};
+    /// use shopify_sdk::rest::resources::v2026_04::common::WebhookTopic;
+    ///
+    /// let registration = WebhookRegistrationBuilder::new(
+    ///     WebhookTopic::OrdersCreate,
+    ///     WebhookDeliveryMethod::Http {
+    ///         uri: "https://example.com/webhooks".to_string(),
+    ///     },
+    /// )
+    /// .format(WebhookFormat::Xml)
+    /// .build();
+    ///
+    /// assert_eq!(registration.format, WebhookFormat::Xml);
+    /// ```
+    #[must_use]
+    pub const fn format(mut self, format: WebhookFormat) -> Self {
+        self.format = format;
+        self
+    }
+
     /// Sets the handler for processing incoming webhooks.
     ///
     /// The handler will be invoked by [`WebhookRegistry::process()`](crate::webhooks::WebhookRegistry::process)
@@ -484,6 +518,7 @@ impl WebhookRegistrationBuilder {
             include_fields: self.include_fields,
             metafield_namespaces: self.metafield_namespaces,
             filter: self.filter,
+            format: self.format,
             handler: self.handler,
         }
     }
@@ -535,6 +570,120 @@ pub enum WebhookRegistrationResult {
     Failed(WebhookError),
 }
 
+/// What registering a webhook would do, without doing it.
+///
+/// Returned by [`WebhookRegistry::plan`](crate::webhooks::WebhookRegistry::plan),
+/// which compares each registration with the subscriptions in Shopify but
+/// issues no mutations.
+///
+/// # Example
+///
+/// ```rust
+/// use shopify_sdk::webhooks::{WebhookPlan, WebhookTopic};
+///
+/// let plan = WebhookPlan::Update {
+///     topic: WebhookTopic::OrdersCreate,
+///     id: "gid://shopify/WebhookSubscription/12345".to_string(),
+/// };
+///
+/// assert_eq!(plan.topic(), &WebhookTopic::OrdersCreate);
+/// assert!(plan.is_change());
+/// ```
+#[derive(Debug)]
+pub enum WebhookPlan {
+    /// No matching subscription exists; registering would create one.
+    Create {
+        /// The webhook topic.
+        topic: WebhookTopic,
+    },
+
+    /// A subscription exists with a different configuration; registering
+    /// would update it.
+    Update {
+        /// The webhook topic.
+        topic: WebhookTopic,
+        /// The Shopify webhook subscription ID.
+        id: String,
+    },
+
+    /// A subscription exists and matches the registration.
+    NoChange {
+        /// The webhook topic.
+        topic: WebhookTopic,
+        /// The Shopify webhook subscription ID.
+        id: String,
+    },
+
+    /// The existing subscription could not be looked up.
+    Error {
+        /// The webhook topic.
+        topic: WebhookTopic,
+        /// The error from looking up the subscription.
+        error: WebhookError,
+    },
+}
+
+impl WebhookPlan {
+    /// Returns the topic this plan entry is for.
+    #[must_use]
+    pub const fn topic(&self) -> &WebhookTopic {
+        match self {
+            Self::Create { topic }
+            | Self::Update { topic, .. }
+            | Self::NoChange { topic, .. }
+            | Self::Error { topic, .. } => topic,
+        }
+    }
+
+    /// Returns `true` if registering would create or update a subscription.
+    #[must_use]
+    pub const fn is_change(&self) -> bool {
+        matches!(self, Self::Create { .. } | Self::Update { .. })
+    }
+}
+
+/// A webhook subscription as it exists in Shopify.
+///
+/// Returned by [`WebhookRegistry::list_remote`](crate::webhooks::WebhookRegistry::list_remote),
+/// which lists every subscription the app has in a shop, whether or not it is
+/// present in the local registry.
+///
+/// # Example
+///
+/// ```rust
+/// use shopify_sdk::webhooks::{RemoteWebhook, WebhookDeliveryMethod, WebhookTopic};
+///
+/// let remote = RemoteWebhook {
+///     id: "gid://shopify/WebhookSubscription/12345".to_string(),
+///     graphql_topic: "ORDERS_CREATE".to_string(),
+///     topic: WebhookTopic::from_graphql_enum("ORDERS_CREATE"),
+///     endpoint: Some(WebhookDeliveryMethod::Http {
+///         uri: "https://example.com/webhooks/orders".to_string(),
+///     }),
+///     filter: None,
+/// };
+///
+/// assert_eq!(remote.topic, Some(WebhookTopic::OrdersCreate));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoteWebhook {
+    /// The Shopify webhook subscription ID.
+    pub id: String,
+
+    /// The topic as returned by Shopify (e.g. `"ORDERS_CREATE"`).
+    pub graphql_topic: String,
+
+    /// The parsed topic, or `None` if this SDK does not model the topic.
+    pub topic: Option<WebhookTopic>,
+
+    /// Where the webhook is delivered, or `None` for endpoint types this SDK
+    /// does not model.
+    pub endpoint: Option<WebhookDeliveryMethod>,
+
+    /// The subscription's filter, if any.
+    pub filter: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
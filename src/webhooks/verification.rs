@@ -103,6 +103,14 @@ pub const HEADER_WEBHOOK_ID: &str = "X-Shopify-Webhook-Id";
 /// This struct holds the raw request body and headers needed for verification.
 /// The body is stored as raw bytes to preserve the exact payload for HMAC computation.
 ///
+/// # Raw Body Required
+///
+/// The body must be the exact bytes Shopify sent. Many web frameworks parse
+/// JSON request bodies automatically; serializing that parsed value back to
+/// bytes reorders keys and changes whitespace, and the HMAC will no longer
+/// match. Read the body with your framework's raw-bytes extractor instead
+/// (e.g. `axum::body::Bytes` or `actix_web::web::Bytes`).
+///
 /// # Example
 ///
 /// ```rust
@@ -141,7 +149,8 @@ impl WebhookRequest {
     ///
     /// # Arguments
     ///
-    /// * `body` - Raw request body as bytes
+    /// * `body` - Raw request body as bytes, exactly as received (never a
+    ///   re-serialized JSON value)
     /// * `hmac_header` - Value of the X-Shopify-Hmac-SHA256 header
     /// * `topic` - Value of the X-Shopify-Topic header (optional)
     /// * `shop_domain` - Value of the X-Shopify-Shop-Domain header (optional)
@@ -303,6 +312,10 @@ impl WebhookContext {
     }
 
     /// Returns the webhook ID, if present in the webhook headers.
+    ///
+    /// The ID is the same for every delivery of a webhook, including retries,
+    /// so it can be used as a deduplication key (see
+    /// [`DedupStore`](crate::webhooks::DedupStore)).
     #[must_use]
     pub fn webhook_id(&self) -> Option<&str> {
         self.webhook_id.as_deref()
@@ -339,6 +352,9 @@ fn parse_topic(topic: &str) -> Option<WebhookTopic> {
 ///
 /// `true` if the signature is valid, `false` otherwise.
 ///
+/// This checks a single secret. Use [`verify_webhook`] to also accept
+/// webhooks signed with `old_api_secret_key` during a key rotation.
+///
 /// # Example
 ///
 /// ```rust
@@ -425,6 +441,12 @@ pub fn verify_webhook(
     }
 
     if !verified {
+        if body_appears_reserialized(body) {
+            tracing::warn!(
+                "Webhook HMAC verification failed and the body looks like re-serialized JSON. \
+                 Verify against the raw request bytes rather than a parsed and re-encoded body."
+            );
+        }
         return Err(WebhookError::InvalidHmac);
     }
 
@@ -445,6 +467,26 @@ pub fn verify_webhook(
     ))
 }
 
+/// Returns `true` if the body looks like JSON that was parsed and serialized
+/// again by `serde_json` rather than the bytes Shopify sent.
+///
+/// Shopify does not sort payload keys, while re-encoding a parsed
+/// `serde_json::Value` produces sorted keys in either compact or pretty form.
+/// A body that round-trips byte-for-byte through that process is therefore a
+/// strong sign it was re-serialized before verification.
+fn body_appears_reserialized(body: &[u8]) -> bool {
+    let Ok(value) = serde_json::from_slice::<serde_json::Value>(body) else {
+        return false;
+    };
+
+    if !value.is_object() {
+        return false;
+    }
+
+    serde_json::to_vec(&value).is_ok_and(|compact| compact == body)
+        || serde_json::to_vec_pretty(&value).is_ok_and(|pretty| pretty == body)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -634,6 +676,51 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_verify_webhook_accepts_both_secrets_during_rotation_window() {
+        let rotated = ShopifyConfig::builder()
+            .api_key(ApiKey::new("key").unwrap())
+            .api_secret_key(ApiSecretKey::new("new-secret").unwrap())
+            .old_api_secret_key(ApiSecretKey::new("old-secret").unwrap())
+            .build()
+            .unwrap();
+
+        // Shopify keeps signing in-flight deliveries with the old secret for a while
+        let body = br#"{"id":820982911946154508}"#;
+        let signed_with_old = WebhookRequest::new(
+            body.to_vec(),
+            compute_signature_base64(body, "old-secret"),
+            Some("orders/create".to_string()),
+            Some("shop.myshopify.com".to_string()),
+            None,
+            None,
+        );
+        let signed_with_new = WebhookRequest::new(
+            body.to_vec(),
+            compute_signature_base64(body, "new-secret"),
+            Some("orders/create".to_string()),
+            None,
+            None,
+            None,
+        );
+
+        let context = verify_webhook(&rotated, &signed_with_old).unwrap();
+        assert_eq!(context.topic(), Some(WebhookTopic::OrdersCreate));
+        assert_eq!(context.shop_domain(), Some("shop.myshopify.com"));
+        assert!(verify_webhook(&rotated, &signed_with_new).is_ok());
+
+        // Once the old secret is dropped from the config, its signatures are rejected
+        let rotation_complete = ShopifyConfig::builder()
+            .api_key(ApiKey::new("key").unwrap())
+            .api_secret_key(ApiSecretKey::new("new-secret").unwrap())
+            .build()
+            .unwrap();
+        assert!(matches!(
+            verify_webhook(&rotation_complete, &signed_with_old),
+            Err(WebhookError::InvalidHmac)
+        ));
+    }
+
     #[test]
     fn test_verify_webhook_fails_when_both_keys_fail() {
         let config = ShopifyConfig::builder()
@@ -757,4 +844,69 @@ mod tests {
         assert_eq!(parse_topic("custom_event"), None);
         assert_eq!(parse_topic(""), None);
     }
+
+    // ========================================================================
+    // Re-serialized Body Tests
+    // ========================================================================
+
+    /// A payload with keys in the order Shopify sends them (not sorted).
+    const RAW_ORDER_PAYLOAD: &[u8] =
+        br#"{"id":820982911946154508,"email":"jon@example.com","total_price":"10.00"}"#;
+
+    #[test]
+    fn test_reserialized_json_fails_verification_while_raw_body_passes() {
+        let config = ShopifyConfig::builder()
+            .api_key(ApiKey::new("key").unwrap())
+            .api_secret_key(ApiSecretKey::new("secret").unwrap())
+            .build()
+            .unwrap();
+        let hmac = compute_signature_base64(RAW_ORDER_PAYLOAD, "secret");
+
+        let raw_request = WebhookRequest::new(
+            RAW_ORDER_PAYLOAD.to_vec(),
+            hmac.clone(),
+            Some("orders/create".to_string()),
+            None,
+            None,
+            None,
+        );
+        assert!(verify_webhook(&config, &raw_request).is_ok());
+
+        // Simulate a framework that parsed the JSON and encoded it again
+        let parsed: serde_json::Value = serde_json::from_slice(RAW_ORDER_PAYLOAD).unwrap();
+        let reserialized = serde_json::to_vec(&parsed).unwrap();
+        assert_ne!(reserialized, RAW_ORDER_PAYLOAD);
+
+        let reserialized_request = WebhookRequest::new(
+            reserialized,
+            hmac,
+            Some("orders/create".to_string()),
+            None,
+            None,
+            None,
+        );
+        assert!(matches!(
+            verify_webhook(&config, &reserialized_request),
+            Err(WebhookError::InvalidHmac)
+        ));
+    }
+
+    #[test]
+    fn test_body_appears_reserialized_detects_serde_output() {
+        let parsed: serde_json::Value = serde_json::from_slice(RAW_ORDER_PAYLOAD).unwrap();
+
+        assert!(body_appears_reserialized(
+            &serde_json::to_vec(&parsed).unwrap()
+        ));
+        assert!(body_appears_reserialized(
+            &serde_json::to_vec_pretty(&parsed).unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_body_appears_reserialized_ignores_raw_and_non_json_bodies() {
+        assert!(!body_appears_reserialized(RAW_ORDER_PAYLOAD));
+        assert!(!body_appears_reserialized(b"not json"));
+        assert!(!body_appears_reserialized(b"[1,2,3]"));
+    }
 }
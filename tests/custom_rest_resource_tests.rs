@@ -0,0 +1,259 @@
+//! Integration tests for app-defined REST resources.
+//!
+//! These tests implement `RestResource` from outside the crate, the way an app
+//! would for its own endpoints, and verify that `find`/`all` resolve paths and
+//! parse responses using only the public API, against a local mock server.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use shopify_sdk::clients::HttpResponse;
+use shopify_sdk::rest::{
+    build_path, get_path, ReadOnlyResource, ResourceError, ResourceOperation, ResourcePath,
+    ResourceResponse, RestResource,
+};
+use shopify_sdk::{AuthScopes, HttpMethod, RestClient, Session, ShopDomain};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+// ============================================================================
+// Custom Read-Only Resource
+// ============================================================================
+
+/// A loyalty program member served by an app-specific endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LoyaltyMember {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    pub email: String,
+    pub points: u32,
+}
+
+/// Parameters for listing loyalty members.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct LoyaltyMemberListParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tier: Option<String>,
+}
+
+impl RestResource for LoyaltyMember {
+    type Id = u64;
+    type FindParams = ();
+    type AllParams = LoyaltyMemberListParams;
+    type CountParams = ();
+
+    const NAME: &'static str = "LoyaltyMember";
+    const PLURAL: &'static str = "members";
+    const PATHS: &'static [ResourcePath] = &[
+        ResourcePath::new(
+            HttpMethod::Get,
+            ResourceOperation::Find,
+            &["program_id", "id"],
+            "programs/{program_id}/members/{id}",
+        ),
+        ResourcePath::new(
+            HttpMethod::Get,
+            ResourceOperation::Find,
+            &["id"],
+            "members/{id}",
+        ),
+        ResourcePath::new(
+            HttpMethod::Get,
+            ResourceOperation::All,
+            &["program_id"],
+            "programs/{program_id}/members",
+        ),
+    ];
+    const PREFIX: Option<&'static str> = Some("apps/loyalty");
+
+    fn get_id(&self) -> Option<Self::Id> {
+        self.id
+    }
+
+    fn resource_key() -> String {
+        "member".to_string()
+    }
+}
+
+impl ReadOnlyResource for LoyaltyMember {}
+
+fn client() -> RestClient {
+    let session = Session::new(
+        "session-id".to_string(),
+        ShopDomain::new("test-shop.myshopify.com").unwrap(),
+        "access-token".to_string(),
+        AuthScopes::new(),
+        false,
+        None,
+    );
+    RestClient::new(&session, None).unwrap()
+}
+
+/// A client whose requests go to `server` instead of the shop.
+fn mock_client(server: &MockServer) -> RestClient {
+    let server_url = reqwest::Url::parse(&server.uri()).unwrap();
+    client().with_url_rewriter(move |url| {
+        url.set_scheme("http").unwrap();
+        url.set_host(server_url.host_str()).unwrap();
+        url.set_port(server_url.port()).unwrap();
+    })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn test_custom_resource_find_path_resolves_under_prefix() {
+    let path = get_path(
+        LoyaltyMember::PATHS,
+        ResourceOperation::Find,
+        &["program_id", "id"],
+    )
+    .unwrap();
+    let ids = HashMap::from([("program_id", "7"), ("id", "42")]);
+
+    assert_eq!(path.http_method, HttpMethod::Get);
+    assert_eq!(
+        LoyaltyMember::build_full_path(&build_path(path.template, &ids)),
+        "apps/loyalty/programs/7/members/42"
+    );
+}
+
+#[test]
+fn test_custom_resource_all_path_resolves_with_parent() {
+    let path = get_path(
+        LoyaltyMember::PATHS,
+        ResourceOperation::All,
+        &["program_id"],
+    )
+    .unwrap();
+    let ids = HashMap::from([("program_id", 7)]);
+
+    assert_eq!(
+        LoyaltyMember::build_full_path(&build_path(path.template, &ids)),
+        "apps/loyalty/programs/7/members"
+    );
+    assert_eq!(
+        LoyaltyMember::required_params(ResourceOperation::All),
+        Some(&["program_id"][..])
+    );
+}
+
+#[test]
+fn test_custom_resource_only_exposes_declared_operations() {
+    assert_eq!(
+        LoyaltyMember::available_operations(),
+        vec![ResourceOperation::Find, ResourceOperation::All]
+    );
+    assert!(LoyaltyMember::required_params(ResourceOperation::Create).is_none());
+}
+
+#[test]
+fn test_custom_resource_parses_find_response() {
+    let response = HttpResponse::new(
+        200,
+        HashMap::new(),
+        json!({ "member": { "id": 42, "email": "bob@example.com", "points": 120 } }),
+    );
+
+    let member: ResourceResponse<LoyaltyMember> =
+        ResourceResponse::from_http_response(response, &LoyaltyMember::resource_key()).unwrap();
+
+    assert_eq!(member.id, Some(42));
+    assert_eq!(member.points, 120);
+}
+
+#[test]
+fn test_custom_resource_parses_all_response() {
+    let response = HttpResponse::new(
+        200,
+        HashMap::new(),
+        json!({
+            "members": [
+                { "id": 1, "email": "a@example.com", "points": 10 },
+                { "id": 2, "email": "b@example.com", "points": 20 }
+            ]
+        }),
+    );
+
+    let members: ResourceResponse<Vec<LoyaltyMember>> =
+        ResourceResponse::from_http_response(response, LoyaltyMember::PLURAL).unwrap();
+
+    assert_eq!(
+        members.iter().map(|m| m.email.as_str()).collect::<Vec<_>>(),
+        vec!["a@example.com", "b@example.com"]
+    );
+}
+
+#[tokio::test]
+async fn test_custom_resource_find_fetches_and_parses_member() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/admin/api/2026-04/apps/loyalty/members/42.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "member": { "id": 42, "email": "bob@example.com", "points": 120 }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let member = LoyaltyMember::find(&mock_client(&server), 42, None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        member.into_inner(),
+        LoyaltyMember {
+            id: Some(42),
+            email: "bob@example.com".to_string(),
+            points: 120,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_custom_resource_all_with_parent_fetches_and_parses_members() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/admin/api/2026-04/apps/loyalty/programs/7/members.json",
+        ))
+        .and(query_param("tier", "gold"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "members": [
+                { "id": 1, "email": "a@example.com", "points": 10 },
+                { "id": 2, "email": "b@example.com", "points": 20 }
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let params = LoyaltyMemberListParams {
+        tier: Some("gold".to_string()),
+    };
+    let members =
+        LoyaltyMember::all_with_parent(&mock_client(&server), "program_id", 7, Some(params))
+            .await
+            .unwrap();
+
+    assert_eq!(
+        members.iter().map(|m| (m.id, m.points)).collect::<Vec<_>>(),
+        vec![(Some(1), 10), (Some(2), 20)]
+    );
+}
+
+#[tokio::test]
+async fn test_custom_resource_all_without_parent_fails_before_request() {
+    let result = LoyaltyMember::all(&client(), None).await;
+
+    assert!(matches!(
+        result,
+        Err(ResourceError::PathResolutionFailed {
+            resource: "LoyaltyMember",
+            operation: "all",
+        })
+    ));
+}
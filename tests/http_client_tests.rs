@@ -68,6 +68,9 @@ async fn test_invalid_request_produces_correct_error() {
         query: None,
         extra_headers: None,
         tries: 1,
+        timeout: None,
+        api_version: None,
+        redirect_policy: None,
     };
 
     let verify_result = request.verify();
@@ -240,8 +243,8 @@ async fn test_client_default_headers() {
     // Should have User-Agent
     assert!(headers.contains_key("User-Agent"));
     let user_agent = headers.get("User-Agent").unwrap();
-    assert!(user_agent.contains("Shopify API Library"));
-    assert!(user_agent.contains("Rust"));
+    assert!(user_agent.starts_with("shopify-api-rust/"));
+    assert!(user_agent.contains("(rustc "));
 
     // Should have Accept: application/json
     assert_eq!(headers.get("Accept"), Some(&"application/json".to_string()));
@@ -166,6 +166,7 @@ fn assert_query_attempted(result: Result<shopify_sdk::HttpResponse, GraphqlError
         Err(GraphqlError::Http(_)) => {
             // Expected: network error for fake shop domain
         }
+        Err(e) => panic!("Unexpected error: {e}"),
         Ok(_) => {
             // Also acceptable: Shopify responded (e.g., 404 or redirect)
             // This means the query was attempted